@@ -74,6 +74,7 @@ pub struct Configuration {
 
     pub telnet: Option<TelnetServerConfig>,
     pub websocket: Option<WebsocketServerConfig>,
+    pub grpc: Option<GrpcServerConfig>,
 }
 
 impl Configuration {
@@ -286,6 +287,69 @@ impl std::fmt::Display for WebsocketBinding {
     }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GrpcServerConfig {
+    /// Address the gateway's gRPC service listens on for world server calls
+    pub addr: EnvField<GrpcBinding>,
+
+    /// Address the world server should dial to reach this gateway, when the
+    /// listen address isn't reachable as-is (for example `0.0.0.0`)
+    #[serde(default)]
+    pub advertise_addr: Option<String>,
+}
+
+impl GrpcServerConfig {
+    /// Callback address to register with the world server
+    ///
+    /// Empty when no reachable address is known, so the server falls back to
+    /// its own configured gateway address.
+    pub fn callback_addr(&self) -> String {
+        match &self.advertise_addr {
+            Some(addr) => addr.clone(),
+            None if self.addr.to_ip().is_unspecified() => String::new(),
+            None => self.addr.to_addr().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrpcBinding(SocketAddr);
+
+impl GrpcBinding {
+    pub fn to_addr(&self) -> SocketAddr {
+        self.0
+    }
+    pub fn to_ip(&self) -> IpAddr {
+        self.0.ip()
+    }
+    pub fn to_port(&self) -> u16 {
+        self.0.port()
+    }
+}
+
+impl FromStr for GrpcBinding {
+    type Err = AddrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(SocketAddr::from_str(s)?))
+    }
+}
+
+impl Default for GrpcBinding {
+    fn default() -> Self {
+        Self(SocketAddr::V4(SocketAddrV4::new(
+            Ipv4Addr::new(0, 0, 0, 0),
+            6005,
+        )))
+    }
+}
+
+impl std::fmt::Display for GrpcBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,6 +382,26 @@ mod tests {
         assert_eq!(config.addr.to_port(), 8080);
     }
 
+    #[test]
+    fn test_grpc_callback_addr() {
+        // A wildcard binding can't be dialed, so the server's setting is used
+        let config = GrpcServerConfig::default();
+        assert_eq!(config.addr.to_port(), 6005);
+        assert_eq!(config.callback_addr(), "");
+
+        let config = GrpcServerConfig {
+            addr: "10.0.0.5:6005".parse::<GrpcBinding>().unwrap().into(),
+            advertise_addr: None,
+        };
+        assert_eq!(config.callback_addr(), "10.0.0.5:6005");
+
+        let config = GrpcServerConfig {
+            advertise_addr: Some("gateway.internal:6005".to_string()),
+            ..GrpcServerConfig::default()
+        };
+        assert_eq!(config.callback_addr(), "gateway.internal:6005");
+    }
+
     #[test]
     fn test_telnet_config_default() {
         let config = TelnetServerConfig::default();
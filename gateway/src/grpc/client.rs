@@ -16,21 +16,29 @@
 
 //! RPC client manager with automatic reconnection and command queuing
 
+use crate::grpc::server::GatewayRpcServer;
 use metrics::{counter, gauge, histogram};
 use std::collections::{HashMap, VecDeque};
 use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{RwLock, mpsc};
 use tokio::time::sleep;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Request;
 use tonic::transport::Channel;
 use tracing::{Level, event};
 use wyldlands_common::proto::{
     AuthenticateGatewayRequest, AuthenticateSessionRequest, CheckUsernameRequest,
-    CreateAccountRequest, GatewayHeartbeatRequest, GatewayManagementClient, SendInputRequest,
-    ServerStatisticsRequest, SessionToWorldClient,
+    CreateAccountRequest, EditResponse, FinishEditingRequest, GatewayHeartbeatRequest,
+    GatewayManagementClient, GatewayStreamMessage, SendInputRequest, ServerStatisticsRequest,
+    SessionDisconnectedRequest, SessionStreamHello, SessionToWorldClient, WorldToSession,
+    gateway_stream_message, server_stream_message,
 };
 
+/// Capacity of the session stream's outbound queue before senders wait
+const SESSION_STREAM_BUFFER: usize = 256;
+
 /// RPC client state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClientState {
@@ -81,12 +89,21 @@ pub struct RpcClientManager {
     /// Authentication key for gateway-to-server communication
     auth_key: String,
 
+    /// Stable identifier for this gateway instance
+    gateway_id: String,
+
+    /// Address the server uses to call back into this gateway (empty = server default)
+    callback_addr: String,
+
     /// Gateway management client (if connected)
     gateway_client: Arc<RwLock<Option<GatewayManagementClient>>>,
 
     /// Session-to-server client (if connected)
     session_client: Arc<RwLock<Option<SessionToWorldClient>>>,
 
+    /// Sender for the session stream (if open)
+    session_stream: Arc<RwLock<Option<mpsc::Sender<GatewayStreamMessage>>>>,
+
     /// Connection state
     state: Arc<RwLock<ClientState>>,
 
@@ -140,8 +157,11 @@ impl RpcClientManager {
         Self {
             server_addr: server_addr.to_string(),
             auth_key: auth_key.to_string(),
+            gateway_id: format!("gateway-{}", uuid::Uuid::new_v4()),
+            callback_addr: String::new(),
             gateway_client: Arc::new(RwLock::new(None)),
             session_client: Arc::new(RwLock::new(None)),
+            session_stream: Arc::new(RwLock::new(None)),
             state: Arc::new(RwLock::new(ClientState::Disconnected)),
             reconnect_interval: Duration::from_secs(reconnect_interval_secs),
             heartbeat_interval: Duration::from_secs(heartbeat_interval_secs),
@@ -153,6 +173,17 @@ impl RpcClientManager {
         }
     }
 
+    /// Set the address the server should use to call back into this gateway
+    pub fn with_callback_addr(mut self, callback_addr: &str) -> Self {
+        self.callback_addr = callback_addr.to_string();
+        self
+    }
+
+    /// Get the identifier this gateway announces to the server
+    pub fn gateway_id(&self) -> &str {
+        &self.gateway_id
+    }
+
     /// Get the current connection state
     pub async fn state(&self) -> ClientState {
         *self.state.read().await
@@ -173,6 +204,11 @@ impl RpcClientManager {
         self.session_client.read().await.clone()
     }
 
+    /// Check if the session stream is open
+    pub async fn has_session_stream(&self) -> bool {
+        self.session_stream().await.is_some()
+    }
+
     /// Get queue statistics
     pub async fn queue_stats(&self) -> QueueStats {
         let queue = self.command_queue.read().await;
@@ -366,6 +402,8 @@ impl RpcClientManager {
                         tracing::info!("Authenticating gateway connection");
                         let auth_request = AuthenticateGatewayRequest {
                             auth_key: self.auth_key.clone(),
+                            gateway_id: self.gateway_id.clone(),
+                            callback_addr: self.callback_addr.clone(),
                         };
 
                         match gateway_client.authenticate_gateway(auth_request).await {
@@ -538,6 +576,10 @@ impl RpcClientManager {
             let mut sess_client = self.session_client.write().await;
             *sess_client = None;
         }
+        {
+            let mut stream = self.session_stream.write().await;
+            *stream = None;
+        }
 
         // Update state
         {
@@ -843,6 +885,196 @@ impl RpcClientManager {
         }
     }
 
+    /// Start the session stream loop
+    ///
+    /// Keeps a bidirectional session stream open to the server whenever the client
+    /// is connected. Output, prompts, editor and disconnect requests arriving on the
+    /// stream are handed to `handler` in the order the server sent them, and input
+    /// sent through [`send_input`](Self::send_input) uses the stream while it is open.
+    pub async fn start_session_stream_loop(self: Arc<Self>, handler: GatewayRpcServer) {
+        tracing::info!(
+            "Starting session stream loop for gateway {}",
+            self.gateway_id
+        );
+
+        loop {
+            if self.is_connected().await && !self.has_session_stream().await {
+                if let Err(e) = self.run_session_stream(&handler).await {
+                    tracing::warn!("Session stream closed: {}", e);
+                }
+            }
+            sleep(self.reconnect_interval).await;
+        }
+    }
+
+    /// Open the session stream and process server messages until it closes
+    async fn run_session_stream(&self, handler: &GatewayRpcServer) -> Result<(), String> {
+        let mut client = self
+            .gateway_client()
+            .await
+            .ok_or_else(|| "Not connected to server".to_string())?;
+
+        let (sender, receiver) = mpsc::channel(SESSION_STREAM_BUFFER);
+        sender
+            .send(GatewayStreamMessage {
+                message: Some(gateway_stream_message::Message::Hello(SessionStreamHello {
+                    auth_key: self.auth_key.clone(),
+                    gateway_id: self.gateway_id.clone(),
+                })),
+            })
+            .await
+            .map_err(|_| "Session stream closed before hello".to_string())?;
+
+        let mut inbound = client
+            .open_session_stream(ReceiverStream::new(receiver))
+            .await
+            .map_err(|e| format!("Failed to open session stream: {}", e))?
+            .into_inner();
+
+        {
+            let mut stream = self.session_stream.write().await;
+            *stream = Some(sender);
+        }
+        tracing::info!("Session stream open to {}", self.server_addr);
+
+        let result = loop {
+            let message = match inbound.message().await {
+                Ok(Some(message)) => message,
+                Ok(None) => break Ok(()),
+                Err(e) => break Err(format!("Session stream error: {}", e)),
+            };
+            let Some(message) = message.message else {
+                continue;
+            };
+
+            // Handled inline so messages reach each session in the order sent
+            let result = match message {
+                server_stream_message::Message::Output(req) => {
+                    handler.send_output(Request::new(req)).await.map(|_| ())
+                }
+                server_stream_message::Message::Prompt(req) => {
+                    handler.send_prompt(Request::new(req)).await.map(|_| ())
+                }
+                server_stream_message::Message::BeginEditing(req) => {
+                    handler.begin_editing(Request::new(req)).await.map(|_| ())
+                }
+                server_stream_message::Message::Disconnect(req) => handler
+                    .disconnect_session(Request::new(req))
+                    .await
+                    .map(|_| ()),
+            };
+            if let Err(status) = result {
+                tracing::warn!("Session stream message failed: {}", status.message());
+            }
+        };
+
+        let mut stream = self.session_stream.write().await;
+        *stream = None;
+        result
+    }
+
+    /// Get the session stream sender, if the stream is open
+    async fn session_stream(&self) -> Option<mpsc::Sender<GatewayStreamMessage>> {
+        self.session_stream
+            .read()
+            .await
+            .clone()
+            .filter(|stream| !stream.is_closed())
+    }
+
+    /// Send a message over the session stream
+    ///
+    /// Waits while the stream's queue is full.
+    async fn send_over_stream(
+        stream: mpsc::Sender<GatewayStreamMessage>,
+        message: gateway_stream_message::Message,
+    ) -> Result<(), String> {
+        stream
+            .send(GatewayStreamMessage {
+                message: Some(message),
+            })
+            .await
+            .map_err(|_| "Session stream closed".to_string())
+    }
+
+    /// Send session input to the server
+    ///
+    /// Uses the session stream when open, otherwise the unary `SendInput` call.
+    /// Output is delivered separately by the server.
+    pub async fn send_input(&self, session_id: String, command: String) -> Result<(), String> {
+        let request = SendInputRequest {
+            session_id,
+            command,
+        };
+        if let Some(stream) = self.session_stream().await {
+            return Self::send_over_stream(stream, gateway_stream_message::Message::Input(request))
+                .await;
+        }
+
+        let mut client = self
+            .session_client()
+            .await
+            .ok_or_else(|| "Server not connected".to_string())?;
+        client
+            .send_input(request)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to send command: {}", e))
+    }
+
+    /// Send the result of an editing session to the server
+    pub async fn finish_editing(
+        &self,
+        session_id: String,
+        content: Option<String>,
+    ) -> Result<(), String> {
+        let request = FinishEditingRequest {
+            session_id,
+            content,
+        };
+        if let Some(stream) = self.session_stream().await {
+            return Self::send_over_stream(
+                stream,
+                gateway_stream_message::Message::FinishEditing(request),
+            )
+            .await;
+        }
+
+        let mut client = self
+            .session_client()
+            .await
+            .ok_or_else(|| "Server not connected".to_string())?;
+        client
+            .finish_editing(EditResponse {
+                content: request.content,
+            })
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to finish editing: {}", e))
+    }
+
+    /// Notify the server that a session has disconnected
+    pub async fn session_disconnected(&self, session_id: String) -> Result<(), String> {
+        let request = SessionDisconnectedRequest { session_id };
+        if let Some(stream) = self.session_stream().await {
+            return Self::send_over_stream(
+                stream,
+                gateway_stream_message::Message::Disconnected(request),
+            )
+            .await;
+        }
+
+        let mut client = self
+            .session_client()
+            .await
+            .ok_or_else(|| "Server not connected".to_string())?;
+        client
+            .session_disconnected(request)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Failed to report disconnect: {}", e))
+    }
+
     /// Check if a username is available
     pub async fn check_username(&self, username: String) -> Result<bool, String> {
         let start = std::time::Instant::now();
@@ -992,6 +1224,7 @@ impl RpcClientManager {
                 username: username.clone(),
                 password,
                 client_addr: client_addr.clone(),
+                gateway_id: self.gateway_id.clone(),
             };
 
             let rpc_start = std::time::Instant::now();
@@ -79,12 +79,19 @@ async fn main() {
 
     // Create an RPC client for server communication
     let _rpc_span = tracing::info_span!("rpc_client_setup").entered();
-    let rpc_client = Arc::new(RpcClientManager::new(
-        config.server.addr.as_str(),
-        config.server.auth_key.as_str(),
-        config.server.reconnect_interval,
-        config.server.heartbeat_interval,
-    ));
+    // The gateway's own gRPC service, which the world server calls back on
+    let grpc_config = config.grpc.clone().unwrap_or_default();
+    let grpc_addr = grpc_config.addr.to_addr();
+
+    let rpc_client = Arc::new(
+        RpcClientManager::new(
+            config.server.addr.as_str(),
+            config.server.auth_key.as_str(),
+            config.server.reconnect_interval,
+            config.server.heartbeat_interval,
+        )
+        .with_callback_addr(&grpc_config.callback_addr()),
+    );
 
     // Start RPC client reconnection loop
     let rpc_client_reconnect = Arc::clone(&rpc_client);
@@ -95,16 +102,12 @@ async fn main() {
         .instrument(info_span!("rpc_reconnection_loop")),
     );
 
-    // Start RPC client heartbeat loop
-    // Note: We use a dummy session ID for the gateway-to-server heartbeat
-    // In a real implementation, you might want to use a proper gateway identifier
+    // Start RPC client heartbeat loop, identified by this gateway's ID
     let rpc_client_heartbeat = Arc::clone(&rpc_client);
-    let gateway_session_id = format!("gateway-{}", uuid::Uuid::new_v4());
+    let gateway_id = rpc_client.gateway_id().to_string();
     tokio::spawn(
         async move {
-            rpc_client_heartbeat
-                .start_heartbeat_loop(gateway_session_id)
-                .await;
+            rpc_client_heartbeat.start_heartbeat_loop(gateway_id).await;
         }
         .instrument(info_span!("rpc_heartbeat_loop")),
     );
@@ -191,19 +194,23 @@ async fn main() {
     );
 
     // Create gRPC server for receiving calls from world server (before telnet to avoid move)
-    let grpc_addr = config
-        .server
-        .addr
-        .into_inner()
-        .to_addrs()
-        .expect("Failed to resolve gRPC address")
-        .next()
-        .expect("No addresses resolved for gRPC server");
     let grpc_server = GatewayRpcServer::new(
         context.connection_pool().clone(),
         context.session_manager().clone(),
     );
 
+    // Open the session stream so the world server can reach sessions without dialing back
+    let rpc_client_stream = Arc::clone(context.rpc_client());
+    let stream_handler = grpc_server.clone();
+    tokio::spawn(
+        async move {
+            rpc_client_stream
+                .start_session_stream_loop(stream_handler)
+                .await;
+        }
+        .instrument(info_span!("rpc_session_stream_loop")),
+    );
+
     // Create termionix telnet server
     let telnet_config = config.telnet.clone().unwrap_or_default();
     let telnet_addr = telnet_config.addr.to_addr();
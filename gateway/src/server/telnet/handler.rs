@@ -139,19 +139,10 @@ impl StateHandler {
             return Ok(());
         }
 
-        let rpc_client = self.context.rpc_client();
-        if let Some(mut client) = rpc_client.session_client().await {
-            let request = wyldlands_common::proto::SendInputRequest {
-                session_id: self.session_id.to_string(),
-                command: input,
-            };
-
-            client.send_input(request).await
-                .map(|_| ())
-                .map_err(|e| format!("Failed to send command: {}", e))
-        } else {
-            Err("Server not connected".to_string())
-        }
+        self.context
+            .rpc_client()
+            .send_input(self.session_id.to_string(), input)
+            .await
     }
 
     /// Send the appropriate prompt based on current state
@@ -732,27 +723,11 @@ impl StateHandler {
     ) -> Result<(), String> {
         match substate {
             AuthenticatedState::Playing => {
-                // Send command to server via RPC
-                let rpc_client = self.context.rpc_client();
-                use wyldlands_common::proto::SessionToWorldClient;
-
-                if let Some(client) = rpc_client.session_client().await {
-                    let mut client: SessionToWorldClient = client;
-                    let request = wyldlands_common::proto::SendInputRequest {
-                        session_id: self.session_id.to_string(),
-                        command: input,
-                    };
-
-                    match client.send_input(request).await {
-                        Ok(_response) => {
-                            // Response is handled by the RPC callback
-                            Ok(())
-                        }
-                        Err(e) => Err(format!("Failed to send command: {}", e)),
-                    }
-                } else {
-                    Err("Server not connected".to_string())
-                }
+                // Send command to server via RPC (output arrives separately)
+                self.context
+                    .rpc_client()
+                    .send_input(self.session_id.to_string(), input)
+                    .await
             }
 
             AuthenticatedState::Editing { title, content } => {
@@ -1015,6 +990,14 @@ impl StateHandler {
                     .send_line("\r\nChanges discarded.\r\n")
                     .await
                     .map_err(|e| e.to_string())?;
+                if let Err(e) = self
+                    .context
+                    .rpc_client()
+                    .finish_editing(self.session_id.to_string(), None)
+                    .await
+                {
+                    tracing::warn!("Failed to cancel editing on server: {}", e);
+                }
                 self.transition_to(SessionState::Authenticated(AuthenticatedState::Playing))
                     .await
             }
@@ -1102,40 +1085,36 @@ impl StateHandler {
             .await
             .map_err(|e| e.to_string())?;
 
-        // Send the edited content to the server
-        let rpc_client = self.context.rpc_client();
-        use wyldlands_common::proto::SessionToWorldClient;
-
-        if let Some(client) = rpc_client.session_client().await {
-            let mut client: SessionToWorldClient = client;
-            let request = wyldlands_common::proto::SendInputRequest {
-                session_id: self.session_id.to_string(),
-                command: format!(".editor_save {}", content),
-            };
-
-            match client.send_input(request).await {
-                Ok(_response) => {
-                    adapter
-                        .send_line("Content saved successfully.\r\n")
-                        .await
-                        .map_err(|e| e.to_string())?;
+        // Send the edited content to the server as a command, as the server
+        // does not yet act on finished editing sessions
+        match self
+            .context
+            .rpc_client()
+            .send_input(
+                self.session_id.to_string(),
+                format!(".editor_save {}", content),
+            )
+            .await
+        {
+            Ok(()) => {
+                adapter
+                    .send_line("Content saved successfully.\r\n")
+                    .await
+                    .map_err(|e| e.to_string())?;
 
-                    // Reset editor state
-                    self.editor_cursor_position = 0;
+                // Reset editor state
+                self.editor_cursor_position = 0;
 
-                    self.transition_to(SessionState::Authenticated(AuthenticatedState::Playing))
-                        .await
-                }
-                Err(e) => {
-                    adapter
-                        .send_line(&format!("Failed to save: {}\r\n", e))
-                        .await
-                        .map_err(|e| e.to_string())?;
-                    Err(format!("Failed to save content: {}", e))
-                }
+                self.transition_to(SessionState::Authenticated(AuthenticatedState::Playing))
+                    .await
+            }
+            Err(e) => {
+                adapter
+                    .send_line(&format!("Failed to save: {}\r\n", e))
+                    .await
+                    .map_err(|e| e.to_string())?;
+                Err(format!("Failed to save content: {}", e))
             }
-        } else {
-            Err("Server not connected".to_string())
         }
     }
 
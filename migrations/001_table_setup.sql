@@ -509,6 +509,22 @@ COMMENT ON COLUMN wyldlands.entity_talents.entity_id IS 'Entity ID of the object
 COMMENT ON COLUMN wyldlands.entity_talents.talent_name IS 'Name of Talent';
 COMMENT ON COLUMN wyldlands.entity_talents.experience IS 'How much experience is in the talent';
 
+--
+-- Name: entity_spellbook; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Component providing an Entities known Spells
+--
+
+CREATE TABLE wyldlands.entity_spellbook
+(
+    entity_id  UUID         NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    spell_name VARCHAR(100) NOT NULL,
+    PRIMARY KEY (entity_id, spell_name)
+);
+
+COMMENT ON TABLE wyldlands.entity_spellbook IS 'Spellbook component - spells an entity has learned';
+COMMENT ON COLUMN wyldlands.entity_spellbook.entity_id IS 'Entity ID of the object';
+COMMENT ON COLUMN wyldlands.entity_spellbook.spell_name IS 'Lowercase name of the Spell';
+
 -- Spatial Components
 
 --
@@ -581,6 +597,51 @@ COMMENT ON COLUMN wyldlands.entity_container_contents.container_id IS 'Entity ID
 COMMENT ON COLUMN wyldlands.entity_container_contents.content_id IS 'Entity ID of Object IN container';
 COMMENT ON COLUMN wyldlands.entity_container_contents.added_at IS 'When the object was put in the container';
 
+--
+-- Name: entity_key; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity is a key that fits locks with a matching unlock code.
+--
+
+CREATE TABLE wyldlands.entity_key
+(
+    entity_id   UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    unlock_code VARCHAR(100) NOT NULL
+);
+
+COMMENT ON TABLE wyldlands.entity_key IS 'Key component - opens matching locks';
+COMMENT ON COLUMN wyldlands.entity_key.entity_id IS 'Entity ID of the key';
+COMMENT ON COLUMN wyldlands.entity_key.unlock_code IS 'Unlock code of the exits and containers this key fits';
+
+--
+-- Name: entity_bind_point; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Room a character respawns in after dying.
+--
+
+CREATE TABLE wyldlands.entity_bind_point
+(
+    entity_id UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    room_id   UUID NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE
+);
+
+COMMENT ON TABLE wyldlands.entity_bind_point IS 'BindPoint component - respawn room';
+COMMENT ON COLUMN wyldlands.entity_bind_point.entity_id IS 'Entity ID of the character';
+COMMENT ON COLUMN wyldlands.entity_bind_point.room_id IS 'Room ID the character respawns in';
+
+--
+-- Name: entity_corpse; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Remains of a dead entity, holding what it carried until it decays.
+--
+
+CREATE TABLE wyldlands.entity_corpse
+(
+    entity_id       UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    decay_remaining REAL NOT NULL
+);
+
+COMMENT ON TABLE wyldlands.entity_corpse IS 'Corpse component - decaying remains';
+COMMENT ON COLUMN wyldlands.entity_corpse.entity_id IS 'Entity ID of the corpse';
+COMMENT ON COLUMN wyldlands.entity_corpse.decay_remaining IS 'Seconds left before the corpse and its contents decay';
+
 --
 -- Name: entity_containable; Type: TABLE; Schema: wyldlands; Owner: wyldlands
 -- Indicates and entity can be placed in a container.
@@ -717,6 +778,24 @@ COMMENT ON TABLE wyldlands.entity_equipable IS 'Equippable Item';
 COMMENT ON COLUMN wyldlands.entity_equipable.entity_id IS 'Entity ID of Equippable Item';
 COMMENT ON COLUMN wyldlands.entity_equipable.slots IS 'What slots is this item valid for';
 
+--
+-- Name: entity_skill_bonus; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity which modifies skill checks when equipped
+--
+
+CREATE TABLE wyldlands.entity_skill_bonus
+(
+    entity_id  UUID         NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    skill_name VARCHAR(100) NOT NULL,
+    bonus      INTEGER      NOT NULL,
+    PRIMARY KEY (entity_id, skill_name)
+);
+
+COMMENT ON TABLE wyldlands.entity_skill_bonus IS 'SkillBonus component - skill check modifiers of equipment';
+COMMENT ON COLUMN wyldlands.entity_skill_bonus.entity_id IS 'Entity ID of the item';
+COMMENT ON COLUMN wyldlands.entity_skill_bonus.skill_name IS 'Name of Skill';
+COMMENT ON COLUMN wyldlands.entity_skill_bonus.bonus IS 'Bonus (or penalty) to checks of the skill';
+
 --
 -- Name: entity_weapon; Type: TABLE; Schema: wyldlands; Owner: wyldlands
 -- Entity which can deal damage when equipped
@@ -765,9 +844,10 @@ COMMENT ON COLUMN wyldlands.entity_material.material IS 'Kind of Material Used';
 
 CREATE TABLE wyldlands.entity_armor_defense
 (
-    entity_id   UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    entity_id   UUID        NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
     damage_kind damage_type NOT NULL,
-    defense     INTEGER     NOT NULL
+    defense     INTEGER     NOT NULL,
+    PRIMARY KEY (entity_id, damage_kind)
 );
 
 COMMENT ON TABLE wyldlands.entity_armor_defense IS 'Armor component - armor properties';
@@ -775,6 +855,87 @@ COMMENT ON COLUMN wyldlands.entity_armor_defense.entity_id IS 'Entity ID of Item
 COMMENT ON COLUMN wyldlands.entity_armor_defense.damage_kind IS 'Kind of Damage Defended Against';
 COMMENT ON COLUMN wyldlands.entity_armor_defense.defense IS 'Defense Rating for this kind of Damage';
 
+--
+-- Name: entity_template; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity made from an Item Template
+--
+
+CREATE TABLE wyldlands.entity_template
+(
+    entity_id   UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    template_id VARCHAR(100) NOT NULL
+);
+
+COMMENT ON TABLE wyldlands.entity_template IS 'Template component - item template an item was made from';
+COMMENT ON COLUMN wyldlands.entity_template.entity_id IS 'Entity ID of Item';
+COMMENT ON COLUMN wyldlands.entity_template.template_id IS 'ID of the Item Template';
+
+--
+-- Name: entity_crafting_station; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity which crafters can work at
+--
+
+CREATE TABLE wyldlands.entity_crafting_station
+(
+    entity_id UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    station   VARCHAR(50) NOT NULL
+);
+
+COMMENT ON TABLE wyldlands.entity_crafting_station IS 'CraftingStation component - fixture used by recipes';
+COMMENT ON COLUMN wyldlands.entity_crafting_station.entity_id IS 'Entity ID of the Fixture';
+COMMENT ON COLUMN wyldlands.entity_crafting_station.station IS 'Kind of Station (forge, loom, ...)';
+
+--
+-- Name: entity_resource_node; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity which resources can be gathered from
+--
+
+CREATE TABLE wyldlands.entity_resource_node
+(
+    entity_id     UUID PRIMARY KEY REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    skill         VARCHAR(100) NOT NULL,
+    difficulty    INTEGER      NOT NULL DEFAULT 10,
+    tool          VARCHAR(100),
+    charges       INTEGER      NOT NULL DEFAULT 5,
+    max_charges   INTEGER      NOT NULL DEFAULT 5,
+    respawn_time  REAL         NOT NULL DEFAULT 300.0,
+    respawn_timer REAL         NOT NULL DEFAULT 0.0
+);
+
+COMMENT ON TABLE wyldlands.entity_resource_node IS 'ResourceNode component - ore veins, herb patches, fishing spots, ...';
+COMMENT ON COLUMN wyldlands.entity_resource_node.entity_id IS 'Entity ID of the Node';
+COMMENT ON COLUMN wyldlands.entity_resource_node.skill IS 'Gathering Skill checked when gathering';
+COMMENT ON COLUMN wyldlands.entity_resource_node.difficulty IS 'Difficulty of the skill check';
+COMMENT ON COLUMN wyldlands.entity_resource_node.tool IS 'Item Template of the tool needed, if any';
+COMMENT ON COLUMN wyldlands.entity_resource_node.charges IS 'Successful gathers left before the node is depleted';
+COMMENT ON COLUMN wyldlands.entity_resource_node.max_charges IS 'Charges the node replenishes to';
+COMMENT ON COLUMN wyldlands.entity_resource_node.respawn_time IS 'Seconds a depleted node takes to replenish';
+COMMENT ON COLUMN wyldlands.entity_resource_node.respawn_timer IS 'Seconds until a depleted node replenishes';
+
+--
+-- Name: entity_resource_yield; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Yield table entries of a resource node
+--
+
+CREATE TABLE wyldlands.entity_resource_yield
+(
+    entity_id    UUID         NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    template_id  VARCHAR(100) NOT NULL,
+    weight       INTEGER      NOT NULL DEFAULT 1,
+    min_quantity INTEGER      NOT NULL DEFAULT 1,
+    max_quantity INTEGER      NOT NULL DEFAULT 1,
+    min_level    INTEGER      NOT NULL DEFAULT 0,
+    PRIMARY KEY (entity_id, template_id)
+);
+
+COMMENT ON TABLE wyldlands.entity_resource_yield IS 'ResourceNode component - yield table entries';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.entity_id IS 'Entity ID of the Node';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.template_id IS 'Item Template gathered';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.weight IS 'Relative chance of this entry being picked';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.min_quantity IS 'Fewest items gathered on a success';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.max_quantity IS 'Most items gathered on a success';
+COMMENT ON COLUMN wyldlands.entity_resource_yield.min_level IS 'Skill level needed before this entry can be found';
+
 ------------------------------------------------------------------------------------------------------------------------
 -- AI Components
 ------------------------------------------------------------------------------------------------------------------------
@@ -997,6 +1158,50 @@ COMMENT ON COLUMN wyldlands.entity_personality_goals.goal IS 'Goal Description';
 COMMENT ON COLUMN wyldlands.entity_personality_goals.priority IS 'Goal Priority (higher is more important)';
 COMMENT ON COLUMN wyldlands.entity_personality_goals.created_at IS 'When goal was created';
 
+--
+-- Name: entity_npc_schedule; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity Daily Schedule Blocks
+--
+
+CREATE TABLE wyldlands.entity_npc_schedule
+(
+    entity_id  UUID    NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    start_hour INTEGER NOT NULL CHECK (start_hour >= 0 AND start_hour < 24),
+    goal       TEXT,
+    room_id    UUID    REFERENCES wyldlands.entities (uuid) ON DELETE SET NULL,
+    PRIMARY KEY (entity_id, start_hour)
+);
+
+COMMENT ON TABLE wyldlands.entity_npc_schedule IS 'NPC Schedule component - daily routine';
+COMMENT ON COLUMN wyldlands.entity_npc_schedule.entity_id IS 'Entity ID';
+COMMENT ON COLUMN wyldlands.entity_npc_schedule.start_hour IS 'In-game hour the block starts';
+COMMENT ON COLUMN wyldlands.entity_npc_schedule.goal IS 'GOAP goal active during the block';
+COMMENT ON COLUMN wyldlands.entity_npc_schedule.room_id IS 'Room to be in during the block';
+
+--
+-- Name: entity_npc_conversation; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Entity Conversation History with other Entities
+--
+
+CREATE TABLE wyldlands.entity_npc_conversation
+(
+    entity_id  UUID    NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    partner_id UUID    NOT NULL REFERENCES wyldlands.entities (uuid) ON DELETE CASCADE,
+    sequence   INTEGER NOT NULL,
+    speaker_id UUID    NOT NULL,
+    message    TEXT    NOT NULL,
+    spoken_at  BIGINT  NOT NULL,
+    PRIMARY KEY (entity_id, partner_id, sequence)
+);
+
+COMMENT ON TABLE wyldlands.entity_npc_conversation IS 'NPC Conversation component - dialogue history per partner';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.entity_id IS 'Entity ID';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.partner_id IS 'Entity the conversation is with';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.sequence IS 'Order of the message within the conversation';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.speaker_id IS 'Entity who said the message';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.message IS 'Message Content';
+COMMENT ON COLUMN wyldlands.entity_npc_conversation.spoken_at IS 'When the message was said (unix timestamp)';
+
 --
 -- Name: entity_memory; Type: TABLE; Schema: wyldlands; Owner: wyldlands
 -- Entity Memory - Enhanced AI Memory System
@@ -1231,6 +1436,163 @@ COMMENT ON COLUMN wyldlands.starting_locations.room_id IS 'UUID of the room enti
 COMMENT ON COLUMN wyldlands.starting_locations.enabled IS 'Whether this location is currently available for selection';
 COMMENT ON COLUMN wyldlands.starting_locations.sort_order IS 'Display order (lower numbers first)';
 
+--
+-- Name: spell_target; Type: ENUM; Schema: wyldlands; Owner: wyldlands
+-- Enumeration of who a Spell can be cast on
+--
+
+CREATE TYPE wyldlands.spell_target AS ENUM ('Caster', 'Ally', 'Enemy');
+
+--
+-- Name: spell_effect; Type: ENUM; Schema: wyldlands; Owner: wyldlands
+-- Enumeration of what a Spell does to its target
+--
+
+CREATE TYPE wyldlands.spell_effect AS ENUM ('Damage', 'Heal', 'Status', 'Cure');
+
+--
+-- Name: spells; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Spell definitions loaded into the spell registry at startup
+--
+
+CREATE TABLE wyldlands.spells
+(
+    name          VARCHAR(100) PRIMARY KEY,
+    school        VARCHAR(100) NOT NULL,
+    description   TEXT         NOT NULL,
+    energy_cost   REAL         NOT NULL,
+    cast_time     REAL         NOT NULL DEFAULT 1.0,
+    difficulty    INTEGER      NOT NULL DEFAULT 10,
+    target        spell_target NOT NULL,
+    effect        spell_effect NOT NULL,
+    damage_type   damage_type,
+    status_effect VARCHAR(50),
+    amount_min    INTEGER      NOT NULL DEFAULT 0,
+    amount_max    INTEGER      NOT NULL DEFAULT 0,
+    duration      REAL         NOT NULL DEFAULT 0.0,
+    enabled       BOOLEAN      NOT NULL DEFAULT TRUE,
+    CONSTRAINT spells_damage_type CHECK (effect <> 'Damage' OR damage_type IS NOT NULL),
+    CONSTRAINT spells_status_effect CHECK (effect NOT IN ('Status', 'Cure') OR status_effect IS NOT NULL),
+    CONSTRAINT spells_amount_range CHECK (amount_min <= amount_max)
+);
+
+COMMENT ON TABLE wyldlands.spells IS 'Spell definitions for the spell registry';
+COMMENT ON COLUMN wyldlands.spells.name IS 'Display name of the spell';
+COMMENT ON COLUMN wyldlands.spells.school IS 'Magic school Skill checked when casting';
+COMMENT ON COLUMN wyldlands.spells.description IS 'Description shown in the spell list';
+COMMENT ON COLUMN wyldlands.spells.energy_cost IS 'Energy spent from the casters soul';
+COMMENT ON COLUMN wyldlands.spells.cast_time IS 'Seconds before the caster can act again';
+COMMENT ON COLUMN wyldlands.spells.difficulty IS 'Difficulty of the school skill check';
+COMMENT ON COLUMN wyldlands.spells.target IS 'Who the spell can be cast on';
+COMMENT ON COLUMN wyldlands.spells.effect IS 'What the spell does to its target';
+COMMENT ON COLUMN wyldlands.spells.damage_type IS 'Type of Damage done by Damage spells';
+COMMENT ON COLUMN wyldlands.spells.status_effect IS 'Status Effect applied by Status spells or removed by Cure spells';
+COMMENT ON COLUMN wyldlands.spells.amount_min IS 'Minimum damage or healing';
+COMMENT ON COLUMN wyldlands.spells.amount_max IS 'Maximum damage or healing, or magnitude of a Status spell';
+COMMENT ON COLUMN wyldlands.spells.duration IS 'Seconds a Status spell lasts';
+COMMENT ON COLUMN wyldlands.spells.enabled IS 'Whether the spell is loaded into the registry';
+
+--
+-- Name: item_templates; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Item definitions loaded into the item template registry at startup
+--
+
+CREATE TABLE wyldlands.item_templates
+(
+    id           VARCHAR(100) PRIMARY KEY,
+    name         VARCHAR(100) NOT NULL,
+    keywords     TEXT[]       NOT NULL DEFAULT '{}',
+    short        TEXT         NOT NULL,
+    long         TEXT         NOT NULL,
+    weight       REAL         NOT NULL DEFAULT 1.0,
+    material     VARCHAR(20),
+    slots        slot_kind[]  NOT NULL DEFAULT '{}',
+    damage_min   INTEGER,
+    damage_max   INTEGER,
+    damage_type  damage_type,
+    attack_speed REAL         NOT NULL DEFAULT 1.0,
+    CONSTRAINT item_templates_weapon CHECK ((damage_min IS NULL) = (damage_type IS NULL))
+);
+
+COMMENT ON TABLE wyldlands.item_templates IS 'Item Templates for crafting and gathering';
+COMMENT ON COLUMN wyldlands.item_templates.id IS 'Unique identifier of the template';
+COMMENT ON COLUMN wyldlands.item_templates.name IS 'Display name of items made from the template';
+COMMENT ON COLUMN wyldlands.item_templates.keywords IS 'Keywords for matching items';
+COMMENT ON COLUMN wyldlands.item_templates.short IS 'Short description of items';
+COMMENT ON COLUMN wyldlands.item_templates.long IS 'Long description of items';
+COMMENT ON COLUMN wyldlands.item_templates.weight IS 'Weight of each item';
+COMMENT ON COLUMN wyldlands.item_templates.material IS 'Kind of Material items are made from';
+COMMENT ON COLUMN wyldlands.item_templates.slots IS 'Slots items can be equipped in';
+COMMENT ON COLUMN wyldlands.item_templates.damage_min IS 'Minimum damage of weapons';
+COMMENT ON COLUMN wyldlands.item_templates.damage_max IS 'Maximum damage of weapons';
+COMMENT ON COLUMN wyldlands.item_templates.damage_type IS 'Type of Damage done by weapons';
+COMMENT ON COLUMN wyldlands.item_templates.attack_speed IS 'Attack speed of weapons';
+
+--
+-- Name: item_template_defenses; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Armor defenses of items made from a template
+--
+
+CREATE TABLE wyldlands.item_template_defenses
+(
+    template_id VARCHAR(100) NOT NULL REFERENCES wyldlands.item_templates (id) ON DELETE CASCADE,
+    damage_kind damage_type  NOT NULL,
+    defense     INTEGER      NOT NULL,
+    PRIMARY KEY (template_id, damage_kind)
+);
+
+COMMENT ON TABLE wyldlands.item_template_defenses IS 'Armor defenses of Item Templates';
+COMMENT ON COLUMN wyldlands.item_template_defenses.template_id IS 'ID of the Item Template';
+COMMENT ON COLUMN wyldlands.item_template_defenses.damage_kind IS 'Kind of Damage Defended Against';
+COMMENT ON COLUMN wyldlands.item_template_defenses.defense IS 'Defense Rating for this kind of Damage';
+
+--
+-- Name: recipes; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Crafting recipes loaded into the recipe registry at startup
+--
+
+CREATE TABLE wyldlands.recipes
+(
+    name            VARCHAR(100) PRIMARY KEY,
+    skill           VARCHAR(100) NOT NULL,
+    min_level       INTEGER      NOT NULL DEFAULT 0,
+    difficulty      INTEGER      NOT NULL DEFAULT 10,
+    station         VARCHAR(50),
+    output          VARCHAR(100) NOT NULL REFERENCES wyldlands.item_templates (id),
+    output_quantity INTEGER      NOT NULL DEFAULT 1,
+    enabled         BOOLEAN      NOT NULL DEFAULT TRUE
+);
+
+COMMENT ON TABLE wyldlands.recipes IS 'Crafting Recipes';
+COMMENT ON COLUMN wyldlands.recipes.name IS 'Display name of the recipe';
+COMMENT ON COLUMN wyldlands.recipes.skill IS 'Crafting Skill checked when crafting';
+COMMENT ON COLUMN wyldlands.recipes.min_level IS 'Skill level needed to attempt the recipe';
+COMMENT ON COLUMN wyldlands.recipes.difficulty IS 'Difficulty of the skill check';
+COMMENT ON COLUMN wyldlands.recipes.station IS 'Crafting Station needed in the room, if any';
+COMMENT ON COLUMN wyldlands.recipes.output IS 'Item Template produced';
+COMMENT ON COLUMN wyldlands.recipes.output_quantity IS 'Number of items produced';
+COMMENT ON COLUMN wyldlands.recipes.enabled IS 'Whether the recipe is loaded into the registry';
+
+--
+-- Name: recipe_components; Type: TABLE; Schema: wyldlands; Owner: wyldlands
+-- Ingredients and tools of a recipe
+--
+
+CREATE TABLE wyldlands.recipe_components
+(
+    recipe_name VARCHAR(100) NOT NULL REFERENCES wyldlands.recipes (name) ON DELETE CASCADE,
+    template_id VARCHAR(100) NOT NULL REFERENCES wyldlands.item_templates (id),
+    quantity    INTEGER      NOT NULL DEFAULT 1,
+    consumed    BOOLEAN      NOT NULL DEFAULT TRUE,
+    PRIMARY KEY (recipe_name, template_id)
+);
+
+COMMENT ON TABLE wyldlands.recipe_components IS 'Ingredients and Tools of Crafting Recipes';
+COMMENT ON COLUMN wyldlands.recipe_components.recipe_name IS 'Name of the Recipe';
+COMMENT ON COLUMN wyldlands.recipe_components.template_id IS 'Item Template required';
+COMMENT ON COLUMN wyldlands.recipe_components.quantity IS 'Number of items required';
+COMMENT ON COLUMN wyldlands.recipe_components.consumed IS 'True for ingredients used up, false for tools';
+
 
 
 --
@@ -71,6 +71,125 @@ inv
 i',
 ARRAY['look', 'get', 'drop', 'equipment']);
 
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('get', 'Command', 'Get Command',
+'The get command picks up items from the room or takes them out of a container. Use ''all'' to take everything, or ''all.<keyword>'' to take every matching item. Containers must be open.',
+'get <item|all> [from <container>]',
+'get sword
+take all
+get all.coin from chest',
+ARRAY['drop', 'put', 'inventory']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('drop', 'Command', 'Drop Command',
+'The drop command places items you are carrying on the ground. Equipped items must be removed first.',
+'drop <item|all>',
+'drop sword
+drop all',
+ARRAY['get', 'give', 'inventory']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('put', 'Command', 'Put Command',
+'The put command places items you are carrying into a container, either one you carry or one in the room. Containers must be open and have room for the item.',
+'put <item|all> in <container>',
+'put sword in chest
+put all.coin in pouch',
+ARRAY['get', 'open', 'inventory']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('give', 'Command', 'Give Command',
+'The give command hands an item you are carrying to someone in the same room.',
+'give <item|all> [to] <target>',
+'give sword to Bob
+give coin Bob',
+ARRAY['drop', 'inventory']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('wear', 'Command', 'Wear Command',
+'The wear command puts on clothing, armor or jewelry you are carrying. Each item only fits the slots it was made for, and each slot holds one item.',
+'wear <item|all>',
+'wear helmet
+wear all',
+ARRAY['wield', 'remove', 'equipment']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('wield', 'Command', 'Wield Command',
+'The wield command takes a weapon or shield you are carrying in hand. Your main hand is used first, then your off hand.',
+'wield <item>',
+'wield sword
+wield shield',
+ARRAY['wear', 'remove', 'equipment']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('remove', 'Command', 'Remove Command',
+'The remove command takes off an equipped item and returns it to your inventory.',
+'remove <item|all>',
+'remove helmet
+rem all',
+ARRAY['wear', 'wield', 'equipment']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('equipment', 'Command', 'Equipment Command',
+'The equipment command shows the items you are wearing and wielding, by slot.',
+'equipment',
+'equipment
+eq',
+ARRAY['wear', 'wield', 'remove', 'inventory']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('open', 'Command', 'Open Command',
+'The open command opens a closed door or container. Name a door by the direction it leads, or use ''door'' for the first door in the room. Locked doors and containers must be unlocked first.',
+'open <direction|door|container>',
+'open north
+open chest',
+ARRAY['close', 'unlock', 'pick']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('close', 'Command', 'Close Command',
+'The close command closes an open door or container. Closing a door closes it on both sides.',
+'close <direction|door|container>',
+'close north
+close chest',
+ARRAY['open', 'lock']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('lock', 'Command', 'Lock Command',
+'The lock command locks a closed door or container. You must be carrying a key that fits the lock.',
+'lock <direction|door|container>',
+'lock north
+lock chest',
+ARRAY['unlock', 'close', 'pick']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('unlock', 'Command', 'Unlock Command',
+'The unlock command unlocks a locked door or container. You must be carrying a key that fits the lock.',
+'unlock <direction|door|container>',
+'unlock north
+unlock chest',
+ARRAY['lock', 'open', 'pick']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('pick', 'Command', 'Pick Command',
+'The pick command tries to open a lock without its key. Your chance depends on your Lockpicking skill against the quality of the lock; a failed attempt can be tried again.',
+'pick <direction|door|container>',
+'pick north
+pick chest',
+ARRAY['unlock', 'open']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('posture', 'Command', 'Posture Commands',
+'Your posture affects how quickly you recover health and energy. Sitting recovers faster than standing, resting faster still, and sleeping fastest of all. You recover slowly while fighting, and you cannot sit, rest or sleep in combat.
+
+You must stand before you can move.',
+'stand
+sit
+rest
+sleep',
+'rest
+stand
+wake',
+ARRAY['score', 'movement']);
+
 INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
 VALUES ('say', 'Command', 'Say Command',
 'The say command allows you to speak to other characters in the same room. Your message will be visible to everyone present.',
@@ -96,6 +215,39 @@ em smiles warmly
 : laughs heartily',
 ARRAY['say', 'yell', 'social']);
 
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('talk', 'Command', 'Talk Command',
+'The talk command lets you speak to a non-player character in the same room. Everyone present hears what you say and the reply. Characters remember your recent conversations with them.',
+'talk <character> <message>
+ask <character> <message>
+tell <character> <message>',
+'talk greta Good morning!
+ask guard Where is the tavern?',
+ARRAY['say', 'social', 'emote']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('social', 'Command', 'Social Commands',
+'Rather than simply talking to a non-player character, you can try to persuade, deceive, intimidate, inspire or console it. Each works like talk, but first checks one of your skills against a moderate difficulty that rises with the character''s mind defence.
+
+  persuade   - Persuasion, to win the character over to your view
+  deceive    - Deception, to make the character believe a lie
+  intimidate - Intimidation, to frighten the character into going along
+  inspire    - Leadership, to rally the character behind you
+  console    - Insight, to comfort the character
+
+You see how the character reacts, and it replies knowing whether you succeeded. Every attempt teaches you something. The Silver Tongue, Intimidating, Natural Leader and Empathic talents add to these checks.',
+'persuade <character> <message>
+deceive <character> <message>
+intimidate <character> <message>
+inspire <character> <message>
+console <character> <message>',
+'persuade merchant Surely a fair price is half that?
+lie guard The captain sent me.
+threaten thief Hand it over or else.
+rally militia We can hold this gate!
+comfort widow He died bravely.',
+ARRAY['talk', 'skills', 'talents']);
+
 INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
 VALUES ('score', 'Command', 'Score Command',
 'The score command displays your character''s statistics, including attributes, skills, health, and other important information about your character''s current state.',
@@ -104,6 +256,98 @@ VALUES ('score', 'Command', 'Score Command',
 stats',
 ARRAY['inventory', 'skills', 'attributes']);
 
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('skills', 'Command', 'Skills Command',
+'The skills command lists the skills you have learned, your rank in each and the experience you have earned.
+
+Skills improve with use. Every attempt earns experience, though failures teach half as much. Attempts that challenge you teach more, while tasks far beneath your rank teach nothing. Knowledge of a skill speeds up learning, up to double, and the Fast Learner talent speeds it further. Ranks run from Untrained through Apprentice, Novice, Initiate, Adept, Journeyman, Master, Expert, Paragon and Mythical to Legendary, and harder skills need more experience for each rank.',
+'skills',
+'skills',
+ARRAY['score', 'talents']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('cast', 'Command', 'Cast Command',
+'The cast command casts a spell you have learned.
+
+Casting costs energy and tests your skill in the spell''s school of magic. If the check fails the spell fizzles, though the energy is still spent and you still learn from the attempt. A critical success strengthens the spell. Hostile spells default to your combat target and start a fight; helpful spells default to yourself. In combat you cannot act again until the spell''s casting time has passed.',
+'cast <spell> [target]',
+'cast firebolt goblin
+cast mending ward
+cast bull''s strength Aldric',
+ARRAY['spells', 'skills', 'attack']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('spells', 'Command', 'Spells Command',
+'The spells command lists the spells you have learned, with their school, energy cost and what they do.',
+'spells',
+'spells',
+ARRAY['cast', 'skills']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('craft', 'Command', 'Craft Command',
+'The craft command makes an item from a recipe.
+
+Each recipe needs a rank in its crafting skill, ingredients in your pack and often tools and a station such as a forge or loom in the room. Tools are kept, but ingredients are used up whether or not you succeed, and every attempt teaches you something. The better your check, the better the item: crude, common, fine, superior or masterwork. Better weapons hit harder and better armor protects more. The Master Craftsman talent improves everything you make, and the Artificer talent helps with Jewelcrafting and Alchemy.',
+'craft <recipe>',
+'craft iron nails
+craft leather jerkin',
+ARRAY['recipes', 'gather', 'skills']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('recipes', 'Command', 'Recipes Command',
+'The recipes command lists the recipes your skills let you attempt, with the rank needed and what each one uses. Give a recipe or skill name to narrow the list.',
+'recipes [filter]',
+'recipes
+recipes tailoring',
+ARRAY['craft', 'skills']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('gather', 'Command', 'Gather Command',
+'The gather command collects resources from a node in the room, such as an ore vein, a herb patch or a fishing spot.
+
+Each node is worked with a gathering skill, and some need a tool such as a pickaxe or fishing pole. A successful check yields raw materials for crafting, with more for a better check, and rarer finds turn up as your skill improves. Every attempt teaches you something. Nodes are exhausted after a few successful gathers and replenish over time. The mine, fish, chop, forage, skin and harvest commands work like gather but only look for nodes of their own skill.',
+'gather [node]
+mine [node]
+fish [node]
+chop [node]
+forage [node]
+skin [node]
+harvest [node]',
+'gather
+mine vein
+fish
+chop oak',
+ARRAY['craft', 'recipes', 'skills']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('combat', 'Command', 'Combat Commands',
+'Combat commands let you fight, alone or alongside others. Any number of fighters in a room can take part in the same fight.
+
+Attacking something makes it your target, and attacking something else while fighting switches to it. Every creature remembers who has hurt it and who has healed its enemies, and turns on whoever it considers the greatest threat. Assist joins a companion''s fight against their target. Rescue is a Shields check to step in front of a companion, drawing everyone attacking them onto you. Fighting ends once no enemy is left standing in the room, whether they fell, fled or left.
+
+Every blow aimed at you can be avoided with your best trained defense: Blocking with a shield in your off hand, Parrying with a weapon in your main hand, or Dodging. Defending makes all three easier. A second weapon in your off hand strikes after your main hand for half damage, and is easier to avoid unless you have the Dual Wielder talent. Use combat to see your weapons, armor and defenses.',
+'attack <target>
+assist <ally>
+rescue <ally>
+defend
+flee
+combat',
+'attack goblin
+assist Aldric
+rescue Aldric',
+ARRAY['cast', 'posture', 'skills']);
+
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
+VALUES ('talents', 'System', 'Talents',
+'Talents are natural gifts chosen when you create your character, and each changes how you play.
+
+In combat, a Weapon Master deals extra damage with any weapon, a Berserker hits much harder once below half health, and a Tactician acts sooner and defends a little better. The Lucky reroll a natural 1 on any check and land critical hits more often. The Hardy recover health half again as fast, and the Resilient shake off poison, burning and other harmful conditions in half the time.
+
+Social and survival talents add to checks with their skills: Silver Tongue to Persuasion, Deception and Bartering, Intimidating to Intimidation, Empathic to Insight and Animal Handling, Natural Leader to Leadership and Performance, Streetwise to Stealth and Lockpicking, Scout to Stealth and Navigation, Woodsman to Navigation and Nature, Tracker to Tracking, and Forager to Foraging, Herbalism and Fishing.',
+'score',
+'score',
+ARRAY['score', 'skills', 'combat']);
+
 INSERT INTO wyldlands.help_topics (keyword, category, title, content, syntax, examples, see_also)
 VALUES ('exit', 'Command', 'Exit Command',
 'The exit command saves your character and returns you to the character selection screen. Your character''s progress is automatically saved.',
@@ -202,11 +446,42 @@ INSERT INTO wyldlands.help_aliases (alias, keyword) VALUES
 ('l', 'look'),
 ('inv', 'inventory'),
 ('i', 'inventory'),
+('take', 'get'),
+('rem', 'remove'),
+('eq', 'equipment'),
+('stand', 'posture'),
+('wake', 'posture'),
+('sit', 'posture'),
+('rest', 'posture'),
+('sleep', 'posture'),
 ('''', 'say'),
 ('"', 'yell'),
 ('em', 'emote'),
 (':', 'emote'),
+('ask', 'talk'),
+('tell', 'talk'),
+('persuade', 'social'),
+('deceive', 'social'),
+('lie', 'social'),
+('intimidate', 'social'),
+('threaten', 'social'),
+('inspire', 'social'),
+('rally', 'social'),
+('console', 'social'),
+('comfort', 'social'),
 ('stats', 'score'),
+('attack', 'combat'),
+('kill', 'combat'),
+('assist', 'combat'),
+('rescue', 'combat'),
+('defend', 'combat'),
+('flee', 'combat'),
+('mine', 'gather'),
+('fish', 'gather'),
+('chop', 'gather'),
+('forage', 'gather'),
+('skin', 'gather'),
+('harvest', 'gather'),
 ('quit', 'exit'),
 ('logout', 'exit'),
 ('logoff', 'exit'),
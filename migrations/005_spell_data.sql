@@ -0,0 +1,87 @@
+-- Migration: Insert Spell Data
+-- This migration adds the starting spells for each school of magic
+
+BEGIN;
+
+SET search_path TO wyldlands, public;
+
+-- Damage spells
+INSERT INTO wyldlands.spells (name, school, description, energy_cost, cast_time, difficulty, target, effect,
+                              damage_type, amount_min, amount_max)
+VALUES ('Firebolt', 'Evocation', 'Hurls a bolt of flame at an enemy.', 10.0, 1.5, 12, 'Enemy', 'Damage', 'Fire', 6,
+        10),
+       ('Acid Splash', 'Conjuration', 'Conjures a spray of caustic acid over an enemy.', 8.0, 1.0, 10, 'Enemy',
+        'Damage', 'Acid', 4, 8),
+       ('Phantasmal Terror', 'Illusion', 'Fills an enemy''s mind with visions that wound like blades.', 12.0, 1.5, 14,
+        'Enemy', 'Damage', 'Psychic', 5, 9),
+       ('Wither', 'Necromancy', 'Draws the life out of an enemy with a touch of raw magic.', 12.0, 2.0, 14, 'Enemy',
+        'Damage', 'Arcane', 6, 12);
+
+-- Healing spells
+INSERT INTO wyldlands.spells (name, school, description, energy_cost, cast_time, difficulty, target, effect,
+                              amount_min, amount_max)
+VALUES ('Mending Ward', 'Abjuration', 'Wraps an ally in a ward that closes their wounds.', 12.0, 2.0, 12, 'Ally',
+        'Heal', 8, 14);
+
+-- Status spells; amount_max is the magnitude of the effect
+INSERT INTO wyldlands.spells (name, school, description, energy_cost, cast_time, difficulty, target, effect,
+                              status_effect, amount_max, duration)
+VALUES ('Enfeeble', 'Enchantment', 'Saps the strength from an enemy''s limbs.', 8.0, 1.0, 12, 'Enemy', 'Status',
+        'Weakened', 2, 30.0),
+       ('Slumber', 'Enchantment', 'Lulls an enemy into a brief, helpless stupor.', 15.0, 2.0, 16, 'Enemy', 'Status',
+        'Stunned', 1, 6.0),
+       ('Foresight', 'Divination', 'Glimpses the next moment before it comes, quickening your actions.', 10.0, 1.0, 12,
+        'Caster', 'Status', 'Hasted', 1, 30.0),
+       ('Bull''s Strength', 'Transmutation', 'Swells an ally''s muscles with borrowed might.', 10.0, 1.5, 12, 'Ally',
+        'Status', 'Strengthened', 2, 60.0),
+       ('Ignite', 'Evocation', 'Sets an enemy alight with clinging flames.', 12.0, 1.5, 14, 'Enemy', 'Status',
+        'Burning', 3, 9.0),
+       ('Venom Dart', 'Conjuration', 'Conjures a dart of venom that poisons an enemy.', 8.0, 1.0, 12, 'Enemy',
+        'Status', 'Poisoned', 2, 15.0);
+
+-- Cure spells
+INSERT INTO wyldlands.spells (name, school, description, energy_cost, cast_time, difficulty, target, effect,
+                              status_effect)
+VALUES ('Purge Toxin', 'Abjuration', 'Draws the poison out of an ally''s blood.', 8.0, 1.0, 12, 'Ally', 'Cure',
+        'Poisoned');
+
+-- Spell help topics
+INSERT INTO wyldlands.help_topics (keyword, category, title, content, see_also)
+VALUES ('firebolt', 'Spell', 'Firebolt',
+        'An Evocation spell that hurls a bolt of flame at an enemy for 6 to 10 fire damage. Costs 10 energy.',
+        ARRAY ['cast', 'spells']),
+       ('acid splash', 'Spell', 'Acid Splash',
+        'A Conjuration spell that sprays an enemy with acid for 4 to 8 acid damage. Costs 8 energy.',
+        ARRAY ['cast', 'spells']),
+       ('phantasmal terror', 'Spell', 'Phantasmal Terror',
+        'An Illusion spell that wounds an enemy''s mind for 5 to 9 psychic damage. Costs 12 energy.',
+        ARRAY ['cast', 'spells']),
+       ('wither', 'Spell', 'Wither',
+        'A Necromancy spell that drains an enemy for 6 to 12 arcane damage. Costs 12 energy.',
+        ARRAY ['cast', 'spells']),
+       ('mending ward', 'Spell', 'Mending Ward',
+        'An Abjuration spell that heals you or an ally for 8 to 14 health. Costs 12 energy.',
+        ARRAY ['cast', 'spells']),
+       ('enfeeble', 'Spell', 'Enfeeble',
+        'An Enchantment spell that weakens an enemy for 30 seconds. Costs 8 energy.',
+        ARRAY ['cast', 'spells']),
+       ('slumber', 'Spell', 'Slumber',
+        'An Enchantment spell that stuns an enemy for 6 seconds. Costs 15 energy.',
+        ARRAY ['cast', 'spells']),
+       ('foresight', 'Spell', 'Foresight',
+        'A Divination spell that hastes you for 30 seconds. Costs 10 energy.',
+        ARRAY ['cast', 'spells']),
+       ('bull''s strength', 'Spell', 'Bull''s Strength',
+        'A Transmutation spell that strengthens you or an ally for 60 seconds. Costs 10 energy.',
+        ARRAY ['cast', 'spells']),
+       ('ignite', 'Spell', 'Ignite',
+        'An Evocation spell that sets an enemy on fire for 9 seconds, burning for 3 damage every 3 seconds. Costs 12 energy.',
+        ARRAY ['cast', 'spells']),
+       ('venom dart', 'Spell', 'Venom Dart',
+        'A Conjuration spell that poisons an enemy for 15 seconds, dealing 2 damage every 3 seconds. Poison stacks. Costs 8 energy.',
+        ARRAY ['cast', 'spells']),
+       ('purge toxin', 'Spell', 'Purge Toxin',
+        'An Abjuration spell that cures you or an ally of poison. Costs 8 energy.',
+        ARRAY ['cast', 'spells']);
+
+COMMIT;
@@ -0,0 +1,161 @@
+-- Migration: Insert Crafting Data
+-- This migration adds item templates, a recipe for each crafting skill and a workshop to practice in
+
+BEGIN;
+
+SET search_path TO wyldlands, public;
+
+-- Raw materials
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material)
+VALUES ('iron_ingot', 'iron ingot', ARRAY ['iron', 'ingot'], 'A dull grey bar of iron',
+        'A heavy bar of smelted iron, ready for the forge.', 2.0, 'Iron'),
+       ('silver_ingot', 'silver ingot', ARRAY ['silver', 'ingot'], 'A small bar of silver',
+        'A small, bright bar of silver, soft enough for fine work.', 1.0, NULL),
+       ('leather_hide', 'leather hide', ARRAY ['leather', 'hide'], 'A tanned leather hide',
+        'A supple hide, tanned and ready to be cut.', 1.5, 'Leather'),
+       ('cloth_bolt', 'bolt of cloth', ARRAY ['cloth', 'bolt'], 'A bolt of woven cloth',
+        'A tightly rolled bolt of plain woven cloth.', 1.0, 'Cloth'),
+       ('thread', 'spool of thread', ARRAY ['thread', 'spool'], 'A spool of stout thread',
+        'A wooden spool wound with stout linen thread.', 0.1, NULL),
+       ('wood_plank', 'wooden plank', ARRAY ['wood', 'plank'], 'A sawn wooden plank',
+        'A length of seasoned oak, sawn flat.', 3.0, NULL),
+       ('stone_block', 'stone block', ARRAY ['stone', 'block'], 'A rough block of stone',
+        'A rough-hewn block of grey granite.', 20.0, NULL),
+       ('gemstone', 'gemstone', ARRAY ['gem', 'gemstone'], 'An uncut gemstone',
+        'A cloudy, uncut gemstone that would shine once polished.', 0.1, NULL),
+       ('herb_bundle', 'bundle of herbs', ARRAY ['herbs', 'bundle'], 'A bundle of healing herbs',
+        'A bundle of feverfew, yarrow and comfrey tied with twine.', 0.2, NULL),
+       ('raw_meat', 'raw meat', ARRAY ['meat', 'raw'], 'A cut of raw meat',
+        'A fresh cut of raw venison.', 1.0, NULL),
+       ('iron_nails', 'iron nails', ARRAY ['nails', 'iron'], 'A handful of iron nails',
+        'A handful of square-headed iron nails.', 0.2, 'Iron');
+
+-- Tools
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material, slots)
+VALUES ('smithing_hammer', 'smithing hammer', ARRAY ['hammer', 'smithing'], 'A heavy smithing hammer',
+        'A heavy cross-peen hammer, its face polished by years at the anvil.', 3.0, 'Iron', ARRAY ['MainHand']::slot_kind[]),
+       ('sewing_needle', 'sewing needle', ARRAY ['needle', 'sewing'], 'A stout sewing needle',
+        'A long steel needle with a wide eye for heavy thread.', 0.1, 'Steel', ARRAY []::slot_kind[]),
+       ('jewelers_tools', 'jeweler''s tools', ARRAY ['tools', 'jeweler'], 'A roll of jeweler''s tools',
+        'A leather roll holding tiny files, tweezers and a loupe.', 0.5, NULL, ARRAY []::slot_kind[]),
+       ('mortar_pestle', 'mortar and pestle', ARRAY ['mortar', 'pestle'], 'A stone mortar and pestle',
+        'A small granite mortar with a worn pestle.', 2.0, NULL, ARRAY []::slot_kind[]),
+       ('saw', 'saw', ARRAY ['saw'], 'A carpenter''s saw',
+        'A long-toothed carpenter''s saw with a wooden handle.', 2.0, 'Steel', ARRAY []::slot_kind[]),
+       ('chisel', 'chisel', ARRAY ['chisel'], 'A mason''s chisel',
+        'A broad steel chisel for dressing stone.', 1.0, 'Steel', ARRAY []::slot_kind[]);
+
+-- Finished goods
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material, slots, damage_min,
+                                      damage_max, damage_type)
+VALUES ('iron_longsword', 'iron longsword', ARRAY ['longsword', 'sword', 'iron'], 'An iron longsword',
+        'A straight, double-edged iron blade with a leather-wrapped grip.', 4.0, 'Iron',
+        ARRAY ['MainHand']::slot_kind[], 4, 9, 'Slashing');
+
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material, slots)
+VALUES ('iron_helm', 'iron helm', ARRAY ['helm', 'helmet', 'iron'], 'An iron helm',
+        'A plain iron helm with a riveted nasal guard.', 3.0, 'Iron', ARRAY ['Head']::slot_kind[]),
+       ('leather_jerkin', 'leather jerkin', ARRAY ['jerkin', 'leather'], 'A leather jerkin',
+        'A sleeveless jerkin of stitched leather.', 4.0, 'Leather', ARRAY ['Chest']::slot_kind[]),
+       ('cloth_tunic', 'cloth tunic', ARRAY ['tunic', 'cloth'], 'A cloth tunic',
+        'A simple tunic of woven cloth.', 1.0, 'Cloth', ARRAY ['Chest']::slot_kind[]),
+       ('silver_ring', 'silver ring', ARRAY ['ring', 'silver'], 'A silver ring',
+        'A silver band set with a polished stone.', 0.1, NULL, ARRAY ['Ring1', 'Ring2']::slot_kind[]),
+       ('wooden_shield', 'wooden shield', ARRAY ['shield', 'wooden'], 'A wooden shield',
+        'A round shield of nailed oak planks.', 5.0, NULL, ARRAY ['OffHand']::slot_kind[]),
+       ('healing_draught', 'healing draught', ARRAY ['draught', 'potion', 'healing'], 'A healing draught',
+        'A small vial of bitter green liquid that smells of yarrow.', 0.3, NULL, ARRAY []::slot_kind[]),
+       ('roast_meat', 'roast meat', ARRAY ['meat', 'roast'], 'A piece of roast meat',
+        'A piece of venison, roasted until the fat crackles.', 0.8, NULL, ARRAY []::slot_kind[]),
+       ('grindstone', 'grindstone', ARRAY ['grindstone', 'stone'], 'A grindstone',
+        'A round, dressed grindstone for sharpening blades.', 35.0, NULL, ARRAY []::slot_kind[]);
+
+INSERT INTO wyldlands.item_template_defenses (template_id, damage_kind, defense)
+VALUES ('iron_helm', 'Blunt', 3),
+       ('iron_helm', 'Slashing', 3),
+       ('iron_helm', 'Piercing', 2),
+       ('leather_jerkin', 'Blunt', 2),
+       ('leather_jerkin', 'Slashing', 2),
+       ('leather_jerkin', 'Piercing', 1),
+       ('cloth_tunic', 'Slashing', 1),
+       ('wooden_shield', 'Blunt', 2),
+       ('wooden_shield', 'Slashing', 3),
+       ('wooden_shield', 'Piercing', 3);
+
+-- A recipe for each crafting skill
+INSERT INTO wyldlands.recipes (name, skill, min_level, difficulty, station, output, output_quantity)
+VALUES ('Iron Nails', 'Blacksmithing', 0, 8, 'forge', 'iron_nails', 4),
+       ('Iron Longsword', 'Weaponsmithing', 1, 14, 'forge', 'iron_longsword', 1),
+       ('Iron Helm', 'Armorsmithing', 1, 13, 'forge', 'iron_helm', 1),
+       ('Leather Jerkin', 'Leatherworking', 0, 11, 'workbench', 'leather_jerkin', 1),
+       ('Cloth Tunic', 'Tailoring', 0, 9, 'loom', 'cloth_tunic', 1),
+       ('Silver Ring', 'Jewelcrafting', 1, 13, 'workbench', 'silver_ring', 1),
+       ('Healing Draught', 'Alchemy', 0, 12, 'alchemy table', 'healing_draught', 1),
+       ('Roast Meat', 'Cooking', 0, 7, 'fire', 'roast_meat', 1),
+       ('Wooden Shield', 'Carpentry', 0, 11, 'workbench', 'wooden_shield', 1),
+       ('Grindstone', 'Masonry', 0, 10, NULL, 'grindstone', 1);
+
+INSERT INTO wyldlands.recipe_components (recipe_name, template_id, quantity, consumed)
+VALUES ('Iron Nails', 'iron_ingot', 1, TRUE),
+       ('Iron Nails', 'smithing_hammer', 1, FALSE),
+       ('Iron Longsword', 'iron_ingot', 3, TRUE),
+       ('Iron Longsword', 'leather_hide', 1, TRUE),
+       ('Iron Longsword', 'smithing_hammer', 1, FALSE),
+       ('Iron Helm', 'iron_ingot', 2, TRUE),
+       ('Iron Helm', 'smithing_hammer', 1, FALSE),
+       ('Leather Jerkin', 'leather_hide', 3, TRUE),
+       ('Leather Jerkin', 'thread', 1, TRUE),
+       ('Leather Jerkin', 'sewing_needle', 1, FALSE),
+       ('Cloth Tunic', 'cloth_bolt', 2, TRUE),
+       ('Cloth Tunic', 'thread', 1, TRUE),
+       ('Cloth Tunic', 'sewing_needle', 1, FALSE),
+       ('Silver Ring', 'silver_ingot', 1, TRUE),
+       ('Silver Ring', 'gemstone', 1, TRUE),
+       ('Silver Ring', 'jewelers_tools', 1, FALSE),
+       ('Healing Draught', 'herb_bundle', 2, TRUE),
+       ('Healing Draught', 'mortar_pestle', 1, FALSE),
+       ('Roast Meat', 'raw_meat', 1, TRUE),
+       ('Wooden Shield', 'wood_plank', 3, TRUE),
+       ('Wooden Shield', 'iron_nails', 4, TRUE),
+       ('Wooden Shield', 'saw', 1, FALSE),
+       ('Grindstone', 'stone_block', 2, TRUE),
+       ('Grindstone', 'chisel', 1, FALSE);
+
+-- Workshop fixtures in the Item Repository
+INSERT INTO wyldlands.entities (uuid)
+VALUES ('20000000-0000-0000-0000-000000000001'),
+       ('20000000-0000-0000-0000-000000000002'),
+       ('20000000-0000-0000-0000-000000000003'),
+       ('20000000-0000-0000-0000-000000000004'),
+       ('20000000-0000-0000-0000-000000000005');
+INSERT INTO wyldlands.entity_name (entity_id, display, keywords)
+VALUES ('20000000-0000-0000-0000-000000000001', 'forge', ARRAY ['forge', 'anvil']),
+       ('20000000-0000-0000-0000-000000000002', 'workbench', ARRAY ['workbench', 'bench']),
+       ('20000000-0000-0000-0000-000000000003', 'loom', ARRAY ['loom']),
+       ('20000000-0000-0000-0000-000000000004', 'alchemy table', ARRAY ['alchemy', 'table']),
+       ('20000000-0000-0000-0000-000000000005', 'cooking fire', ARRAY ['fire', 'cooking']);
+INSERT INTO wyldlands.entity_description (entity_id, short, long)
+VALUES ('20000000-0000-0000-0000-000000000001', 'A glowing forge stands beside an anvil.',
+        'A brick forge glows with banked coals. A scarred anvil stands within arm''s reach.'),
+       ('20000000-0000-0000-0000-000000000002', 'A sturdy workbench lines one wall.',
+        'A long oak workbench, scored and stained by countless projects.'),
+       ('20000000-0000-0000-0000-000000000003', 'A wooden loom stands in the corner.',
+        'A floor loom strung with a half-finished length of cloth.'),
+       ('20000000-0000-0000-0000-000000000004', 'An alchemy table is cluttered with glassware.',
+        'A stone-topped table crowded with flasks, burners and stoppered jars.'),
+       ('20000000-0000-0000-0000-000000000005', 'A small cooking fire crackles in a stone ring.',
+        'A ring of blackened stones holds a small, steady cooking fire.');
+INSERT INTO wyldlands.entity_location (entity_id, room_id)
+VALUES ('20000000-0000-0000-0000-000000000001', '10000000-0000-0000-0000-000000000003'),
+       ('20000000-0000-0000-0000-000000000002', '10000000-0000-0000-0000-000000000003'),
+       ('20000000-0000-0000-0000-000000000003', '10000000-0000-0000-0000-000000000003'),
+       ('20000000-0000-0000-0000-000000000004', '10000000-0000-0000-0000-000000000003'),
+       ('20000000-0000-0000-0000-000000000005', '10000000-0000-0000-0000-000000000003');
+INSERT INTO wyldlands.entity_crafting_station (entity_id, station)
+VALUES ('20000000-0000-0000-0000-000000000001', 'forge'),
+       ('20000000-0000-0000-0000-000000000002', 'workbench'),
+       ('20000000-0000-0000-0000-000000000003', 'loom'),
+       ('20000000-0000-0000-0000-000000000004', 'alchemy table'),
+       ('20000000-0000-0000-0000-000000000005', 'fire');
+
+COMMIT;
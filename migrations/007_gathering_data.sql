@@ -0,0 +1,145 @@
+-- Migration: Insert Gathering Data
+-- This migration adds raw resource templates, recipes to refine them and resource nodes to gather them from
+
+BEGIN;
+
+SET search_path TO wyldlands, public;
+
+-- Raw resources
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material)
+VALUES ('iron_ore', 'iron ore', ARRAY ['ore', 'iron'], 'A lump of iron ore',
+        'A heavy, rust-streaked lump of iron ore.', 3.0, NULL),
+       ('silver_ore', 'silver ore', ARRAY ['ore', 'silver'], 'A lump of silver ore',
+        'A lump of dark rock threaded with veins of silver.', 3.0, NULL),
+       ('wood_log', 'oak log', ARRAY ['log', 'oak', 'wood'], 'A length of oak log',
+        'A length of freshly felled oak, the bark still on.', 10.0, NULL),
+       ('raw_fish', 'raw fish', ARRAY ['fish', 'raw'], 'A raw fish',
+        'A silver-scaled river trout, still glistening.', 0.8, NULL),
+       ('raw_hide', 'raw hide', ARRAY ['hide', 'raw'], 'A raw hide',
+        'A freshly skinned hide that needs tanning before it is any use.', 2.0, NULL),
+       ('wild_berries', 'handful of wild berries', ARRAY ['berries', 'wild'], 'A handful of wild berries',
+        'A handful of tart red berries.', 0.1, NULL),
+       ('grilled_fish', 'grilled fish', ARRAY ['fish', 'grilled'], 'A grilled fish',
+        'A trout grilled over an open fire, its skin crisp and blackened.', 0.6, NULL);
+
+-- Gathering tools
+INSERT INTO wyldlands.item_templates (id, name, keywords, short, long, weight, material, slots)
+VALUES ('pickaxe', 'pickaxe', ARRAY ['pickaxe', 'pick'], 'A miner''s pickaxe',
+        'A heavy iron pickaxe with a hickory handle.', 5.0, 'Iron', ARRAY ['MainHand']::slot_kind[]),
+       ('woodcutters_axe', 'woodcutter''s axe', ARRAY ['axe', 'woodcutter'], 'A woodcutter''s axe',
+        'A long-hafted axe with a wide, wedge-shaped head.', 4.0, 'Iron', ARRAY ['MainHand']::slot_kind[]),
+       ('fishing_pole', 'fishing pole', ARRAY ['pole', 'fishing', 'rod'], 'A fishing pole',
+        'A flexible ash pole strung with line and a bone hook.', 1.5, NULL, ARRAY []::slot_kind[]),
+       ('skinning_knife', 'skinning knife', ARRAY ['knife', 'skinning'], 'A skinning knife',
+        'A short, curved knife for parting hide from flesh.', 0.5, 'Steel', ARRAY ['MainHand']::slot_kind[]);
+
+-- Recipes refining raw resources into crafting materials
+INSERT INTO wyldlands.recipes (name, skill, min_level, difficulty, station, output, output_quantity)
+VALUES ('Iron Ingot', 'Blacksmithing', 0, 8, 'forge', 'iron_ingot', 1),
+       ('Silver Ingot', 'Blacksmithing', 1, 11, 'forge', 'silver_ingot', 1),
+       ('Wooden Plank', 'Carpentry', 0, 6, 'workbench', 'wood_plank', 2),
+       ('Tanned Hide', 'Leatherworking', 0, 8, 'workbench', 'leather_hide', 1),
+       ('Grilled Fish', 'Cooking', 0, 6, 'fire', 'grilled_fish', 1);
+
+INSERT INTO wyldlands.recipe_components (recipe_name, template_id, quantity, consumed)
+VALUES ('Iron Ingot', 'iron_ore', 2, TRUE),
+       ('Iron Ingot', 'smithing_hammer', 1, FALSE),
+       ('Silver Ingot', 'silver_ore', 2, TRUE),
+       ('Silver Ingot', 'smithing_hammer', 1, FALSE),
+       ('Wooden Plank', 'wood_log', 1, TRUE),
+       ('Wooden Plank', 'saw', 1, FALSE),
+       ('Tanned Hide', 'raw_hide', 1, TRUE),
+       ('Grilled Fish', 'raw_fish', 1, TRUE);
+
+-- Resource nodes in the Environment Simulator
+INSERT INTO wyldlands.entities (uuid)
+VALUES ('20000000-0000-0000-0000-000000000011'),
+       ('20000000-0000-0000-0000-000000000012'),
+       ('20000000-0000-0000-0000-000000000013'),
+       ('20000000-0000-0000-0000-000000000014'),
+       ('20000000-0000-0000-0000-000000000015'),
+       ('20000000-0000-0000-0000-000000000016');
+INSERT INTO wyldlands.entity_name (entity_id, display, keywords)
+VALUES ('20000000-0000-0000-0000-000000000011', 'ore vein', ARRAY ['vein', 'ore', 'rock']),
+       ('20000000-0000-0000-0000-000000000012', 'herb patch', ARRAY ['patch', 'herbs', 'herb']),
+       ('20000000-0000-0000-0000-000000000013', 'oak tree', ARRAY ['tree', 'oak']),
+       ('20000000-0000-0000-0000-000000000014', 'fishing spot', ARRAY ['spot', 'fishing', 'pool']),
+       ('20000000-0000-0000-0000-000000000015', 'berry bushes', ARRAY ['bushes', 'berry', 'berries']),
+       ('20000000-0000-0000-0000-000000000016', 'deer carcass', ARRAY ['carcass', 'deer']);
+INSERT INTO wyldlands.entity_description (entity_id, short, long)
+VALUES ('20000000-0000-0000-0000-000000000011', 'A vein of ore glints in a rocky outcrop.',
+        'Rust-red streaks of iron run through the grey rock, with the odd glint of something brighter.'),
+       ('20000000-0000-0000-0000-000000000012', 'A patch of herbs grows in the shade.',
+        'Feverfew, yarrow and comfrey crowd together in a damp, shady corner.'),
+       ('20000000-0000-0000-0000-000000000013', 'A tall oak tree spreads its branches overhead.',
+        'A broad, straight oak, old enough to yield good timber.'),
+       ('20000000-0000-0000-0000-000000000014', 'A still pool looks like a good fishing spot.',
+        'Trout dart through the clear water of a deep, still pool.'),
+       ('20000000-0000-0000-0000-000000000015', 'Berry bushes grow along the edge of the clearing.',
+        'Thorny bushes heavy with tart red berries.'),
+       ('20000000-0000-0000-0000-000000000016', 'A deer carcass lies on the ground.',
+        'The carcass of a red deer, not long dead.');
+INSERT INTO wyldlands.entity_location (entity_id, room_id)
+VALUES ('20000000-0000-0000-0000-000000000011', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000012', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000013', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000014', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000015', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000016', '10000000-0000-0000-0000-000000000005');
+INSERT INTO wyldlands.entity_resource_node (entity_id, skill, difficulty, tool, charges, max_charges, respawn_time)
+VALUES ('20000000-0000-0000-0000-000000000011', 'Mining', 10, 'pickaxe', 6, 6, 600.0),
+       ('20000000-0000-0000-0000-000000000012', 'Herbalism', 9, NULL, 4, 4, 300.0),
+       ('20000000-0000-0000-0000-000000000013', 'Logging', 8, 'woodcutters_axe', 5, 5, 900.0),
+       ('20000000-0000-0000-0000-000000000014', 'Fishing', 9, 'fishing_pole', 8, 8, 300.0),
+       ('20000000-0000-0000-0000-000000000015', 'Foraging', 6, NULL, 5, 5, 300.0),
+       ('20000000-0000-0000-0000-000000000016', 'Skinning', 8, 'skinning_knife', 2, 2, 1200.0);
+INSERT INTO wyldlands.entity_resource_yield (entity_id, template_id, weight, min_quantity, max_quantity, min_level)
+VALUES ('20000000-0000-0000-0000-000000000011', 'iron_ore', 10, 1, 3, 0),
+       ('20000000-0000-0000-0000-000000000011', 'stone_block', 4, 1, 1, 0),
+       ('20000000-0000-0000-0000-000000000011', 'silver_ore', 3, 1, 2, 2),
+       ('20000000-0000-0000-0000-000000000011', 'gemstone', 1, 1, 1, 3),
+       ('20000000-0000-0000-0000-000000000012', 'herb_bundle', 1, 1, 3, 0),
+       ('20000000-0000-0000-0000-000000000013', 'wood_log', 1, 1, 2, 0),
+       ('20000000-0000-0000-0000-000000000014', 'raw_fish', 1, 1, 2, 0),
+       ('20000000-0000-0000-0000-000000000015', 'wild_berries', 6, 1, 4, 0),
+       ('20000000-0000-0000-0000-000000000015', 'herb_bundle', 1, 1, 1, 1),
+       ('20000000-0000-0000-0000-000000000016', 'raw_hide', 3, 1, 1, 0),
+       ('20000000-0000-0000-0000-000000000016', 'raw_meat', 2, 1, 3, 0);
+
+-- Gathering tools left beside the nodes
+INSERT INTO wyldlands.entities (uuid)
+VALUES ('20000000-0000-0000-0000-000000000021'),
+       ('20000000-0000-0000-0000-000000000022'),
+       ('20000000-0000-0000-0000-000000000023'),
+       ('20000000-0000-0000-0000-000000000024');
+INSERT INTO wyldlands.entity_name (entity_id, display, keywords)
+VALUES ('20000000-0000-0000-0000-000000000021', 'pickaxe', ARRAY ['pickaxe', 'pick']),
+       ('20000000-0000-0000-0000-000000000022', 'woodcutter''s axe', ARRAY ['axe', 'woodcutter']),
+       ('20000000-0000-0000-0000-000000000023', 'fishing pole', ARRAY ['pole', 'fishing', 'rod']),
+       ('20000000-0000-0000-0000-000000000024', 'skinning knife', ARRAY ['knife', 'skinning']);
+INSERT INTO wyldlands.entity_description (entity_id, short, long)
+VALUES ('20000000-0000-0000-0000-000000000021', 'A miner''s pickaxe',
+        'A heavy iron pickaxe with a hickory handle.'),
+       ('20000000-0000-0000-0000-000000000022', 'A woodcutter''s axe',
+        'A long-hafted axe with a wide, wedge-shaped head.'),
+       ('20000000-0000-0000-0000-000000000023', 'A fishing pole',
+        'A flexible ash pole strung with line and a bone hook.'),
+       ('20000000-0000-0000-0000-000000000024', 'A skinning knife',
+        'A short, curved knife for parting hide from flesh.');
+INSERT INTO wyldlands.entity_location (entity_id, room_id)
+VALUES ('20000000-0000-0000-0000-000000000021', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000022', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000023', '10000000-0000-0000-0000-000000000005'),
+       ('20000000-0000-0000-0000-000000000024', '10000000-0000-0000-0000-000000000005');
+INSERT INTO wyldlands.entity_containable (entity_id, weight, size)
+VALUES ('20000000-0000-0000-0000-000000000021', 5.0, 'Medium'),
+       ('20000000-0000-0000-0000-000000000022', 4.0, 'Medium'),
+       ('20000000-0000-0000-0000-000000000023', 1.5, 'Medium'),
+       ('20000000-0000-0000-0000-000000000024', 0.5, 'Small');
+INSERT INTO wyldlands.entity_template (entity_id, template_id)
+VALUES ('20000000-0000-0000-0000-000000000021', 'pickaxe'),
+       ('20000000-0000-0000-0000-000000000022', 'woodcutters_axe'),
+       ('20000000-0000-0000-0000-000000000023', 'fishing_pole'),
+       ('20000000-0000-0000-0000-000000000024', 'skinning_knife');
+
+COMMIT;
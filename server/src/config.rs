@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 use serde_env_field::EnvField;
 use std::net::{AddrParseError, IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
@@ -53,6 +54,8 @@ impl Default for Arguments {
 pub struct Configuration {
     pub database: DatabaseConfig,
     pub listener: GatewayListenerConfig,
+    #[serde(default)]
+    pub world: WorldConfig,
 }
 
 impl Configuration {
@@ -94,6 +97,79 @@ impl Default for GatewayListenerConfig {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorldConfig {
+    /// World tick rate in pulses per second
+    pub pulse_rate: EnvField<u32>,
+
+    /// Death, corpse and respawn settings
+    #[serde(default)]
+    pub death: DeathConfig,
+
+    /// In-game clock settings
+    #[serde(default)]
+    pub clock: ClockConfig,
+}
+
+impl WorldConfig {
+    /// Interval between world pulses
+    pub fn pulse_interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / (*self.pulse_rate).max(1) as f64)
+    }
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            pulse_rate: 4.into(),
+            death: DeathConfig::default(),
+            clock: ClockConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeathConfig {
+    /// Seconds before a corpse and anything left in it decays
+    pub corpse_decay_seconds: f32,
+
+    /// Fraction of maximum health and energy a player respawns with
+    pub respawn_vitals: f32,
+
+    /// Whether dead players leave their inventory behind in a corpse
+    pub player_corpse: bool,
+}
+
+impl Default for DeathConfig {
+    fn default() -> Self {
+        Self {
+            corpse_decay_seconds: 300.0,
+            respawn_vitals: 0.5,
+            player_corpse: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClockConfig {
+    /// Real minutes an in-game day lasts
+    pub day_length_minutes: f32,
+
+    /// In-game hour the clock shows when the server starts
+    pub start_hour: u32,
+}
+
+impl Default for ClockConfig {
+    fn default() -> Self {
+        Self {
+            day_length_minutes: 120.0,
+            start_hour: 6,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GatewayListenerBinding(SocketAddr);
 
@@ -207,6 +283,17 @@ mod tests {
         );
         assert_eq!(config.listener.addr.to_port(), 6006);
         assert_eq!(config.database.url.into_inner(), "");
+        assert_eq!(*config.world.pulse_rate, 4);
+        assert_eq!(config.world.death.respawn_vitals, 0.5);
+    }
+
+    #[test]
+    fn test_world_config_pulse_interval() {
+        let config = WorldConfig {
+            pulse_rate: 10.into(),
+            ..Default::default()
+        };
+        assert_eq!(config.pulse_interval(), Duration::from_millis(100));
     }
 
     #[test]
@@ -253,6 +340,7 @@ mod tests {
         );
         assert_eq!(config.listener.addr.to_port(), 7000);
         assert_eq!(config.database.url.into_inner(), "postgres://localhost/db");
+        assert_eq!(*config.world.pulse_rate, 4);
     }
 
     #[test]
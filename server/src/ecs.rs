@@ -39,6 +39,7 @@ pub type GameWorld = World;
 pub mod components;
 pub mod events;
 pub mod memory;
+pub mod output;
 pub mod registry;
 pub mod systems;
 
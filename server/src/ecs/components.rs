@@ -22,8 +22,12 @@
 mod ai;
 pub mod character;
 mod combat;
+mod crafting;
+mod gathering;
 mod identity;
 mod interaction;
+mod item;
+mod magic;
 mod npc;
 mod persistence;
 mod spatial;
@@ -32,8 +36,12 @@ mod spatial;
 pub use ai::*;
 pub use character::*;
 pub use combat::*;
+pub use crafting::*;
+pub use gathering::*;
 pub use identity::*;
 pub use interaction::*;
+pub use item::*;
+pub use magic::*;
 pub use npc::*;
 pub use persistence::*;
 pub use spatial::*;
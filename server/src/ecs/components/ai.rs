@@ -24,7 +24,8 @@ pub use self::emotion::{
     PersonalityTraits,
 };
 pub use self::goap::{
-    ActionCost, BehaviorType, GoapAction, GoapGoal, GoapPlanner, StateType, WorldState,
+    ActionCost, BehaviorType, DEFAULT_MAX_NODES, DEFAULT_MAX_TIME, GoapAction, GoapGoal,
+    GoapPlanner, PlanReport, SearchLimits, SearchOutcome, StateType, WorldState,
 };
 use crate::ecs::components::EntityId;
 use serde::{Deserialize, Serialize};
@@ -70,6 +71,86 @@ impl AIController {
     }
 }
 
+/// Hours in an in-game day
+pub const HOURS_PER_DAY: u32 = 24;
+
+/// One block of an NPC's daily routine, lasting until the next block starts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleBlock {
+    /// In-game hour the block starts (0-23)
+    pub start_hour: u32,
+    /// GOAP goal pursued during the block
+    pub goal: Option<String>,
+    /// Room the NPC goes to when the block starts
+    pub room: Option<EntityId>,
+}
+
+/// NPC daily schedule component
+/// Maps to: entity_npc_schedule table (one row per block)
+///
+/// The last block of the day carries on past midnight until the first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NpcSchedule {
+    /// Blocks in order of start hour
+    pub blocks: Vec<ScheduleBlock>,
+    /// Start hour of the block last put into effect
+    #[serde(skip)]
+    pub applied: Option<u32>,
+}
+
+impl NpcSchedule {
+    /// Create an empty schedule
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The block starting at an hour, added if there is none yet
+    ///
+    /// Editing a block puts the schedule into effect again on the next update.
+    pub fn block_mut(&mut self, start_hour: u32) -> &mut ScheduleBlock {
+        let start_hour = start_hour % HOURS_PER_DAY;
+        self.applied = None;
+        let index = match self
+            .blocks
+            .binary_search_by_key(&start_hour, |block| block.start_hour)
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.blocks.insert(
+                    index,
+                    ScheduleBlock {
+                        start_hour,
+                        goal: None,
+                        room: None,
+                    },
+                );
+                index
+            }
+        };
+        &mut self.blocks[index]
+    }
+
+    /// Drop blocks that no longer set a goal or a room
+    pub fn prune(&mut self) {
+        self.blocks
+            .retain(|block| block.goal.is_some() || block.room.is_some());
+    }
+
+    /// The block in effect at an hour
+    pub fn block_at(&self, hour: u32) -> Option<&ScheduleBlock> {
+        self.blocks
+            .iter()
+            .rev()
+            .find(|block| block.start_hour <= hour)
+            .or_else(|| self.blocks.last())
+    }
+
+    /// Goals the schedule switches between
+    pub fn goals(&self) -> impl Iterator<Item = &str> {
+        self.blocks.iter().filter_map(|block| block.goal.as_deref())
+    }
+}
+
 // Legacy compatibility type (deprecated)
 #[deprecated(note = "Use StateType instead")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,7 +165,7 @@ pub enum AIState {
 
 #[cfg(test)]
 mod tests {
-    use super::{AIController, BehaviorType};
+    use super::{AIController, BehaviorType, EntityId, NpcSchedule};
 
     #[test]
     fn test_ai_controller_update() {
@@ -97,4 +178,31 @@ mod tests {
         ai.mark_updated();
         assert!(!ai.should_update(0.0));
     }
+
+    #[test]
+    fn test_schedule_blocks_wrap_past_midnight() {
+        let mut schedule = NpcSchedule::new();
+        assert!(schedule.block_at(12).is_none());
+
+        let tavern = EntityId::from_uuid(uuid::Uuid::new_v4());
+        schedule.block_mut(18).room = Some(tavern);
+        schedule.block_mut(8).goal = Some("open_shop".to_string());
+        schedule.block_mut(22).goal = Some("sleep".to_string());
+        let hours: Vec<u32> = schedule.blocks.iter().map(|b| b.start_hour).collect();
+        assert_eq!(hours, vec![8, 18, 22]);
+
+        assert_eq!(schedule.block_at(8).unwrap().start_hour, 8);
+        assert_eq!(schedule.block_at(17).unwrap().start_hour, 8);
+        assert_eq!(schedule.block_at(19).unwrap().start_hour, 18);
+        assert_eq!(schedule.block_at(3).unwrap().start_hour, 22);
+        assert_eq!(
+            schedule.goals().collect::<Vec<_>>(),
+            vec!["open_shop", "sleep"]
+        );
+
+        schedule.block_mut(18).room = None;
+        schedule.prune();
+        assert_eq!(schedule.blocks.len(), 2);
+        assert_eq!(schedule.block_at(19).unwrap().start_hour, 8);
+    }
 }
@@ -16,8 +16,11 @@
 
 //! GOAP (Goal-Oriented Action Planning) components for NPC AI
 
+use crate::ecs::components::EntityId;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::time::{Duration, Instant};
 
 /// AI behavior types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -104,7 +107,7 @@ pub type WorldState = HashMap<String, bool>;
 pub type ActionCost = f32;
 
 /// GOAP Action definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GoapAction {
     /// Unique identifier for this action
     pub id: String,
@@ -167,7 +170,7 @@ impl GoapAction {
 }
 
 /// GOAP Goal definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GoapGoal {
     /// Unique identifier for this goal
     pub id: String,
@@ -207,22 +210,136 @@ impl GoapGoal {
     }
 }
 
-/// A node in the GOAP planning graph
+/// Most states one planning update may expand by default
+pub const DEFAULT_MAX_NODES: usize = 2048;
+
+/// Longest one planning update may search by default
+pub const DEFAULT_MAX_TIME: Duration = Duration::from_millis(5);
+
+/// Budgets for the planning done in one update
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SearchLimits {
+    /// Most states expanded before the search gives up
+    pub max_nodes: usize,
+    /// Longest the search runs before giving up
+    pub max_time: Duration,
+}
+
+impl Default for SearchLimits {
+    fn default() -> Self {
+        Self {
+            max_nodes: DEFAULT_MAX_NODES,
+            max_time: DEFAULT_MAX_TIME,
+        }
+    }
+}
+
+/// Why a planning search stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOutcome {
+    /// The cheapest plan to the goal was found
+    Found,
+    /// Every reachable state was searched without reaching the goal
+    Unreachable,
+    /// The node budget ran out first
+    NodeLimit,
+    /// The time budget ran out first
+    TimeLimit,
+}
+
+impl SearchOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchOutcome::Found => "Found",
+            SearchOutcome::Unreachable => "Unreachable",
+            SearchOutcome::NodeLimit => "Node limit reached",
+            SearchOutcome::TimeLimit => "Time limit reached",
+        }
+    }
+}
+
+/// What a planning search found, kept for diagnostics
+#[derive(Debug, Clone)]
+pub struct PlanReport {
+    /// Goal that was planned for
+    pub goal: String,
+    /// Why the search stopped
+    pub outcome: SearchOutcome,
+    /// Actions of the plan, in order, if one was found
+    pub plan: Vec<String>,
+    /// Total cost of the plan
+    pub cost: ActionCost,
+    /// States the search expanded
+    pub nodes_expanded: usize,
+    /// How long the search ran
+    pub elapsed: Duration,
+    /// Actions left out of the plan, each with the reason
+    pub rejected: Vec<(String, String)>,
+    /// Whether the plan came from the cache rather than a fresh search
+    pub cached: bool,
+}
+
+/// A search result reused while nothing it depended on changes
+#[derive(Debug, Clone)]
+struct CachedPlan {
+    start: WorldState,
+    goal: GoapGoal,
+    actions: Vec<GoapAction>,
+    report: PlanReport,
+}
+
+/// A state reached during planning
 #[derive(Debug, Clone)]
-struct PlanNode {
+struct SearchNode {
     state: WorldState,
-    action: Option<String>,
+    action: Option<usize>,
     parent: Option<usize>,
     cost: ActionCost,
-    heuristic: ActionCost,
 }
 
-impl PlanNode {
-    fn total_cost(&self) -> ActionCost {
-        self.cost + self.heuristic
+/// A state waiting to be expanded, cheapest estimate first
+#[derive(Debug, Clone, Copy)]
+struct OpenEntry {
+    estimate: ActionCost,
+    cost: ActionCost,
+    node: usize,
+}
+
+impl PartialEq for OpenEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
     }
 }
 
+impl Eq for OpenEntry {}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so the binary heap pops the lowest estimate, oldest first on ties
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+/// Identity of a world state for search, where an unset key counts as false
+fn state_key(state: &WorldState) -> Vec<String> {
+    let mut keys: Vec<String> = state
+        .iter()
+        .filter(|(_, value)| **value)
+        .map(|(key, _)| key.clone())
+        .collect();
+    keys.sort();
+    keys
+}
+
 /// GOAP Planner - uses A* to find action sequences
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoapPlanner {
@@ -236,6 +353,30 @@ pub struct GoapPlanner {
     pub current_plan: VecDeque<String>,
     /// Current goal being pursued
     pub current_goal: Option<String>,
+    /// Action being carried out, which may take several updates to finish
+    #[serde(default)]
+    pub current_action: Option<String>,
+    /// Room the NPC guards
+    #[serde(default)]
+    pub guard_post: Option<EntityId>,
+    /// Rooms the NPC patrols between, in order
+    #[serde(default)]
+    pub patrol_route: Vec<EntityId>,
+    /// Index into `patrol_route` of the next room to visit
+    #[serde(default)]
+    pub patrol_index: usize,
+    /// Room the NPC is travelling to, such as one set by its schedule
+    #[serde(default)]
+    pub destination: Option<EntityId>,
+    /// Budgets for the planning done in one update
+    #[serde(default)]
+    pub limits: SearchLimits,
+    /// Report from the most recent planning search
+    #[serde(skip)]
+    pub last_report: Option<PlanReport>,
+    /// Search results per goal, reused while the world state is unchanged
+    #[serde(skip)]
+    plan_cache: HashMap<String, CachedPlan>,
 }
 
 impl GoapPlanner {
@@ -247,6 +388,14 @@ impl GoapPlanner {
             world_state: HashMap::new(),
             current_plan: VecDeque::new(),
             current_goal: None,
+            current_action: None,
+            guard_post: None,
+            patrol_route: Vec::new(),
+            patrol_index: 0,
+            destination: None,
+            limits: SearchLimits::default(),
+            last_report: None,
+            plan_cache: HashMap::new(),
         }
     }
 
@@ -278,117 +427,224 @@ impl GoapPlanner {
             .max_by_key(|g| g.priority)
     }
 
-    /// Calculate heuristic (number of unsatisfied conditions)
-    fn heuristic(&self, state: &WorldState, goal: &WorldState) -> ActionCost {
-        goal.iter()
-            .filter(|(key, value)| state.get(*key).copied().unwrap_or(false) != **value)
-            .count() as ActionCost
-    }
-
-    /// Plan a sequence of actions to achieve a goal using A*
-    pub fn plan(&mut self, goal: &GoapGoal) -> Option<VecDeque<String>> {
-        let mut open_list: Vec<PlanNode> = Vec::new();
-        let mut closed_list: Vec<PlanNode> = Vec::new();
+    /// Search for the cheapest plan to a goal using A*
+    ///
+    /// The search stops early once it has expanded `limits.max_nodes` states
+    /// or run for `limits.max_time`. The heuristic never overestimates, as
+    /// each remaining condition is assumed to be met as cheaply and as many
+    /// at a time as any action allows, so a plan found is the cheapest one.
+    pub fn search(&self, goal: &GoapGoal, limits: SearchLimits) -> PlanReport {
+        let started = Instant::now();
+        let actions: Vec<&GoapAction> = self.actions.iter().filter(|a| a.enabled).collect();
+        let cheapest = actions
+            .iter()
+            .map(|action| action.cost.max(0.0))
+            .fold(ActionCost::INFINITY, ActionCost::min);
+        let widest = actions
+            .iter()
+            .map(|action| action.effects.len())
+            .max()
+            .unwrap_or(1)
+            .max(1);
+        let heuristic = |state: &WorldState| {
+            let unmet = goal
+                .desired_state
+                .iter()
+                .filter(|(key, value)| state.get(*key).copied().unwrap_or(false) != **value)
+                .count();
+            if unmet == 0 || !cheapest.is_finite() {
+                0.0
+            } else {
+                (unmet as ActionCost / widest as ActionCost).ceil() * cheapest
+            }
+        };
 
-        // Start node
-        let start_node = PlanNode {
+        let mut nodes = vec![SearchNode {
             state: self.world_state.clone(),
             action: None,
             parent: None,
             cost: 0.0,
-            heuristic: self.heuristic(&self.world_state, &goal.desired_state),
-        };
-
-        open_list.push(start_node);
+        }];
+        let mut best_cost = HashMap::from([(state_key(&self.world_state), 0.0)]);
+        let mut open = BinaryHeap::from([OpenEntry {
+            estimate: heuristic(&self.world_state),
+            cost: 0.0,
+            node: 0,
+        }]);
+        // Cheapest estimated plan through each action that changed anything
+        let mut through: HashMap<usize, ActionCost> = HashMap::new();
+        let mut runnable: HashSet<usize> = HashSet::new();
+        let mut expanded = 0;
+        let mut goal_node = None;
+
+        let outcome = loop {
+            let Some(entry) = open.pop() else {
+                break SearchOutcome::Unreachable;
+            };
+            let key = state_key(&nodes[entry.node].state);
+            if best_cost.get(&key).is_some_and(|cost| *cost < entry.cost) {
+                // A cheaper way here was found after this entry was queued
+                continue;
+            }
+            if goal.is_satisfied(&nodes[entry.node].state) {
+                goal_node = Some(entry.node);
+                break SearchOutcome::Found;
+            }
+            if expanded >= limits.max_nodes {
+                break SearchOutcome::NodeLimit;
+            }
+            if started.elapsed() >= limits.max_time {
+                break SearchOutcome::TimeLimit;
+            }
+            expanded += 1;
 
-        while !open_list.is_empty() {
-            // Find node with lowest total cost
-            let current_idx = open_list
-                .iter()
-                .enumerate()
-                .min_by(|(_, a), (_, b)| {
-                    a.total_cost()
-                        .partial_cmp(&b.total_cost())
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                })
-                .map(|(idx, _)| idx)?;
-
-            let current = open_list.remove(current_idx);
-
-            // Check if goal is satisfied
-            if goal.is_satisfied(&current.state) {
-                // Reconstruct plan by backtracking through parents
-                let mut plan = VecDeque::new();
-                let mut node_chain = Vec::new();
-
-                // Follow parent chain to reconstruct full path
-                let mut current_node = current.clone();
-                loop {
-                    node_chain.push(current_node.clone());
-                    if let Some(parent_idx) = current_node.parent {
-                        if parent_idx < closed_list.len() {
-                            current_node = closed_list[parent_idx].clone();
-                        } else {
-                            break;
-                        }
-                    } else {
-                        break;
-                    }
+            for (index, action) in actions.iter().enumerate() {
+                if !action.preconditions_met(&nodes[entry.node].state) {
+                    continue;
                 }
-
-                // Extract actions from node chain in reverse order (skip last node which has no action)
-                node_chain.reverse();
-                for node in &node_chain {
-                    if let Some(ref action_id) = node.action {
-                        plan.push_back(action_id.clone());
-                    }
+                runnable.insert(index);
+                let mut state = nodes[entry.node].state.clone();
+                action.apply_effects(&mut state);
+                let next_key = state_key(&state);
+                if next_key == key {
+                    continue;
                 }
 
-                return Some(plan);
+                let cost = entry.cost + action.cost;
+                let estimate = cost + heuristic(&state);
+                through
+                    .entry(index)
+                    .and_modify(|best| *best = best.min(estimate))
+                    .or_insert(estimate);
+                if best_cost.get(&next_key).is_some_and(|best| *best <= cost) {
+                    continue;
+                }
+                best_cost.insert(next_key, cost);
+                nodes.push(SearchNode {
+                    state,
+                    action: Some(index),
+                    parent: Some(entry.node),
+                    cost,
+                });
+                open.push(OpenEntry {
+                    estimate,
+                    cost,
+                    node: nodes.len() - 1,
+                });
             }
+        };
 
-            closed_list.push(current.clone());
+        // Walk back from the goal to recover the plan
+        let mut steps = Vec::new();
+        let mut cursor = goal_node;
+        while let Some(node) = cursor {
+            if let Some(action) = nodes[node].action {
+                steps.push(action);
+            }
+            cursor = nodes[node].parent;
+        }
+        steps.reverse();
+        let cost = goal_node.map_or(0.0, |node| nodes[node].cost);
 
-            // Expand neighbors (try each action)
-            for action in &self.actions {
-                if !action.enabled || !action.preconditions_met(&current.state) {
-                    continue;
+        let mut rejected = Vec::new();
+        for action in self.actions.iter().filter(|action| !action.enabled) {
+            rejected.push((action.id.clone(), "Disabled".to_string()));
+        }
+        for (index, action) in actions.iter().enumerate() {
+            if steps.contains(&index) {
+                continue;
+            }
+            let reason = if !runnable.contains(&index) {
+                let needs: Vec<String> = action
+                    .preconditions
+                    .iter()
+                    .filter(|(key, value)| {
+                        self.world_state.get(*key).copied().unwrap_or(false) != **value
+                    })
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect();
+                format!("Preconditions never met ({})", needs.join(", "))
+            } else if let Some(estimate) = through.get(&index) {
+                if goal_node.is_some() {
+                    format!("Only on costlier plans (at least {:.1})", estimate)
+                } else {
+                    format!("Leads nowhere within the search (at least {:.1})", estimate)
                 }
+            } else {
+                "Changes nothing whenever it can run".to_string()
+            };
+            rejected.push((action.id.clone(), reason));
+        }
 
-                let mut new_state = current.state.clone();
-                action.apply_effects(&mut new_state);
+        PlanReport {
+            goal: goal.id.clone(),
+            outcome,
+            plan: steps
+                .into_iter()
+                .map(|index| actions[index].id.clone())
+                .collect(),
+            cost,
+            nodes_expanded: expanded,
+            elapsed: started.elapsed(),
+            rejected,
+            cached: false,
+        }
+    }
 
-                // Skip if already in closed list
-                if closed_list.iter().any(|n| n.state == new_state) {
-                    continue;
-                }
+    /// Plan a sequence of actions to achieve a goal using A*
+    ///
+    /// Results are cached per goal and reused while the world state, the goal
+    /// and the actions stay the same. Goals found to be unreachable are cached
+    /// too, but searches cut short by a budget are tried again next time.
+    pub fn plan(&mut self, goal: &GoapGoal) -> Option<VecDeque<String>> {
+        self.plan_within(goal, self.limits);
+        self.last_report
+            .as_ref()
+            .filter(|report| report.outcome == SearchOutcome::Found)
+            .map(|report| report.plan.iter().cloned().collect())
+    }
 
-                let new_cost = current.cost + action.cost;
-                let new_heuristic = self.heuristic(&new_state, &goal.desired_state);
-
-                let new_node = PlanNode {
-                    state: new_state,
-                    action: Some(action.id.clone()),
-                    parent: Some(closed_list.len() - 1),
-                    cost: new_cost,
-                    heuristic: new_heuristic,
-                };
-
-                // Check if this state is already in open list with higher cost
-                if let Some(existing) = open_list.iter_mut().find(|n| n.state == new_node.state) {
-                    if new_cost < existing.cost {
-                        *existing = new_node;
-                    }
-                } else {
-                    open_list.push(new_node);
-                }
+    /// Plan for a goal within the given budgets, leaving the result in `last_report`
+    fn plan_within(&mut self, goal: &GoapGoal, limits: SearchLimits) {
+        if let Some(cached) = self.plan_cache.get(&goal.id) {
+            if cached.start == self.world_state
+                && cached.goal == *goal
+                && cached.actions == self.actions
+            {
+                let mut report = cached.report.clone();
+                report.cached = true;
+                self.last_report = Some(report);
+                return;
             }
         }
 
-        None // No plan found
+        let report = self.search(goal, limits);
+        if matches!(
+            report.outcome,
+            SearchOutcome::Found | SearchOutcome::Unreachable
+        ) {
+            self.plan_cache.insert(
+                goal.id.clone(),
+                CachedPlan {
+                    start: self.world_state.clone(),
+                    goal: goal.clone(),
+                    actions: self.actions.clone(),
+                    report: report.clone(),
+                },
+            );
+        }
+        self.last_report = Some(report);
+    }
+
+    /// Forget cached plans so every goal is searched afresh
+    pub fn clear_plan_cache(&mut self) {
+        self.plan_cache.clear();
     }
 
     /// Update the planner - select goal and create plan if needed
+    ///
+    /// Goals are tried from the highest priority down until one can be
+    /// planned, all searches together staying within `limits`.
     pub fn update(&mut self) -> bool {
         // If we have a plan and it's still valid, keep executing it
         if !self.current_plan.is_empty() {
@@ -415,19 +671,39 @@ impl GoapPlanner {
             return true;
         }
 
-        // Select a new unsatisfied goal
-        let goal = match self.select_goal() {
-            Some(g) => g.clone(),
-            None => return false,
-        };
-
-        let goal_id = goal.id.clone();
-
-        // Try to create a plan
-        if let Some(plan) = self.plan(&goal) {
-            self.current_plan = plan;
-            self.current_goal = Some(goal_id);
-            return true;
+        let mut goals: Vec<GoapGoal> = self
+            .goals
+            .iter()
+            .filter(|g| g.active && !g.is_satisfied(&self.world_state))
+            .cloned()
+            .collect();
+        goals.sort_by_key(|g| std::cmp::Reverse(g.priority));
+
+        let started = Instant::now();
+        let mut nodes_left = self.limits.max_nodes;
+        for goal in goals {
+            let limits = SearchLimits {
+                max_nodes: nodes_left,
+                max_time: self.limits.max_time.saturating_sub(started.elapsed()),
+            };
+            self.plan_within(&goal, limits);
+            let Some(report) = &self.last_report else {
+                break;
+            };
+            match report.outcome {
+                SearchOutcome::Found => {
+                    self.current_plan = report.plan.iter().cloned().collect();
+                    self.current_goal = Some(goal.id);
+                    return true;
+                }
+                SearchOutcome::Unreachable => {
+                    if !report.cached {
+                        nodes_left = nodes_left.saturating_sub(report.nodes_expanded);
+                    }
+                }
+                // Out of budget for this update
+                SearchOutcome::NodeLimit | SearchOutcome::TimeLimit => break,
+            }
         }
 
         false
@@ -438,6 +714,20 @@ impl GoapPlanner {
         self.current_plan.pop_front()
     }
 
+    /// Abandon the current plan and action so the next update plans afresh
+    pub fn replan(&mut self) {
+        self.current_plan.clear();
+        self.current_action = None;
+    }
+
+    /// Room the NPC is patrolling toward
+    pub fn next_waypoint(&self) -> Option<EntityId> {
+        if self.patrol_route.is_empty() {
+            return None;
+        }
+        Some(self.patrol_route[self.patrol_index % self.patrol_route.len()])
+    }
+
     /// Get action by ID
     pub fn get_action(&self, action_id: &str) -> Option<&GoapAction> {
         self.actions.iter().find(|a| a.id == action_id)
@@ -636,4 +926,114 @@ mod tests {
         assert_eq!(planner.next_action(), Some("to_waypoint".to_string()));
         assert_eq!(planner.next_action(), Some("from_waypoint".to_string()));
     }
+
+    #[test]
+    fn test_goap_planner_prefers_cheapest_plan() {
+        let mut planner = GoapPlanner::new();
+        planner.add_action(
+            GoapAction::new("teleport", "Teleport inside")
+                .with_effect("inside", true)
+                .with_cost(10.0),
+        );
+        planner.add_action(
+            GoapAction::new("walk", "Walk to the door")
+                .with_effect("at_door", true)
+                .with_cost(1.0),
+        );
+        planner.add_action(
+            GoapAction::new("enter", "Go through the door")
+                .with_precondition("at_door", true)
+                .with_effect("inside", true)
+                .with_cost(1.0),
+        );
+        planner.add_action(
+            GoapAction::new("unlock", "Unlock the door")
+                .with_precondition("has_key", true)
+                .with_effect("door_unlocked", true),
+        );
+        let goal = GoapGoal::new("be_inside", "Be inside", 10).with_condition("inside", true);
+
+        let report = planner.search(&goal, SearchLimits::default());
+        assert_eq!(report.outcome, SearchOutcome::Found);
+        assert_eq!(report.plan, vec!["walk".to_string(), "enter".to_string()]);
+        assert_eq!(report.cost, 2.0);
+
+        let reason = |id: &str| {
+            report
+                .rejected
+                .iter()
+                .find(|(action, _)| action == id)
+                .map(|(_, reason)| reason.clone())
+                .unwrap()
+        };
+        assert!(reason("teleport").starts_with("Only on costlier plans"));
+        assert!(reason("unlock").contains("has_key=true"));
+    }
+
+    #[test]
+    fn test_goap_search_budgets() {
+        let mut planner = GoapPlanner::new();
+        for step in 0..5 {
+            let mut action = GoapAction::new(format!("step{}", step), "Step")
+                .with_effect(format!("done{}", step), true);
+            if step > 0 {
+                action = action.with_precondition(format!("done{}", step - 1), true);
+            }
+            planner.add_action(action);
+        }
+        planner.add_goal(GoapGoal::new("finish", "Finish", 10).with_condition("done4", true));
+
+        planner.limits.max_nodes = 2;
+        assert!(!planner.update());
+        let report = planner.last_report.clone().unwrap();
+        assert_eq!(report.outcome, SearchOutcome::NodeLimit);
+        assert_eq!(report.nodes_expanded, 2);
+
+        // Budget failures are not cached, so more budget finds the plan
+        planner.limits.max_nodes = DEFAULT_MAX_NODES;
+        assert!(planner.update());
+        assert_eq!(planner.current_plan.len(), 5);
+        assert!(!planner.last_report.as_ref().unwrap().cached);
+    }
+
+    #[test]
+    fn test_goap_plan_cache() {
+        let mut planner = GoapPlanner::new();
+        planner.add_action(
+            GoapAction::new("eat", "Eat")
+                .with_precondition("has_food", true)
+                .with_effect("fed", true),
+        );
+        let goal = GoapGoal::new("be_fed", "Be fed", 10).with_condition("fed", true);
+
+        assert!(planner.plan(&goal).is_none());
+        assert_eq!(
+            planner.last_report.as_ref().unwrap().outcome,
+            SearchOutcome::Unreachable
+        );
+        assert!(planner.plan(&goal).is_none());
+        assert!(planner.last_report.as_ref().unwrap().cached);
+
+        // A change of world state means searching again
+        planner.set_state("has_food", true);
+        assert_eq!(
+            planner.plan(&goal).unwrap(),
+            VecDeque::from(["eat".to_string()])
+        );
+        assert!(!planner.last_report.as_ref().unwrap().cached);
+        assert!(planner.plan(&goal).is_some());
+        assert!(planner.last_report.as_ref().unwrap().cached);
+    }
+
+    #[test]
+    fn test_goap_update_falls_back_to_reachable_goal() {
+        let mut planner = GoapPlanner::new();
+        planner.add_action(GoapAction::new("nap", "Nap").with_effect("rested", true));
+        planner.add_goal(GoapGoal::new("fly", "Fly", 20).with_condition("flying", true));
+        planner.add_goal(GoapGoal::new("rest", "Rest", 5).with_condition("rested", true));
+
+        assert!(planner.update());
+        assert_eq!(planner.current_goal.as_deref(), Some("rest"));
+        assert_eq!(planner.next_action(), Some("nap".to_string()));
+    }
 }
@@ -31,11 +31,11 @@ pub use self::builder::CharacterBuilder;
 pub use self::nationality::Nationality;
 pub use self::skills::{
     Skill, SkillCategory, SkillDifficulty, SkillEntry, Skills, chargen_skill_cost,
-    chargen_total_skill_cost, skill_experience_floor_for_level, skill_knowledge_cap_for_level,
-    skill_level_from_experience,
+    chargen_total_skill_cost, skill_experience_floor_for_level, skill_experience_gain,
+    skill_knowledge_cap_for_level, skill_level_from_experience, skill_rank_name,
 };
 pub use self::talents::{
-    Talent, TalentCategory, TalentEntry, Talents, talent_experience_floor_for_rank,
+    Talent, TalentCategory, TalentEffect, TalentEntry, Talents, talent_experience_floor_for_rank,
     talent_rank_from_experience,
 };
 use serde::{Deserialize, Serialize};
@@ -56,6 +56,38 @@ impl Default for Memory {
     }
 }
 
+/// How an entity is holding itself, which affects how quickly it recovers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Posture {
+    #[default]
+    Standing,
+    Sitting,
+    Resting,
+    Sleeping,
+}
+
+impl Posture {
+    /// Multiplier applied to health and energy regeneration
+    pub fn regen_multiplier(&self) -> f32 {
+        match self {
+            Posture::Standing => 1.0,
+            Posture::Sitting => 1.5,
+            Posture::Resting => 2.0,
+            Posture::Sleeping => 3.0,
+        }
+    }
+
+    /// Description used in status output ("You are sitting.")
+    pub fn label(&self) -> &'static str {
+        match self {
+            Posture::Standing => "standing",
+            Posture::Sitting => "sitting",
+            Posture::Resting => "resting",
+            Posture::Sleeping => "sleeping",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -819,6 +819,44 @@ pub fn skill_knowledge_cap_for_level(level: i32, difficulty: SkillDifficulty) ->
     (difficulty.difficulty() * (level + 1).pow(2)) - 1
 }
 
+/// Experience gained per use of a skill, before rounding
+///
+/// Unlike [`experience_gain`] the knowledge ratio is kept fractional, so every
+/// point of knowledge speeds up learning rather than only a full cap's worth.
+///
+/// ## Equation
+/// ΔE = B * (1 + min(K/M, 1))
+pub fn skill_experience_gain(
+    base: f32,
+    knowledge: i32,
+    level: i32,
+    difficulty: SkillDifficulty,
+) -> f32 {
+    let cap = skill_knowledge_cap_for_level(level, difficulty);
+    if cap <= 0 {
+        base
+    } else {
+        base * (1.0 + (knowledge.max(0) as f32 / cap as f32).min(1.0))
+    }
+}
+
+/// Name of a skill level, from Untrained (0) to Legendary (10)
+pub fn skill_rank_name(level: i32) -> &'static str {
+    match level {
+        i32::MIN..=0 => "Untrained",
+        1 => "Apprentice",
+        2 => "Novice",
+        3 => "Initiate",
+        4 => "Adept",
+        5 => "Journeyman",
+        6 => "Master",
+        7 => "Expert",
+        8 => "Paragon",
+        9 => "Mythical",
+        _ => "Legendary",
+    }
+}
+
 /// Experience gained per action
 ///
 /// ## Equation
@@ -901,6 +939,22 @@ mod tests {
         assert_eq!(skill_level_from_experience(500, skill.difficulty()), 10); // Still capped at 10
     }
 
+    #[test]
+    fn test_skill_experience_gain_scales_with_knowledge() {
+        let difficulty = SkillDifficulty::Moderate;
+        let cap = skill_knowledge_cap_for_level(1, difficulty);
+
+        assert_eq!(skill_experience_gain(2.0, 0, 1, difficulty), 2.0);
+        assert_eq!(skill_experience_gain(2.0, cap, 1, difficulty), 4.0);
+        assert_eq!(skill_experience_gain(2.0, cap * 3, 1, difficulty), 4.0);
+        let partial = skill_experience_gain(2.0, cap / 2, 1, difficulty);
+        assert!(partial > 2.0 && partial < 4.0);
+
+        assert_eq!(skill_rank_name(0), "Untrained");
+        assert_eq!(skill_rank_name(5), "Journeyman");
+        assert_eq!(skill_rank_name(10), "Legendary");
+    }
+
     #[test]
     fn test_difficulty_levels() {
         assert_eq!(SkillDifficulty::VeryEasy.difficulty(), 1);
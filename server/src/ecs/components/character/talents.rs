@@ -14,6 +14,7 @@
 // limitations under the License.
 //
 
+use super::skills::Skill;
 use crate::define_talents;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
@@ -224,6 +225,67 @@ define_talents! {
     },
 }
 
+/// A mechanical effect a talent has on play
+///
+/// Effects that only matter to one system, like Spellweaver's casting bonus
+/// or Dual Wielder's off-hand accuracy, live in that system instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TalentEffect {
+    /// Bonus to checks with any of the listed skills
+    SkillBonus(&'static [Skill], i32),
+    /// Bonus damage on every hit with a weapon
+    WeaponDamage(i32),
+    /// Bonus damage while health is at or below a fraction of its maximum
+    Rage { threshold: f32, damage: i32 },
+    /// Added to the chance of landing a critical hit
+    CriticalChance(f32),
+    /// Bonus to initiative
+    Initiative(i32),
+    /// Multiplier on health regeneration
+    HealthRegen(f32),
+    /// Multiplier on the duration of harmful status effects
+    HarmfulDuration(f32),
+    /// Natural 1s on checks are rolled again
+    RerollFumbles,
+}
+
+impl Talent {
+    /// Mechanical effects of this talent
+    pub fn effects(&self) -> &'static [TalentEffect] {
+        use TalentEffect::*;
+        match self {
+            Talent::WeaponMaster => &[WeaponDamage(2)],
+            Talent::Berserker => &[Rage {
+                threshold: 0.5,
+                damage: 4,
+            }],
+            Talent::Tactician => &[
+                Initiative(5),
+                SkillBonus(&[Skill::Dodging, Skill::Parrying, Skill::Shields], 1),
+            ],
+            Talent::SilverTongue => &[SkillBonus(
+                &[Skill::Persuasion, Skill::Deception, Skill::Bartering],
+                3,
+            )],
+            Talent::NaturalLeader => &[SkillBonus(&[Skill::Leadership, Skill::Performance], 3)],
+            Talent::Intimidating => &[SkillBonus(&[Skill::Intimidation], 3)],
+            Talent::Empathic => &[SkillBonus(&[Skill::Insight, Skill::AnimalHandling], 3)],
+            Talent::Streetwise => &[SkillBonus(&[Skill::Stealth, Skill::Lockpicking], 2)],
+            Talent::Woodsman => &[SkillBonus(&[Skill::Navigation, Skill::Nature], 2)],
+            Talent::Tracker => &[SkillBonus(&[Skill::Tracking], 3)],
+            Talent::Scout => &[SkillBonus(&[Skill::Stealth, Skill::Navigation], 2)],
+            Talent::Forager => &[SkillBonus(
+                &[Skill::Foraging, Skill::Herbalism, Skill::Fishing],
+                2,
+            )],
+            Talent::Hardy => &[HealthRegen(1.5)],
+            Talent::Resilient => &[HarmfulDuration(0.5)],
+            Talent::Lucky => &[RerollFumbles, CriticalChance(0.05)],
+            _ => &[],
+        }
+    }
+}
+
 /// An individual talent acquired by a character.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TalentEntry {
@@ -323,6 +385,82 @@ impl Talents {
         self.0.len()
     }
 
+    /// Every effect of every acquired talent
+    pub fn effects(&self) -> impl Iterator<Item = (Talent, TalentEffect)> + '_ {
+        self.0
+            .keys()
+            .flat_map(|talent| talent.effects().iter().map(|effect| (*talent, *effect)))
+    }
+
+    /// Check bonuses each talent gives to a skill
+    pub fn skill_bonuses(&self, skill: Skill) -> Vec<(Talent, i32)> {
+        self.effects()
+            .filter_map(|(talent, effect)| match effect {
+                TalentEffect::SkillBonus(skills, bonus) if skills.contains(&skill) => {
+                    Some((talent, bonus))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Bonus damage on a weapon hit, given the attacker's remaining fraction of health
+    pub fn damage_bonus(&self, armed: bool, health_fraction: f32) -> i32 {
+        self.effects()
+            .map(|(_, effect)| match effect {
+                TalentEffect::WeaponDamage(damage) if armed => damage,
+                TalentEffect::Rage { threshold, damage } if health_fraction <= threshold => damage,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Extra chance of landing a critical hit
+    pub fn critical_chance(&self) -> f32 {
+        self.effects()
+            .map(|(_, effect)| match effect {
+                TalentEffect::CriticalChance(chance) => chance,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Bonus to initiative
+    pub fn initiative(&self) -> i32 {
+        self.effects()
+            .map(|(_, effect)| match effect {
+                TalentEffect::Initiative(bonus) => bonus,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Multiplier on health regeneration
+    pub fn health_regen(&self) -> f32 {
+        self.effects()
+            .map(|(_, effect)| match effect {
+                TalentEffect::HealthRegen(factor) => factor,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    /// Multiplier on the duration of harmful status effects
+    pub fn harmful_duration(&self) -> f32 {
+        self.effects()
+            .map(|(_, effect)| match effect {
+                TalentEffect::HarmfulDuration(factor) => factor,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    /// Whether natural 1s on checks are rolled again
+    pub fn rerolls_fumbles(&self) -> bool {
+        self.effects()
+            .any(|(_, effect)| effect == TalentEffect::RerollFumbles)
+    }
+
     /// Add experience to a specific talent.
     pub fn add_talent_experience(&mut self, talent: Talent, experience: i32) {
         self.0
@@ -347,3 +485,31 @@ pub fn talent_rank_from_experience(experience: i32) -> u8 {
 pub fn talent_experience_floor_for_rank(rank: u8) -> i32 {
     (C * (E * (rank as f32 / A) - 1.0)) as i32
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_talent_effects_combine() {
+        let mut talents = Talents::new();
+        assert_eq!(talents.damage_bonus(true, 0.1), 0);
+        assert_eq!(talents.health_regen(), 1.0);
+        assert!(!talents.rerolls_fumbles());
+
+        talents.add_talent(Talent::Scout, 0);
+        talents.add_talent(Talent::Streetwise, 0);
+        talents.add_talent(Talent::Lucky, 0);
+        talents.add_talent(Talent::WeaponMaster, 0);
+        talents.add_talent(Talent::Berserker, 0);
+        assert_eq!(
+            talents.skill_bonuses(Skill::Stealth),
+            vec![(Talent::Streetwise, 2), (Talent::Scout, 2)]
+        );
+        assert!(talents.rerolls_fumbles());
+        assert_eq!(talents.critical_chance(), 0.05);
+        assert_eq!(talents.damage_bonus(false, 1.0), 0);
+        assert_eq!(talents.damage_bonus(true, 1.0), 2);
+        assert_eq!(talents.damage_bonus(true, 0.5), 6);
+    }
+}
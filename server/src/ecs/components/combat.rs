@@ -16,7 +16,7 @@
 
 //! Combat components for fighting and equipment
 
-use super::EntityId;
+use super::{EntityId, Skill};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -51,6 +51,81 @@ impl StatusEffectType {
             StatusEffectType::Hasted => "Hasted",
         }
     }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Stunned" => Some(StatusEffectType::Stunned),
+            "Poisoned" => Some(StatusEffectType::Poisoned),
+            "Burning" => Some(StatusEffectType::Burning),
+            "Bleeding" => Some(StatusEffectType::Bleeding),
+            "Defending" => Some(StatusEffectType::Defending),
+            "Weakened" => Some(StatusEffectType::Weakened),
+            "Strengthened" => Some(StatusEffectType::Strengthened),
+            "Slowed" => Some(StatusEffectType::Slowed),
+            "Hasted" => Some(StatusEffectType::Hasted),
+            _ => None,
+        }
+    }
+}
+
+/// Seconds between the ticks of a damage-over-time effect
+pub const STATUS_TICK_INTERVAL: f32 = 3.0;
+
+/// Highest magnitude stacking effects can build up to
+pub const MAX_STACKED_MAGNITUDE: i32 = 10;
+
+/// Attack speed gained or lost per point of Hasted or Slowed
+pub const SPEED_PER_MAGNITUDE: f32 = 0.25;
+
+/// How a new status effect combines with one of the same type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectStacking {
+    /// The new effect replaces the old one
+    Replace,
+    /// Magnitudes add up, to [`MAX_STACKED_MAGNITUDE`], keeping the longer duration
+    Stack,
+    /// The stronger magnitude and the longer duration are kept
+    Strongest,
+}
+
+impl StatusEffectType {
+    /// How repeat applications of this effect combine
+    pub fn stacking(&self) -> EffectStacking {
+        match self {
+            StatusEffectType::Poisoned | StatusEffectType::Bleeding => EffectStacking::Stack,
+            StatusEffectType::Defending => EffectStacking::Replace,
+            _ => EffectStacking::Strongest,
+        }
+    }
+
+    /// Damage dealt each tick by damage-over-time effects
+    pub fn damage_type(&self) -> Option<DamageType> {
+        match self {
+            StatusEffectType::Poisoned => Some(DamageType::Poison),
+            StatusEffectType::Burning => Some(DamageType::Fire),
+            StatusEffectType::Bleeding => Some(DamageType::Slashing),
+            _ => None,
+        }
+    }
+
+    /// Whether the effect hinders whoever has it
+    pub fn is_harmful(&self) -> bool {
+        !matches!(
+            self,
+            StatusEffectType::Defending | StatusEffectType::Strengthened | StatusEffectType::Hasted
+        )
+    }
+
+    /// Effect that this one cancels out when applied
+    pub fn opposite(&self) -> Option<StatusEffectType> {
+        match self {
+            StatusEffectType::Weakened => Some(StatusEffectType::Strengthened),
+            StatusEffectType::Strengthened => Some(StatusEffectType::Weakened),
+            StatusEffectType::Slowed => Some(StatusEffectType::Hasted),
+            StatusEffectType::Hasted => Some(StatusEffectType::Slowed),
+            _ => None,
+        }
+    }
 }
 
 /// Individual status effect
@@ -59,6 +134,10 @@ pub struct StatusEffect {
     pub effect_type: StatusEffectType,
     pub duration: f32,
     pub magnitude: i32,
+    /// Entity that inflicted the effect, credited with any kill it causes
+    pub source: Option<EntityId>,
+    /// Seconds until a damage-over-time effect next deals damage
+    pub tick_timer: f32,
 }
 
 impl StatusEffect {
@@ -67,8 +146,24 @@ impl StatusEffect {
             effect_type,
             duration,
             magnitude,
+            source: None,
+            tick_timer: STATUS_TICK_INTERVAL,
         }
     }
+
+    pub fn with_source(mut self, source: EntityId) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+/// What happened to an entity's status effects over one update
+#[derive(Debug, Clone, Default)]
+pub struct StatusUpdate {
+    /// Damage-over-time effects due to deal their magnitude in damage
+    pub ticked: Vec<StatusEffect>,
+    /// Effects that ran out
+    pub expired: Vec<StatusEffectType>,
 }
 
 /// Status effects component
@@ -84,14 +179,60 @@ impl StatusEffects {
         }
     }
 
-    pub fn add_effect(&mut self, effect: StatusEffect) {
-        // Remove existing effect of same type
-        self.effects.retain(|e| e.effect_type != effect.effect_type);
-        self.effects.push(effect);
+    /// Apply an effect, following its stacking rule
+    ///
+    /// An effect cancels out its opposite. Returns whether the effect is new
+    /// rather than added to one already present.
+    pub fn add_effect(&mut self, effect: StatusEffect) -> bool {
+        if let Some(opposite) = effect.effect_type.opposite() {
+            self.remove_effect(opposite);
+        }
+        let Some(existing) = self
+            .effects
+            .iter_mut()
+            .find(|e| e.effect_type == effect.effect_type)
+        else {
+            self.effects.push(effect);
+            return true;
+        };
+        match effect.effect_type.stacking() {
+            EffectStacking::Replace => *existing = effect,
+            EffectStacking::Stack => {
+                existing.magnitude =
+                    (existing.magnitude + effect.magnitude).min(MAX_STACKED_MAGNITUDE);
+                existing.duration = existing.duration.max(effect.duration);
+                existing.source = effect.source.or(existing.source);
+            }
+            EffectStacking::Strongest => {
+                if effect.magnitude >= existing.magnitude {
+                    existing.magnitude = effect.magnitude;
+                    existing.source = effect.source.or(existing.source);
+                }
+                existing.duration = existing.duration.max(effect.duration);
+            }
+        }
+        false
+    }
+
+    /// Remove an effect, returning it if it was present
+    pub fn remove_effect(&mut self, effect_type: StatusEffectType) -> Option<StatusEffect> {
+        let index = self
+            .effects
+            .iter()
+            .position(|e| e.effect_type == effect_type)?;
+        Some(self.effects.remove(index))
     }
 
-    pub fn remove_effect(&mut self, effect_type: StatusEffectType) {
-        self.effects.retain(|e| e.effect_type != effect_type);
+    /// Remove every harmful effect, returning the types cured
+    pub fn cure_harmful(&mut self) -> Vec<StatusEffectType> {
+        let cured = self
+            .effects
+            .iter()
+            .map(|e| e.effect_type)
+            .filter(StatusEffectType::is_harmful)
+            .collect();
+        self.effects.retain(|e| !e.effect_type.is_harmful());
+        cured
     }
 
     pub fn has_effect(&self, effect_type: StatusEffectType) -> bool {
@@ -102,12 +243,36 @@ impl StatusEffects {
         self.effects.iter().find(|e| e.effect_type == effect_type)
     }
 
-    pub fn update(&mut self, delta_time: f32) {
-        // Update durations and remove expired effects
+    /// Magnitude of an effect, zero when it is absent
+    pub fn magnitude(&self, effect_type: StatusEffectType) -> i32 {
+        self.get_effect(effect_type).map_or(0, |e| e.magnitude)
+    }
+
+    /// Multiplier on how quickly combat actions come around
+    pub fn speed_factor(&self) -> f32 {
+        let hasted = self.magnitude(StatusEffectType::Hasted).max(0) as f32;
+        let slowed = self.magnitude(StatusEffectType::Slowed).max(0) as f32;
+        (1.0 + hasted * SPEED_PER_MAGNITUDE) / (1.0 + slowed * SPEED_PER_MAGNITUDE)
+    }
+
+    /// Advance durations and damage-over-time ticks, removing expired effects
+    pub fn update(&mut self, delta_time: f32) -> StatusUpdate {
+        let mut update = StatusUpdate::default();
         for effect in &mut self.effects {
             effect.duration -= delta_time;
+            if effect.effect_type.damage_type().is_some() {
+                effect.tick_timer -= delta_time;
+                if effect.tick_timer <= 0.0 {
+                    effect.tick_timer += STATUS_TICK_INTERVAL;
+                    update.ticked.push(effect.clone());
+                }
+            }
+            if effect.duration <= 0.0 {
+                update.expired.push(effect.effect_type);
+            }
         }
         self.effects.retain(|e| e.duration > 0.0);
+        update
     }
 }
 
@@ -178,6 +343,105 @@ impl Default for Combatant {
     }
 }
 
+/// One hostile entity and how much it has angered the table's owner
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ThreatEntry {
+    pub source: EntityId,
+    pub threat: f32,
+}
+
+/// Every hostile an entity is engaged with, by threat
+///
+/// Runtime state only: engagements are rebuilt by fighting and do not
+/// survive a restart. Entries are matched by their ECS entity handle.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThreatTable {
+    pub entries: Vec<ThreatEntry>,
+}
+
+impl ThreatTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add threat from a hostile, engaging it if it wasn't already
+    pub fn add(&mut self, source: EntityId, amount: f32) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.source.entity() == source.entity())
+        {
+            Some(entry) => entry.threat = (entry.threat + amount).max(0.0),
+            None => self.entries.push(ThreatEntry {
+                source,
+                threat: amount.max(0.0),
+            }),
+        }
+    }
+
+    /// Stop tracking a hostile, returning the threat it had built up
+    pub fn remove(&mut self, entity: hecs::Entity) -> Option<f32> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.source.entity() == entity)?;
+        Some(self.entries.remove(index).threat)
+    }
+
+    pub fn contains(&self, entity: hecs::Entity) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.source.entity() == entity)
+    }
+
+    /// Threat a hostile has built up, zero if it isn't engaged
+    pub fn threat_of(&self, entity: hecs::Entity) -> f32 {
+        self.entries
+            .iter()
+            .find(|entry| entry.source.entity() == entity)
+            .map_or(0.0, |entry| entry.threat)
+    }
+
+    /// The hostile with the most threat; the earliest engaged wins ties
+    pub fn highest(&self) -> Option<EntityId> {
+        self.entries
+            .iter()
+            .fold(None::<&ThreatEntry>, |best, entry| match best {
+                Some(best) if best.threat >= entry.threat => Some(best),
+                _ => Some(entry),
+            })
+            .map(|entry| entry.source)
+    }
+
+    pub fn hostiles(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entries.iter().map(|entry| entry.source)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Remains of a dead entity, holding what it carried until it decays
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Corpse {
+    /// Seconds left before the corpse and its contents decay
+    pub decay_remaining: f32,
+}
+
+impl Corpse {
+    /// Create a corpse that decays after the given number of seconds
+    pub fn new(decay_seconds: f32) -> Self {
+        Self {
+            decay_remaining: decay_seconds,
+        }
+    }
+}
+
 /// Equipment slots
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EquipSlot {
@@ -197,6 +461,45 @@ pub enum EquipSlot {
 }
 
 impl EquipSlot {
+    /// All slots, in head-to-toe display order
+    pub const ALL: [EquipSlot; 13] = [
+        EquipSlot::Head,
+        EquipSlot::Neck,
+        EquipSlot::Back,
+        EquipSlot::Chest,
+        EquipSlot::Hands,
+        EquipSlot::Ring1,
+        EquipSlot::Ring2,
+        EquipSlot::MainHand,
+        EquipSlot::OffHand,
+        EquipSlot::Wings,
+        EquipSlot::Tail,
+        EquipSlot::Legs,
+        EquipSlot::Feet,
+    ];
+
+    /// Slots used by held items
+    pub const HELD: [EquipSlot; 2] = [EquipSlot::MainHand, EquipSlot::OffHand];
+
+    /// Human readable slot name
+    pub fn label(&self) -> &'static str {
+        match self {
+            EquipSlot::Head => "head",
+            EquipSlot::Chest => "chest",
+            EquipSlot::Legs => "legs",
+            EquipSlot::Feet => "feet",
+            EquipSlot::Hands => "hands",
+            EquipSlot::MainHand => "main hand",
+            EquipSlot::OffHand => "off hand",
+            EquipSlot::Ring1 => "finger",
+            EquipSlot::Ring2 => "finger",
+            EquipSlot::Neck => "neck",
+            EquipSlot::Back => "back",
+            EquipSlot::Tail => "tail",
+            EquipSlot::Wings => "wings",
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             EquipSlot::Head => "Head",
@@ -290,28 +593,116 @@ impl Equipable {
     }
 }
 
+/// Bonuses to skill checks granted while an item is equipped
+/// Maps to: entity_skill_bonus table (one row per skill)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillBonus {
+    pub bonuses: HashMap<Skill, i32>,
+}
+
+impl SkillBonus {
+    /// Create an empty set of bonuses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bonus (or penalty) to a skill
+    pub fn with_bonus(mut self, skill: Skill, bonus: i32) -> Self {
+        self.bonuses.insert(skill, bonus);
+        self
+    }
+
+    /// Get the bonus to a skill, zero when there is none
+    pub fn get(&self, skill: Skill) -> i32 {
+        self.bonuses.get(&skill).copied().unwrap_or(0)
+    }
+}
+
+/// Ways a defender can avoid an incoming attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DefenseReaction {
+    /// Get out of the way
+    Dodge,
+    /// Turn the blow aside with a weapon
+    Parry,
+    /// Catch the blow on a shield
+    Block,
+}
+
+impl DefenseReaction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DefenseReaction::Dodge => "Dodge",
+            DefenseReaction::Parry => "Parry",
+            DefenseReaction::Block => "Block",
+        }
+    }
+
+    /// Skill checked to react this way
+    pub fn skill(&self) -> Skill {
+        match self {
+            DefenseReaction::Dodge => Skill::Dodging,
+            DefenseReaction::Parry => Skill::Parrying,
+            DefenseReaction::Block => Skill::Shields,
+        }
+    }
+}
+
 /// Damage types
+/// Maps to: damage_type enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DamageType {
     Blunt,
     Piercing,
     Slashing,
+    Cold,
+    Poison,
     Fire,
+    Necrotic,
+    Radiant,
+    Electric,
     Acid,
     Arcane,
     Psychic,
+    Sonic,
+    Force,
 }
 
 impl DamageType {
+    /// Every damage type, in database order
+    pub const ALL: [DamageType; 14] = [
+        DamageType::Blunt,
+        DamageType::Piercing,
+        DamageType::Slashing,
+        DamageType::Cold,
+        DamageType::Poison,
+        DamageType::Fire,
+        DamageType::Necrotic,
+        DamageType::Radiant,
+        DamageType::Electric,
+        DamageType::Acid,
+        DamageType::Arcane,
+        DamageType::Psychic,
+        DamageType::Sonic,
+        DamageType::Force,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             DamageType::Blunt => "Blunt",
             DamageType::Piercing => "Piercing",
             DamageType::Slashing => "Slashing",
+            DamageType::Cold => "Cold",
+            DamageType::Poison => "Poison",
             DamageType::Fire => "Fire",
+            DamageType::Necrotic => "Necrotic",
+            DamageType::Radiant => "Radiant",
+            DamageType::Electric => "Electric",
             DamageType::Acid => "Acid",
             DamageType::Arcane => "Arcane",
             DamageType::Psychic => "Psychic",
+            DamageType::Sonic => "Sonic",
+            DamageType::Force => "Force",
         }
     }
 
@@ -320,13 +711,28 @@ impl DamageType {
             "Blunt" => Some(DamageType::Blunt),
             "Piercing" => Some(DamageType::Piercing),
             "Slashing" => Some(DamageType::Slashing),
+            "Cold" => Some(DamageType::Cold),
+            "Poison" => Some(DamageType::Poison),
             "Fire" => Some(DamageType::Fire),
+            "Necrotic" => Some(DamageType::Necrotic),
+            "Radiant" => Some(DamageType::Radiant),
+            "Electric" => Some(DamageType::Electric),
             "Acid" => Some(DamageType::Acid),
             "Arcane" => Some(DamageType::Arcane),
             "Psychic" => Some(DamageType::Psychic),
+            "Sonic" => Some(DamageType::Sonic),
+            "Force" => Some(DamageType::Force),
             _ => None,
         }
     }
+
+    /// Whether this is physical damage from a blow, cut or stab
+    pub fn is_physical(&self) -> bool {
+        matches!(
+            self,
+            DamageType::Blunt | DamageType::Piercing | DamageType::Slashing
+        )
+    }
 }
 
 /// Weapon properties
@@ -389,6 +795,32 @@ impl MaterialKind {
             _ => None,
         }
     }
+
+    /// How well armor of this material holds up against a damage type
+    ///
+    /// Multiplies the armor's defense: metal turns blades but conducts
+    /// lightning, while mana-woven armor wards off magic but not blows.
+    pub fn resistance(&self, damage_type: DamageType) -> f32 {
+        use DamageType::*;
+        match (self, damage_type) {
+            (MaterialKind::Cloth, Blunt | Piercing | Slashing | Fire) => 0.5,
+            (MaterialKind::Leather, Blunt) => 1.25,
+            (MaterialKind::Leather, Piercing | Fire | Acid) => 0.75,
+            (MaterialKind::Chain, Slashing) => 1.5,
+            (MaterialKind::Chain, Piercing | Electric) => 0.5,
+            (MaterialKind::Chain, Blunt) => 0.75,
+            (MaterialKind::Iron, Slashing) => 1.25,
+            (MaterialKind::Iron, Electric | Acid) => 0.5,
+            (MaterialKind::Iron, Cold) => 0.75,
+            (MaterialKind::Steel, Slashing) => 1.5,
+            (MaterialKind::Steel, Piercing) => 1.25,
+            (MaterialKind::Steel, Electric) => 0.5,
+            (MaterialKind::Steel, Acid) => 0.75,
+            (MaterialKind::Mana, Arcane | Psychic | Force) => 1.5,
+            (MaterialKind::Mana, Blunt | Piercing | Slashing) => 0.5,
+            _ => 1.0,
+        }
+    }
 }
 
 /// Material component
@@ -451,6 +883,76 @@ mod tests {
         assert!(combatant.can_attack());
     }
 
+    #[test]
+    fn test_status_effect_stacking() {
+        let mut effects = StatusEffects::new();
+        assert!(effects.add_effect(StatusEffect::new(StatusEffectType::Poisoned, 6.0, 2)));
+        assert!(!effects.add_effect(StatusEffect::new(StatusEffectType::Poisoned, 9.0, 3)));
+        let poison = effects.get_effect(StatusEffectType::Poisoned).unwrap();
+        assert_eq!((poison.magnitude, poison.duration), (5, 9.0));
+
+        effects.add_effect(StatusEffect::new(StatusEffectType::Weakened, 30.0, 3));
+        effects.add_effect(StatusEffect::new(StatusEffectType::Weakened, 10.0, 1));
+        assert_eq!(effects.magnitude(StatusEffectType::Weakened), 3);
+
+        // Strength cancels weakness
+        effects.add_effect(StatusEffect::new(StatusEffectType::Strengthened, 30.0, 2));
+        assert!(!effects.has_effect(StatusEffectType::Weakened));
+        assert_eq!(effects.cure_harmful(), vec![StatusEffectType::Poisoned]);
+        assert!(effects.has_effect(StatusEffectType::Strengthened));
+    }
+
+    #[test]
+    fn test_status_effect_ticks_and_expiry() {
+        let mut effects = StatusEffects::new();
+        effects.add_effect(StatusEffect::new(StatusEffectType::Burning, 6.0, 2));
+        effects.add_effect(StatusEffect::new(StatusEffectType::Hasted, 4.0, 2));
+        assert_eq!(effects.speed_factor(), 1.5);
+
+        assert!(effects.update(2.0).ticked.is_empty());
+        let update = effects.update(2.0);
+        assert_eq!(update.ticked.len(), 1);
+        assert_eq!(update.expired, vec![StatusEffectType::Hasted]);
+        let update = effects.update(2.0);
+        assert_eq!(update.ticked.len(), 1);
+        assert_eq!(update.expired, vec![StatusEffectType::Burning]);
+        assert!(effects.effects.is_empty());
+    }
+
+    #[test]
+    fn test_material_resistance() {
+        assert_eq!(MaterialKind::Steel.resistance(DamageType::Slashing), 1.5);
+        assert_eq!(MaterialKind::Chain.resistance(DamageType::Piercing), 0.5);
+        assert_eq!(MaterialKind::Mana.resistance(DamageType::Arcane), 1.5);
+        assert_eq!(MaterialKind::Leather.resistance(DamageType::Radiant), 1.0);
+        for damage_type in DamageType::ALL {
+            assert_eq!(
+                DamageType::from_str(damage_type.as_str()),
+                Some(damage_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_threat_table() {
+        let mut world = hecs::World::new();
+        let wolf = EntityId::new(world.spawn(()), Uuid::new_v4());
+        let bear = EntityId::new(world.spawn(()), Uuid::new_v4());
+
+        let mut table = ThreatTable::new();
+        table.add(wolf, 5.0);
+        table.add(bear, 5.0);
+        assert_eq!(table.highest().map(|id| id.uuid()), Some(wolf.uuid()));
+
+        table.add(bear, 3.0);
+        assert_eq!(table.highest().map(|id| id.uuid()), Some(bear.uuid()));
+        assert_eq!(table.threat_of(bear.entity()), 8.0);
+
+        assert_eq!(table.remove(bear.entity()), Some(8.0));
+        assert!(!table.contains(bear.entity()));
+        assert_eq!(table.highest().map(|id| id.uuid()), Some(wolf.uuid()));
+    }
+
     #[test]
     fn test_equipment() {
         let mut equipment = Equipment::new();
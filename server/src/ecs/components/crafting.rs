@@ -0,0 +1,175 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Crafting components and recipe definitions
+//!
+//! Recipes are data: they are loaded from the `recipes` table into a
+//! [`RecipeRegistry`]. Ingredients and tools are item templates, and stations
+//! are fixtures in a room such as a forge or a loom.
+
+use super::{ItemTemplateRegistry, Skill, SkillCategory};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A fixture crafters can work at, such as a forge or a loom
+/// Maps to: entity_crafting_station table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CraftingStation {
+    pub station: String,
+}
+
+impl CraftingStation {
+    pub fn new(station: impl Into<String>) -> Self {
+        Self {
+            station: station.into(),
+        }
+    }
+}
+
+/// Items of one template used by a recipe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ingredient {
+    pub template_id: String,
+    pub quantity: u32,
+}
+
+impl Ingredient {
+    pub fn new(template_id: impl Into<String>, quantity: u32) -> Self {
+        Self {
+            template_id: template_id.into(),
+            quantity,
+        }
+    }
+}
+
+/// Definition of something that can be crafted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recipe {
+    pub name: String,
+    /// Crafting skill checked when crafting
+    pub skill: Skill,
+    /// Skill level needed to attempt the recipe
+    pub min_level: i32,
+    /// Check difficulty of the skill
+    pub difficulty: i32,
+    /// Items consumed by crafting
+    pub ingredients: Vec<Ingredient>,
+    /// Templates of tools that must be carried but are not consumed
+    pub tools: Vec<String>,
+    /// Station that must be in the room, if any
+    pub station: Option<String>,
+    /// Template of the item produced
+    pub output: String,
+    pub output_quantity: u32,
+}
+
+/// All recipes, keyed by lowercase name
+#[derive(Debug, Clone, Default)]
+pub struct RecipeRegistry {
+    recipes: HashMap<String, Recipe>,
+}
+
+impl RecipeRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a recipe
+    ///
+    /// Recipes must use a crafting skill, and every template they mention
+    /// must exist.
+    pub fn register(
+        &mut self,
+        recipe: Recipe,
+        templates: &ItemTemplateRegistry,
+    ) -> Result<(), String> {
+        if recipe.skill.category() != SkillCategory::Crafting {
+            return Err(format!(
+                "Recipe '{}' uses {}, which is not a crafting skill",
+                recipe.name,
+                recipe.skill.name()
+            ));
+        }
+        let missing = std::iter::once(&recipe.output)
+            .chain(recipe.ingredients.iter().map(|i| &i.template_id))
+            .chain(recipe.tools.iter())
+            .find(|id| !templates.contains(id));
+        if let Some(id) = missing {
+            return Err(format!(
+                "Recipe '{}' uses unknown item template '{}'",
+                recipe.name, id
+            ));
+        }
+        self.recipes.insert(recipe.name.to_lowercase(), recipe);
+        Ok(())
+    }
+
+    /// Look up a recipe by name, ignoring case
+    pub fn get(&self, name: &str) -> Option<&Recipe> {
+        self.recipes.get(&name.to_lowercase())
+    }
+
+    /// Iterate over every recipe
+    pub fn iter(&self) -> impl Iterator<Item = &Recipe> {
+        self.recipes.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.recipes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recipes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::ItemTemplate;
+
+    #[test]
+    fn test_registry_validates_skill_and_templates() {
+        let mut templates = ItemTemplateRegistry::new();
+        templates.register(ItemTemplate::new("iron_ingot", "an iron ingot", 1.0));
+        templates.register(ItemTemplate::new("iron_dagger", "an iron dagger", 1.0));
+        let recipe = Recipe {
+            name: "Iron Dagger".to_string(),
+            skill: Skill::Weaponsmithing,
+            min_level: 0,
+            difficulty: 10,
+            ingredients: vec![Ingredient::new("iron_ingot", 1)],
+            tools: vec![],
+            station: Some("forge".to_string()),
+            output: "iron_dagger".to_string(),
+            output_quantity: 1,
+        };
+
+        let mut registry = RecipeRegistry::new();
+        assert!(registry.register(recipe.clone(), &templates).is_ok());
+        assert!(registry.get("iron dagger").is_some());
+
+        let mut wrong_skill = recipe.clone();
+        wrong_skill.skill = Skill::Evocation;
+        assert!(registry.register(wrong_skill, &templates).is_err());
+
+        let mut missing_tool = recipe;
+        missing_tool.tools.push("hammer".to_string());
+        assert!(registry.register(missing_tool, &templates).is_err());
+        assert_eq!(registry.len(), 1);
+    }
+}
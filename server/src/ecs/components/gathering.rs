@@ -0,0 +1,180 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Resource gathering components
+//!
+//! Resource nodes are fixtures builders place in rooms, such as an ore vein
+//! or a fishing spot. Each yields items of the templates in its yield table
+//! until its charges run out, then replenishes after a while.
+
+use super::Skill;
+use serde::{Deserialize, Serialize};
+
+/// One entry in a resource node's yield table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceYield {
+    /// Item template gathered
+    pub template_id: String,
+    /// Relative chance of this entry being picked
+    pub weight: u32,
+    pub min_quantity: u32,
+    pub max_quantity: u32,
+    /// Skill level needed before this entry can be found
+    pub min_level: i32,
+}
+
+impl ResourceYield {
+    pub fn new(template_id: impl Into<String>, weight: u32) -> Self {
+        Self {
+            template_id: template_id.into(),
+            weight,
+            min_quantity: 1,
+            max_quantity: 1,
+            min_level: 0,
+        }
+    }
+
+    pub fn with_quantity(mut self, min: u32, max: u32) -> Self {
+        self.min_quantity = min;
+        self.max_quantity = max.max(min);
+        self
+    }
+
+    pub fn with_min_level(mut self, min_level: i32) -> Self {
+        self.min_level = min_level;
+        self
+    }
+}
+
+/// A place resources can be gathered from
+/// Maps to: entity_resource_node and entity_resource_yield tables
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceNode {
+    /// Gathering skill checked when gathering
+    pub skill: Skill,
+    /// Check difficulty of the skill
+    pub difficulty: i32,
+    /// Item template of the tool needed, if any
+    pub tool: Option<String>,
+    pub yields: Vec<ResourceYield>,
+    /// Successful gathers left before the node is depleted
+    pub charges: u32,
+    pub max_charges: u32,
+    /// Seconds a depleted node takes to replenish
+    pub respawn_time: f32,
+    /// Seconds until a depleted node replenishes
+    pub respawn_timer: f32,
+}
+
+impl ResourceNode {
+    /// Create a node with five charges that replenishes after five minutes
+    pub fn new(skill: Skill, difficulty: i32) -> Self {
+        Self {
+            skill,
+            difficulty,
+            tool: None,
+            yields: Vec::new(),
+            charges: 5,
+            max_charges: 5,
+            respawn_time: 300.0,
+            respawn_timer: 0.0,
+        }
+    }
+
+    pub fn with_tool(mut self, template_id: impl Into<String>) -> Self {
+        self.tool = Some(template_id.into());
+        self
+    }
+
+    pub fn with_yield(mut self, entry: ResourceYield) -> Self {
+        self.yields.push(entry);
+        self
+    }
+
+    pub fn with_charges(mut self, charges: u32) -> Self {
+        self.charges = charges;
+        self.max_charges = charges;
+        self
+    }
+
+    pub fn with_respawn_time(mut self, seconds: f32) -> Self {
+        self.respawn_time = seconds;
+        self
+    }
+
+    pub fn is_depleted(&self) -> bool {
+        self.charges == 0
+    }
+
+    /// Use up one charge, starting the respawn timer if it was the last
+    pub fn use_charge(&mut self) {
+        self.charges = self.charges.saturating_sub(1);
+        if self.charges == 0 {
+            self.respawn_timer = self.respawn_time;
+        }
+    }
+
+    /// Advance the respawn timer, returning whether the node replenished
+    pub fn tick(&mut self, delta_time: f32) -> bool {
+        if !self.is_depleted() {
+            return false;
+        }
+        self.respawn_timer -= delta_time;
+        if self.respawn_timer > 0.0 {
+            return false;
+        }
+        self.respawn_timer = 0.0;
+        self.charges = self.max_charges;
+        true
+    }
+
+    /// Yield table entries available at the given skill level
+    pub fn yields_for(&self, level: i32) -> impl Iterator<Item = &ResourceYield> {
+        self.yields
+            .iter()
+            .filter(move |entry| entry.weight > 0 && entry.min_level <= level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_depletes_and_replenishes() {
+        let mut node = ResourceNode::new(Skill::Mining, 10)
+            .with_charges(2)
+            .with_respawn_time(60.0);
+        node.use_charge();
+        assert!(!node.is_depleted());
+        assert!(!node.tick(100.0));
+        node.use_charge();
+        assert!(node.is_depleted());
+        assert!(!node.tick(30.0));
+        assert!(node.tick(30.0));
+        assert_eq!(node.charges, 2);
+    }
+
+    #[test]
+    fn test_yields_for_level() {
+        let node = ResourceNode::new(Skill::Mining, 10)
+            .with_yield(ResourceYield::new("iron_ore", 10))
+            .with_yield(ResourceYield::new("silver_ore", 2).with_min_level(3))
+            .with_yield(ResourceYield::new("nothing", 0));
+        assert_eq!(node.yields_for(0).count(), 1);
+        assert_eq!(node.yields_for(3).count(), 2);
+    }
+}
@@ -0,0 +1,168 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Item templates
+//!
+//! Templates describe kinds of item, such as an iron ingot or a leather
+//! jerkin. They are loaded from the `item_templates` table into an
+//! [`ItemTemplateRegistry`], and items made from one carry a [`Template`]
+//! component naming it so recipes can recognize them.
+
+use super::{Armor, EquipSlot, MaterialKind, Weapon};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Template an item was made from
+/// Maps to: entity_template table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Template {
+    pub template_id: String,
+}
+
+impl Template {
+    pub fn new(template_id: impl Into<String>) -> Self {
+        Self {
+            template_id: template_id.into(),
+        }
+    }
+}
+
+/// Definition of a kind of item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemTemplate {
+    pub id: String,
+    pub name: String,
+    pub keywords: Vec<String>,
+    pub short: String,
+    pub long: String,
+    pub weight: f32,
+    pub material: Option<MaterialKind>,
+    /// Slots the item can be equipped in, empty if it can't be
+    pub slots: Vec<EquipSlot>,
+    pub weapon: Option<Weapon>,
+    pub armor: Option<Armor>,
+}
+
+impl ItemTemplate {
+    /// Create a template for a plain item
+    pub fn new(id: impl Into<String>, name: impl Into<String>, weight: f32) -> Self {
+        let name = name.into();
+        Self {
+            id: id.into(),
+            keywords: vec![name.to_lowercase()],
+            short: name.clone(),
+            long: name.clone(),
+            name,
+            weight,
+            material: None,
+            slots: Vec::new(),
+            weapon: None,
+            armor: None,
+        }
+    }
+}
+
+/// How well an item was made
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Quality {
+    Poor,
+    Common,
+    Fine,
+    Superior,
+    Masterwork,
+}
+
+impl Quality {
+    /// Multiplier applied to weapon damage and armor defense
+    pub fn factor(&self) -> f32 {
+        match self {
+            Quality::Poor => 0.8,
+            Quality::Common => 1.0,
+            Quality::Fine => 1.15,
+            Quality::Superior => 1.3,
+            Quality::Masterwork => 1.5,
+        }
+    }
+
+    /// Word added to the item name, if any
+    pub fn prefix(&self) -> Option<&'static str> {
+        match self {
+            Quality::Poor => Some("crude"),
+            Quality::Common => None,
+            Quality::Fine => Some("fine"),
+            Quality::Superior => Some("superior"),
+            Quality::Masterwork => Some("masterwork"),
+        }
+    }
+
+    /// The next quality up, or the same at the top
+    pub fn improved(&self) -> Self {
+        match self {
+            Quality::Poor => Quality::Common,
+            Quality::Common => Quality::Fine,
+            Quality::Fine => Quality::Superior,
+            Quality::Superior | Quality::Masterwork => Quality::Masterwork,
+        }
+    }
+}
+
+/// All item templates, keyed by id
+#[derive(Debug, Clone, Default)]
+pub struct ItemTemplateRegistry {
+    templates: HashMap<String, ItemTemplate>,
+}
+
+impl ItemTemplateRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a template
+    pub fn register(&mut self, template: ItemTemplate) {
+        self.templates.insert(template.id.clone(), template);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ItemTemplate> {
+        self.templates.get(id)
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.templates.contains_key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.templates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.templates.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quality_ordering() {
+        assert!(Quality::Masterwork > Quality::Common);
+        assert_eq!(Quality::Poor.improved(), Quality::Common);
+        assert_eq!(Quality::Masterwork.improved(), Quality::Masterwork);
+        assert!(Quality::Fine.factor() > Quality::Common.factor());
+        assert_eq!(Quality::Common.prefix(), None);
+    }
+}
@@ -0,0 +1,229 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Magic components and spell definitions
+//!
+//! Spells are data: their definitions are loaded from the `spells` table into
+//! a [`SpellRegistry`], and characters record the spells they know in a
+//! [`Spellbook`].
+
+use super::{DamageType, Skill, SkillCategory, StatusEffectType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Who a spell can be cast on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpellTarget {
+    /// Only the caster
+    Caster,
+    /// The caster or another character, defaulting to the caster
+    Ally,
+    /// Another character, defaulting to the caster's combat target
+    Enemy,
+}
+
+impl SpellTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpellTarget::Caster => "Caster",
+            SpellTarget::Ally => "Ally",
+            SpellTarget::Enemy => "Enemy",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Caster" => Some(SpellTarget::Caster),
+            "Ally" => Some(SpellTarget::Ally),
+            "Enemy" => Some(SpellTarget::Enemy),
+            _ => None,
+        }
+    }
+}
+
+/// What a spell does to its target
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SpellEffect {
+    /// Deal damage of a type
+    Damage {
+        damage_type: DamageType,
+        min: i32,
+        max: i32,
+    },
+    /// Restore health
+    Heal { min: i32, max: i32 },
+    /// Apply a status effect
+    Status {
+        effect_type: StatusEffectType,
+        duration: f32,
+        magnitude: i32,
+    },
+    /// Remove a status effect
+    Cure { effect_type: StatusEffectType },
+}
+
+/// Definition of a spell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Spell {
+    pub name: String,
+    /// Magic school skill checked when casting
+    pub school: Skill,
+    pub description: String,
+    /// Energy spent from the caster's soul
+    pub energy_cost: f32,
+    /// Seconds before the caster can act again
+    pub cast_time: f32,
+    /// Check difficulty of the school skill
+    pub difficulty: i32,
+    pub target: SpellTarget,
+    pub effect: SpellEffect,
+}
+
+impl Spell {
+    /// Check whether the spell is hostile to its target
+    pub fn is_hostile(&self) -> bool {
+        self.target == SpellTarget::Enemy
+    }
+}
+
+/// All spell definitions, keyed by lowercase name
+#[derive(Debug, Clone, Default)]
+pub struct SpellRegistry {
+    spells: HashMap<String, Spell>,
+}
+
+impl SpellRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a spell definition
+    ///
+    /// Spells of a school that is not a magic skill are rejected.
+    pub fn register(&mut self, spell: Spell) -> Result<(), String> {
+        if spell.school.category() != SkillCategory::Magic {
+            return Err(format!(
+                "Spell '{}' uses {}, which is not a school of magic",
+                spell.name,
+                spell.school.name()
+            ));
+        }
+        self.spells.insert(spell.name.to_lowercase(), spell);
+        Ok(())
+    }
+
+    /// Look up a spell by name, ignoring case
+    pub fn get(&self, name: &str) -> Option<&Spell> {
+        self.spells.get(&name.to_lowercase())
+    }
+
+    /// Iterate over every spell
+    pub fn iter(&self) -> impl Iterator<Item = &Spell> {
+        self.spells.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.spells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spells.is_empty()
+    }
+}
+
+/// Spells a character has learned
+/// Maps to: entity_spellbook table (one row per spell)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spellbook {
+    /// Lowercase names of known spells
+    pub spells: BTreeSet<String>,
+}
+
+impl Spellbook {
+    /// Create an empty spellbook
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Learn a spell, returning false if it was already known
+    pub fn learn(&mut self, name: &str) -> bool {
+        self.spells.insert(name.to_lowercase())
+    }
+
+    /// Forget a spell, returning false if it was not known
+    pub fn forget(&mut self, name: &str) -> bool {
+        self.spells.remove(&name.to_lowercase())
+    }
+
+    /// Check whether a spell is known, ignoring case
+    pub fn knows(&self, name: &str) -> bool {
+        self.spells.contains(&name.to_lowercase())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.spells.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.spells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spells.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spark(school: Skill) -> Spell {
+        Spell {
+            name: "Spark".to_string(),
+            school,
+            description: "A crackle of fire".to_string(),
+            energy_cost: 5.0,
+            cast_time: 1.0,
+            difficulty: 10,
+            target: SpellTarget::Enemy,
+            effect: SpellEffect::Damage {
+                damage_type: DamageType::Fire,
+                min: 2,
+                max: 4,
+            },
+        }
+    }
+
+    #[test]
+    fn test_registry_rejects_non_magic_schools() {
+        let mut registry = SpellRegistry::new();
+        assert!(registry.register(spark(Skill::Evocation)).is_ok());
+        assert!(registry.register(spark(Skill::Lockpicking)).is_err());
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("SPARK").unwrap().school, Skill::Evocation);
+    }
+
+    #[test]
+    fn test_spellbook_ignores_case() {
+        let mut spellbook = Spellbook::new();
+        assert!(spellbook.learn("Magic Missile"));
+        assert!(!spellbook.learn("magic missile"));
+        assert!(spellbook.knows("MAGIC MISSILE"));
+        assert!(spellbook.forget("Magic missile"));
+        assert!(spellbook.is_empty());
+    }
+}
@@ -177,6 +177,14 @@ impl NpcConversation {
             .unwrap_or_default()
     }
 
+    /// Forget all but the most recent messages (up to limit) with an entity
+    pub fn truncate(&mut self, entity_id: uuid::Uuid, limit: usize) {
+        if let Some(msgs) = self.conversations.get_mut(&entity_id) {
+            let excess = msgs.len().saturating_sub(limit);
+            msgs.drain(..excess);
+        }
+    }
+
     /// Clear conversation with an entity
     pub fn clear_conversation(&mut self, entity_id: uuid::Uuid) {
         self.conversations.remove(&entity_id);
@@ -338,6 +346,12 @@ mod tests {
         let history = conv.get_history(player_id).unwrap();
         assert_eq!(history.len(), 2);
         assert_eq!(history[0].message, "Hello!");
+
+        conv.add_message(player_id, player_id, "Farewell!");
+        conv.truncate(player_id, 2);
+        let history = conv.get_history(player_id).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].message, "Greetings, traveler!");
     }
 
     #[test]
@@ -157,6 +157,36 @@ impl Containable {
     }
 }
 
+/// Entity holding this one, either a carrier's inventory or a container
+///
+/// Contained entities have no Location of their own.
+/// Maps to: entity_container_contents table
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContainedBy {
+    pub container_id: EntityId,
+}
+
+impl ContainedBy {
+    /// Create a new containment link to the given holder
+    pub fn new(container_id: EntityId) -> Self {
+        Self { container_id }
+    }
+}
+
+/// Room a character returns to after dying
+/// Maps to: entity_bind_point table
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BindPoint {
+    pub room_id: EntityId,
+}
+
+impl BindPoint {
+    /// Create a new bind point in the given room
+    pub fn new(room_id: EntityId) -> Self {
+        Self { room_id }
+    }
+}
+
 /// Marks entities that can be entered (rooms, vehicles)
 /// Maps to: entity_enterable table
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -262,6 +292,164 @@ impl ExitData {
     }
 }
 
+/// Reason an [`Openable`] refused to change state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockError {
+    NotCloseable,
+    NotLockable,
+    AlreadyOpen,
+    AlreadyClosed,
+    AlreadyLocked,
+    AlreadyUnlocked,
+    /// Must be closed before it can be locked
+    NotClosed,
+    /// Must be unlocked before it can be opened
+    Locked,
+    /// None of the offered unlock codes fit the lock
+    NoKey,
+}
+
+/// Door or lid that can be opened, closed, locked and unlocked
+///
+/// Implemented by [`ExitData`], [`Container`] and [`Enterable`], which all
+/// carry the same door and lock fields.
+pub trait Openable {
+    fn is_closeable(&self) -> bool;
+    fn is_closed(&self) -> bool;
+    fn is_lockable(&self) -> bool;
+    fn is_locked(&self) -> bool;
+    fn set_closed(&mut self, closed: bool);
+    fn set_locked(&mut self, locked: bool);
+    fn unlock_code(&self) -> Option<&str>;
+    fn lock_rating(&self) -> Option<i32>;
+
+    /// Check whether one of the given codes fits the lock
+    fn fits(&self, codes: &[&str]) -> bool {
+        self.unlock_code().is_some_and(|code| codes.contains(&code))
+    }
+
+    /// Open a closed, unlocked door or lid
+    fn open(&mut self) -> Result<(), LockError> {
+        if !self.is_closeable() {
+            return Err(LockError::NotCloseable);
+        }
+        if self.is_locked() {
+            return Err(LockError::Locked);
+        }
+        if !self.is_closed() {
+            return Err(LockError::AlreadyOpen);
+        }
+        self.set_closed(false);
+        Ok(())
+    }
+
+    /// Close an open door or lid
+    fn close(&mut self) -> Result<(), LockError> {
+        if !self.is_closeable() {
+            return Err(LockError::NotCloseable);
+        }
+        if self.is_closed() {
+            return Err(LockError::AlreadyClosed);
+        }
+        self.set_closed(true);
+        Ok(())
+    }
+
+    /// Lock a closed door or lid with one of the given codes
+    fn lock(&mut self, codes: &[&str]) -> Result<(), LockError> {
+        if !self.is_lockable() {
+            return Err(LockError::NotLockable);
+        }
+        if self.is_locked() {
+            return Err(LockError::AlreadyLocked);
+        }
+        if !self.is_closed() {
+            return Err(LockError::NotClosed);
+        }
+        if !self.fits(codes) {
+            return Err(LockError::NoKey);
+        }
+        self.set_locked(true);
+        Ok(())
+    }
+
+    /// Unlock a locked door or lid with one of the given codes
+    fn unlock(&mut self, codes: &[&str]) -> Result<(), LockError> {
+        if !self.is_lockable() {
+            return Err(LockError::NotLockable);
+        }
+        if !self.is_locked() {
+            return Err(LockError::AlreadyUnlocked);
+        }
+        if !self.fits(codes) {
+            return Err(LockError::NoKey);
+        }
+        self.set_locked(false);
+        Ok(())
+    }
+
+    /// Unlock a locked door or lid without a key, once a pick attempt succeeds
+    fn pick(&mut self) -> Result<(), LockError> {
+        if !self.is_lockable() {
+            return Err(LockError::NotLockable);
+        }
+        if !self.is_locked() {
+            return Err(LockError::AlreadyUnlocked);
+        }
+        self.set_locked(false);
+        Ok(())
+    }
+}
+
+macro_rules! impl_openable {
+    ($($ty:ty),*) => {$(
+        impl Openable for $ty {
+            fn is_closeable(&self) -> bool {
+                self.closeable
+            }
+            fn is_closed(&self) -> bool {
+                self.closed
+            }
+            fn is_lockable(&self) -> bool {
+                self.lockable
+            }
+            fn is_locked(&self) -> bool {
+                self.locked
+            }
+            fn set_closed(&mut self, closed: bool) {
+                self.closed = closed;
+            }
+            fn set_locked(&mut self, locked: bool) {
+                self.locked = locked;
+            }
+            fn unlock_code(&self) -> Option<&str> {
+                self.unlock_code.as_deref()
+            }
+            fn lock_rating(&self) -> Option<i32> {
+                self.lock_rating
+            }
+        }
+    )*};
+}
+
+impl_openable!(ExitData, Container, Enterable);
+
+/// Key that opens locks with a matching `unlock_code`
+/// Maps to: entity_key table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Key {
+    pub unlock_code: String,
+}
+
+impl Key {
+    /// Create a new key for the given unlock code
+    pub fn new(unlock_code: impl Into<String>) -> Self {
+        Self {
+            unlock_code: unlock_code.into(),
+        }
+    }
+}
+
 /// Collection of exits from a room
 /// Maps to: entity_room_exits table (multiple rows)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -382,6 +570,28 @@ mod tests {
         assert!(exit.transparent);
     }
 
+    #[test]
+    fn test_openable_lock_cycle() {
+        let dest_id = EntityId::from_uuid(Uuid::new_v4());
+        let mut exit = ExitData::new("north", dest_id)
+            .with_lock(10, 5, "key123")
+            .closed()
+            .locked();
+
+        assert_eq!(exit.open(), Err(LockError::Locked));
+        assert_eq!(exit.unlock(&["wrong"]), Err(LockError::NoKey));
+        assert_eq!(exit.unlock(&["wrong", "key123"]), Ok(()));
+        assert_eq!(exit.open(), Ok(()));
+        assert_eq!(exit.lock(&["key123"]), Err(LockError::NotClosed));
+        assert_eq!(exit.close(), Ok(()));
+        assert_eq!(exit.lock(&["key123"]), Ok(()));
+        assert!(exit.is_locked());
+
+        let mut chest = Container::new(None);
+        assert_eq!(chest.close(), Err(LockError::NotCloseable));
+        assert_eq!(chest.unlock(&[]), Err(LockError::NotLockable));
+    }
+
     #[test]
     fn test_exits_collection() {
         let room1_id = EntityId::from_uuid(Uuid::new_v4());
@@ -15,10 +15,13 @@
 //
 
 use crate::ecs::EcsEntity;
-use crate::ecs::components::{CharacterBuilder, EntityId};
+use crate::ecs::components::{
+    CharacterBuilder, EntityId, ItemTemplateRegistry, RecipeRegistry, SpellRegistry,
+};
 use crate::ecs::events::EventBus;
+use crate::ecs::output::OutputSink;
 use crate::ecs::registry::EntityRegistry;
-use crate::ecs::systems::CommandSystem;
+use crate::ecs::systems::{CommandSystem, WorldScheduler};
 use crate::models::ModelManager;
 use crate::persistence::PersistenceManager;
 use std::sync::Arc;
@@ -38,6 +41,9 @@ use uuid::Uuid;
 /// - **ECS World**: hecs::World for entity-component storage
 /// - **Registry**: Bidirectional mapping between ECS entities and persistent UUIDs
 /// - **Persistence Manager**: Database operations for loading/saving entities
+/// - **Scheduler**: Fixed-rate tick loop driving the world systems
+/// - **Spells**: Spell definitions loaded from the database
+/// - **Item Templates** and **Recipes**: Crafting definitions loaded from the database
 ///
 /// # Safe Operation Methods
 ///
@@ -59,15 +65,23 @@ use uuid::Uuid;
 /// - `mark_dirty()` / `mark_entity_dirty()` - Mark entities for saving
 /// - `save()` - Save all dirty entities
 /// - `save_entity()` / `save_entity_by_uuid()` - Save specific entities
-/// - `load()` - Load entire world from database
+/// - `load()` - Load entire world and spell definitions from database
 /// - `load_character()` - Load specific character
 /// - `create_character()` - Create new character in database
 /// - `delete_entity()` - Remove entity from database
 /// - `clear_dirty()` / `dirty_count()` / `is_dirty()` - Dirty tracking utilities
 ///
+/// ## Output Delivery
+/// - `set_output_sink()` - Register where pushed output is delivered
+/// - `send_to_entity()` / `send_to_entities()` - Push text to player sessions
+/// - `send_structured_to_entity()` - Push side channel data to a player session
+///
 /// ## Manual Lock Access (for complex operations)
 /// - `entities()` - Get Arc<RwLock<World>> for manual management
 /// - `registry()` - Get Arc<RwLock<EntityRegistry>> for manual management
+/// - `spells()` - Get Arc<RwLock<SpellRegistry>> for spell lookups
+/// - `item_templates()` - Get Arc<RwLock<ItemTemplateRegistry>> for item template lookups
+/// - `recipes()` - Get Arc<RwLock<RecipeRegistry>> for recipe lookups
 /// - `persistence_manager()` - Get Arc<PersistenceManager> reference
 ///
 /// # Examples
@@ -95,26 +109,21 @@ use uuid::Uuid;
 pub struct WorldContext {
     entities: Arc<RwLock<hecs::World>>,
     registry: Arc<RwLock<EntityRegistry>>,
+    spells: Arc<RwLock<SpellRegistry>>,
+    item_templates: Arc<RwLock<ItemTemplateRegistry>>,
+    recipes: Arc<RwLock<RecipeRegistry>>,
     persistence_manager: Arc<PersistenceManager>,
     llm_manager: Arc<ModelManager>,
-    command_system: Arc<RwLock<CommandSystem>>,
+    command_system: Arc<CommandSystem>,
+    scheduler: Arc<WorldScheduler>,
+    output_sink: std::sync::RwLock<Option<Arc<dyn OutputSink>>>,
     event_bus: EventBus,
 }
 
 impl WorldContext {
     /// Create a new world engine context
     pub fn new(persistence_manager: Arc<PersistenceManager>) -> Self {
-        let event_bus = EventBus::new();
-        let command_system = CommandSystem::new(event_bus.clone());
-
-        Self {
-            entities: Arc::new(RwLock::new(hecs::World::new())),
-            registry: Arc::new(RwLock::new(EntityRegistry::new())),
-            persistence_manager,
-            llm_manager: Arc::new(ModelManager::new()),
-            command_system: Arc::new(RwLock::new(command_system)),
-            event_bus,
-        }
+        Self::with_llm_manager(persistence_manager, Arc::new(ModelManager::new()))
     }
 
     /// Create a new world engine context with a custom LLM manager
@@ -124,13 +133,19 @@ impl WorldContext {
     ) -> Self {
         let event_bus = EventBus::new();
         let command_system = CommandSystem::new(event_bus.clone());
+        let scheduler = WorldScheduler::new(event_bus.clone(), llm_manager.clone());
 
         Self {
             entities: Arc::new(RwLock::new(hecs::World::new())),
             registry: Arc::new(RwLock::new(EntityRegistry::new())),
+            spells: Arc::new(RwLock::new(SpellRegistry::new())),
+            item_templates: Arc::new(RwLock::new(ItemTemplateRegistry::new())),
+            recipes: Arc::new(RwLock::new(RecipeRegistry::new())),
             persistence_manager,
             llm_manager,
-            command_system: Arc::new(RwLock::new(command_system)),
+            command_system: Arc::new(command_system),
+            scheduler: Arc::new(scheduler),
+            output_sink: std::sync::RwLock::new(None),
             event_bus,
         }
     }
@@ -149,6 +164,21 @@ impl WorldContext {
         &self.registry
     }
 
+    /// Get the spell registry
+    pub fn spells(&self) -> &Arc<RwLock<SpellRegistry>> {
+        &self.spells
+    }
+
+    /// Get the item template registry
+    pub fn item_templates(&self) -> &Arc<RwLock<ItemTemplateRegistry>> {
+        &self.item_templates
+    }
+
+    /// Get the recipe registry
+    pub fn recipes(&self) -> &Arc<RwLock<RecipeRegistry>> {
+        &self.recipes
+    }
+
     /// Get the persistence manager
     pub fn persistence(&self) -> &Arc<PersistenceManager> {
         &self.persistence_manager
@@ -159,11 +189,16 @@ impl WorldContext {
         &self.llm_manager
     }
 
-    /// Get the command system - use only when you need manual lock management
-    pub fn command_system(&self) -> &Arc<RwLock<CommandSystem>> {
+    /// Get the command system (shared without a lock; commands run concurrently)
+    pub fn command_system(&self) -> &Arc<CommandSystem> {
         &self.command_system
     }
 
+    /// Get the world tick scheduler
+    pub fn scheduler(&self) -> &Arc<WorldScheduler> {
+        &self.scheduler
+    }
+
     /// Get the event bus
     pub fn event_bus(&self) -> &EventBus {
         &self.event_bus
@@ -359,9 +394,14 @@ impl WorldContext {
         self.persistence_manager.auto_save(&world).await
     }
 
-    /// Load all persistent entities from the database into the world
+    /// Load spell, item template and recipe definitions and all persistent
+    /// entities from the database
     #[instrument(skip(self))]
     pub async fn load(&self) -> Result<usize, String> {
+        *self.spells.write().await = self.persistence_manager.load_spells().await?;
+        let templates = self.persistence_manager.load_item_templates().await?;
+        *self.recipes.write().await = self.persistence_manager.load_recipes(&templates).await?;
+        *self.item_templates.write().await = templates;
         let mut world = self.entities.write().await;
         let mut registry = self.registry.write().await;
         self.persistence_manager
@@ -431,4 +471,57 @@ impl WorldContext {
         // Then delete from database
         self.persistence_manager.delete_entity(entity_id).await
     }
+
+    // ============================================================================
+    // Output Delivery
+    // ============================================================================
+
+    /// Register the sink used to push output to player sessions
+    pub fn set_output_sink(&self, sink: Arc<dyn OutputSink>) {
+        *self.output_sink.write().unwrap() = Some(sink);
+    }
+
+    /// Push a message to the session controlling an entity
+    ///
+    /// Entities without a session (NPCs, disconnected players) are skipped.
+    pub async fn send_to_entity(&self, entity: EcsEntity, message: impl Into<String>) {
+        let sink = self.output_sink.read().unwrap().clone();
+        if let Some(sink) = sink {
+            if let Err(e) = sink.send_to_entity(entity, message.into()).await {
+                tracing::debug!("Output to entity {:?} not delivered: {}", entity, e);
+            }
+        }
+    }
+
+    /// Push the same message to the sessions of several entities
+    pub async fn send_to_entities(&self, entities: &[EcsEntity], message: &str) {
+        for &entity in entities {
+            self.send_to_entity(entity, message).await;
+        }
+    }
+
+    /// Push structured data to the session controlling an entity
+    ///
+    /// Delivered over the client's side channel (GMCP, MSDP or JSON) when it
+    /// has one; entities without a session are skipped.
+    pub async fn send_structured_to_entity(
+        &self,
+        entity: EcsEntity,
+        output_type: &str,
+        data: serde_json::Value,
+    ) {
+        let sink = self.output_sink.read().unwrap().clone();
+        if let Some(sink) = sink {
+            if let Err(e) = sink
+                .send_structured_to_entity(entity, output_type.to_string(), data)
+                .await
+            {
+                tracing::debug!(
+                    "Structured output to entity {:?} not delivered: {}",
+                    entity,
+                    e
+                );
+            }
+        }
+    }
 }
@@ -17,6 +17,7 @@
 //! Event type definitions
 
 use crate::ecs::EcsEntity;
+use crate::ecs::components::{DamageType, DefenseReaction, Skill, StatusEffectType};
 use serde::{Deserialize, Serialize};
 
 /// All possible game events
@@ -63,7 +64,20 @@ pub enum GameEvent {
     EntityAttacked {
         attacker: EcsEntity,
         defender: EcsEntity,
+        /// Damage dealt after armor
         damage: i32,
+        damage_type: DamageType,
+        /// Damage the defender's armor absorbed
+        absorbed: i32,
+        critical: bool,
+        off_hand: bool,
+    },
+    /// The defender dodged, parried or blocked an attack
+    AttackDefended {
+        attacker: EcsEntity,
+        defender: EcsEntity,
+        reaction: DefenseReaction,
+        off_hand: bool,
     },
     EntityDefended {
         entity: EcsEntity,
@@ -76,6 +90,28 @@ pub enum GameEvent {
         killer: Option<EcsEntity>,
     },
 
+    // Status effects
+    StatusEffectStarted {
+        entity: EcsEntity,
+        effect_type: StatusEffectType,
+    },
+    StatusEffectTicked {
+        entity: EcsEntity,
+        effect_type: StatusEffectType,
+        damage: i32,
+    },
+    /// An effect ran out or was cured
+    StatusEffectEnded {
+        entity: EcsEntity,
+        effect_type: StatusEffectType,
+    },
+
+    // Vitals
+    VitalsChanged {
+        entity: EcsEntity,
+        vitals: Vitals,
+    },
+
     // Items
     ItemPickedUp {
         entity: EcsEntity,
@@ -99,6 +135,21 @@ pub enum GameEvent {
         item: EcsEntity,
         slot: String,
     },
+    ItemGiven {
+        entity: EcsEntity,
+        recipient: EcsEntity,
+        item: EcsEntity,
+    },
+    ItemStored {
+        entity: EcsEntity,
+        item: EcsEntity,
+        container: EcsEntity,
+    },
+    ItemRetrieved {
+        entity: EcsEntity,
+        item: EcsEntity,
+        container: EcsEntity,
+    },
 
     // Commands
     CommandExecuted {
@@ -118,10 +169,14 @@ pub enum GameEvent {
     // Experience and progression
     ExperienceGained {
         entity: EcsEntity,
+        /// Skill the experience went to, or `None` for kill credit
+        skill: Option<Skill>,
         amount: u64,
     },
     LevelUp {
         entity: EcsEntity,
+        /// Skill that improved, or `None` for the character as a whole
+        skill: Option<Skill>,
         new_level: u32,
     },
 
@@ -132,6 +187,28 @@ pub enum GameEvent {
     },
 }
 
+/// Health and energy of a single attribute class
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct VitalPool {
+    pub health: f32,
+    pub health_maximum: f32,
+    pub energy: f32,
+    pub energy_maximum: f32,
+}
+
+/// Snapshot of an entity's body, mind and soul pools
+///
+/// Entities that only carry a plain `AttributeScores` report it as `body`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Vitals {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<VitalPool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mind: Option<VitalPool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soul: Option<VitalPool>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MessageChannel {
     Say,
@@ -0,0 +1,167 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Output delivery to player sessions
+//!
+//! Command handlers only return text to the entity that issued the command.
+//! Anything other entities should see (speech, emotes, world events) is pushed
+//! through an [`OutputSink`], which the gRPC listener registers with the
+//! [`WorldContext`](crate::ecs::context::WorldContext).
+
+mod router;
+
+pub use router::*;
+
+use crate::ecs::components::{Avatar, EntityUuid, Exits, Location};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use std::collections::{HashMap, HashSet, VecDeque};
+use uuid::Uuid;
+
+/// Destination for text pushed to entities outside of command responses
+#[async_trait::async_trait]
+pub trait OutputSink: Send + Sync {
+    /// Deliver a message to the session controlling the given entity
+    async fn send_to_entity(&self, entity: EcsEntity, message: String) -> Result<(), String>;
+
+    /// Deliver structured data (e.g. `char.vitals`) for clients with a side channel
+    ///
+    /// Sinks without side channel support drop structured output.
+    async fn send_structured_to_entity(
+        &self,
+        _entity: EcsEntity,
+        _output_type: String,
+        _data: serde_json::Value,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Find all player-controlled entities located in the given room
+pub fn players_in_room(world: &GameWorld, room_uuid: Uuid) -> Vec<EcsEntity> {
+    world
+        .query::<(Entity, &Avatar, &Location)>()
+        .iter()
+        .filter(|(_, _, location)| location.room_id.uuid() == room_uuid)
+        .map(|(entity, _, _)| entity)
+        .collect()
+}
+
+/// Find all rooms reachable within `max_hops` exits of a room
+///
+/// Returns each room UUID with its distance in hops; the starting room is
+/// included at distance 0.
+pub fn rooms_within(world: &GameWorld, room_uuid: Uuid, max_hops: u32) -> Vec<(Uuid, u32)> {
+    let exits: HashMap<Uuid, Vec<Uuid>> = world
+        .query::<(&EntityUuid, &Exits)>()
+        .iter()
+        .map(|(uuid, exits)| {
+            (
+                uuid.0,
+                exits.exits.iter().map(|exit| exit.dest_id.uuid()).collect(),
+            )
+        })
+        .collect();
+
+    let mut visited = HashSet::from([room_uuid]);
+    let mut rooms = vec![(room_uuid, 0)];
+    let mut queue = VecDeque::from([(room_uuid, 0)]);
+
+    while let Some((room, distance)) = queue.pop_front() {
+        if distance >= max_hops {
+            continue;
+        }
+        for &dest in exits.get(&room).into_iter().flatten() {
+            if visited.insert(dest) {
+                rooms.push((dest, distance + 1));
+                queue.push_back((dest, distance + 1));
+            }
+        }
+    }
+
+    rooms
+}
+
+/// Capitalize the first letter of a sentence
+pub(crate) fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{EntityId, ExitData, Name};
+
+    fn location(room: Uuid) -> Location {
+        Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room))
+    }
+
+    #[test]
+    fn test_players_in_room() {
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let other_room = Uuid::new_v4();
+
+        let alice = world.spawn((
+            Name::new("Alice"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+        let _bob = world.spawn((
+            Name::new("Bob"),
+            Avatar::new(Uuid::new_v4()),
+            location(other_room),
+        ));
+        let _goblin = world.spawn((Name::new("Goblin"), location(room)));
+
+        assert_eq!(players_in_room(&world, room), vec![alice]);
+    }
+
+    #[test]
+    fn test_capitalize() {
+        assert_eq!(capitalize("the goblin dies."), "The goblin dies.");
+        assert_eq!(capitalize("élan"), "Élan");
+        assert_eq!(capitalize(""), "");
+    }
+
+    #[test]
+    fn test_rooms_within() {
+        let mut world = GameWorld::new();
+        let rooms: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+
+        // Linear chain: 0 <-> 1 <-> 2 <-> 3
+        for (i, room) in rooms.iter().enumerate() {
+            let mut exits = Exits::new();
+            if i > 0 {
+                exits = exits.add_exit(ExitData::new("west", EntityId::from_uuid(rooms[i - 1])));
+            }
+            if i + 1 < rooms.len() {
+                exits = exits.add_exit(ExitData::new("east", EntityId::from_uuid(rooms[i + 1])));
+            }
+            world.spawn((EntityUuid(*room), exits));
+        }
+
+        let nearby = rooms_within(&world, rooms[0], 2);
+        assert_eq!(nearby, vec![(rooms[0], 0), (rooms[1], 1), (rooms[2], 2)]);
+
+        let only_start = rooms_within(&world, rooms[0], 0);
+        assert_eq!(only_start, vec![(rooms[0], 0)]);
+    }
+}
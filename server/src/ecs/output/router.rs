@@ -0,0 +1,794 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Event-to-session output router
+//!
+//! Subscribes to the [`EventBus`](crate::ecs::events::EventBus) and turns game
+//! events into observer-specific text ("A goblin arrives from the north.",
+//! "Bob hits the rat for 4 damage.") for players in the affected rooms.
+//! Vitals changes are forwarded to the affected player as `char.vitals`
+//! structured output.
+
+use super::{capitalize, players_in_room};
+use crate::ecs::components::{
+    DefenseReaction, EntityUuid, Exits, Location, Name, Room, StatusEffectType, skill_rank_name,
+};
+use crate::ecs::context::WorldContext;
+use crate::ecs::events::GameEvent;
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// A message to deliver to a set of entities
+#[derive(Debug, Clone, PartialEq)]
+pub struct Narration {
+    pub recipients: Vec<EcsEntity>,
+    pub message: String,
+}
+
+impl Narration {
+    fn new(recipients: Vec<EcsEntity>, message: String) -> Self {
+        Self {
+            recipients,
+            message,
+        }
+    }
+}
+
+/// Routes game events to the sessions of players who can observe them
+pub struct EventOutputRouter;
+
+impl EventOutputRouter {
+    /// Subscribe to the context's event bus and start the delivery task
+    ///
+    /// Event handlers run synchronously while the bus is processed, so events
+    /// are forwarded over a channel and narrated on a separate task that can
+    /// take the world lock and await session delivery.
+    pub fn start(context: Arc<WorldContext>) {
+        let (tx, mut rx) = mpsc::unbounded_channel::<GameEvent>();
+
+        context.event_bus().subscribe(move |event| {
+            if is_observable(event) || matches!(event, GameEvent::VitalsChanged { .. }) {
+                let _ = tx.send(event.clone());
+            }
+        });
+
+        tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                // Vitals only go to the entity itself, over its side channel
+                if let GameEvent::VitalsChanged { entity, vitals } = &event {
+                    if let Ok(data) = serde_json::to_value(vitals) {
+                        context
+                            .send_structured_to_entity(*entity, "char.vitals", data)
+                            .await;
+                    }
+                    continue;
+                }
+
+                let narrations = {
+                    let world = context.entities().read().await;
+                    narrate(&world, &event)
+                };
+                for narration in narrations {
+                    context
+                        .send_to_entities(&narration.recipients, &narration.message)
+                        .await;
+                }
+            }
+        });
+
+        tracing::info!("Event output router started");
+    }
+}
+
+/// Check whether an event produces any observer output
+fn is_observable(event: &GameEvent) -> bool {
+    matches!(
+        event,
+        GameEvent::EntityMoved { .. }
+            | GameEvent::EntityEnteredRoom { .. }
+            | GameEvent::EntityLeftRoom { .. }
+            | GameEvent::CombatStarted { .. }
+            | GameEvent::EntityAttacked { .. }
+            | GameEvent::AttackDefended { .. }
+            | GameEvent::EntityDefended { .. }
+            | GameEvent::EntityFled { .. }
+            | GameEvent::EntityDied { .. }
+            | GameEvent::StatusEffectStarted { .. }
+            | GameEvent::StatusEffectTicked { .. }
+            | GameEvent::StatusEffectEnded { .. }
+            | GameEvent::ItemPickedUp { .. }
+            | GameEvent::ItemDropped { .. }
+            | GameEvent::ItemUsed { .. }
+            | GameEvent::ItemEquipped { .. }
+            | GameEvent::ItemUnequipped { .. }
+            | GameEvent::ItemGiven { .. }
+            | GameEvent::ItemStored { .. }
+            | GameEvent::ItemRetrieved { .. }
+            | GameEvent::LevelUp { .. }
+    )
+}
+
+/// Translate an event into messages for each observer
+pub fn narrate(world: &GameWorld, event: &GameEvent) -> Vec<Narration> {
+    match event {
+        GameEvent::EntityMoved { entity, from, to } => {
+            let name = capitalize(&display_name(world, *entity));
+            let leave = match exit_direction(world, from.1, to.1) {
+                Some(direction) => format!("{} leaves {}.", name, direction),
+                None => format!("{} leaves.", name),
+            };
+            let arrive = match exit_direction(world, to.1, from.1) {
+                Some(direction) => format!("{} arrives {}.", name, arrival_phrase(&direction)),
+                None => format!("{} arrives.", name),
+            };
+            vec![
+                Narration::new(observers(world, from.1, &[*entity]), leave),
+                Narration::new(observers(world, to.1, &[*entity]), arrive),
+            ]
+        }
+        GameEvent::EntityEnteredRoom { entity, room } => {
+            let Ok(room_uuid) = world.get::<&EntityUuid>(*room).map(|uuid| uuid.0) else {
+                return Vec::new();
+            };
+            let name = capitalize(&display_name(world, *entity));
+            vec![Narration::new(
+                observers(world, room_uuid, &[*entity]),
+                format!("{} arrives.", name),
+            )]
+        }
+        GameEvent::EntityLeftRoom { entity, room } => {
+            let Ok(room_uuid) = world.get::<&EntityUuid>(*room).map(|uuid| uuid.0) else {
+                return Vec::new();
+            };
+            let name = capitalize(&display_name(world, *entity));
+            vec![Narration::new(
+                observers(world, room_uuid, &[*entity]),
+                format!("{} leaves.", name),
+            )]
+        }
+        GameEvent::CombatStarted { attacker, defender } => {
+            let attacker_name = display_name(world, *attacker);
+            let defender_name = display_name(world, *defender);
+            let mut narrations = vec![Narration::new(
+                vec![*defender],
+                format!("{} attacks you!", capitalize(&attacker_name)),
+            )];
+            if let Some(room) = room_of(world, *attacker) {
+                narrations.push(Narration::new(
+                    observers(world, room, &[*attacker, *defender]),
+                    format!("{} attacks {}!", capitalize(&attacker_name), defender_name),
+                ));
+            }
+            narrations
+        }
+        GameEvent::EntityAttacked {
+            attacker,
+            defender,
+            damage,
+            damage_type,
+            absorbed,
+            critical,
+            off_hand,
+        } => {
+            let attacker_name = display_name(world, *attacker);
+            let defender_name = display_name(world, *defender);
+            let (hit, hits) = if *critical {
+                ("critically hit", "critically hits")
+            } else {
+                ("hit", "hits")
+            };
+            let (own_hand, their_hand) = if *off_hand {
+                (" with your off hand", " with an off-hand blow")
+            } else {
+                ("", "")
+            };
+            let mut detail = format!(
+                "for {} {} damage",
+                damage,
+                damage_type.as_str().to_lowercase()
+            );
+            if *absorbed > 0 {
+                detail.push_str(&format!(" ({} absorbed by armor)", absorbed));
+            }
+            let mut narrations = vec![
+                Narration::new(
+                    vec![*attacker],
+                    format!("You {} {}{} {}.", hit, defender_name, own_hand, detail),
+                ),
+                Narration::new(
+                    vec![*defender],
+                    format!(
+                        "{} {} you{} {}.",
+                        capitalize(&attacker_name),
+                        hits,
+                        their_hand,
+                        detail
+                    ),
+                ),
+            ];
+            if let Some(room) = room_of(world, *attacker) {
+                narrations.push(Narration::new(
+                    observers(world, room, &[*attacker, *defender]),
+                    format!(
+                        "{} {} {}{} {}.",
+                        capitalize(&attacker_name),
+                        hits,
+                        defender_name,
+                        their_hand,
+                        detail
+                    ),
+                ));
+            }
+            narrations
+        }
+        GameEvent::AttackDefended {
+            attacker,
+            defender,
+            reaction,
+            off_hand,
+        } => {
+            let attacker_name = display_name(world, *attacker);
+            let defender_name = display_name(world, *defender);
+            let (react, reacts) = match reaction {
+                DefenseReaction::Dodge => ("dodge", "dodges"),
+                DefenseReaction::Parry => ("parry", "parries"),
+                DefenseReaction::Block => ("block", "blocks"),
+            };
+            let attack = if *off_hand {
+                "off-hand attack"
+            } else {
+                "attack"
+            };
+            let mut narrations = vec![
+                Narration::new(
+                    vec![*attacker],
+                    format!("{} {} your {}.", capitalize(&defender_name), reacts, attack),
+                ),
+                Narration::new(
+                    vec![*defender],
+                    format!("You {} {}'s {}.", react, attacker_name, attack),
+                ),
+            ];
+            if let Some(room) = room_of(world, *defender) {
+                narrations.push(Narration::new(
+                    observers(world, room, &[*attacker, *defender]),
+                    format!(
+                        "{} {} {}'s {}.",
+                        capitalize(&defender_name),
+                        reacts,
+                        attacker_name,
+                        attack
+                    ),
+                ));
+            }
+            narrations
+        }
+        GameEvent::EntityDefended { entity } => {
+            room_narration(world, *entity, "takes a defensive stance.")
+        }
+        GameEvent::EntityFled { entity } => room_narration(world, *entity, "flees from combat!"),
+        GameEvent::EntityDied { entity, killer } => {
+            let name = display_name(world, *entity);
+            let mut narrations = vec![Narration::new(
+                vec![*entity],
+                "You have been slain!".to_string(),
+            )];
+            let mut excluded = vec![*entity];
+            if let Some(killer) = killer {
+                narrations.push(Narration::new(
+                    vec![*killer],
+                    format!("You have slain {}!", name),
+                ));
+                excluded.push(*killer);
+            }
+            let room = room_of(world, *entity).or_else(|| killer.and_then(|k| room_of(world, k)));
+            if let Some(room) = room {
+                narrations.push(Narration::new(
+                    observers(world, room, &excluded),
+                    format!("{} has been slain!", capitalize(&name)),
+                ));
+            }
+            narrations
+        }
+        // Defending is narrated by EntityDefended
+        GameEvent::StatusEffectStarted {
+            effect_type: StatusEffectType::Defending,
+            ..
+        }
+        | GameEvent::StatusEffectEnded {
+            effect_type: StatusEffectType::Defending,
+            ..
+        } => Vec::new(),
+        GameEvent::StatusEffectStarted {
+            entity,
+            effect_type,
+        } => {
+            let condition = condition(*effect_type);
+            let mut narrations = vec![Narration::new(
+                vec![*entity],
+                format!("You are {}!", condition),
+            )];
+            narrations.extend(room_narration(
+                world,
+                *entity,
+                &format!("is {}!", condition),
+            ));
+            narrations
+        }
+        GameEvent::StatusEffectTicked {
+            entity,
+            effect_type,
+            damage,
+        } => {
+            let cause = affliction(*effect_type);
+            let mut narrations = vec![Narration::new(
+                vec![*entity],
+                format!("You take {} damage from {}.", damage, cause),
+            )];
+            narrations.extend(room_narration(
+                world,
+                *entity,
+                &format!("takes {} damage from {}.", damage, cause),
+            ));
+            narrations
+        }
+        GameEvent::StatusEffectEnded {
+            entity,
+            effect_type,
+        } => {
+            let condition = condition(*effect_type);
+            let mut narrations = vec![Narration::new(
+                vec![*entity],
+                format!("You are no longer {}.", condition),
+            )];
+            narrations.extend(room_narration(
+                world,
+                *entity,
+                &format!("is no longer {}.", condition),
+            ));
+            narrations
+        }
+        GameEvent::ItemPickedUp { entity, item } => {
+            item_narration(world, *entity, *item, "picks up")
+        }
+        GameEvent::ItemDropped { entity, item } => item_narration(world, *entity, *item, "drops"),
+        GameEvent::ItemUsed { entity, item } => item_narration(world, *entity, *item, "uses"),
+        GameEvent::ItemEquipped { entity, item, .. } => {
+            item_narration(world, *entity, *item, "equips")
+        }
+        GameEvent::ItemUnequipped { entity, item, .. } => {
+            item_narration(world, *entity, *item, "removes")
+        }
+        GameEvent::ItemGiven {
+            entity,
+            recipient,
+            item,
+        } => {
+            let name = capitalize(&display_name(world, *entity));
+            let recipient_name = display_name(world, *recipient);
+            let item_name = display_name(world, *item);
+            let mut narrations = vec![Narration::new(
+                vec![*recipient],
+                format!("{} gives you {}.", name, item_name),
+            )];
+            if let Some(room) = room_of(world, *entity) {
+                narrations.push(Narration::new(
+                    observers(world, room, &[*entity, *recipient]),
+                    format!("{} gives {} to {}.", name, item_name, recipient_name),
+                ));
+            }
+            narrations
+        }
+        GameEvent::ItemStored {
+            entity,
+            item,
+            container,
+        } => container_narration(world, *entity, *item, *container, "puts", "in"),
+        GameEvent::ItemRetrieved {
+            entity,
+            item,
+            container,
+        } => container_narration(world, *entity, *item, *container, "gets", "from"),
+        GameEvent::LevelUp {
+            entity,
+            skill: Some(skill),
+            new_level,
+        } => vec![Narration::new(
+            vec![*entity],
+            format!(
+                "Your {} skill has improved to {}!",
+                skill.name(),
+                skill_rank_name(*new_level as i32)
+            ),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Narrate an action by an entity to everyone else in its room
+fn room_narration(world: &GameWorld, entity: EcsEntity, action: &str) -> Vec<Narration> {
+    let Some(room) = room_of(world, entity) else {
+        return Vec::new();
+    };
+    let name = capitalize(&display_name(world, entity));
+    vec![Narration::new(
+        observers(world, room, &[entity]),
+        format!("{} {}", name, action),
+    )]
+}
+
+/// How someone with a status effect is described, e.g. "on fire"
+fn condition(effect_type: StatusEffectType) -> &'static str {
+    match effect_type {
+        StatusEffectType::Stunned => "stunned",
+        StatusEffectType::Poisoned => "poisoned",
+        StatusEffectType::Burning => "on fire",
+        StatusEffectType::Bleeding => "bleeding",
+        StatusEffectType::Defending => "defending",
+        StatusEffectType::Weakened => "weakened",
+        StatusEffectType::Strengthened => "strengthened",
+        StatusEffectType::Slowed => "slowed",
+        StatusEffectType::Hasted => "hastened",
+    }
+}
+
+/// What a damage-over-time effect hurts its victim with
+fn affliction(effect_type: StatusEffectType) -> &'static str {
+    match effect_type {
+        StatusEffectType::Poisoned => "poison",
+        StatusEffectType::Burning => "the flames",
+        StatusEffectType::Bleeding => "blood loss",
+        _ => "its effects",
+    }
+}
+
+/// Narrate an item interaction to everyone else in the actor's room
+fn item_narration(
+    world: &GameWorld,
+    entity: EcsEntity,
+    item: EcsEntity,
+    verb: &str,
+) -> Vec<Narration> {
+    let Some(room) = room_of(world, entity) else {
+        return Vec::new();
+    };
+    let name = capitalize(&display_name(world, entity));
+    let item_name = display_name(world, item);
+    vec![Narration::new(
+        observers(world, room, &[entity]),
+        format!("{} {} {}.", name, verb, item_name),
+    )]
+}
+
+/// Narrate moving an item into or out of a container to the actor's room
+fn container_narration(
+    world: &GameWorld,
+    entity: EcsEntity,
+    item: EcsEntity,
+    container: EcsEntity,
+    verb: &str,
+    preposition: &str,
+) -> Vec<Narration> {
+    let Some(room) = room_of(world, entity) else {
+        return Vec::new();
+    };
+    let name = capitalize(&display_name(world, entity));
+    let item_name = display_name(world, item);
+    let container_name = display_name(world, container);
+    vec![Narration::new(
+        observers(world, room, &[entity]),
+        format!(
+            "{} {} {} {} {}.",
+            name, verb, item_name, preposition, container_name
+        ),
+    )]
+}
+
+/// Players in a room, excluding the given entities
+fn observers(world: &GameWorld, room: Uuid, excluded: &[EcsEntity]) -> Vec<EcsEntity> {
+    players_in_room(world, room)
+        .into_iter()
+        .filter(|entity| !excluded.contains(entity))
+        .collect()
+}
+
+/// The room an entity is currently in
+fn room_of(world: &GameWorld, entity: EcsEntity) -> Option<Uuid> {
+    world
+        .get::<&Location>(entity)
+        .ok()
+        .map(|location| location.room_id.uuid())
+}
+
+/// Display name of an entity, or "someone" if it has none
+fn display_name(world: &GameWorld, entity: EcsEntity) -> String {
+    world
+        .get::<&Name>(entity)
+        .map(|name| name.display.clone())
+        .unwrap_or_else(|_| "someone".to_string())
+}
+
+/// Direction of the exit in `room` leading to `dest`, if any
+fn exit_direction(world: &GameWorld, room: Uuid, dest: Uuid) -> Option<String> {
+    for (entity, uuid, _) in world.query::<(Entity, &EntityUuid, &Room)>().iter() {
+        if uuid.0 != room {
+            continue;
+        }
+        let exits = world.get::<&Exits>(entity).ok()?;
+        return exits
+            .exits
+            .iter()
+            .find(|exit| exit.dest_id.uuid() == dest)
+            .map(|exit| exit.direction.to_lowercase());
+    }
+    None
+}
+
+/// Phrase describing where an arrival came from
+fn arrival_phrase(direction: &str) -> String {
+    match direction {
+        "up" => "from above".to_string(),
+        "down" => "from below".to_string(),
+        _ => format!("from the {}", direction),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Avatar, DamageType, EntityId, ExitData, Skill};
+
+    fn location(room: Uuid) -> Location {
+        Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room))
+    }
+
+    #[test]
+    fn test_narrate_movement_with_directions() {
+        let mut world = GameWorld::new();
+        let area = Uuid::new_v4();
+        let hall = Uuid::new_v4();
+        let yard = Uuid::new_v4();
+
+        world.spawn((
+            EntityUuid(hall),
+            Room::new(EntityId::from_uuid(area)),
+            Exits::new().add_exit(ExitData::new("South", EntityId::from_uuid(yard))),
+        ));
+        world.spawn((
+            EntityUuid(yard),
+            Room::new(EntityId::from_uuid(area)),
+            Exits::new().add_exit(ExitData::new("North", EntityId::from_uuid(hall))),
+        ));
+
+        let goblin = world.spawn((Name::new("a goblin"), location(yard)));
+        let in_hall = world.spawn((Name::new("Alice"), Avatar::new(Uuid::new_v4()), location(hall)));
+        let in_yard = world.spawn((Name::new("Bob"), Avatar::new(Uuid::new_v4()), location(yard)));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::EntityMoved {
+                entity: goblin,
+                from: (area, hall),
+                to: (area, yard),
+            },
+        );
+
+        assert_eq!(
+            narrations,
+            vec![
+                Narration::new(vec![in_hall], "A goblin leaves south.".to_string()),
+                Narration::new(vec![in_yard], "A goblin arrives from the north.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_narrate_attack_perspectives() {
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+
+        let bob = world.spawn((Name::new("Bob"), Avatar::new(Uuid::new_v4()), location(room)));
+        let rat = world.spawn((Name::new("the rat"), location(room)));
+        let alice = world.spawn((Name::new("Alice"), Avatar::new(Uuid::new_v4()), location(room)));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::EntityAttacked {
+                attacker: bob,
+                defender: rat,
+                damage: 4,
+                damage_type: DamageType::Slashing,
+                absorbed: 2,
+                critical: false,
+                off_hand: false,
+            },
+        );
+
+        assert!(narrations.contains(&Narration::new(
+            vec![bob],
+            "You hit the rat for 4 slashing damage (2 absorbed by armor).".to_string()
+        )));
+        assert!(narrations.contains(&Narration::new(
+            vec![alice],
+            "Bob hits the rat for 4 slashing damage (2 absorbed by armor).".to_string()
+        )));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::EntityAttacked {
+                attacker: rat,
+                defender: bob,
+                damage: 8,
+                damage_type: DamageType::Piercing,
+                absorbed: 0,
+                critical: true,
+                off_hand: true,
+            },
+        );
+        assert!(narrations.contains(&Narration::new(
+            vec![bob],
+            "The rat critically hits you with an off-hand blow for 8 piercing damage.".to_string()
+        )));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::AttackDefended {
+                attacker: rat,
+                defender: bob,
+                reaction: DefenseReaction::Parry,
+                off_hand: false,
+            },
+        );
+        assert!(narrations.contains(&Narration::new(
+            vec![bob],
+            "You parry the rat's attack.".to_string()
+        )));
+        assert!(narrations.contains(&Narration::new(
+            vec![alice],
+            "Bob parries the rat's attack.".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_narrate_status_effects() {
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+
+        let bob = world.spawn((
+            Name::new("Bob"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+        let alice = world.spawn((
+            Name::new("Alice"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::StatusEffectTicked {
+                entity: bob,
+                effect_type: StatusEffectType::Poisoned,
+                damage: 3,
+            },
+        );
+        assert!(narrations.contains(&Narration::new(
+            vec![bob],
+            "You take 3 damage from poison.".to_string()
+        )));
+        assert!(narrations.contains(&Narration::new(
+            vec![alice],
+            "Bob takes 3 damage from poison.".to_string()
+        )));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::StatusEffectEnded {
+                entity: bob,
+                effect_type: StatusEffectType::Burning,
+            },
+        );
+        assert!(narrations.contains(&Narration::new(
+            vec![bob],
+            "You are no longer on fire.".to_string()
+        )));
+        let narrations = narrate(
+            &world,
+            &GameEvent::StatusEffectStarted {
+                entity: bob,
+                effect_type: StatusEffectType::Defending,
+            },
+        );
+        assert!(narrations.is_empty());
+    }
+
+    #[test]
+    fn test_narrate_item_given() {
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+
+        let bob = world.spawn((
+            Name::new("Bob"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+        let alice = world.spawn((
+            Name::new("Alice"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+        let carol = world.spawn((
+            Name::new("Carol"),
+            Avatar::new(Uuid::new_v4()),
+            location(room),
+        ));
+        let sword = world.spawn((Name::new("a sword"),));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::ItemGiven {
+                entity: bob,
+                recipient: alice,
+                item: sword,
+            },
+        );
+
+        assert_eq!(
+            narrations,
+            vec![
+                Narration::new(vec![alice], "Bob gives you a sword.".to_string()),
+                Narration::new(vec![carol], "Bob gives a sword to Alice.".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unobservable_events_are_ignored() {
+        let world = GameWorld::new();
+        let event = GameEvent::Custom {
+            event_type: "test".to_string(),
+            data: String::new(),
+        };
+        assert!(!is_observable(&event));
+        assert!(narrate(&world, &event).is_empty());
+    }
+
+    #[test]
+    fn test_narrate_skill_level_up() {
+        let mut world = GameWorld::new();
+        let player = world.spawn((Name::new("Alice"), Avatar::new(Uuid::new_v4())));
+
+        let narrations = narrate(
+            &world,
+            &GameEvent::LevelUp {
+                entity: player,
+                skill: Some(Skill::Lockpicking),
+                new_level: 2,
+            },
+        );
+        assert_eq!(
+            narrations,
+            vec![Narration::new(
+                vec![player],
+                "Your Lockpicking skill has improved to Novice!".to_string()
+            )]
+        );
+    }
+}
@@ -22,16 +22,34 @@
 mod actions;
 mod combat;
 mod command;
+mod crafting;
+mod death;
+mod gathering;
 mod inventory;
+mod magic;
 mod movement;
 mod npc_ai;
 pub mod persistence;
+mod progression;
+mod regen;
+mod scheduler;
+mod skill_check;
+mod social;
 
 // Re-export all systems
 pub use actions::*;
 pub use combat::*;
 pub use command::*;
+pub use crafting::*;
+pub use death::*;
+pub use gathering::*;
 pub use inventory::*;
+pub use magic::*;
 pub use movement::*;
 pub use npc_ai::*;
 pub use persistence::*;
+pub use progression::*;
+pub use regen::*;
+pub use scheduler::*;
+pub use skill_check::*;
+pub use social::*;
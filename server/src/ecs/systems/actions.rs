@@ -15,23 +15,49 @@
 //
 
 //! Pre-built GOAP actions for NPCs
+//!
+//! Actions act through the same commands players type, run as the NPC by the
+//! [`CommandSystem`](crate::ecs::systems::CommandSystem). An action that takes
+//! several updates, such as walking to a distant room, reports
+//! [`ActionStatus::Running`] until it is done. Targets come from the NPC
+//! itself: its state target or opponent, and the guard post, patrol route and
+//! destination on its [`GoapPlanner`].
 
 use crate::ecs::components::*;
 use crate::ecs::context::WorldContext;
+use crate::ecs::systems::{
+    CommandResult, body_scores, npc_target, passable_exits, path_step, resolve, room_of,
+    wander_exit,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use rand::seq::IndexedRandom;
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// Progress of an action after one update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionStatus {
+    /// The action needs further updates to finish
+    Running,
+    /// The action is finished and its effects hold
+    Done,
+}
 
 /// Action handler trait for executing GOAP actions
 #[async_trait::async_trait]
 pub trait ActionHandler: Send + Sync {
-    /// Execute the action for the given entity
-    async fn execute(&self, context: Arc<WorldContext>, entity: hecs::Entity)
-    -> Result<(), String>;
+    /// Carry the action one update further for the given entity
+    async fn execute(
+        &self,
+        context: Arc<WorldContext>,
+        entity: hecs::Entity,
+    ) -> Result<ActionStatus, String>;
 
     /// Get the action definition
     fn definition(&self) -> GoapAction;
 }
 
-/// Wander action - NPC moves to a random adjacent location
+/// Wander action - NPC moves to a random adjacent location in its area
 pub struct WanderAction;
 
 #[async_trait::async_trait]
@@ -40,26 +66,14 @@ impl ActionHandler for WanderAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
+    ) -> Result<ActionStatus, String> {
         tracing::debug!("NPC {:?}: Executing wander action", entity);
 
-        // Get current location
-        let location = {
+        let dest = {
             let world = context.entities().read().await;
-            *world
-                .get::<&Location>(entity)
-                .map_err(|_| "NPC has no location")?
+            wander_exit(&world, entity).ok_or("Nowhere to wander")?
         };
-
-        // TODO: Get available exits and pick random one
-        // For now, just log the action
-        tracing::info!(
-            "NPC {:?} is wandering from location {:?}",
-            entity,
-            location.room_id
-        );
-
-        Ok(())
+        walk_toward(&context, entity, dest).await
     }
 
     fn definition(&self) -> GoapAction {
@@ -70,10 +84,8 @@ impl ActionHandler for WanderAction {
     }
 }
 
-/// Follow action - NPC follows a target entity
-pub struct FollowAction {
-    pub target: uuid::Uuid,
-}
+/// Follow action - NPC walks to wherever its target is
+pub struct FollowAction;
 
 #[async_trait::async_trait]
 impl ActionHandler for FollowAction {
@@ -81,40 +93,15 @@ impl ActionHandler for FollowAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
-        tracing::debug!(
-            "NPC {:?}: Executing follow action for target {}",
-            entity,
-            self.target
-        );
-
-        // Get target location
-        let target_entity = context
-            .get_entity_by_uuid(self.target)
-            .await
-            .ok_or("Target not found")?;
-
-        let (target_location, npc_location) = {
+    ) -> Result<ActionStatus, String> {
+        tracing::debug!("NPC {:?}: Executing follow action", entity);
+
+        let dest = {
             let world = context.entities().read().await;
-            let target_loc = *world
-                .get::<&Location>(target_entity)
-                .map_err(|_| "Target has no location")?;
-            let npc_loc = *world
-                .get::<&Location>(entity)
-                .map_err(|_| "NPC has no location")?;
-            (target_loc, npc_loc)
+            let target = npc_target(&world, entity).ok_or("Target not found")?;
+            room_of(&world, target).ok_or("Target has no location")?
         };
-
-        if target_location.room_id != npc_location.room_id {
-            tracing::info!(
-                "NPC {:?} following target to room {:?}",
-                entity,
-                target_location.room_id
-            );
-            // TODO: Pathfinding and movement
-        }
-
-        Ok(())
+        walk_toward(&context, entity, dest).await
     }
 
     fn definition(&self) -> GoapAction {
@@ -125,10 +112,10 @@ impl ActionHandler for FollowAction {
     }
 }
 
-/// Attack action - NPC attacks a target
-pub struct AttackAction {
-    pub target: uuid::Uuid,
-}
+/// Attack action - NPC starts a fight with its target
+///
+/// The combat system carries the fight on from there.
+pub struct AttackAction;
 
 #[async_trait::async_trait]
 impl ActionHandler for AttackAction {
@@ -136,22 +123,26 @@ impl ActionHandler for AttackAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
-        tracing::debug!(
-            "NPC {:?}: Executing attack action on target {}",
-            entity,
-            self.target
-        );
-
-        let target_entity = context
-            .get_entity_by_uuid(self.target)
-            .await
-            .ok_or("Target not found")?;
-
-        // TODO: Implement combat system integration
-        tracing::info!("NPC {:?} attacking target {:?}", entity, target_entity);
-
-        Ok(())
+    ) -> Result<ActionStatus, String> {
+        tracing::debug!("NPC {:?}: Executing attack action", entity);
+
+        let keyword = {
+            let world = context.entities().read().await;
+            let target = npc_target(&world, entity).ok_or("Target not found")?;
+            let fighting = world.get::<&Combatant>(entity).is_ok_and(|combatant| {
+                combatant.in_combat
+                    && combatant
+                        .target_id
+                        .is_some_and(|id| resolve(&world, id) == Some(target))
+            });
+            if fighting {
+                return Ok(ActionStatus::Done);
+            }
+            keyword_of(&world, target).ok_or("Target has no name")?
+        };
+
+        issue(&context, entity, "attack", &[keyword]).await?;
+        Ok(ActionStatus::Done)
     }
 
     fn definition(&self) -> GoapAction {
@@ -163,7 +154,9 @@ impl ActionHandler for AttackAction {
     }
 }
 
-/// Flee action - NPC flees from danger
+/// Flee action - NPC breaks off its fight and runs through a random exit
+///
+/// Failed attempts to get away are retried on the next update.
 pub struct FleeAction;
 
 #[async_trait::async_trait]
@@ -172,13 +165,29 @@ impl ActionHandler for FleeAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
+    ) -> Result<ActionStatus, String> {
         tracing::debug!("NPC {:?}: Executing flee action", entity);
 
-        // TODO: Find safe location and move there
-        tracing::info!("NPC {:?} is fleeing", entity);
+        let in_combat = {
+            let world = context.entities().read().await;
+            world
+                .get::<&Combatant>(entity)
+                .is_ok_and(|combatant| combatant.in_combat)
+        };
+        if in_combat && issue(&context, entity, "flee", &[]).await.is_err() {
+            return Ok(ActionStatus::Running);
+        }
 
-        Ok(())
+        let exit = {
+            let world = context.entities().read().await;
+            room_of(&world, entity)
+                .map(|room| passable_exits(&world, room))
+                .and_then(|exits| exits.choose(&mut rand::rng()).cloned())
+        };
+        if let Some((_, dest)) = exit {
+            walk_toward(&context, entity, dest).await?;
+        }
+        Ok(ActionStatus::Done)
     }
 
     fn definition(&self) -> GoapAction {
@@ -190,11 +199,8 @@ impl ActionHandler for FleeAction {
     }
 }
 
-/// Patrol action - NPC patrols between waypoints
-pub struct PatrolAction {
-    pub waypoints: Vec<uuid::Uuid>,
-    pub current_index: usize,
-}
+/// Patrol action - NPC walks to the next room on its patrol route
+pub struct PatrolAction;
 
 #[async_trait::async_trait]
 impl ActionHandler for PatrolAction {
@@ -202,23 +208,26 @@ impl ActionHandler for PatrolAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
+    ) -> Result<ActionStatus, String> {
         tracing::debug!("NPC {:?}: Executing patrol action", entity);
 
-        if self.waypoints.is_empty() {
-            return Err("No waypoints defined".to_string());
-        }
-
-        let target_waypoint = self.waypoints[self.current_index % self.waypoints.len()];
-        tracing::info!(
-            "NPC {:?} patrolling to waypoint {:?}",
-            entity,
-            target_waypoint
-        );
-
-        // TODO: Move to waypoint
+        let waypoint = {
+            let world = context.entities().read().await;
+            world
+                .get::<&GoapPlanner>(entity)
+                .ok()
+                .and_then(|planner| planner.next_waypoint())
+                .ok_or("No waypoints defined")?
+        };
 
-        Ok(())
+        let status = walk_toward(&context, entity, waypoint.uuid()).await?;
+        if status == ActionStatus::Done {
+            let world = context.entities().read().await;
+            if let Ok(mut planner) = world.get::<&mut GoapPlanner>(entity) {
+                planner.patrol_index = (planner.patrol_index + 1) % planner.patrol_route.len();
+            }
+        }
+        Ok(status)
     }
 
     fn definition(&self) -> GoapAction {
@@ -229,10 +238,8 @@ impl ActionHandler for PatrolAction {
     }
 }
 
-/// Guard action - NPC guards a location
-pub struct GuardAction {
-    pub location: EntityId,
-}
+/// Guard action - NPC returns to its guard post
+pub struct GuardAction;
 
 #[async_trait::async_trait]
 impl ActionHandler for GuardAction {
@@ -240,32 +247,18 @@ impl ActionHandler for GuardAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
-        tracing::debug!(
-            "NPC {:?}: Executing guard action at {:?}",
-            entity,
-            self.location
-        );
-
-        let npc_location = {
+    ) -> Result<ActionStatus, String> {
+        tracing::debug!("NPC {:?}: Executing guard action", entity);
+
+        let post = {
             let world = context.entities().read().await;
-            *world
-                .get::<&Location>(entity)
-                .map_err(|_| "NPC has no location")?
+            world
+                .get::<&GoapPlanner>(entity)
+                .ok()
+                .and_then(|planner| planner.guard_post)
+                .ok_or("No guard post defined")?
         };
-
-        if npc_location.room_id != self.location {
-            tracing::info!(
-                "NPC {:?} returning to guard post {:?}",
-                entity,
-                self.location
-            );
-            // TODO: Move to guard location
-        } else {
-            tracing::debug!("NPC {:?} is guarding location", entity);
-        }
-
-        Ok(())
+        walk_toward(&context, entity, post.uuid()).await
     }
 
     fn definition(&self) -> GoapAction {
@@ -276,7 +269,38 @@ impl ActionHandler for GuardAction {
     }
 }
 
-/// Rest action - NPC rests to recover
+/// Travel action - NPC walks to its destination, such as one set by its schedule
+pub struct TravelAction;
+
+#[async_trait::async_trait]
+impl ActionHandler for TravelAction {
+    async fn execute(
+        &self,
+        context: Arc<WorldContext>,
+        entity: hecs::Entity,
+    ) -> Result<ActionStatus, String> {
+        tracing::debug!("NPC {:?}: Executing travel action", entity);
+
+        let destination = {
+            let world = context.entities().read().await;
+            world
+                .get::<&GoapPlanner>(entity)
+                .ok()
+                .and_then(|planner| planner.destination)
+                .ok_or("No destination defined")?
+        };
+        walk_toward(&context, entity, destination.uuid()).await
+    }
+
+    fn definition(&self) -> GoapAction {
+        GoapAction::new("travel", "Travel to Destination")
+            .with_precondition("has_destination", true)
+            .with_effect("at_destination", true)
+            .with_cost(1.0)
+    }
+}
+
+/// Rest action - NPC rests until its health and energy are full, then stands
 pub struct RestAction;
 
 #[async_trait::async_trait]
@@ -285,13 +309,28 @@ impl ActionHandler for RestAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
+    ) -> Result<ActionStatus, String> {
         tracing::debug!("NPC {:?}: Executing rest action", entity);
 
-        // TODO: Restore health/mana
-        tracing::info!("NPC {:?} is resting", entity);
+        let (posture, rested) = {
+            let world = context.entities().read().await;
+            let rested = body_scores(&world, entity).is_some_and(|scores| {
+                scores.health_current >= scores.health_maximum
+                    && scores.energy_current >= scores.energy_maximum
+            });
+            (posture_of(&world, entity), rested)
+        };
 
-        Ok(())
+        if rested {
+            if posture != Posture::Standing {
+                issue(&context, entity, "stand", &[]).await?;
+            }
+            return Ok(ActionStatus::Done);
+        }
+        if posture == Posture::Standing {
+            issue(&context, entity, "rest", &[]).await?;
+        }
+        Ok(ActionStatus::Running)
     }
 
     fn definition(&self) -> GoapAction {
@@ -303,10 +342,8 @@ impl ActionHandler for RestAction {
     }
 }
 
-/// Interact action - NPC interacts with an object
-pub struct InteractAction {
-    pub target: uuid::Uuid,
-}
+/// Interact action - NPC picks up the item it is after
+pub struct InteractAction;
 
 #[async_trait::async_trait]
 impl ActionHandler for InteractAction {
@@ -314,17 +351,17 @@ impl ActionHandler for InteractAction {
         &self,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
-        tracing::debug!(
-            "NPC {:?}: Executing interact action with {:?}",
-            entity,
-            self.target
-        );
+    ) -> Result<ActionStatus, String> {
+        tracing::debug!("NPC {:?}: Executing interact action", entity);
 
-        // TODO: Implement interaction system
-        tracing::info!("NPC {:?} interacting with {:?}", entity, self.target);
+        let keyword = {
+            let world = context.entities().read().await;
+            let target = npc_target(&world, entity).ok_or("Target not found")?;
+            keyword_of(&world, target).ok_or("Target has no name")?
+        };
 
-        Ok(())
+        issue(&context, entity, "get", &[keyword]).await?;
+        Ok(ActionStatus::Done)
     }
 
     fn definition(&self) -> GoapAction {
@@ -335,6 +372,80 @@ impl ActionHandler for InteractAction {
     }
 }
 
+/// Run a command as an NPC, turning anything but success into an error
+async fn issue(
+    context: &Arc<WorldContext>,
+    entity: EcsEntity,
+    command: &str,
+    args: &[String],
+) -> Result<String, String> {
+    match context
+        .command_system()
+        .execute(context.clone(), entity, command, args)
+        .await
+    {
+        CommandResult::Success(output) => Ok(output),
+        CommandResult::Failure(message) | CommandResult::Invalid(message) => Err(message),
+    }
+}
+
+/// Take one step along the shortest path to a room
+///
+/// Done once the NPC is standing in the room.
+async fn walk_toward(
+    context: &Arc<WorldContext>,
+    entity: EcsEntity,
+    dest: Uuid,
+) -> Result<ActionStatus, String> {
+    let (here, next, posture) = {
+        let world = context.entities().read().await;
+        let here = room_of(&world, entity).ok_or("NPC has no location")?;
+        (
+            here,
+            path_step(&world, here, dest),
+            posture_of(&world, entity),
+        )
+    };
+    if here == dest {
+        return Ok(ActionStatus::Done);
+    }
+    let (direction, next) = next.ok_or_else(|| format!("No path to room {}", dest))?;
+
+    if posture != Posture::Standing {
+        issue(context, entity, "stand", &[]).await?;
+    }
+    // Moving shows the NPC the new room, so where it ends up tells whether it moved
+    let result = issue(context, entity, &direction, &[]).await;
+    let now = room_of(&*context.entities().read().await, entity);
+    if now != Some(next) {
+        return Err(result
+            .err()
+            .unwrap_or_else(|| format!("Could not go {}", direction)));
+    }
+    if next == dest {
+        Ok(ActionStatus::Done)
+    } else {
+        Ok(ActionStatus::Running)
+    }
+}
+
+fn posture_of(world: &GameWorld, entity: EcsEntity) -> Posture {
+    world
+        .get::<&Posture>(entity)
+        .map(|posture| *posture)
+        .unwrap_or_default()
+}
+
+/// Keyword that picks an entity out by name in commands
+fn keyword_of(world: &GameWorld, entity: EcsEntity) -> Option<String> {
+    world.get::<&Name>(entity).ok().map(|name| {
+        name.keywords
+            .first()
+            .cloned()
+            .unwrap_or_else(|| name.display.to_lowercase())
+    })
+}
+
 /// Action library for managing pre-built actions
 pub struct ActionLibrary {
     handlers: std::collections::HashMap<String, Box<dyn ActionHandler>>,
@@ -349,39 +460,14 @@ impl ActionLibrary {
 
         // Register all default actions
         library.register("wander", Box::new(WanderAction));
-        library.register(
-            "follow",
-            Box::new(FollowAction {
-                target: uuid::Uuid::nil(),
-            }),
-        );
-        library.register(
-            "attack",
-            Box::new(AttackAction {
-                target: uuid::Uuid::nil(),
-            }),
-        );
+        library.register("follow", Box::new(FollowAction));
+        library.register("attack", Box::new(AttackAction));
         library.register("flee", Box::new(FleeAction));
-        library.register(
-            "patrol",
-            Box::new(PatrolAction {
-                waypoints: vec![],
-                current_index: 0,
-            }),
-        );
-        library.register(
-            "guard",
-            Box::new(GuardAction {
-                location: EntityId::from_uuid(uuid::Uuid::nil()),
-            }),
-        );
+        library.register("patrol", Box::new(PatrolAction));
+        library.register("guard", Box::new(GuardAction));
+        library.register("travel", Box::new(TravelAction));
         library.register("rest", Box::new(RestAction));
-        library.register(
-            "interact",
-            Box::new(InteractAction {
-                target: uuid::Uuid::nil(),
-            }),
-        );
+        library.register("interact", Box::new(InteractAction));
 
         library
     }
@@ -396,13 +482,13 @@ impl ActionLibrary {
         self.handlers.get(id).map(|h| h.as_ref())
     }
 
-    /// Execute an action
+    /// Carry an action one update further
     pub async fn execute(
         &self,
         action_id: &str,
         context: Arc<WorldContext>,
         entity: hecs::Entity,
-    ) -> Result<(), String> {
+    ) -> Result<ActionStatus, String> {
         match self.get(action_id) {
             Some(handler) => handler.execute(context, entity).await,
             None => Err(format!("Unknown action: {}", action_id)),
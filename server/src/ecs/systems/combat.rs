@@ -15,28 +15,83 @@
 //
 
 //! Combat system for fighting mechanics
+//!
+//! Any number of combatants in a room can fight one another. Each keeps a
+//! [`ThreatTable`] of the hostiles it is engaged with; damage dealt to it and
+//! healing given to its enemies build threat, and NPCs always turn on the
+//! hostile with the most. Players keep the target they chose until it falls
+//! or leaves. A combatant drops out of combat, with `GameEvent::CombatEnded`,
+//! once the last hostile it was engaged with has died, fled or left the room.
 
 use crate::ecs::components::{
-    AttributeScores, Combatant, EntityId, EntityUuid, EquipSlot, Equipment, StatusEffect,
-    StatusEffectType, StatusEffects,
+    Armor, AttributeScores, AttributeType, Avatar, BodyAttributeScores, Combatant, DamageType,
+    DefenseReaction, EntityId, EntityUuid, EquipSlot, Equipment, Location, Material, Skill, Skills,
+    StatusEffect, StatusEffectType, StatusEffects, StatusUpdate, Talent, Talents, ThreatTable,
+    Weapon,
 };
 use crate::ecs::events::{EventBus, GameEvent};
 use crate::ecs::registry::EntityRegistry;
+use crate::ecs::systems::{CheckResult, ProgressionSystem, SkillCheck, SkillCheckResolver};
 use crate::ecs::{EcsEntity, GameWorld};
-use tracing::instrument;
 use hecs::Entity;
-use std::collections::HashMap;
+use std::collections::HashSet;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Damage of a blow before weapon and offence are added
+pub const BASE_DAMAGE: i32 = 10;
+
+/// Chance of a hit being critical
+pub const CRITICAL_CHANCE: f32 = 0.1;
+
+/// Bleeding inflicted by a critical cut or stab
+pub const CRITICAL_BLEED_MAGNITUDE: i32 = 2;
+
+/// Seconds a critical wound bleeds for
+pub const CRITICAL_BLEED_DURATION: f32 = 9.0;
+
+/// Difficulty of a defensive reaction against an attacker of average offence
+pub const REACTION_DIFFICULTY: i32 = 18;
+
+/// Block bonus from the Shield Expert talent
+pub const SHIELD_EXPERT_BONUS: i32 = 3;
+
+/// Reaction bonus against off-hand attacks from those without Dual Wielder
+pub const OFF_HAND_PENALTY: i32 = 4;
+
+/// Share of damage an off-hand attack deals without Ambidextrous
+pub const OFF_HAND_DAMAGE_FACTOR: f32 = 0.5;
+
+/// Threat a defender starts with against whoever attacked it
+pub const ENGAGE_THREAT: f32 = 1.0;
+
+/// Threat gained per point of damage dealt
+pub const THREAT_PER_DAMAGE: f32 = 1.0;
+
+/// Threat gained per point of healing, from everyone fighting the healed entity
+pub const THREAT_PER_HEALING: f32 = 0.5;
+
+/// Difficulty of the Shields check to rescue an ally
+pub const RESCUE_DIFFICULTY: i32 = 10;
 
 pub struct CombatSystem {
     event_bus: EventBus,
-    target_map: HashMap<EcsEntity, EcsEntity>,
+    resolver: SkillCheckResolver,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AttackResult {
     pub hit: bool,
+    /// Damage dealt after armor
     pub damage: i32,
     pub critical: bool,
+    pub damage_type: DamageType,
+    /// Damage the defender's armor absorbed
+    pub absorbed: i32,
+    /// How the defender avoided the attack, if it missed
+    pub reaction: Option<DefenseReaction>,
+    /// Whether the attack was made with an off-hand weapon
+    pub off_hand: bool,
 }
 
 impl CombatSystem {
@@ -44,37 +99,32 @@ impl CombatSystem {
     pub fn new(event_bus: EventBus) -> Self {
         Self {
             event_bus,
-            target_map: HashMap::new(),
+            resolver: SkillCheckResolver::new(),
         }
     }
 
+    /// Use a fixed seed for defensive reaction checks
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.resolver = SkillCheckResolver::seeded(seed);
+        self
+    }
+
     /// Start combat between two entities
+    ///
+    /// The attacker switches to the defender, which fights back unless it is
+    /// already fighting someone else.
     pub fn start_combat(
         &mut self,
         world: &mut GameWorld,
         attacker: EcsEntity,
         defender: EcsEntity,
     ) {
-        // Set attacker in combat and store defender EntityId
-        if let Ok(defender_uuid) = world.get::<&EntityUuid>(defender) {
-            if let Ok(mut combatant) = world.get::<&mut Combatant>(attacker) {
-                combatant.in_combat = true;
-                combatant.target_id = Some(EntityId::new(defender, defender_uuid.0));
-            }
+        if let (Some(attacker_id), Some(defender_id)) =
+            (entity_id(world, attacker), entity_id(world, defender))
+        {
+            engage(world, attacker_id, defender_id);
         }
 
-        // Set defender in combat and store attacker EntityId
-        if let Ok(attacker_uuid) = world.get::<&EntityUuid>(attacker) {
-            if let Ok(mut combatant) = world.get::<&mut Combatant>(defender) {
-                combatant.in_combat = true;
-                combatant.target_id = Some(EntityId::new(attacker, attacker_uuid.0));
-            }
-        }
-
-        // Track targets in the system
-        self.target_map.insert(attacker, defender);
-        self.target_map.insert(defender, attacker);
-
         self.event_bus
             .publish(GameEvent::CombatStarted { attacker, defender });
     }
@@ -95,21 +145,7 @@ impl CombatSystem {
             .get_entity_id(attacker)
             .ok_or_else(|| "Attacker not registered".to_string())?;
 
-        // Set attacker in combat
-        if let Ok(mut combatant) = world.get::<&mut Combatant>(attacker) {
-            combatant.in_combat = true;
-            combatant.target_id = Some(defender_id);
-        }
-
-        // Set defender in combat
-        if let Ok(mut combatant) = world.get::<&mut Combatant>(defender) {
-            combatant.in_combat = true;
-            combatant.target_id = Some(attacker_id);
-        }
-
-        // Track targets in the system
-        self.target_map.insert(attacker, defender);
-        self.target_map.insert(defender, attacker);
+        engage(world, attacker_id, defender_id);
 
         self.event_bus
             .publish(GameEvent::CombatStarted { attacker, defender });
@@ -117,16 +153,23 @@ impl CombatSystem {
         Ok(())
     }
 
-    /// End combat for an entity
+    /// Take an entity out of combat
+    ///
+    /// Everyone engaged with it forgets it, and those left without a hostile
+    /// leave combat too, reported by a single `CombatEnded`.
     pub fn end_combat(&mut self, world: &mut GameWorld, entity: EcsEntity) {
-        if let Ok(mut combatant) = world.get::<&mut Combatant>(entity) {
-            combatant.in_combat = false;
-            combatant.target_id = None;
+        let participants = leave_combat(world, entity);
+        if !participants.is_empty() {
+            self.event_bus
+                .publish(GameEvent::CombatEnded { participants });
         }
-        self.target_map.remove(&entity);
     }
 
     /// Perform an attack
+    ///
+    /// Attacks with the main hand, then follows up with the off hand when the
+    /// attacker wields a second weapon and the defender is still standing.
+    /// Returns the result of the main hand attack.
     #[instrument(skip(self, world))]
     pub fn attack(
         &mut self,
@@ -134,46 +177,65 @@ impl CombatSystem {
         attacker: EcsEntity,
         defender: EcsEntity,
     ) -> Option<AttackResult> {
-        // Calculate base damage
-        let mut damage = 10; // Base damage
-
-        // Add offence score modifier
-        if let Ok(attrs) = world.get::<&AttributeScores>(attacker) {
-            damage += (attrs.score_offence - 10) / 2; // Modifier calculation
+        let result = self.strike(world, attacker, defender, EquipSlot::MainHand)?;
+        if is_alive(world, defender) && weapon_in(world, attacker, EquipSlot::OffHand).is_some() {
+            self.strike(world, attacker, defender, EquipSlot::OffHand);
         }
+        Some(result)
+    }
 
-        // Add weapon damage
-        if let Ok(equipment) = world.get::<&Equipment>(attacker) {
-            if let Some(weapon_id) = equipment.get(EquipSlot::MainHand) {
-                // Convert Uuid to EcsEntity - for now just use base damage
-                // TODO: Properly handle weapon entity lookup
-                damage += 5; // Placeholder weapon damage
+    /// Attack with the weapon in one hand, giving the defender a chance to react
+    fn strike(
+        &mut self,
+        world: &mut GameWorld,
+        attacker: EcsEntity,
+        defender: EcsEntity,
+        hand: EquipSlot,
+    ) -> Option<AttackResult> {
+        body_scores(world, defender)?;
+        let off_hand = hand == EquipSlot::OffHand;
+        if let Some(reaction) = self.react(world, attacker, defender, off_hand) {
+            self.event_bus.publish(GameEvent::AttackDefended {
+                attacker,
+                defender,
+                reaction,
+                off_hand,
+            });
+            if let (Some(attacker_id), Some(defender_id)) =
+                (entity_id(world, attacker), entity_id(world, defender))
+            {
+                add_threat(world, attacker, defender_id, 0.0);
+                add_threat(world, defender, attacker_id, 0.0);
             }
+            return Some(AttackResult {
+                hit: false,
+                damage: 0,
+                critical: false,
+                damage_type: weapon_in(world, attacker, hand)
+                    .map_or(DamageType::Blunt, |weapon| weapon.damage_type),
+                absorbed: 0,
+                reaction: Some(reaction),
+                off_hand,
+            });
         }
 
-        // Ensure minimum damage
-        damage = damage.max(1);
-
-        // Check for critical hit (10% chance)
-        let critical = rand::random::<f32>() < 0.1;
-        if critical {
-            damage *= 2;
-        }
+        let result = resolve_damage(world, attacker, defender, hand, &mut ::rand::rng());
+        let damage = result.damage;
 
         // Apply damage to defender
-        let mut target_died = false;
-        if let Ok(mut health) = world.get::<&mut AttributeScores>(defender) {
+        let target_died = with_body_scores(world, defender, |health| {
             health.health_current = (health.health_current - damage as f32).max(0.0);
-            target_died = health.health_current <= 0.0;
-
-            self.event_bus.publish(GameEvent::EntityAttacked {
-                attacker,
-                defender,
-                damage,
-            });
-        } else {
-            return None;
-        }
+            health.health_current <= 0.0
+        })?;
+        self.event_bus.publish(GameEvent::EntityAttacked {
+            attacker,
+            defender,
+            damage,
+            damage_type: result.damage_type,
+            absorbed: result.absorbed,
+            critical: result.critical,
+            off_hand,
+        });
 
         // Handle death
         if target_died {
@@ -182,55 +244,90 @@ impl CombatSystem {
                 killer: Some(attacker),
             });
 
-            // End combat for both entities
-            self.end_combat(world, attacker);
+            // Everyone fighting the defender moves on or stops
             self.end_combat(world, defender);
+        } else if let (Some(attacker_id), Some(defender_id)) =
+            (entity_id(world, attacker), entity_id(world, defender))
+        {
+            add_threat(world, attacker, defender_id, 0.0);
+            add_threat(
+                world,
+                defender,
+                attacker_id,
+                damage as f32 * THREAT_PER_DAMAGE,
+            );
+
+            // Critical cuts and stabs leave the defender bleeding
+            if result.critical
+                && matches!(
+                    result.damage_type,
+                    DamageType::Slashing | DamageType::Piercing
+                )
+            {
+                let bleed = StatusEffect::new(
+                    StatusEffectType::Bleeding,
+                    CRITICAL_BLEED_DURATION,
+                    CRITICAL_BLEED_MAGNITUDE,
+                )
+                .with_source(attacker_id);
+                self.apply_status_effect(world, defender, bleed);
+            }
         }
 
-        Some(AttackResult {
-            hit: true,
-            damage,
-            critical,
-        })
+        Some(result)
     }
 
-    /// Update the combat system
-    #[instrument(skip(self, world))]
-    pub fn update(&mut self, world: &mut GameWorld, delta_time: f32) {
-        let mut attacks = Vec::new();
-        let mut _dead_targets: Vec<hecs::Entity> = Vec::new();
-
-        // Find entities ready to attack and check target health
-        for (entity, combatant) in world.query_mut::<(Entity, &mut Combatant)>() {
-            if combatant.in_combat {
-                combatant.update_timer(delta_time);
-
-                if combatant.can_attack() {
-                    if let Some(&target) = self.target_map.get(&entity) {
-                        attacks.push((entity, target));
-                        combatant.reset_timer();
-                    }
-                }
-            }
+    /// Let a defender try to avoid an attack with its best trained reaction
+    ///
+    /// The check is harder against attackers with higher offence, and easier
+    /// while defending or against an off-hand attack from someone without
+    /// Dual Wielder. Returns the reaction if it succeeded.
+    fn react(
+        &mut self,
+        world: &GameWorld,
+        attacker: EcsEntity,
+        defender: EcsEntity,
+        off_hand: bool,
+    ) -> Option<DefenseReaction> {
+        let reaction = defense_reaction(world, defender)?;
+        let attribute = match reaction {
+            DefenseReaction::Dodge => AttributeType::BodyFinesse,
+            DefenseReaction::Parry => AttributeType::BodyOffence,
+            DefenseReaction::Block => AttributeType::BodyDefence,
+        };
+        let offence = body_scores(world, attacker)
+            .map(|attrs| (attrs.score_offence - 10) / 2)
+            .unwrap_or(0);
+        let mut check =
+            SkillCheck::new(reaction.skill(), attribute).against(REACTION_DIFFICULTY + offence);
+        if reaction == DefenseReaction::Block && has_talent(world, defender, Talent::ShieldExpert) {
+            check = check.with_modifier(Talent::ShieldExpert.name(), SHIELD_EXPERT_BONUS);
         }
-
-        // Check which targets are alive (separate pass to avoid borrow issues)
-        attacks.retain(|(_, target)| {
-            if let Ok(health) = world.get::<&AttributeScores>(*target) {
-                health.health_current > 0.0
-            } else {
-                false
+        if off_hand && !has_talent(world, attacker, Talent::DualWielder) {
+            check = check.with_modifier("Off-hand attack", OFF_HAND_PENALTY);
+        }
+        if let Ok(combatant) = world.get::<&Combatant>(defender) {
+            if combatant.is_defending {
+                check = check.with_modifier("Defending", combatant.defense_bonus);
             }
-        });
-
-        // Execute attacks
-        for (attacker, defender) in attacks {
-            self.attack(world, attacker, defender);
         }
 
-        // Note: Target cleanup via UUID->Entity lookup requires registry access
-        // This is handled by the start_combat_with_registry method
-        // For now, the target_map provides runtime target tracking
+        let result = self.resolver.resolve(world, defender, &check);
+        let progression = ProgressionSystem::new(self.event_bus.clone());
+        progression.record_use(
+            world,
+            defender,
+            reaction.skill(),
+            result.challenge_level(),
+            result.outcome.is_success(),
+        );
+        result.outcome.is_success().then_some(reaction)
+    }
+
+    /// Update the combat system, returning the UUIDs of everyone who fought
+    #[instrument(skip(self, world))]
+    pub fn update(&mut self, world: &mut GameWorld, delta_time: f32) -> Vec<Uuid> {
+        self.resolve_round(world, delta_time, |world, entity| world.contains(entity))
     }
 
     /// Update with registry for proper UUID->Entity resolution
@@ -241,50 +338,241 @@ impl CombatSystem {
         world: &mut GameWorld,
         registry: &EntityRegistry,
         delta_time: f32,
-    ) {
-        let mut attacks = Vec::new();
+    ) -> Vec<Uuid> {
+        self.resolve_round(world, delta_time, |_, entity| {
+            registry.contains_entity(entity)
+        })
+    }
+
+    /// Let every combatant that is ready attack its target
+    ///
+    /// Returns the UUIDs of the attackers and defenders, whose health and
+    /// skills the round may have changed, so they can be saved.
+    fn resolve_round(
+        &mut self,
+        world: &mut GameWorld,
+        delta_time: f32,
+        is_present: impl Fn(&GameWorld, EcsEntity) -> bool,
+    ) -> Vec<Uuid> {
+        self.drop_departed(world, &is_present);
+        follow_threat(world);
 
         // Find entities ready to attack
-        for (entity, combatant) in world.query_mut::<(Entity, &mut Combatant)>() {
+        // Haste and slowness change how quickly attacks come around, and
+        // stunned combatants can't attack at all
+        let mut attacks = Vec::new();
+        for (entity, combatant, effects) in
+            world.query_mut::<(Entity, &mut Combatant, Option<&StatusEffects>)>()
+        {
             if combatant.in_combat {
-                combatant.update_timer(delta_time);
+                let speed = effects.map_or(1.0, |effects| effects.speed_factor());
+                combatant.update_timer(delta_time * speed);
 
-                if combatant.can_attack() {
-                    // Get target from EntityId
+                let stunned =
+                    effects.is_some_and(|effects| effects.has_effect(StatusEffectType::Stunned));
+                if combatant.can_attack() && !stunned {
                     if let Some(target_id) = combatant.target_id {
-                        // Verify the entity is still in the registry
-                        if registry.contains_entity(target_id.entity()) {
-                            attacks.push((entity, target_id.entity()));
-                            combatant.reset_timer();
-                        }
+                        attacks.push((entity, target_id.entity()));
                     }
                 }
             }
         }
+        attacks.retain(|(_, target)| is_present(world, *target));
 
-        // Check which targets are alive
-        attacks.retain(|(_, target)| {
-            if let Ok(health) = world.get::<&AttributeScores>(*target) {
-                health.health_current > 0.0
-            } else {
-                false
-            }
-        });
-
-        // Execute attacks
+        // Execute attacks, skipping anyone killed earlier in the round
+        let mut changed = Vec::new();
         for (attacker, defender) in attacks {
+            let attacker_down =
+                body_scores(world, attacker).is_some_and(|health| health.health_current <= 0.0);
+            if attacker_down || !is_alive(world, defender) {
+                continue;
+            }
+            if let Ok(mut combatant) = world.get::<&mut Combatant>(attacker) {
+                combatant.reset_timer();
+            }
             self.attack(world, attacker, defender);
+            for entity in [attacker, defender] {
+                if let Some(id) = entity_id(world, entity) {
+                    if !changed.contains(&id.uuid()) {
+                        changed.push(id.uuid());
+                    }
+                }
+            }
+        }
+        changed
+    }
+
+    /// Forget hostiles that are gone or have left the room
+    fn drop_departed(
+        &mut self,
+        world: &mut GameWorld,
+        is_present: &impl Fn(&GameWorld, EcsEntity) -> bool,
+    ) {
+        let mut departed = Vec::new();
+        for (entity, combatant, threat) in
+            world.query::<(Entity, &Combatant, &ThreatTable)>().iter()
+        {
+            if !combatant.in_combat {
+                continue;
+            }
+            let room = room_of(world, entity);
+            for hostile in threat.hostiles().map(|id| id.entity()) {
+                if !world.contains(hostile)
+                    || !is_present(world, hostile)
+                    || room_of(world, hostile) != room
+                {
+                    departed.push((entity, hostile));
+                }
+            }
+        }
+
+        let mut participants = Vec::new();
+        for (entity, hostile) in departed {
+            if let Ok(mut threat) = world.get::<&mut ThreatTable>(entity) {
+                threat.remove(hostile);
+            }
+            if settle(world, entity) {
+                participants.push(entity);
+            }
+        }
+        if !participants.is_empty() {
+            self.event_bus
+                .publish(GameEvent::CombatEnded { participants });
+        }
+    }
+
+    /// Spread threat for healing to everyone fighting the healed entity
+    ///
+    /// Each of them engages the healer, pulling it into the fight.
+    pub fn add_healing_threat(
+        &mut self,
+        world: &mut GameWorld,
+        healer: EcsEntity,
+        healed: EcsEntity,
+        amount: i32,
+    ) {
+        let Some(healer_id) = entity_id(world, healer) else {
+            return;
+        };
+        let enemies: Vec<EcsEntity> = world
+            .query::<(Entity, &Combatant, &ThreatTable)>()
+            .iter()
+            .filter(|(other, combatant, threat)| {
+                *other != healer && combatant.in_combat && threat.contains(healed)
+            })
+            .map(|(other, _, _)| other)
+            .collect();
+        for enemy in enemies {
+            add_threat(
+                world,
+                enemy,
+                healer_id,
+                amount.max(0) as f32 * THREAT_PER_HEALING,
+            );
+            if let Some(enemy_id) = entity_id(world, enemy) {
+                add_threat(world, healer, enemy_id, 0.0);
+            }
+        }
+    }
+
+    /// Join an ally's fight against its current target, returning the target
+    pub fn assist(
+        &mut self,
+        world: &mut GameWorld,
+        registry: &EntityRegistry,
+        helper: EcsEntity,
+        ally: EcsEntity,
+    ) -> Result<EcsEntity, String> {
+        let target = world
+            .get::<&Combatant>(ally)
+            .ok()
+            .filter(|combatant| combatant.in_combat)
+            .and_then(|combatant| combatant.target_id)
+            .map(|target| target.entity())
+            .filter(|target| registry.contains_entity(*target))
+            .ok_or_else(|| "They aren't fighting anyone.".to_string())?;
+        if target == helper {
+            return Err("They are fighting you!".to_string());
         }
+        self.start_combat_with_registry(world, registry, helper, target)?;
+        Ok(target)
+    }
+
+    /// Step in to draw everyone attacking an ally onto the rescuer
+    ///
+    /// Needs a Shields check against [`RESCUE_DIFFICULTY`]. On success every
+    /// combatant targeting the ally turns on the rescuer, which takes over the
+    /// threat the ally had built up and ends up at the top of their tables.
+    /// Returns the check and the attackers drawn off.
+    pub fn rescue<R: ::rand::Rng>(
+        &mut self,
+        world: &mut GameWorld,
+        resolver: &mut SkillCheckResolver<R>,
+        rescuer: EcsEntity,
+        ally: EcsEntity,
+    ) -> Result<(CheckResult, Vec<EcsEntity>), String> {
+        let rescuer_id =
+            entity_id(world, rescuer).ok_or_else(|| "You can't rescue anyone.".to_string())?;
+        let attackers: Vec<EcsEntity> = world
+            .query::<(Entity, &Combatant)>()
+            .iter()
+            .filter(|(attacker, combatant)| {
+                *attacker != rescuer
+                    && combatant.in_combat
+                    && combatant
+                        .target_id
+                        .is_some_and(|target| target.entity() == ally)
+            })
+            .map(|(attacker, _)| attacker)
+            .collect();
+        if attackers.is_empty() {
+            return Err("Nobody is attacking them.".to_string());
+        }
+
+        let check = SkillCheck::for_skill(Skill::Shields).against(RESCUE_DIFFICULTY);
+        let check = resolver.resolve(world, rescuer, &check);
+        if !check.outcome.is_success() {
+            return Ok((check, Vec::new()));
+        }
+
+        let ally_id = entity_id(world, ally);
+        for attacker in &attackers {
+            add_threat(world, *attacker, rescuer_id, 0.0);
+            if let Ok(mut threat) = world.get::<&mut ThreatTable>(*attacker) {
+                let ally_threat = threat.remove(ally).unwrap_or(0.0);
+                let top = threat
+                    .entries
+                    .iter()
+                    .map(|entry| entry.threat)
+                    .fold(0.0, f32::max);
+                let gain = top - threat.threat_of(rescuer) + ally_threat.max(ENGAGE_THREAT);
+                threat.add(rescuer_id, gain);
+                if let Some(ally_id) = ally_id {
+                    threat.add(ally_id, 0.0);
+                }
+            }
+            if let Ok(mut combatant) = world.get::<&mut Combatant>(*attacker) {
+                combatant.target_id = Some(rescuer_id);
+            }
+            if let Some(attacker_id) = entity_id(world, *attacker) {
+                add_threat(world, rescuer, attacker_id, 0.0);
+            }
+        }
+        Ok((check, attackers))
     }
 
     /// Calculate initiative for combat order
     pub fn calculate_initiative(&self, world: &GameWorld, entity: EcsEntity) -> i32 {
         let mut initiative = 10; // Base initiative
 
-        if let Ok(attrs) = world.get::<&AttributeScores>(entity) {
+        if let Some(attrs) = body_scores(world, entity) {
             initiative += (attrs.score_finesse - 10) / 2;
         }
 
+        if let Ok(talents) = world.get::<&Talents>(entity) {
+            initiative += talents.initiative();
+        }
+
         // Add random component
         initiative += (rand::random::<f32>() * 10.0) as i32;
 
@@ -294,7 +582,7 @@ impl CombatSystem {
     /// Start defending - increases defense for one round
     pub fn defend(&mut self, world: &mut GameWorld, entity: EcsEntity) -> Result<(), String> {
         // Calculate defense bonus based on attributes
-        let defense_bonus = if let Ok(attrs) = world.get::<&AttributeScores>(entity) {
+        let defense_bonus = if let Some(attrs) = body_scores(world, entity) {
             5 + (attrs.score_defence - 10) / 2
         } else {
             5
@@ -345,7 +633,7 @@ impl CombatSystem {
         }
 
         // Calculate flee chance based on finesse
-        let flee_chance = if let Ok(attrs) = world.get::<&AttributeScores>(entity) {
+        let flee_chance = if let Some(attrs) = body_scores(world, entity) {
             0.5 + (attrs.score_finesse as f32 - 10.0) * 0.02
         } else {
             0.5
@@ -370,31 +658,504 @@ impl CombatSystem {
         Ok(success)
     }
 
+    /// Apply a status effect to an entity, following its stacking rule
+    ///
+    /// Publishes `StatusEffectStarted` when the entity didn't already have the
+    /// effect, and `StatusEffectEnded` for any opposite effect it cancels.
+    /// Returns whether the effect is new.
+    pub fn apply_status_effect(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        mut effect: StatusEffect,
+    ) -> bool {
+        let effect_type = effect.effect_type;
+        effect.duration = status_duration(world, entity, effect_type, effect.duration);
+        let has_effects = world.get::<&StatusEffects>(entity).is_ok();
+        if !has_effects && world.insert_one(entity, StatusEffects::new()).is_err() {
+            return false;
+        }
+        let Ok(mut effects) = world.get::<&mut StatusEffects>(entity) else {
+            return false;
+        };
+        let cancelled = effect_type
+            .opposite()
+            .filter(|opposite| effects.has_effect(*opposite));
+        let started = effects.add_effect(effect);
+        drop(effects);
+
+        if let Some(cancelled) = cancelled {
+            self.event_bus.publish(GameEvent::StatusEffectEnded {
+                entity,
+                effect_type: cancelled,
+            });
+        }
+        if started {
+            self.event_bus.publish(GameEvent::StatusEffectStarted {
+                entity,
+                effect_type,
+            });
+        }
+        started
+    }
+
+    /// Cure an entity of a status effect, returning whether it had it
+    pub fn cure(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        effect_type: StatusEffectType,
+    ) -> bool {
+        let cured = world
+            .get::<&mut StatusEffects>(entity)
+            .is_ok_and(|mut effects| effects.remove_effect(effect_type).is_some());
+        if cured {
+            self.event_bus.publish(GameEvent::StatusEffectEnded {
+                entity,
+                effect_type,
+            });
+        }
+        cured
+    }
+
     /// Update status effects for all entities
-    pub fn update_status_effects(&mut self, world: &mut GameWorld, delta_time: f32) {
-        let mut expired_defending: Vec<EcsEntity> = Vec::new();
+    ///
+    /// Damage-over-time effects deal their magnitude in damage each tick.
+    /// Whoever inflicted one gains threat for the damage and is credited with
+    /// the kill if it proves fatal. Returns the UUIDs of entities whose
+    /// effects ticked or wore off, so they can be saved.
+    pub fn update_status_effects(&mut self, world: &mut GameWorld, delta_time: f32) -> Vec<Uuid> {
+        let updates: Vec<(EcsEntity, StatusUpdate, bool)> = world
+            .query_mut::<(Entity, &mut StatusEffects)>()
+            .into_iter()
+            .map(|(entity, status_effects)| {
+                let update = status_effects.update(delta_time);
+                let defending = status_effects.has_effect(StatusEffectType::Defending);
+                (entity, update, defending)
+            })
+            .collect();
+
+        let mut changed = Vec::new();
+        for (entity, update, defending) in updates {
+            if !update.ticked.is_empty() || !update.expired.is_empty() {
+                if let Some(id) = entity_id(world, entity) {
+                    changed.push(id.uuid());
+                }
+            }
+            // Remove defending state from combatants whose effect expired
+            if !defending {
+                if let Ok(mut combatant) = world.get::<&mut Combatant>(entity) {
+                    if combatant.is_defending {
+                        combatant.stop_defending();
+                    }
+                }
+            }
+            for effect in &update.ticked {
+                self.effect_damage(world, entity, effect);
+            }
+            for effect_type in update.expired {
+                self.event_bus.publish(GameEvent::StatusEffectEnded {
+                    entity,
+                    effect_type,
+                });
+            }
+        }
+        changed
+    }
+
+    /// Deal one tick of a damage-over-time effect
+    fn effect_damage(&mut self, world: &mut GameWorld, entity: EcsEntity, effect: &StatusEffect) {
+        let damage = effect.magnitude.max(1);
+        let Some(died) = with_body_scores(world, entity, |health| {
+            (health.health_current > 0.0).then(|| {
+                health.health_current = (health.health_current - damage as f32).max(0.0);
+                health.health_current <= 0.0
+            })
+        })
+        .flatten() else {
+            return;
+        };
+        self.event_bus.publish(GameEvent::StatusEffectTicked {
+            entity,
+            effect_type: effect.effect_type,
+            damage,
+        });
+
+        let source = effect
+            .source
+            .filter(|source| source.entity() != entity && world.contains(source.entity()));
+        if died {
+            self.event_bus.publish(GameEvent::EntityDied {
+                entity,
+                killer: source.map(|source| source.entity()),
+            });
+            self.end_combat(world, entity);
+        } else if let Some(source) = source {
+            add_threat(world, entity, source, damage as f32 * THREAT_PER_DAMAGE);
+        }
+    }
+}
+
+/// How long a status effect lasts on an entity
+///
+/// Talents like Resilient shorten harmful effects.
+pub fn status_duration(
+    world: &GameWorld,
+    entity: EcsEntity,
+    effect_type: StatusEffectType,
+    duration: f32,
+) -> f32 {
+    if !effect_type.is_harmful() {
+        return duration;
+    }
+    world
+        .get::<&Talents>(entity)
+        .map_or(duration, |talents| duration * talents.harmful_duration())
+}
+
+/// Work out the damage of one hit
+///
+/// The weapon in the attacking hand sets the damage type and adds its roll,
+/// up to the weapon's damage cap, to the base damage and offence modifier.
+/// Bare hands deal blunt damage, and off-hand blows deal only
+/// [`OFF_HAND_DAMAGE_FACTOR`] of it unless the attacker is Ambidextrous.
+/// Talents may add damage and raise the [`CRITICAL_CHANCE`]. A critical hit doubles the damage and meets only half the defender's armor,
+/// which absorbs damage point for point but always lets at least one through.
+/// A defender with no reaction to fall back on adds its defending bonus to
+/// its armor instead.
+pub fn resolve_damage<R: ::rand::Rng>(
+    world: &GameWorld,
+    attacker: EcsEntity,
+    defender: EcsEntity,
+    hand: EquipSlot,
+    rng: &mut R,
+) -> AttackResult {
+    let off_hand = hand == EquipSlot::OffHand;
+    let weapon = weapon_in(world, attacker, hand);
+    let damage_type = weapon
+        .as_ref()
+        .map_or(DamageType::Blunt, |weapon| weapon.damage_type);
+
+    let mut raw = BASE_DAMAGE;
+    let mut health_fraction = 1.0;
+    if let Some(attrs) = body_scores(world, attacker) {
+        raw += (attrs.score_offence - 10) / 2;
+        if attrs.health_maximum > 0.0 {
+            health_fraction = attrs.health_current / attrs.health_maximum;
+        }
+    }
+    let mut critical_chance = CRITICAL_CHANCE;
+    if let Ok(talents) = world.get::<&Talents>(attacker) {
+        raw += talents.damage_bonus(weapon.is_some(), health_fraction);
+        critical_chance += talents.critical_chance();
+    }
+    if let Ok(effects) = world.get::<&StatusEffects>(attacker) {
+        raw += effects.magnitude(StatusEffectType::Strengthened)
+            - effects.magnitude(StatusEffectType::Weakened);
+    }
+    if let Some(weapon) = &weapon {
+        let max = weapon.damage_max.max(weapon.damage_min);
+        raw += rng
+            .random_range(weapon.damage_min..=max)
+            .min(weapon.damage_cap);
+    }
+    if off_hand && !has_talent(world, attacker, Talent::Ambidextrous) {
+        raw = (raw as f32 * OFF_HAND_DAMAGE_FACTOR).round() as i32;
+    }
+    raw = raw.max(1);
+
+    let mut defense = armor_defense(world, defender, damage_type);
+    if defense_reaction(world, defender).is_none() {
+        if let Ok(combatant) = world.get::<&Combatant>(defender) {
+            if combatant.is_defending {
+                defense += combatant.defense_bonus;
+            }
+        }
+    }
+
+    let critical = rng.random::<f32>() < critical_chance;
+    if critical {
+        raw *= 2;
+        defense /= 2;
+    }
+
+    let damage = (raw - defense.max(0)).max(1);
+    AttackResult {
+        hit: true,
+        damage,
+        critical,
+        damage_type,
+        absorbed: raw - damage,
+        reaction: None,
+        off_hand,
+    }
+}
+
+/// Weapon an entity holds in its main hand
+pub fn wielded_weapon(world: &GameWorld, entity: EcsEntity) -> Option<Weapon> {
+    weapon_in(world, entity, EquipSlot::MainHand)
+}
+
+/// Weapon an entity holds in a hand slot
+pub fn weapon_in(world: &GameWorld, entity: EcsEntity, hand: EquipSlot) -> Option<Weapon> {
+    let item = equipped(world, entity, hand)?;
+    world
+        .get::<&Weapon>(item)
+        .ok()
+        .map(|weapon| (*weapon).clone())
+}
+
+/// Shield an entity carries in its off hand
+///
+/// Any armor that isn't also a weapon counts as a shield there.
+pub fn held_shield(world: &GameWorld, entity: EcsEntity) -> Option<EcsEntity> {
+    equipped(world, entity, EquipSlot::OffHand)
+        .filter(|item| world.get::<&Armor>(*item).is_ok() && world.get::<&Weapon>(*item).is_err())
+}
+
+/// Best trained reaction open to a defender
+///
+/// Blocking needs a shield and parrying a weapon; anyone can dodge. Nobody
+/// reacts while stunned or without training in the reaction's skill. Ties go
+/// to blocking, then parrying.
+pub fn defense_reaction(world: &GameWorld, entity: EcsEntity) -> Option<DefenseReaction> {
+    if world
+        .get::<&StatusEffects>(entity)
+        .is_ok_and(|effects| effects.has_effect(StatusEffectType::Stunned))
+    {
+        return None;
+    }
+    let skills = world.get::<&Skills>(entity).ok()?;
+    let mut options = Vec::new();
+    if held_shield(world, entity).is_some() {
+        options.push(DefenseReaction::Block);
+    }
+    if wielded_weapon(world, entity).is_some() {
+        options.push(DefenseReaction::Parry);
+    }
+    options.push(DefenseReaction::Dodge);
+
+    let mut best: Option<(DefenseReaction, i32)> = None;
+    for reaction in options {
+        let level = skills.level(reaction.skill());
+        if level > 0 && best.is_none_or(|(_, best_level)| level > best_level) {
+            best = Some((reaction, level));
+        }
+    }
+    best.map(|(reaction, _)| reaction)
+}
+
+/// Item equipped in a slot, if it is loaded
+fn equipped(world: &GameWorld, entity: EcsEntity, slot: EquipSlot) -> Option<EcsEntity> {
+    let item = world.get::<&Equipment>(entity).ok()?.get(slot)?;
+    (!item.needs_resolution() && world.contains(item.entity())).then(|| item.entity())
+}
 
-        // Update all status effects
-        for (entity, status_effects) in world.query_mut::<(Entity, &mut StatusEffects)>() {
-            status_effects.update(delta_time);
+fn has_talent(world: &GameWorld, entity: EcsEntity, talent: Talent) -> bool {
+    world
+        .get::<&Talents>(entity)
+        .is_ok_and(|talents| talents.has_talent(talent))
+}
 
-            // Check if defending expired
-            if !status_effects.has_effect(StatusEffectType::Defending) {
-                expired_defending.push(entity);
+/// Defense an entity's armor gives against a damage type
+///
+/// Adds up every piece of armor it has equipped, and any natural armor of its
+/// own, each scaled by how well its material resists that kind of damage.
+/// Armor without a `Material` is treated as its armor type.
+pub fn armor_defense(world: &GameWorld, entity: EcsEntity, damage_type: DamageType) -> i32 {
+    let piece = |item: EcsEntity| {
+        let Ok(armor) = world.get::<&Armor>(item) else {
+            return 0.0;
+        };
+        let material = world
+            .get::<&Material>(item)
+            .map(|material| material.material_kind)
+            .unwrap_or(armor.armor_type);
+        armor.get_defense(damage_type) as f32 * material.resistance(damage_type)
+    };
+
+    let mut total = piece(entity);
+    if let Ok(equipment) = world.get::<&Equipment>(entity) {
+        let mut seen = HashSet::new();
+        for item in equipment.slots.values() {
+            if item.needs_resolution() || !world.contains(item.entity()) || !seen.insert(*item) {
+                continue;
             }
+            total += piece(item.entity());
         }
+    }
+    total.round() as i32
+}
 
-        // Remove defending state from combatants whose effect expired
-        for entity in expired_defending {
-            if let Ok(mut combatant) = world.get::<&mut Combatant>(entity) {
-                if combatant.is_defending {
-                    combatant.stop_defending();
-                }
+/// Add threat against a hostile to an entity's table, pulling it into combat
+///
+/// A combatant without a target turns on the hostile. Entities that can't
+/// fight are left alone.
+pub fn add_threat(world: &mut GameWorld, entity: EcsEntity, source: EntityId, amount: f32) {
+    if entity == source.entity() {
+        return;
+    }
+    let Ok(mut combatant) = world.get::<&mut Combatant>(entity) else {
+        return;
+    };
+    combatant.in_combat = true;
+    if combatant.target_id.is_none() {
+        combatant.target_id = Some(source);
+    }
+    drop(combatant);
+
+    if let Ok(mut threat) = world.get::<&mut ThreatTable>(entity) {
+        threat.add(source, amount);
+        return;
+    }
+    let mut threat = ThreatTable::new();
+    threat.add(source, amount);
+    let _ = world.insert_one(entity, threat);
+}
+
+/// Take an entity out of combat and out of every threat table
+///
+/// Whoever was targeting it switches to its next hostile. Returns every
+/// entity whose combat ended, the departing one included.
+pub fn leave_combat(world: &mut GameWorld, entity: EcsEntity) -> Vec<EcsEntity> {
+    let uuid = world.get::<&EntityUuid>(entity).map(|uuid| uuid.0).ok();
+    let is_entity =
+        |id: EntityId| id.entity() == entity || uuid.is_some_and(|uuid| id.uuid() == uuid);
+
+    let mut participants = Vec::new();
+    if let Ok(mut combatant) = world.get::<&mut Combatant>(entity) {
+        if combatant.in_combat || combatant.target_id.is_some() {
+            participants.push(entity);
+        }
+        combatant.in_combat = false;
+        combatant.target_id = None;
+        combatant.stop_defending();
+    }
+    if let Ok(mut threat) = world.get::<&mut ThreatTable>(entity) {
+        threat.clear();
+    }
+
+    let mut affected = Vec::new();
+    for (other, combatant, threat) in
+        world.query_mut::<(Entity, &mut Combatant, Option<&mut ThreatTable>)>()
+    {
+        if other == entity {
+            continue;
+        }
+        let engaged = threat.is_some_and(|threat| {
+            let before = threat.entries.len();
+            threat.entries.retain(|entry| !is_entity(entry.source));
+            threat.entries.len() < before
+        });
+        let targeted = combatant.target_id.is_some_and(is_entity);
+        if targeted {
+            combatant.target_id = None;
+        }
+        if engaged || targeted {
+            affected.push(other);
+        }
+    }
+    for other in affected {
+        if settle(world, other) {
+            participants.push(other);
+        }
+    }
+    participants
+}
+
+/// Point an entity at a hostile it is still engaged with, ending its combat
+/// if none are left; returns whether its combat ended
+fn settle(world: &GameWorld, entity: EcsEntity) -> bool {
+    let Ok(mut combatant) = world.get::<&mut Combatant>(entity) else {
+        return false;
+    };
+    let threat = world.get::<&ThreatTable>(entity).ok();
+    let engaged = |target: EntityId| {
+        threat
+            .as_ref()
+            .is_some_and(|threat| threat.contains(target.entity()))
+    };
+    if !combatant.target_id.is_some_and(engaged) {
+        combatant.target_id = threat.as_ref().and_then(|threat| threat.highest());
+    }
+    if combatant.target_id.is_some() || !combatant.in_combat {
+        return false;
+    }
+    combatant.in_combat = false;
+    combatant.stop_defending();
+    true
+}
+
+/// Turn NPCs on whichever hostile has built up the most threat
+fn follow_threat(world: &mut GameWorld) {
+    for (combatant, threat, avatar) in
+        world.query_mut::<(&mut Combatant, &ThreatTable, Option<&Avatar>)>()
+    {
+        if combatant.in_combat && avatar.is_none() {
+            if let Some(highest) = threat.highest() {
+                combatant.target_id = Some(highest);
             }
         }
     }
 }
 
+fn entity_id(world: &GameWorld, entity: EcsEntity) -> Option<EntityId> {
+    world
+        .get::<&EntityUuid>(entity)
+        .ok()
+        .map(|uuid| EntityId::new(entity, uuid.0))
+}
+
+/// Engage two entities, the attacker switching to the defender
+fn engage(world: &mut GameWorld, attacker: EntityId, defender: EntityId) {
+    add_threat(world, attacker.entity(), defender, 0.0);
+    if let Ok(mut combatant) = world.get::<&mut Combatant>(attacker.entity()) {
+        combatant.target_id = Some(defender);
+    }
+    add_threat(world, defender.entity(), attacker, ENGAGE_THREAT);
+}
+
+fn room_of(world: &GameWorld, entity: EcsEntity) -> Option<Uuid> {
+    world
+        .get::<&Location>(entity)
+        .ok()
+        .map(|location| location.room_id.uuid())
+}
+
+fn is_alive(world: &GameWorld, entity: EcsEntity) -> bool {
+    body_scores(world, entity).is_some_and(|scores| scores.health_current > 0.0)
+}
+
+/// Body scores of an entity, falling back to bare scores for simple creatures
+pub fn body_scores(world: &GameWorld, entity: EcsEntity) -> Option<AttributeScores> {
+    world
+        .get::<&BodyAttributeScores>(entity)
+        .map(|scores| scores.0.clone())
+        .or_else(|_| {
+            world
+                .get::<&AttributeScores>(entity)
+                .map(|scores| (*scores).clone())
+        })
+        .ok()
+}
+
+/// Change an entity's body scores, falling back to bare scores for simple creatures
+///
+/// Returns `None` if the entity has neither.
+pub fn with_body_scores<T>(
+    world: &GameWorld,
+    entity: EcsEntity,
+    change: impl FnOnce(&mut AttributeScores) -> T,
+) -> Option<T> {
+    if let Ok(mut scores) = world.get::<&mut BodyAttributeScores>(entity) {
+        Some(change(&mut scores.0))
+    } else if let Ok(mut scores) = world.get::<&mut AttributeScores>(entity) {
+        Some(change(&mut scores))
+    } else {
+        None
+    }
+}
+
 // Simple random number generator for tests
 mod rand {
     use std::cell::Cell;
@@ -419,7 +1180,40 @@ mod rand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ecs::components::Name;
+    use crate::ecs::components::{MaterialKind, Name, Skill};
+    use ::rand::SeedableRng;
+    use ::rand::rngs::StdRng;
+    use std::sync::{Arc, Mutex};
+
+    fn fighter(world: &mut GameWorld, name: &str, room: Uuid, player: bool) -> EcsEntity {
+        let entity = world.spawn((
+            Name::new(name),
+            Combatant::new(),
+            AttributeScores::new(),
+            EntityUuid::new(),
+            Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room)),
+        ));
+        if player {
+            world
+                .insert_one(entity, Avatar::new(Uuid::new_v4()))
+                .unwrap();
+        }
+        entity
+    }
+
+    fn target_of(world: &GameWorld, entity: EcsEntity) -> Option<EcsEntity> {
+        world
+            .get::<&Combatant>(entity)
+            .ok()
+            .and_then(|combatant| combatant.target_id)
+            .map(|target| target.entity())
+    }
+
+    fn in_combat(world: &GameWorld, entity: EcsEntity) -> bool {
+        world
+            .get::<&Combatant>(entity)
+            .is_ok_and(|combatant| combatant.in_combat)
+    }
 
     #[test]
     fn test_combat_system_creation() {
@@ -477,6 +1271,294 @@ mod tests {
         assert!(health.health_current < 100.0);
     }
 
+    #[test]
+    fn test_attack_loaded_character() {
+        let mut world = GameWorld::new();
+        let mut system = CombatSystem::new(EventBus::new());
+        let attacker = world.spawn((Combatant::new(), AttributeScores::new()));
+        // Characters loaded from the database carry only body scores
+        let defender = world.spawn((Combatant::new(), BodyAttributeScores::new()));
+
+        let result = system.attack(&mut world, attacker, defender).unwrap();
+        assert!(result.hit);
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(defender)
+                .unwrap()
+                .0
+                .health_current,
+            100.0 - result.damage as f32
+        );
+    }
+
+    #[test]
+    fn test_damage_against_armor() {
+        let mut world = GameWorld::new();
+        let sword = world.spawn((Weapon::new(4, 4, DamageType::Slashing),));
+        let mut equipment = Equipment::new();
+        equipment.equip(EquipSlot::MainHand, EntityId::new(sword, Uuid::new_v4()));
+        let attacker = world.spawn((AttributeScores::new(), equipment));
+
+        let mut armor = Armor::new();
+        armor.set_defense(DamageType::Slashing, 4);
+        armor.set_defense(DamageType::Piercing, 4);
+        armor.set_defense(DamageType::Fire, 4);
+        let mail = world.spawn((armor, Material::new(MaterialKind::Chain)));
+        let mut equipment = Equipment::new();
+        let mail_id = EntityId::new(mail, Uuid::new_v4());
+        equipment.equip(EquipSlot::Chest, mail_id);
+        equipment.equip(EquipSlot::Legs, mail_id);
+        let defender = world.spawn((AttributeScores::new(), equipment));
+
+        // Chain turns blades but not points, and counts once for both slots
+        assert_eq!(armor_defense(&world, defender, DamageType::Slashing), 6);
+        assert_eq!(armor_defense(&world, defender, DamageType::Piercing), 2);
+        assert_eq!(armor_defense(&world, defender, DamageType::Fire), 4);
+        assert_eq!(armor_defense(&world, attacker, DamageType::Slashing), 0);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..20 {
+            let result = resolve_damage(&world, attacker, defender, EquipSlot::MainHand, &mut rng);
+            assert_eq!(result.damage_type, DamageType::Slashing);
+            if result.critical {
+                assert_eq!((result.damage, result.absorbed), (25, 3));
+            } else {
+                assert_eq!((result.damage, result.absorbed), (8, 6));
+            }
+        }
+
+        // Bare fists against no armor
+        let result = resolve_damage(&world, defender, attacker, EquipSlot::MainHand, &mut rng);
+        assert_eq!(result.damage_type, DamageType::Blunt);
+        assert_eq!(result.absorbed, 0);
+    }
+
+    #[test]
+    fn test_defending_without_reaction_adds_to_armor() {
+        let mut world = GameWorld::new();
+        let mut system = CombatSystem::new(EventBus::new());
+        let mut strong = BodyAttributeScores::new();
+        strong.0.score_offence = 20;
+        let attacker = world.spawn((Combatant::new(), strong));
+        let defender = world.spawn((Combatant::new(), BodyAttributeScores::new()));
+        assert_eq!(defense_reaction(&world, defender), None);
+
+        // Seed 0 rolls no critical hit
+        let hit = |world: &GameWorld| {
+            let mut rng = StdRng::seed_from_u64(0);
+            resolve_damage(world, attacker, defender, EquipSlot::MainHand, &mut rng)
+        };
+        let result = hit(&world);
+        assert!(!result.critical);
+        assert_eq!((result.damage, result.absorbed), (15, 0));
+
+        system.defend(&mut world, defender).unwrap();
+        let result = hit(&world);
+        assert_eq!((result.damage, result.absorbed), (10, 5));
+    }
+
+    #[test]
+    fn test_defense_reaction_choice() {
+        let mut world = GameWorld::new();
+        let sword = world.spawn((Weapon::new(4, 4, DamageType::Slashing),));
+        let shield = world.spawn((Armor::new(),));
+        let mut equipment = Equipment::new();
+        equipment.equip(EquipSlot::MainHand, EntityId::new(sword, Uuid::new_v4()));
+        let mut skills = Skills::new();
+        // Dodging 2 and Parrying 3; Parrying is the harder skill to raise
+        skills.add_skill(Skill::Dodging, 16, 0);
+        skills.add_skill(Skill::Parrying, 72, 0);
+        let entity = world.spawn((AttributeScores::new(), equipment, skills));
+        assert_eq!(
+            defense_reaction(&world, entity),
+            Some(DefenseReaction::Parry)
+        );
+
+        // A shield wins a tie with the sword
+        world
+            .get::<&mut Equipment>(entity)
+            .unwrap()
+            .equip(EquipSlot::OffHand, EntityId::new(shield, Uuid::new_v4()));
+        world
+            .get::<&mut Skills>(entity)
+            .unwrap()
+            .add_skill(Skill::Shields, 36, 0);
+        assert_eq!(held_shield(&world, entity), Some(shield));
+        assert_eq!(
+            defense_reaction(&world, entity),
+            Some(DefenseReaction::Block)
+        );
+
+        let mut effects = StatusEffects::new();
+        effects.add_effect(StatusEffect::new(StatusEffectType::Stunned, 5.0, 1));
+        world.insert_one(entity, effects).unwrap();
+        assert_eq!(defense_reaction(&world, entity), None);
+
+        let untrained = world.spawn((AttributeScores::new(),));
+        assert_eq!(defense_reaction(&world, untrained), None);
+    }
+
+    #[test]
+    fn test_dodged_attack_deals_no_damage() {
+        let mut world = GameWorld::new();
+        let mut system = CombatSystem::new(EventBus::new()).with_seed(3);
+        let attacker = world.spawn((
+            Name::new("Attacker"),
+            Combatant::new(),
+            AttributeScores::new(),
+        ));
+        let mut skills = Skills::new();
+        skills.add_skill(Skill::Dodging, 400, 0);
+        let defender = world.spawn((
+            Name::new("Defender"),
+            Combatant::new(),
+            AttributeScores::new(),
+            skills,
+        ));
+
+        let dodged = (0..20)
+            .filter_map(|_| system.attack(&mut world, attacker, defender))
+            .find(|result| result.reaction.is_some())
+            .expect("a master of dodging should avoid some attacks");
+        assert_eq!(dodged.reaction, Some(DefenseReaction::Dodge));
+        assert!(!dodged.hit);
+        assert_eq!(dodged.damage, 0);
+    }
+
+    #[test]
+    fn test_off_hand_attack() {
+        let mut world = GameWorld::new();
+        let event_bus = EventBus::new();
+        let attacks = Arc::new(Mutex::new(Vec::new()));
+        let seen = attacks.clone();
+        event_bus.subscribe(move |event| {
+            if let GameEvent::EntityAttacked {
+                damage,
+                critical,
+                off_hand,
+                ..
+            } = event
+            {
+                seen.lock().unwrap().push((*damage, *critical, *off_hand));
+            }
+        });
+        let mut system = CombatSystem::new(event_bus.clone());
+
+        let main = world.spawn((Weapon::new(4, 4, DamageType::Slashing),));
+        let off = world.spawn((Weapon::new(4, 4, DamageType::Piercing),));
+        let mut equipment = Equipment::new();
+        equipment.equip(EquipSlot::MainHand, EntityId::new(main, Uuid::new_v4()));
+        equipment.equip(EquipSlot::OffHand, EntityId::new(off, Uuid::new_v4()));
+        let attacker = world.spawn((Combatant::new(), AttributeScores::new(), equipment));
+        let defender = world.spawn((Combatant::new(), AttributeScores::new()));
+        assert_eq!(held_shield(&world, attacker), None);
+
+        let result = system.attack(&mut world, attacker, defender).unwrap();
+        assert!(!result.off_hand);
+        event_bus.process_events();
+
+        let attacks = attacks.lock().unwrap();
+        assert_eq!(attacks.len(), 2);
+        let (damage, critical, off_hand) = attacks[1];
+        assert!(off_hand);
+        // Half of base 10 plus the weapon's 4
+        assert_eq!(damage, if critical { 14 } else { 7 });
+    }
+
+    #[test]
+    fn test_damage_talents() {
+        let mut world = GameWorld::new();
+        let sword = world.spawn((Weapon::new(4, 4, DamageType::Slashing),));
+        let armed = |world: &mut GameWorld, talent: Option<Talent>| {
+            let mut equipment = Equipment::new();
+            equipment.equip(EquipSlot::MainHand, EntityId::new(sword, Uuid::new_v4()));
+            let mut talents = Talents::new();
+            if let Some(talent) = talent {
+                talents.add_talent(talent, 0);
+            }
+            world.spawn((AttributeScores::new(), equipment, talents))
+        };
+        let plain = armed(&mut world, None);
+        let master = armed(&mut world, Some(Talent::WeaponMaster));
+        let berserker = armed(&mut world, Some(Talent::Berserker));
+        let defender = world.spawn((AttributeScores::new(),));
+
+        let damage = |world: &GameWorld, attacker| {
+            let mut rng = StdRng::seed_from_u64(5);
+            (0..10)
+                .map(|_| resolve_damage(world, attacker, defender, EquipSlot::MainHand, &mut rng))
+                .map(|result| (result.damage, result.critical))
+                .collect::<Vec<_>>()
+        };
+        let bonus = |world: &GameWorld, attacker| {
+            damage(world, plain)
+                .into_iter()
+                .zip(damage(world, attacker))
+                .map(|((base, critical), (damage, _))| {
+                    if critical {
+                        (damage - base) / 2
+                    } else {
+                        damage - base
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Weapon Masters hit harder with any weapon
+        assert!(bonus(&world, master).iter().all(|bonus| *bonus == 2));
+
+        // Berserkers only once badly hurt
+        assert!(bonus(&world, berserker).iter().all(|bonus| *bonus == 0));
+        world
+            .get::<&mut AttributeScores>(berserker)
+            .unwrap()
+            .health_current = 40.0;
+        assert!(bonus(&world, berserker).iter().all(|bonus| *bonus == 4));
+
+        // Characters loaded from the database rage on their body scores
+        let loaded = armed(&mut world, Some(Talent::Berserker));
+        let mut wounded = BodyAttributeScores::new();
+        wounded.0.health_current = 40.0;
+        world.remove_one::<AttributeScores>(loaded).unwrap();
+        world.insert_one(loaded, wounded).unwrap();
+        assert!(bonus(&world, loaded).iter().all(|bonus| *bonus == 4));
+    }
+
+    #[test]
+    fn test_tactician_and_resilient() {
+        let mut world = GameWorld::new();
+        let mut system = CombatSystem::new(EventBus::new());
+        let mut talents = Talents::new();
+        talents.add_talent(Talent::Tactician, 0);
+        talents.add_talent(Talent::Resilient, 0);
+        let veteran = world.spawn((AttributeScores::new(), talents));
+        let recruit = world.spawn((AttributeScores::new(),));
+
+        for _ in 0..10 {
+            assert!(system.calculate_initiative(&world, veteran) >= 15);
+            assert!(system.calculate_initiative(&world, recruit) < 20);
+        }
+
+        // Resilient shakes off harmful effects in half the time
+        let poison = StatusEffect::new(StatusEffectType::Poisoned, 30.0, 2);
+        let haste = StatusEffect::new(StatusEffectType::Hasted, 30.0, 2);
+        for entity in [veteran, recruit] {
+            system.apply_status_effect(&mut world, entity, poison.clone());
+            system.apply_status_effect(&mut world, entity, haste.clone());
+        }
+        let duration = |entity, effect_type| {
+            world
+                .get::<&StatusEffects>(entity)
+                .unwrap()
+                .get_effect(effect_type)
+                .unwrap()
+                .duration
+        };
+        assert_eq!(duration(veteran, StatusEffectType::Poisoned), 15.0);
+        assert_eq!(duration(veteran, StatusEffectType::Hasted), 30.0);
+        assert_eq!(duration(recruit, StatusEffectType::Poisoned), 30.0);
+    }
+
     #[test]
     fn test_death() {
         let mut world = GameWorld::new();
@@ -505,6 +1587,29 @@ mod tests {
         assert!(!attacker_combat.in_combat);
     }
 
+    #[test]
+    fn test_is_alive_reads_body_scores() {
+        let mut world = GameWorld::new();
+        // Loaded characters carry only body scores
+        let character = world.spawn((BodyAttributeScores::new(),));
+        let creature = world.spawn((AttributeScores::new(),));
+        assert!(is_alive(&world, character));
+        assert!(is_alive(&world, creature));
+
+        with_body_scores(&world, character, |scores| scores.health_current = 0.0);
+        assert!(!is_alive(&world, character));
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(character)
+                .unwrap()
+                .0
+                .health_current,
+            0.0
+        );
+        let shade = world.spawn((Combatant::new(),));
+        assert!(!is_alive(&world, shade));
+    }
+
     #[test]
     fn test_combat_update() {
         let mut world = GameWorld::new();
@@ -534,6 +1639,176 @@ mod tests {
         assert!(health.health_current < 100.0);
     }
 
+    #[test]
+    fn test_npc_turns_on_highest_threat() {
+        let event_bus = EventBus::new();
+        let ended = Arc::new(Mutex::new(Vec::new()));
+        let sink = ended.clone();
+        event_bus.subscribe(move |event| {
+            if let GameEvent::CombatEnded { participants } = event {
+                sink.lock().unwrap().push(participants.clone());
+            }
+        });
+        let mut system = CombatSystem::new(event_bus.clone());
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let wolf = fighter(&mut world, "wolf", room, false);
+        let alice = fighter(&mut world, "Alice", room, true);
+        let bob = fighter(&mut world, "Bob", room, true);
+
+        system.start_combat(&mut world, alice, wolf);
+        system.start_combat(&mut world, bob, wolf);
+        assert_eq!(target_of(&world, wolf), Some(alice));
+
+        let bob_id = entity_id(&world, bob).unwrap();
+        add_threat(&mut world, wolf, bob_id, 50.0);
+        system.update(&mut world, 0.0);
+        assert_eq!(target_of(&world, wolf), Some(bob));
+        // Players keep the target they chose
+        assert_eq!(target_of(&world, alice), Some(wolf));
+
+        // Bob walking away ends his fight but not Alice's
+        *world.get::<&mut Location>(bob).unwrap() = Location::new(
+            EntityId::from_uuid(Uuid::nil()),
+            EntityId::from_uuid(Uuid::new_v4()),
+        );
+        system.update(&mut world, 0.0);
+        assert!(!in_combat(&world, bob));
+        assert_eq!(target_of(&world, wolf), Some(alice));
+
+        system.end_combat(&mut world, wolf);
+        assert!(!in_combat(&world, alice));
+        event_bus.process_events();
+        assert_eq!(*ended.lock().unwrap(), vec![vec![bob], vec![wolf, alice]]);
+    }
+
+    #[test]
+    fn test_damage_over_time_credits_source() {
+        let event_bus = EventBus::new();
+        let deaths = Arc::new(Mutex::new(Vec::new()));
+        let sink = deaths.clone();
+        event_bus.subscribe(move |event| {
+            if let GameEvent::EntityDied { entity, killer } = event {
+                sink.lock().unwrap().push((*entity, *killer));
+            }
+        });
+        let mut system = CombatSystem::new(event_bus.clone());
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let rat = fighter(&mut world, "rat", room, false);
+        let alice = fighter(&mut world, "Alice", room, true);
+        world
+            .get::<&mut AttributeScores>(rat)
+            .unwrap()
+            .health_current = 5.0;
+
+        let alice_id = entity_id(&world, alice).unwrap();
+        let poison = StatusEffect::new(StatusEffectType::Poisoned, 30.0, 2).with_source(alice_id);
+        assert!(system.apply_status_effect(&mut world, rat, poison.clone()));
+        assert!(!system.apply_status_effect(&mut world, rat, poison));
+
+        // Stacked poison deals 4 a tick, and the rat turns on the poisoner
+        let rat_uuid = world.get::<&EntityUuid>(rat).unwrap().0;
+        assert_eq!(
+            system.update_status_effects(&mut world, 3.0),
+            vec![rat_uuid]
+        );
+        assert_eq!(
+            world.get::<&AttributeScores>(rat).unwrap().health_current,
+            1.0
+        );
+        assert_eq!(target_of(&world, rat), Some(alice));
+
+        system.update_status_effects(&mut world, 3.0);
+        event_bus.process_events();
+        assert_eq!(*deaths.lock().unwrap(), vec![(rat, Some(alice))]);
+        assert!(!in_combat(&world, alice));
+
+        // Cures remove the effect
+        assert!(system.cure(&mut world, rat, StatusEffectType::Poisoned));
+        assert!(!system.cure(&mut world, rat, StatusEffectType::Poisoned));
+    }
+
+    #[test]
+    fn test_stun_and_haste_in_combat() {
+        let mut system = CombatSystem::new(EventBus::new());
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let alice = fighter(&mut world, "Alice", room, true);
+        let wolf = fighter(&mut world, "wolf", room, false);
+        system.start_combat(&mut world, alice, wolf);
+        let health = |world: &GameWorld, entity| {
+            world
+                .get::<&AttributeScores>(entity)
+                .unwrap()
+                .health_current
+        };
+
+        system.apply_status_effect(
+            &mut world,
+            wolf,
+            StatusEffect::new(StatusEffectType::Stunned, 10.0, 1),
+        );
+        world.get::<&mut Combatant>(alice).unwrap().reset_timer();
+        let fought = system.update(&mut world, 1.0);
+        let uuid = |entity| world.get::<&EntityUuid>(entity).unwrap().0;
+        assert_eq!(fought, vec![uuid(alice), uuid(wolf)]);
+        assert!(health(&world, wolf) < 100.0);
+        assert_eq!(health(&world, alice), 100.0);
+
+        // Hasted by 4, Alice's one second cooldown comes around in half a second
+        system.apply_status_effect(
+            &mut world,
+            alice,
+            StatusEffect::new(StatusEffectType::Hasted, 10.0, 4),
+        );
+        let before = health(&world, wolf);
+        system.update(&mut world, 0.5);
+        assert!(health(&world, wolf) < before);
+    }
+
+    #[test]
+    fn test_healing_threat_and_rescue() {
+        let mut system = CombatSystem::new(EventBus::new());
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let wolf = fighter(&mut world, "wolf", room, false);
+        let alice = fighter(&mut world, "Alice", room, true);
+        let bob = fighter(&mut world, "Bob", room, true);
+
+        system.start_combat(&mut world, wolf, alice);
+        system.add_healing_threat(&mut world, bob, alice, 20);
+        assert_eq!(
+            world.get::<&ThreatTable>(wolf).unwrap().threat_of(bob),
+            20.0 * THREAT_PER_HEALING
+        );
+        assert_eq!(target_of(&world, bob), Some(wolf));
+        assert_eq!(target_of(&world, wolf), Some(alice));
+
+        let mut resolver = SkillCheckResolver::seeded(7);
+        let attackers = loop {
+            let (check, attackers) = system
+                .rescue(&mut world, &mut resolver, bob, alice)
+                .unwrap();
+            if check.outcome.is_success() {
+                break attackers;
+            }
+            assert!(attackers.is_empty());
+        };
+        assert_eq!(attackers, vec![wolf]);
+        assert_eq!(target_of(&world, wolf), Some(bob));
+        let threat = world.get::<&ThreatTable>(wolf).unwrap();
+        assert_eq!(threat.highest().map(|id| id.entity()), Some(bob));
+        assert!(threat.contains(alice));
+        drop(threat);
+
+        assert!(
+            system
+                .rescue(&mut world, &mut resolver, bob, alice)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_initiative() {
         let mut world = GameWorld::new();
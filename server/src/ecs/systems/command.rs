@@ -19,12 +19,17 @@
 mod admin;
 mod combat;
 mod comms;
+mod crafting;
+mod door;
 mod exit;
+mod gathering;
 mod help;
 mod inventory;
 mod llm_generate;
 mod look;
+mod magic;
 mod npc;
+mod posture;
 mod query;
 mod score;
 
@@ -38,6 +43,7 @@ use hecs::Entity;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 pub type CommandFn = Box<
     dyn Fn(
@@ -58,6 +64,27 @@ pub enum CommandResult {
     Invalid(String),
 }
 
+/// How a command may run alongside other commands
+///
+/// Commands lock the world themselves for as long as they need it, so most can
+/// run concurrently. Commands that replace or bulk-delete world state declare
+/// exclusive access so no other command observes it half-changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CommandAccess {
+    /// Runs concurrently with other shared commands
+    #[default]
+    Shared,
+    /// Waits for running commands to finish and holds off new ones until done
+    Exclusive,
+}
+
+/// Held while a command runs to enforce its [`CommandAccess`]
+#[allow(dead_code)] // The guards are only held, never read
+enum CommandGuard<'a> {
+    Shared(RwLockReadGuard<'a, ()>),
+    Exclusive(RwLockWriteGuard<'a, ()>),
+}
+
 /// Information about an available command
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailableCommand {
@@ -74,12 +101,18 @@ struct CommandMetadata {
     help_text: String,
     aliases: Vec<String>,
     required_role: Option<AccountRole>,
+    access: CommandAccess,
 }
 
+/// Registry and dispatcher for commands
+///
+/// Dispatch only needs `&self`, so the system is shared between sessions without
+/// a lock and commands from different players execute concurrently.
 pub struct CommandSystem {
     commands: HashMap<String, CommandMetadata>,
     aliases: HashMap<String, String>,
     event_bus: EventBus,
+    access_gate: RwLock<()>,
 }
 
 impl CommandSystem {
@@ -89,6 +122,7 @@ impl CommandSystem {
             commands: HashMap::new(),
             aliases: HashMap::new(),
             event_bus,
+            access_gate: RwLock::new(()),
         };
 
         system.register_default_commands();
@@ -120,6 +154,29 @@ impl CommandSystem {
     ) where
         F: Fn(Arc<WorldContext>, EcsEntity, String, Vec<String>) -> Fut + Send + Sync + 'static,
         Fut: std::future::Future<Output = CommandResult> + Send + 'static,
+    {
+        self.register_command_with_access(
+            name,
+            aliases,
+            help_text,
+            required_role,
+            CommandAccess::Shared,
+            handler,
+        )
+    }
+
+    /// Register a command with role requirement and world access mode
+    pub fn register_command_with_access<F, Fut>(
+        &mut self,
+        name: String,
+        aliases: Vec<String>,
+        help_text: String,
+        required_role: Option<AccountRole>,
+        access: CommandAccess,
+        handler: F,
+    ) where
+        F: Fn(Arc<WorldContext>, EcsEntity, String, Vec<String>) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = CommandResult> + Send + 'static,
     {
         let handler = Box::new(
             move |ctx: Arc<WorldContext>, entity: EcsEntity, cmd: String, args: Vec<String>| {
@@ -134,6 +191,7 @@ impl CommandSystem {
             help_text,
             aliases: aliases.clone(),
             required_role,
+            access,
         };
         self.commands.insert(name.clone(), metadata);
         for alias in aliases {
@@ -141,6 +199,21 @@ impl CommandSystem {
         }
     }
 
+    /// Get the world access mode of a registered command or alias
+    pub fn command_access(&self, command: &str) -> Option<CommandAccess> {
+        let cmd_name = command.to_lowercase();
+        let cmd_name = self.aliases.get(&cmd_name).unwrap_or(&cmd_name);
+        self.commands.get(cmd_name).map(|metadata| metadata.access)
+    }
+
+    /// Wait until a command with the given access mode may run
+    async fn acquire(&self, access: CommandAccess) -> CommandGuard<'_> {
+        match access {
+            CommandAccess::Shared => CommandGuard::Shared(self.access_gate.read().await),
+            CommandAccess::Exclusive => CommandGuard::Exclusive(self.access_gate.write().await),
+        }
+    }
+
     /// Check if entity has required role for a command
     async fn check_role_permission(
         &self,
@@ -384,7 +457,7 @@ impl CommandSystem {
     /// Execute a command
     #[instrument(skip(self, context))]
     pub async fn execute(
-        &mut self,
+        &self,
         context: Arc<WorldContext>,
         entity: EcsEntity,
         command: &str,
@@ -433,8 +506,10 @@ impl CommandSystem {
                 }
             }
 
+            let guard = self.acquire(metadata.access).await;
             let result =
                 (metadata.handler)(context.clone(), entity, cmd_name.clone(), args.to_vec()).await;
+            drop(guard);
 
             self.event_bus.publish(GameEvent::CommandExecuted {
                 entity,
@@ -469,6 +544,7 @@ impl CommandSystem {
 
                 // Pass remaining args (excluding the subcommand)
                 let remaining_args = args[1..].to_vec();
+                let guard = self.acquire(metadata.access).await;
                 let result = (metadata.handler)(
                     context.clone(),
                     entity,
@@ -476,6 +552,7 @@ impl CommandSystem {
                     remaining_args,
                 )
                 .await;
+                drop(guard);
 
                 self.event_bus.publish(GameEvent::CommandExecuted {
                     entity,
@@ -508,6 +585,128 @@ impl CommandSystem {
             |ctx, entity, cmd, args| inventory::inventory_command(ctx, entity, cmd, args),
         );
 
+        // Object manipulation commands
+        self.register_command(
+            "get".to_string(),
+            vec!["take".to_string()],
+            "get (take) <item|all> [from <container>] - Pick up an item".to_string(),
+            |ctx, entity, cmd, args| inventory::get_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "drop".to_string(),
+            vec![],
+            "drop <item|all>    - Drop an item".to_string(),
+            |ctx, entity, cmd, args| inventory::drop_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "put".to_string(),
+            vec![],
+            "put <item|all> in <container> - Put an item in a container".to_string(),
+            |ctx, entity, cmd, args| inventory::put_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "give".to_string(),
+            vec![],
+            "give <item|all> [to] <target> - Give an item to someone".to_string(),
+            |ctx, entity, cmd, args| inventory::give_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "wear".to_string(),
+            vec![],
+            "wear <item|all>    - Wear an item".to_string(),
+            |ctx, entity, cmd, args| inventory::wear_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "wield".to_string(),
+            vec![],
+            "wield <item>       - Wield an item in hand".to_string(),
+            |ctx, entity, cmd, args| inventory::wield_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "remove".to_string(),
+            vec!["rem".to_string()],
+            "remove (rem) <item|all> - Remove an equipped item".to_string(),
+            |ctx, entity, cmd, args| inventory::remove_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "equipment".to_string(),
+            vec!["eq".to_string()],
+            "equipment (eq)     - View your equipped items".to_string(),
+            |ctx, entity, cmd, args| inventory::equipment_command(ctx, entity, cmd, args),
+        );
+
+        // Door and container commands
+        self.register_command(
+            "open".to_string(),
+            vec![],
+            "open <direction|door|container> - Open a door or container".to_string(),
+            |ctx, entity, cmd, args| door::open_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "close".to_string(),
+            vec![],
+            "close <direction|door|container> - Close a door or container".to_string(),
+            |ctx, entity, cmd, args| door::close_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "lock".to_string(),
+            vec![],
+            "lock <direction|door|container> - Lock a door or container with a key".to_string(),
+            |ctx, entity, cmd, args| door::lock_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "unlock".to_string(),
+            vec![],
+            "unlock <direction|door|container> - Unlock a door or container with a key".to_string(),
+            |ctx, entity, cmd, args| door::unlock_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "pick".to_string(),
+            vec![],
+            "pick <direction|door|container> - Pick a lock".to_string(),
+            |ctx, entity, cmd, args| door::pick_command(ctx, entity, cmd, args),
+        );
+
+        // Posture commands
+        self.register_command(
+            "stand".to_string(),
+            vec!["wake".to_string()],
+            "stand - Stand up, or wake up if asleep".to_string(),
+            |ctx, entity, cmd, args| posture::stand_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "sit".to_string(),
+            vec![],
+            "sit - Sit down and recover a little faster".to_string(),
+            |ctx, entity, cmd, args| posture::sit_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "rest".to_string(),
+            vec![],
+            "rest - Rest and recover faster".to_string(),
+            |ctx, entity, cmd, args| posture::rest_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command(
+            "sleep".to_string(),
+            vec![],
+            "sleep - Go to sleep and recover fastest".to_string(),
+            |ctx, entity, cmd, args| posture::sleep_command(ctx, entity, cmd, args),
+        );
+
         // Say command
         self.register_command(
             "say".to_string(),
@@ -532,6 +731,44 @@ impl CommandSystem {
             |ctx, entity, cmd, args| comms::emote_command(ctx, entity, cmd, args),
         );
 
+        // Talk command
+        self.register_command(
+            "talk".to_string(),
+            vec!["ask".to_string(), "tell".to_string()],
+            "talk (ask, tell)   - Talk to someone in the room".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "persuade".to_string(),
+            vec![],
+            "persuade <npc> <message> - Try to talk someone round".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "deceive".to_string(),
+            vec!["lie".to_string()],
+            "deceive (lie) <npc> <message> - Try to mislead someone".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "intimidate".to_string(),
+            vec!["threaten".to_string()],
+            "intimidate (threaten) <npc> <message> - Try to frighten someone".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "inspire".to_string(),
+            vec!["rally".to_string()],
+            "inspire (rally) <npc> <message> - Try to rouse someone to follow you".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "console".to_string(),
+            vec!["comfort".to_string()],
+            "console (comfort) <npc> <message> - Try to ease someone's feelings".to_string(),
+            |ctx, entity, cmd, args| comms::talk_command(ctx, entity, cmd, args),
+        );
+
         // Score/stats command
         self.register_command(
             "score".to_string(),
@@ -539,6 +776,84 @@ impl CommandSystem {
             "score (stats)      - View your stats".to_string(),
             |ctx, entity, cmd, args| score::score_command(ctx, entity, cmd, args),
         );
+        self.register_command(
+            "skills".to_string(),
+            vec![],
+            "skills             - List your skills and how far they have progressed".to_string(),
+            |ctx, entity, cmd, args| score::skills_command(ctx, entity, cmd, args),
+        );
+
+        // Magic commands
+        self.register_command(
+            "cast".to_string(),
+            vec![],
+            "cast <spell> [target] - Cast a spell you know".to_string(),
+            |ctx, entity, cmd, args| magic::cast_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "spells".to_string(),
+            vec![],
+            "spells             - List the spells you know".to_string(),
+            |ctx, entity, cmd, args| magic::spells_command(ctx, entity, cmd, args),
+        );
+
+        // Crafting commands
+        self.register_command(
+            "craft".to_string(),
+            vec![],
+            "craft <recipe>     - Craft an item from a recipe".to_string(),
+            |ctx, entity, cmd, args| crafting::craft_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "recipes".to_string(),
+            vec![],
+            "recipes [filter]   - List the recipes you can attempt".to_string(),
+            |ctx, entity, cmd, args| crafting::recipes_command(ctx, entity, cmd, args),
+        );
+
+        // Gathering commands
+        self.register_command(
+            "gather".to_string(),
+            vec![],
+            "gather [node]      - Gather resources from a node in the room".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "mine".to_string(),
+            vec![],
+            "mine [node]        - Mine ore and stone".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "fish".to_string(),
+            vec![],
+            "fish [node]        - Fish at a fishing spot".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "chop".to_string(),
+            vec![],
+            "chop [node]        - Fell trees for wood".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "forage".to_string(),
+            vec![],
+            "forage [node]      - Forage for food and useful things".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "skin".to_string(),
+            vec![],
+            "skin [node]        - Skin a carcass for hides".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
+        self.register_command(
+            "harvest".to_string(),
+            vec![],
+            "harvest [node]     - Harvest herbs and plants".to_string(),
+            |ctx, entity, cmd, args| gathering::gather_command(ctx, entity, cmd, args),
+        );
 
         // Combat commands
         self.register_command(
@@ -553,6 +868,30 @@ impl CommandSystem {
             },
         );
 
+        self.register_command(
+            "assist".to_string(),
+            vec![],
+            "assist <ally>      - Join an ally's fight against their target".to_string(),
+            |ctx, entity, _cmd, args| async move {
+                match combat::handle_assist(ctx, entity, &args).await {
+                    Ok(msg) => CommandResult::Success(msg),
+                    Err(msg) => CommandResult::Failure(msg),
+                }
+            },
+        );
+
+        self.register_command(
+            "rescue".to_string(),
+            vec![],
+            "rescue <ally>      - Draw an ally's attackers onto yourself".to_string(),
+            |ctx, entity, _cmd, args| async move {
+                match combat::handle_rescue(ctx, entity, &args).await {
+                    Ok(msg) => CommandResult::Success(msg),
+                    Err(msg) => CommandResult::Failure(msg),
+                }
+            },
+        );
+
         self.register_command(
             "defend".to_string(),
             vec!["def".to_string()],
@@ -637,15 +976,50 @@ impl CommandSystem {
             |ctx, entity, cmd, args| admin::world_save_command(ctx, entity, cmd, args),
         );
 
-        // World reload command (admin)
-        self.register_command_with_role(
+        // World reload command (admin) - replaces the whole world, so nothing else may run
+        self.register_command_with_access(
             "world reload".to_string(),
             vec!["wreload".to_string()],
             "world reload (wreload) - Clear ECS and reload entities from database".to_string(),
             Some(AccountRole::Admin),
+            CommandAccess::Exclusive,
             |ctx, entity, cmd, args| admin::world_reload_command(ctx, entity, cmd, args),
         );
 
+        // World tick control commands (admin)
+        self.register_command_with_role(
+            "world pause".to_string(),
+            vec!["wpause".to_string()],
+            "world pause (wpause)   - Pause the world tick loop".to_string(),
+            Some(AccountRole::Admin),
+            |ctx, entity, cmd, args| admin::world_pause_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command_with_role(
+            "world resume".to_string(),
+            vec!["wresume".to_string()],
+            "world resume (wresume) - Resume a paused world tick loop".to_string(),
+            Some(AccountRole::Admin),
+            |ctx, entity, cmd, args| admin::world_resume_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command_with_role(
+            "world step".to_string(),
+            vec!["wstep".to_string()],
+            "world step (wstep) [count] - Advance a paused world by one or more pulses"
+                .to_string(),
+            Some(AccountRole::Admin),
+            |ctx, entity, cmd, args| admin::world_step_command(ctx, entity, cmd, args),
+        );
+
+        self.register_command_with_role(
+            "world tick".to_string(),
+            vec!["wtick".to_string()],
+            "world tick (wtick)     - Show world tick timing statistics".to_string(),
+            Some(AccountRole::Admin),
+            |ctx, entity, cmd, args| admin::world_tick_command(ctx, entity, cmd, args),
+        );
+
         // Area commands (builder)
         self.register_command_with_role(
             "area create".to_string(),
@@ -756,11 +1130,12 @@ impl CommandSystem {
             admin::room_edit_command,
         );
 
-        self.register_command_with_role(
+        self.register_command_with_access(
             "room deleteall".to_string(),
             vec!["rdeleteall".to_string()],
             "room deleteall (rdeleteall) <area-uuid> - Delete all rooms in an area".to_string(),
             Some(AccountRole::Builder),
+            CommandAccess::Exclusive,
             admin::room_delete_bulk_command,
         );
 
@@ -822,6 +1197,24 @@ impl CommandSystem {
             admin::item_list_command,
         );
 
+        // Resource node commands (builder)
+        self.register_command_with_role(
+            "node create".to_string(),
+            vec!["nodecreate".to_string()],
+            "node create (nodecreate) <skill> <name> - Create a resource node in current room"
+                .to_string(),
+            Some(AccountRole::Builder),
+            |ctx, entity, cmd, args| gathering::node_create_command(ctx, entity, cmd, args),
+        );
+        self.register_command_with_role(
+            "node edit".to_string(),
+            vec!["nodeedit".to_string()],
+            "node edit (nodeedit) <uuid> <field> <value> - Edit resource node properties"
+                .to_string(),
+            Some(AccountRole::Builder),
+            |ctx, entity, cmd, args| gathering::node_edit_command(ctx, entity, cmd, args),
+        );
+
         // NPC commands (storyteller)
         self.register_command_with_role(
             "npc create".to_string(),
@@ -1026,7 +1419,7 @@ impl CommandSystem {
         entity: EcsEntity,
         direction: String,
     ) -> CommandResult {
-        use crate::ecs::components::{EntityUuid, Exits, Room};
+        use crate::ecs::components::{EntityUuid, Exits, Openable, Posture, Room};
 
         // Normalize direction
         let normalized_direction = Self::normalize_direction(&direction);
@@ -1035,14 +1428,24 @@ impl CommandSystem {
         }
         let normalized_direction = normalized_direction.unwrap();
 
-        // Get current location and exits - scope the borrow
-        let (current_loc, exit_data) = {
-            let world = context.entities().read().await;
+        // Check the exit and move under one lock so a door cannot close in between
+        let (current_loc, new_location) = {
+            let world = context.entities().write().await;
             let current_loc = match world.get::<&Location>(entity) {
                 Ok(loc) => *loc,
                 Err(_) => return CommandResult::Failure("You have no location".to_string()),
             };
 
+            match world.get::<&Posture>(entity).map(|posture| *posture) {
+                Ok(Posture::Sleeping) => {
+                    return CommandResult::Failure("You can't do that while asleep.".to_string());
+                }
+                Ok(Posture::Sitting | Posture::Resting) => {
+                    return CommandResult::Failure("You need to stand up first.".to_string());
+                }
+                _ => {}
+            }
+
             // Find the room entity by UUID (since EntityId.entity may not be resolved)
             let room_uuid = current_loc.room_id.uuid();
             let mut room_entity_opt = None;
@@ -1083,9 +1486,9 @@ impl CommandSystem {
                 }
             };
 
-            // Find the exit in the requested direction and clone it
+            // Find the exit in the requested direction
             let exit_data = match exits.find_exit(&normalized_direction) {
-                Some(exit) => exit.clone(),
+                Some(exit) => exit,
                 None => {
                     return CommandResult::Failure(format!(
                         "You try to go {}, but there is no exit in that direction.",
@@ -1094,35 +1497,39 @@ impl CommandSystem {
                 }
             };
 
-            (current_loc, exit_data)
-        }; // world lock is released here
-
-        // Check if exit is blocked
-        if exit_data.closeable && exit_data.closed {
-            return CommandResult::Failure(format!(
-                "You try to go {}, but the door is closed.",
-                direction
-            ));
-        }
+            // Check if exit is blocked
+            if exit_data.is_lockable() && exit_data.is_locked() {
+                return CommandResult::Failure(format!(
+                    "You try to go {}, but the door is locked.",
+                    direction
+                ));
+            }
 
-        if exit_data.lockable && exit_data.locked {
-            return CommandResult::Failure(format!(
-                "You try to go {}, but the door is locked.",
-                direction
-            ));
-        }
+            if exit_data.is_closeable() && exit_data.is_closed() {
+                return CommandResult::Failure(format!(
+                    "You try to go {}, but the door is closed.",
+                    direction
+                ));
+            }
 
-        // Perform the movement
-        let new_location = Location::new(current_loc.area_id, exit_data.dest_id);
+            // Perform the movement
+            let new_location = Location::new(current_loc.area_id, exit_data.dest_id);
+            drop(exits);
 
-        {
-            let world = context.entities().read().await;
             if let Ok(mut location) = world.get::<&mut Location>(entity) {
                 *location = new_location;
             } else {
                 return CommandResult::Failure("Failed to move".to_string());
             }
-        } // world write lock is released here
+
+            (current_loc, new_location)
+        }; // world lock is released here
+
+        context.event_bus().publish(GameEvent::EntityMoved {
+            entity,
+            from: (current_loc.area_id.uuid(), current_loc.room_id.uuid()),
+            to: (new_location.area_id.uuid(), new_location.room_id.uuid()),
+        });
 
         // Return success with new room description
         // Call the look command to show the new room
@@ -1147,7 +1554,7 @@ impl CommandSystem {
     }
 
     /// Update the command system, processing queued commands
-    pub async fn update(&mut self, context: Arc<WorldContext>) {
+    pub async fn update(&self, context: Arc<WorldContext>) {
         let mut commands_to_execute = Vec::new();
 
         // Collect commands from all commandable entities
@@ -1179,6 +1586,120 @@ mod tests {
         let _system = CommandSystem::new(event_bus);
     }
 
+    fn test_context() -> Arc<WorldContext> {
+        let persistence = Arc::new(crate::persistence::PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence))
+    }
+
+    #[test]
+    fn test_command_access_declarations() {
+        let system = CommandSystem::new(EventBus::new());
+
+        assert_eq!(system.command_access("look"), Some(CommandAccess::Shared));
+        assert_eq!(
+            system.command_access("world save"),
+            Some(CommandAccess::Shared)
+        );
+        assert_eq!(
+            system.command_access("world reload"),
+            Some(CommandAccess::Exclusive)
+        );
+        assert_eq!(
+            system.command_access("wreload"),
+            Some(CommandAccess::Exclusive)
+        );
+        assert_eq!(system.command_access("no such command"), None);
+    }
+
+    #[tokio::test]
+    async fn test_shared_commands_run_concurrently() {
+        let context = test_context();
+        let mut system = CommandSystem::new(EventBus::new());
+
+        // Neither command can finish until both are running at once
+        let barrier = Arc::new(tokio::sync::Barrier::new(2));
+        system.register_command(
+            "rendezvous".to_string(),
+            vec![],
+            "rendezvous".to_string(),
+            move |_, _, _, _| {
+                let barrier = barrier.clone();
+                async move {
+                    barrier.wait().await;
+                    CommandResult::Success(String::new())
+                }
+            },
+        );
+
+        let results = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            tokio::join!(
+                system.execute(context.clone(), Entity::DANGLING, "rendezvous", &[]),
+                system.execute(context.clone(), Entity::DANGLING, "rendezvous", &[]),
+            )
+        })
+        .await
+        .expect("shared commands should not block each other");
+
+        assert!(matches!(results.0, CommandResult::Success(_)));
+        assert!(matches!(results.1, CommandResult::Success(_)));
+    }
+
+    #[tokio::test]
+    async fn test_exclusive_command_runs_alone() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let context = test_context();
+        let mut system = CommandSystem::new(EventBus::new());
+        let running = Arc::new(AtomicUsize::new(0));
+        let exclusive_saw = Arc::new(AtomicUsize::new(0));
+
+        let shared_running = running.clone();
+        system.register_command(
+            "work".to_string(),
+            vec![],
+            "work".to_string(),
+            move |_, _, _, _| {
+                let running = shared_running.clone();
+                async move {
+                    running.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    CommandResult::Success(String::new())
+                }
+            },
+        );
+
+        let exclusive_running = running.clone();
+        let saw = exclusive_saw.clone();
+        system.register_command_with_access(
+            "rebuild".to_string(),
+            vec![],
+            "rebuild".to_string(),
+            None,
+            CommandAccess::Exclusive,
+            move |_, _, _, _| {
+                let running = exclusive_running.clone();
+                let saw = saw.clone();
+                async move {
+                    let before = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    let after = running.fetch_sub(1, Ordering::SeqCst);
+                    saw.store(before.max(after), Ordering::SeqCst);
+                    CommandResult::Success(String::new())
+                }
+            },
+        );
+
+        tokio::join!(
+            system.execute(context.clone(), Entity::DANGLING, "work", &[]),
+            system.execute(context.clone(), Entity::DANGLING, "rebuild", &[]),
+            system.execute(context.clone(), Entity::DANGLING, "work", &[]),
+        );
+
+        assert_eq!(exclusive_saw.load(Ordering::SeqCst), 1);
+        assert_eq!(running.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     #[ignore = "Requires WorldEngineContext - convert to integration test"]
     fn test_inventory_command() {
@@ -16,7 +16,7 @@
 
 use crate::ecs::components::*;
 use crate::ecs::context::WorldContext;
-use crate::ecs::systems::CommandResult;
+use crate::ecs::systems::{CommandResult, TickStage, TickStatistics};
 use crate::ecs::{EcsEntity, GameWorld};
 use std::sync::Arc;
 
@@ -163,6 +163,148 @@ pub async fn world_reload_command(
     }
 }
 
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn world_pause_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    tracing::info!("World Pause Command from {}", entity.id());
+
+    let scheduler = context.scheduler();
+    if scheduler.is_paused() {
+        return CommandResult::Failure("The world is already paused.".to_string());
+    }
+    scheduler.pause();
+
+    CommandResult::Success(
+        "The world is now paused. Use 'world step' to advance one pulse or 'world resume' to continue."
+            .to_string(),
+    )
+}
+
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn world_resume_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    tracing::info!("World Resume Command from {}", entity.id());
+
+    let scheduler = context.scheduler();
+    if !scheduler.is_paused() {
+        return CommandResult::Failure("The world is not paused.".to_string());
+    }
+    scheduler.resume();
+
+    CommandResult::Success("The world resumes.".to_string())
+}
+
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn world_step_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    tracing::debug!(
+        "World Step Command from {}: {}",
+        entity.id(),
+        args.join(" ")
+    );
+
+    let count = match args.first() {
+        Some(arg) => match arg.parse::<u32>() {
+            Ok(count) if (1..=100).contains(&count) => count,
+            _ => {
+                return CommandResult::Failure(
+                    "Usage: world step [count] (count must be between 1 and 100)".to_string(),
+                );
+            }
+        },
+        None => 1,
+    };
+
+    let scheduler = context.scheduler().clone();
+    let mut stats = None;
+    for _ in 0..count {
+        match scheduler.step(context.clone()).await {
+            Ok(s) => stats = Some(s),
+            Err(e) => return CommandResult::Failure(e),
+        }
+    }
+
+    let mut output = format!("\r\nStepped {} pulse(s)\r\n", count);
+    if let Some(stats) = stats {
+        output.push_str(&format_tick_statistics(&stats, scheduler.pulse_interval(), true));
+    }
+    CommandResult::Success(output)
+}
+
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn world_tick_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    tracing::debug!("World Tick Command from {}", entity.id());
+
+    let scheduler = context.scheduler();
+    let stats = scheduler.statistics();
+
+    CommandResult::Success(format_tick_statistics(
+        &stats,
+        scheduler.pulse_interval(),
+        scheduler.is_paused(),
+    ))
+}
+
+/// Helper function to render scheduler statistics
+fn format_tick_statistics(
+    stats: &TickStatistics,
+    interval: std::time::Duration,
+    paused: bool,
+) -> String {
+    let mut output = String::from("\r\nWorld Tick Statistics\r\n");
+    output.push_str(&"=".repeat(80));
+    output.push_str("\r\n");
+    output.push_str(&format!(
+        "State: {}\r\n",
+        if paused { "paused" } else { "running" }
+    ));
+    output.push_str(&format!("Pulse Interval: {:?}\r\n", interval));
+    output.push_str(&format!("Pulses: {}\r\n", stats.pulse_count));
+    output.push_str(&format!(
+        "Overruns: {} ({} pulses skipped)\r\n",
+        stats.overrun_count, stats.skipped_pulses
+    ));
+    output.push_str(&format!("Last Delta: {:.3}s\r\n", stats.last_delta));
+    output.push_str(&format!(
+        "Last Pulse: {:?} (max {:?})\r\n\r\n",
+        stats.last_pulse, stats.max_pulse
+    ));
+    output.push_str(&format!(
+        "{:<16} {:>12} {:>12} {:>12}\r\n",
+        "Stage", "Last", "Average", "Max"
+    ));
+    for stage in TickStage::ORDER {
+        let timing = stats.stages.get(&stage).cloned().unwrap_or_default();
+        output.push_str(&format!(
+            "{:<16} {:>12} {:>12} {:>12}\r\n",
+            stage.as_str(),
+            format!("{:?}", timing.last),
+            format!("{:?}", stats.average(stage)),
+            format!("{:?}", timing.max)
+        ));
+    }
+    output.push_str(&"=".repeat(80));
+    output.push_str("\r\n");
+    output
+}
+
 /// Helper function to get component type names for an entity
 fn get_component_types(world: &GameWorld, entity: EcsEntity) -> Vec<String> {
     let mut components = Vec::new();
@@ -200,9 +342,15 @@ fn get_component_types(world: &GameWorld, entity: EcsEntity) -> Vec<String> {
     if world.get::<&Talents>(entity).is_ok() {
         components.push("Talents");
     }
+    if world.get::<&Spellbook>(entity).is_ok() {
+        components.push("Spellbook");
+    }
     if world.get::<&Combatant>(entity).is_ok() {
         components.push("Combatant");
     }
+    if world.get::<&ThreatTable>(entity).is_ok() {
+        components.push("ThreatTable");
+    }
     if world.get::<&Equipment>(entity).is_ok() {
         components.push("Equipment");
     }
@@ -221,12 +369,27 @@ fn get_component_types(world: &GameWorld, entity: EcsEntity) -> Vec<String> {
     if world.get::<&Containable>(entity).is_ok() {
         components.push("Containable");
     }
+    if world.get::<&ContainedBy>(entity).is_ok() {
+        components.push("ContainedBy");
+    }
+    if world.get::<&Key>(entity).is_ok() {
+        components.push("Key");
+    }
+    if world.get::<&BindPoint>(entity).is_ok() {
+        components.push("BindPoint");
+    }
+    if world.get::<&Corpse>(entity).is_ok() {
+        components.push("Corpse");
+    }
     if world.get::<&Enterable>(entity).is_ok() {
         components.push("Enterable");
     }
     if world.get::<&Equipable>(entity).is_ok() {
         components.push("Equipable");
     }
+    if world.get::<&SkillBonus>(entity).is_ok() {
+        components.push("SkillBonus");
+    }
     if world.get::<&Weapon>(entity).is_ok() {
         components.push("Weapon");
     }
@@ -236,6 +399,15 @@ fn get_component_types(world: &GameWorld, entity: EcsEntity) -> Vec<String> {
     if world.get::<&Armor>(entity).is_ok() {
         components.push("ArmorDefense");
     }
+    if world.get::<&Template>(entity).is_ok() {
+        components.push("Template");
+    }
+    if world.get::<&CraftingStation>(entity).is_ok() {
+        components.push("CraftingStation");
+    }
+    if world.get::<&ResourceNode>(entity).is_ok() {
+        components.push("ResourceNode");
+    }
     if world.get::<&Commandable>(entity).is_ok() {
         components.push("Commandable");
     }
@@ -501,6 +673,22 @@ pub async fn query_entity_command(
         ));
     }
 
+    // Threat
+    if let Ok(threat) = world.get::<&ThreatTable>(target_entity) {
+        output.push_str("\r\n  Threat:\r\n");
+        if threat.is_empty() {
+            output.push_str("    (none)\r\n");
+        } else {
+            for entry in &threat.entries {
+                output.push_str(&format!(
+                    "    {}: {:.1}\r\n",
+                    entry.source.uuid(),
+                    entry.threat
+                ));
+            }
+        }
+    }
+
     // Equipment
     if let Ok(equipment) = world.get::<&Equipment>(target_entity) {
         output.push_str("\r\n  Equipment:\r\n");
@@ -2667,15 +2855,22 @@ pub async fn item_edit_command(
                 Ok(v) => v,
                 Err(_) => return CommandResult::Failure("Invalid max damage".to_string()),
             };
-            let damage_type = match value_args[2].to_lowercase().as_str() {
-                "slashing" => DamageType::Slashing,
-                "piercing" => DamageType::Piercing,
-                "blunt" | "bludgeoning" => DamageType::Blunt,
-                "fire" => DamageType::Fire,
-                "acid" => DamageType::Acid,
-                "arcane" => DamageType::Arcane,
-                "psychic" => DamageType::Psychic,
-                _ => return CommandResult::Failure("Invalid damage type. Valid types: slashing, piercing, blunt, fire, acid, arcane, psychic".to_string()),
+            let damage_type = if value_args[2].eq_ignore_ascii_case("bludgeoning") {
+                Some(DamageType::Blunt)
+            } else {
+                DamageType::ALL
+                    .into_iter()
+                    .find(|kind| kind.as_str().eq_ignore_ascii_case(&value_args[2]))
+            };
+            let Some(damage_type) = damage_type else {
+                let valid: Vec<String> = DamageType::ALL
+                    .iter()
+                    .map(|kind| kind.as_str().to_lowercase())
+                    .collect();
+                return CommandResult::Failure(format!(
+                    "Invalid damage type. Valid types: {}",
+                    valid.join(", ")
+                ));
             };
 
             let mut world = context.entities().write().await;
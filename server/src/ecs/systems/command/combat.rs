@@ -16,14 +16,42 @@
 
 //! Combat command handlers
 
-use crate::ecs::EcsEntity;
-use crate::ecs::components::{AttributeScores, Combatant, Location, Name, StatusEffects};
+use super::inventory::{name_of, room_entities};
+use crate::ecs::components::{
+    Combatant, DamageType, EntityUuid, EquipSlot, Location, Name, Skill, Skills, StatusEffects,
+    Talent, Talents, ThreatTable,
+};
 use crate::ecs::context::WorldContext;
-use crate::ecs::events::EventBus;
-use crate::ecs::systems::CombatSystem;
+use crate::ecs::output::{capitalize, players_in_room};
+use crate::ecs::systems::{
+    BASE_DAMAGE, CombatSystem, OFF_HAND_DAMAGE_FACTOR, ProgressionSystem, SkillCheckResolver,
+    armor_defense, body_scores, defense_reaction, weapon_in, wielded_weapon,
+};
+use crate::ecs::{EcsEntity, GameWorld};
 use hecs::Entity;
 use std::sync::Arc;
 
+/// Find another combatant in the room by name
+fn find_combatant(
+    world: &GameWorld,
+    entity: EcsEntity,
+    keyword: &str,
+) -> Result<EcsEntity, String> {
+    let found = room_entities(world, entity).into_iter().find(|other| {
+        world
+            .get::<&Name>(*other)
+            .is_ok_and(|name| name.matches(keyword))
+    });
+    match found {
+        Some(other) if world.get::<&Combatant>(other).is_ok() => Ok(other),
+        Some(other) => Err(format!(
+            "{} isn't fighting.",
+            capitalize(&name_of(world, other))
+        )),
+        None => Err(format!("You don't see '{}' here.", keyword)),
+    }
+}
+
 /// Handle the attack command
 pub async fn handle_attack(
     context: Arc<WorldContext>,
@@ -80,7 +108,7 @@ pub async fn handle_attack(
     // Start combat using the combat system
     let mut world = context.entities().write().await;
     let registry = context.registry().read().await;
-    let event_bus = EventBus::new();
+    let event_bus = context.event_bus().clone();
     let mut combat_system = CombatSystem::new(event_bus);
 
     combat_system
@@ -96,15 +124,101 @@ pub async fn handle_attack(
         } else {
             ""
         };
-        Ok(format!(
-            "You attack {} for {} damage!{}",
-            target_display_name, attack_result.damage, crit_msg
-        ))
+        // Damage is reported to everyone involved by the event output router
+        Ok(format!("You attack {}!{}", target_display_name, crit_msg))
     } else {
         Err("Attack failed.".to_string())
     }
 }
 
+/// Handle the assist command: join an ally's fight against its target
+pub async fn handle_assist(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    args: &[String],
+) -> Result<String, String> {
+    if args.is_empty() {
+        return Ok("Assist who?".to_string());
+    }
+    let keyword = args.join(" ").to_lowercase();
+
+    let mut world = context.entities().write().await;
+    let registry = context.registry().read().await;
+    let ally = find_combatant(&world, entity, &keyword)?;
+    let ally_name = name_of(&world, ally);
+
+    let mut combat_system = CombatSystem::new(context.event_bus().clone());
+    let target = combat_system.assist(&mut world, &registry, entity, ally)?;
+    let target_name = name_of(&world, target);
+    combat_system.attack(&mut world, entity, target);
+
+    Ok(format!(
+        "You join {} in attacking {}!",
+        ally_name, target_name
+    ))
+}
+
+/// Handle the rescue command: draw an ally's attackers onto yourself
+pub async fn handle_rescue(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    args: &[String],
+) -> Result<String, String> {
+    if args.is_empty() {
+        return Ok("Rescue who?".to_string());
+    }
+    let keyword = args.join(" ").to_lowercase();
+
+    let mut world = context.entities().write().await;
+    let ally = find_combatant(&world, entity, &keyword)?;
+    let ally_name = name_of(&world, ally);
+
+    let mut combat_system = CombatSystem::new(context.event_bus().clone());
+    let mut resolver = SkillCheckResolver::new();
+    let (check, attackers) = combat_system.rescue(&mut world, &mut resolver, entity, ally)?;
+
+    let progression = ProgressionSystem::new(context.event_bus().clone());
+    progression.record_use(
+        &world,
+        entity,
+        Skill::Shields,
+        check.challenge_level(),
+        check.outcome.is_success(),
+    );
+    if attackers.is_empty() {
+        return Err(format!("You fail to rescue {}.", ally_name));
+    }
+
+    let actor = capitalize(&name_of(&world, entity));
+    let observers: Vec<EcsEntity> = world
+        .get::<&Location>(entity)
+        .map(|location| players_in_room(&world, location.room_id.uuid()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|other| *other != entity && *other != ally)
+        .collect();
+    let dirty: Vec<_> = std::iter::once(entity)
+        .chain(attackers.iter().copied())
+        .filter_map(|e| world.get::<&EntityUuid>(e).ok().map(|uuid| uuid.0))
+        .collect();
+    drop(world);
+
+    for uuid in dirty {
+        context.mark_dirty(uuid).await;
+    }
+    context
+        .send_to_entities(&[ally], &format!("{} rescues you!", actor))
+        .await;
+    context
+        .send_to_entities(
+            &observers,
+            &format!("{} heroically rescues {}!", actor, ally_name),
+        )
+        .await;
+
+    Ok(format!("You rescue {}!", ally_name))
+}
+
 /// Handle the defend command
 pub async fn handle_defend(
     context: Arc<WorldContext>,
@@ -127,7 +241,7 @@ pub async fn handle_defend(
 
     // Apply defend
     let mut world = context.entities().write().await;
-    let event_bus = EventBus::new();
+    let event_bus = context.event_bus().clone();
     let mut combat_system = CombatSystem::new(event_bus);
 
     combat_system.defend(&mut world, entity)?;
@@ -173,7 +287,7 @@ pub async fn handle_flee(
 
     // Attempt to flee
     let mut world = context.entities().write().await;
-    let event_bus = EventBus::new();
+    let event_bus = context.event_bus().clone();
     let mut combat_system = CombatSystem::new(event_bus);
 
     let success = combat_system.flee(&mut world, entity)?;
@@ -213,18 +327,93 @@ pub async fn handle_combat_status(
         }
     }
 
+    // Show everyone else engaged
+    if let Ok(threat) = world.get::<&ThreatTable>(entity) {
+        let others: Vec<String> = threat
+            .hostiles()
+            .filter(|hostile| {
+                combatant
+                    .target_id
+                    .is_none_or(|target| target.entity() != hostile.entity())
+            })
+            .map(|hostile| name_of(&world, hostile.entity()))
+            .collect();
+        if !others.is_empty() {
+            status.push_str(&format!("Also fighting: {}\n", others.join(", ")));
+        }
+    }
+
     // Show health
-    if let Ok(attrs) = world.get::<&AttributeScores>(entity) {
+    if let Some(attrs) = body_scores(&world, entity) {
         status.push_str(&format!(
             "Health: {:.0}/{:.0}\n",
             attrs.health_current, attrs.health_maximum
         ));
     }
 
+    // Show what the entity hits with and how its armor holds up
+    let offence = body_scores(&world, entity)
+        .map(|attrs| (attrs.score_offence - 10) / 2)
+        .unwrap_or(0);
+    let damage = |roll: i32, factor: f32| {
+        (((BASE_DAMAGE + offence + roll) as f32 * factor).round() as i32).max(1)
+    };
+    match wielded_weapon(&world, entity) {
+        Some(weapon) => status.push_str(&format!(
+            "Damage: {}-{} {}\n",
+            damage(weapon.damage_min.min(weapon.damage_cap), 1.0),
+            damage(weapon.damage_max.min(weapon.damage_cap), 1.0),
+            weapon.damage_type.as_str()
+        )),
+        None => status.push_str(&format!("Damage: {} Blunt (unarmed)\n", damage(0, 1.0))),
+    }
+    if let Some(weapon) = weapon_in(&world, entity, EquipSlot::OffHand) {
+        let ambidextrous = world
+            .get::<&Talents>(entity)
+            .is_ok_and(|talents| talents.has_talent(Talent::Ambidextrous));
+        let factor = if ambidextrous {
+            1.0
+        } else {
+            OFF_HAND_DAMAGE_FACTOR
+        };
+        status.push_str(&format!(
+            "Off hand: {}-{} {}\n",
+            damage(weapon.damage_min.min(weapon.damage_cap), factor),
+            damage(weapon.damage_max.min(weapon.damage_cap), factor),
+            weapon.damage_type.as_str()
+        ));
+    }
+    let defenses: Vec<String> = DamageType::ALL
+        .into_iter()
+        .map(|kind| (kind, armor_defense(&world, entity, kind)))
+        .filter(|(_, defense)| *defense != 0)
+        .map(|(kind, defense)| format!("{} {}", kind.as_str(), defense))
+        .collect();
+    if defenses.is_empty() {
+        status.push_str("Armor: none\n");
+    } else {
+        status.push_str(&format!("Armor: {}\n", defenses.join(", ")));
+    }
+    match defense_reaction(&world, entity) {
+        Some(reaction) => {
+            let level = world
+                .get::<&Skills>(entity)
+                .map(|skills| skills.level(reaction.skill()))
+                .unwrap_or(0);
+            status.push_str(&format!(
+                "Reaction: {} ({} {})\n",
+                reaction.as_str(),
+                reaction.skill().name(),
+                level
+            ));
+        }
+        None => status.push_str("Reaction: none\n"),
+    }
+
     // Show defending status
     if combatant.is_defending {
         status.push_str(&format!(
-            "Defending: +{} defense\n",
+            "Defending: +{} to dodge, parry and block\n",
             combatant.defense_bonus
         ));
     }
@@ -235,8 +424,9 @@ pub async fn handle_combat_status(
             status.push_str("\nStatus Effects:\n");
             for effect in &status_effects.effects {
                 status.push_str(&format!(
-                    "  - {} ({:.1}s remaining)\n",
+                    "  - {} {} ({:.1}s remaining)\n",
                     effect.effect_type.as_str(),
+                    effect.magnitude,
                     effect.duration
                 ));
             }
@@ -248,6 +438,40 @@ pub async fn handle_combat_status(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::ecs::components::{AttributeScores, BodyAttributeScores, EntityId};
+    use crate::persistence::PersistenceManager;
+    use uuid::Uuid;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    /// Alice, Bob and a goblin in the same room
+    async fn spawn_scene(context: &WorldContext) -> (EcsEntity, EcsEntity, EcsEntity) {
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        let mut spawned = Vec::new();
+        for name in ["Alice", "Bob", "goblin"] {
+            let uuid = Uuid::new_v4();
+            let entity = context.entities().write().await.spawn((
+                Name::new(name),
+                EntityUuid(uuid),
+                Location::new(area, room),
+                Combatant::new(),
+                AttributeScores::new(),
+            ));
+            context.register_entity(entity, uuid).await;
+            spawned.push(entity);
+        }
+        (spawned[0], spawned[1], spawned[2])
+    }
+
     // TODO: Write comprehensive tests for combat commands
     #[test]
     fn test_combat_commands_exist() {
@@ -255,4 +479,61 @@ mod tests {
         // Full integration tests will be in the integration test file
         assert!(true);
     }
+
+    #[tokio::test]
+    async fn test_assist_joins_ally_fight() {
+        let context = setup();
+        let (alice, bob, goblin) = spawn_scene(&context).await;
+
+        let result = handle_assist(context.clone(), bob, &args("alice")).await;
+        assert!(result.is_err());
+
+        handle_attack(context.clone(), alice, &args("goblin"))
+            .await
+            .unwrap();
+        let result = handle_assist(context.clone(), bob, &args("alice")).await;
+        assert!(result.unwrap().contains("goblin"));
+
+        let world = context.entities().read().await;
+        let combatant = world.get::<&Combatant>(bob).unwrap();
+        assert_eq!(combatant.target_id.map(|id| id.entity()), Some(goblin));
+        let threat = world.get::<&ThreatTable>(goblin).unwrap();
+        assert!(threat.contains(alice));
+        assert!(threat.contains(bob));
+    }
+
+    #[tokio::test]
+    async fn test_status_reads_body_scores() {
+        let context = setup();
+        let (alice, _, _) = spawn_scene(&context).await;
+        // Characters loaded from the database carry only body scores
+        {
+            let mut world = context.entities().write().await;
+            let mut body = BodyAttributeScores::new();
+            body.0.score_offence = 14;
+            body.0.health_current = 60.0;
+            world.remove_one::<AttributeScores>(alice).unwrap();
+            world.insert_one(alice, body).unwrap();
+        }
+
+        handle_attack(context.clone(), alice, &args("goblin"))
+            .await
+            .unwrap();
+        let status = handle_combat_status(context.clone(), alice, &[])
+            .await
+            .unwrap();
+        assert!(status.contains("Health: 60/100"));
+        assert!(status.contains(&format!("Damage: {} Blunt (unarmed)", BASE_DAMAGE + 2)));
+    }
+
+    #[tokio::test]
+    async fn test_rescue_needs_someone_to_rescue() {
+        let context = setup();
+        let (_, bob, _) = spawn_scene(&context).await;
+
+        let result = handle_rescue(context.clone(), bob, &args("bob")).await;
+        assert!(result.is_err());
+        let result = handle_rescue(context.clone(), bob, &args("alice")).await;
+        assert!(result.unwrap_err().contains("Nobody"));
+    }
 }
@@ -14,12 +14,37 @@
 // limitations under the License.
 //
 
+use super::inventory::{name_of, room_entities};
 use crate::ecs::EcsEntity;
-use crate::ecs::components::Name;
+use crate::ecs::components::{EntityUuid, Location, Name, Npc, NpcDialogue};
 use crate::ecs::context::WorldContext;
-use crate::ecs::systems::CommandResult;
+use crate::ecs::events::{GameEvent, MessageChannel};
+use crate::ecs::output::{capitalize, players_in_room, rooms_within};
+use crate::ecs::systems::{
+    CommandResult, NpcAiSystem, ProgressionSystem, SkillCheckResolver, SocialApproach, social_check,
+};
 use std::sync::Arc;
 
+/// How many exits away a yell can be heard
+const YELL_RANGE: u32 = 2;
+
+/// Resolve the speaker's name and the other players sharing their room
+async fn room_audience(
+    context: &WorldContext,
+    entity: EcsEntity,
+) -> Option<(String, Vec<EcsEntity>)> {
+    let world = context.entities().read().await;
+    let name = world.get::<&Name>(entity).ok()?.display.clone();
+    let audience = match world.get::<&Location>(entity) {
+        Ok(location) => players_in_room(&world, location.room_id.uuid())
+            .into_iter()
+            .filter(|&other| other != entity)
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    Some((name, audience))
+}
+
 #[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
 pub async fn say_command(
     context: Arc<WorldContext>,
@@ -31,17 +56,152 @@ pub async fn say_command(
         return CommandResult::Invalid("Say what?".to_string());
     }
 
-    // TODO: Say to other people as well
-
     let message = args.join(" ");
-    let world = context.entities().read().await;
-    let result = if let Ok(_name) = world.get::<&Name>(entity) {
-        CommandResult::Success(format!("You say: '{}'", message))
-    } else {
-        CommandResult::Failure("You cannot speak".to_string())
+    let Some((name, recipients)) = room_audience(&context, entity).await else {
+        return CommandResult::Failure("You cannot speak".to_string());
+    };
+
+    context
+        .send_to_entities(&recipients, &format!("{} says: '{}'", name, message))
+        .await;
+    context.event_bus().publish(GameEvent::MessageSent {
+        sender: entity,
+        recipients,
+        message: message.clone(),
+        channel: MessageChannel::Say,
+    });
+
+    CommandResult::Success(format!("You say: '{}'", message))
+}
+
+/// Speak to an NPC in the room; its reply is said aloud to the whole room
+///
+/// Persuading, deceiving and the other social approaches make a social check
+/// first, which the speaker sees the NPC react to and the NPC replies in
+/// light of.
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn talk_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let approach = SocialApproach::from_command(&cmd);
+    if args.len() < 2 {
+        return CommandResult::Invalid(match approach {
+            Some(approach) => format!("Usage: {} <npc> <message>", approach.verb()),
+            None => "Talk to whom about what?".to_string(),
+        });
+    }
+
+    let keyword = &args[0];
+    let message = args[1..].join(" ");
+    let mut learner = None;
+    let (name, npc, npc_name, audience, social) = {
+        // Written to, as social checks give the speaker experience
+        let world = context.entities().write().await;
+        let Ok(name) = world.get::<&Name>(entity).map(|name| name.display.clone()) else {
+            return CommandResult::Failure("You cannot speak".to_string());
+        };
+        let found = room_entities(&world, entity).into_iter().find(|other| {
+            world.get::<&Npc>(*other).is_ok()
+                && world
+                    .get::<&Name>(*other)
+                    .is_ok_and(|name| name.matches(keyword))
+        });
+        let Some(npc) = found else {
+            return CommandResult::Failure(format!("You don't see '{}' here.", keyword));
+        };
+        let npc_name = name_of(&world, npc);
+        let talkative = world.get::<&Npc>(npc).is_ok_and(|npc| npc.active)
+            && world.get::<&NpcDialogue>(npc).is_ok();
+        if !talkative {
+            return CommandResult::Failure(format!(
+                "{} has nothing to say to you.",
+                capitalize(&npc_name)
+            ));
+        }
+        let audience = match world.get::<&Location>(entity) {
+            Ok(location) => players_in_room(&world, location.room_id.uuid()),
+            Err(_) => Vec::new(),
+        };
+        let social = approach.map(|approach| {
+            let mut resolver = SkillCheckResolver::new();
+            let check = social_check(&world, &mut resolver, entity, npc, approach);
+            let succeeded = check.outcome.is_success();
+            if ProgressionSystem::new(context.event_bus().clone())
+                .record_use(
+                    &world,
+                    entity,
+                    approach.skill(),
+                    check.challenge_level(),
+                    succeeded,
+                )
+                .is_some()
+            {
+                learner = world.get::<&EntityUuid>(entity).ok().map(|uuid| uuid.0);
+            }
+            (approach, succeeded)
+        });
+        (name, npc, npc_name, audience, social)
     };
-    drop(world);
-    result
+    if let Some(uuid) = learner {
+        context.mark_dirty(uuid).await;
+    }
+    let others: Vec<EcsEntity> = audience
+        .iter()
+        .copied()
+        .filter(|&other| other != entity)
+        .collect();
+
+    context
+        .send_to_entities(
+            &others,
+            &format!("{} says to {}: '{}'", name, npc_name, message),
+        )
+        .await;
+    context.event_bus().publish(GameEvent::MessageSent {
+        sender: entity,
+        recipients: others.iter().copied().chain([npc]).collect(),
+        message: message.clone(),
+        channel: MessageChannel::Say,
+    });
+
+    let reply = match NpcAiSystem::new(context.llm_manager().clone())
+        .handle_dialogue(
+            context.clone(),
+            npc,
+            entity,
+            message.clone(),
+            social.map(|(approach, succeeded)| approach.situation(&name, succeeded)),
+        )
+        .await
+    {
+        Ok(reply) => reply,
+        Err(e) => return CommandResult::Failure(e),
+    };
+
+    let line = format!("{} says: '{}'", capitalize(&npc_name), reply);
+    context.send_to_entities(&others, &line).await;
+    context.event_bus().publish(GameEvent::MessageSent {
+        sender: npc,
+        recipients: audience,
+        message: reply,
+        channel: MessageChannel::Say,
+    });
+
+    let reaction = social
+        .map(|(approach, succeeded)| {
+            format!(
+                "{}\r\n",
+                approach.reaction(&capitalize(&npc_name), succeeded)
+            )
+        })
+        .unwrap_or_default();
+    CommandResult::Success(format!(
+        "You say to {}: '{}'\r\n{}{}",
+        npc_name, message, reaction, line
+    ))
 }
 
 #[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
@@ -55,17 +215,56 @@ pub async fn yell_command(
         return CommandResult::Invalid("Yell what?".to_string());
     }
 
-    // TODO: Yell to other people in same and nearby rooms
-
     let message = args.join(" ");
-    let world = context.entities().read().await;
-    let result = if let Ok(_name) = world.get::<&Name>(entity) {
-        CommandResult::Success(format!("You say: '{}'", message))
-    } else {
-        CommandResult::Failure("You cannot speak".to_string())
+
+    // Players in the same room hear the speaker directly; players in rooms
+    // within range only hear the voice from nearby
+    let (name, nearby, distant) = {
+        let world = context.entities().read().await;
+        let name = match world.get::<&Name>(entity) {
+            Ok(name) => name.display.clone(),
+            Err(_) => return CommandResult::Failure("You cannot speak".to_string()),
+        };
+
+        let mut nearby = Vec::new();
+        let mut distant = Vec::new();
+        if let Ok(location) = world.get::<&Location>(entity) {
+            for (room, distance) in rooms_within(&world, location.room_id.uuid(), YELL_RANGE) {
+                for listener in players_in_room(&world, room) {
+                    if listener == entity {
+                        continue;
+                    }
+                    if distance == 0 {
+                        nearby.push(listener);
+                    } else {
+                        distant.push(listener);
+                    }
+                }
+            }
+        }
+        (name, nearby, distant)
     };
-    drop(world);
-    result
+
+    context
+        .send_to_entities(&nearby, &format!("{} yells: '{}'", name, message))
+        .await;
+    context
+        .send_to_entities(
+            &distant,
+            &format!("You hear {} yell from nearby: '{}'", name, message),
+        )
+        .await;
+
+    let mut recipients = nearby;
+    recipients.extend(distant);
+    context.event_bus().publish(GameEvent::MessageSent {
+        sender: entity,
+        recipients,
+        message: message.clone(),
+        channel: MessageChannel::Shout,
+    });
+
+    CommandResult::Success(format!("You yell: '{}'", message))
 }
 
 #[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
@@ -80,12 +279,236 @@ pub async fn emote_command(
     }
 
     let action = args.join(" ");
-    let world = context.entities().read().await;
-    let result = if let Ok(name) = world.get::<&Name>(entity) {
-        CommandResult::Success(format!("{} {}", name.display, action))
-    } else {
-        CommandResult::Failure("You cannot emote".to_string())
+    let Some((name, recipients)) = room_audience(&context, entity).await else {
+        return CommandResult::Failure("You cannot emote".to_string());
     };
-    drop(world);
-    result
+
+    let line = format!("{} {}", name, action);
+    context.send_to_entities(&recipients, &line).await;
+    context.event_bus().publish(GameEvent::MessageSent {
+        sender: entity,
+        recipients,
+        message: action,
+        channel: MessageChannel::Emote,
+    });
+
+    CommandResult::Success(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Avatar, EntityId, EntityUuid, ExitData, Exits, NpcConversation};
+    use crate::ecs::output::OutputSink;
+    use crate::persistence::PersistenceManager;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    /// Output sink that records everything delivered to it
+    #[derive(Default)]
+    struct RecordingSink {
+        delivered: Mutex<Vec<(EcsEntity, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl OutputSink for RecordingSink {
+        async fn send_to_entity(&self, entity: EcsEntity, message: String) -> Result<(), String> {
+            self.delivered.lock().unwrap().push((entity, message));
+            Ok(())
+        }
+    }
+
+    fn player(name: &str, room: Uuid) -> (Name, Avatar, Location) {
+        (
+            Name::new(name),
+            Avatar::new(Uuid::new_v4()),
+            Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room)),
+        )
+    }
+
+    fn setup() -> (Arc<WorldContext>, Arc<RecordingSink>) {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        let context = Arc::new(WorldContext::new(persistence_manager));
+        let sink = Arc::new(RecordingSink::default());
+        context.set_output_sink(sink.clone());
+        (context, sink)
+    }
+
+    #[tokio::test]
+    async fn test_say_reaches_room_only() {
+        let (context, sink) = setup();
+        let room = Uuid::new_v4();
+        let (alice, bob, carol) = {
+            let mut world = context.entities().write().await;
+            (
+                world.spawn(player("Alice", room)),
+                world.spawn(player("Bob", room)),
+                world.spawn(player("Carol", Uuid::new_v4())),
+            )
+        };
+
+        let result = say_command(
+            context.clone(),
+            alice,
+            "say".to_string(),
+            vec!["hello".to_string()],
+        )
+        .await;
+
+        assert!(matches!(result, CommandResult::Success(ref msg) if msg == "You say: 'hello'"));
+        let delivered = sink.delivered.lock().unwrap().clone();
+        assert_eq!(delivered, vec![(bob, "Alice says: 'hello'".to_string())]);
+        assert!(delivered.iter().all(|(e, _)| *e != carol));
+
+        // Event carries the real recipient list
+        assert_eq!(context.event_bus().queue_len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_talk_to_npc_replies_to_room() {
+        let (context, sink) = setup();
+        let (room, greta_uuid) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut dialogue = NpcDialogue::new("gpt-4");
+        dialogue.history_limit = 2;
+        dialogue.fallback_responses = vec!["Welcome to my shop!".to_string()];
+        let (alice, bob, greta) = {
+            let mut world = context.entities().write().await;
+            let alice = world.spawn(player("Alice", room));
+            world.insert_one(alice, EntityUuid::new()).unwrap();
+            let greta = world.spawn((
+                EntityUuid(greta_uuid),
+                Name::new("Greta").with_keywords(vec!["greta".into(), "shopkeeper".into()]),
+                Npc::new(),
+                dialogue,
+                Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room)),
+            ));
+            (alice, world.spawn(player("Bob", room)), greta)
+        };
+        context.register_entity(greta, greta_uuid).await;
+
+        let words = |text: &str| text.split(' ').map(String::from).collect::<Vec<_>>();
+        let result = talk_command(
+            context.clone(),
+            alice,
+            "talk".to_string(),
+            words("shopkeeper hello there"),
+        )
+        .await;
+
+        assert!(matches!(result, CommandResult::Success(ref msg)
+            if msg == "You say to Greta: 'hello there'\r\nGreta says: 'Welcome to my shop!'"));
+        let delivered = sink.delivered.lock().unwrap().clone();
+        assert_eq!(
+            delivered,
+            vec![
+                (bob, "Alice says to Greta: 'hello there'".to_string()),
+                (bob, "Greta says: 'Welcome to my shop!'".to_string()),
+            ]
+        );
+        assert!(context.is_entity_dirty(greta).await);
+
+        // History with each player is kept to the NPC's limit
+        talk_command(
+            context.clone(),
+            alice,
+            "talk".to_string(),
+            words("greta bye"),
+        )
+        .await;
+        let world = context.entities().read().await;
+        let alice_uuid = world.get::<&EntityUuid>(alice).unwrap().0;
+        let conversation = world.get::<&NpcConversation>(greta).unwrap();
+        let history = conversation.get_history(alice_uuid).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].message, "bye");
+    }
+
+    #[tokio::test]
+    async fn test_social_approach_usage() {
+        let (context, _sink) = setup();
+        let alice = context
+            .entities()
+            .write()
+            .await
+            .spawn(player("Alice", Uuid::new_v4()));
+
+        let result = talk_command(
+            context.clone(),
+            alice,
+            "intimidate".to_string(),
+            vec!["guard".to_string()],
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Invalid(ref msg)
+            if msg == "Usage: intimidate <npc> <message>"));
+    }
+
+    #[tokio::test]
+    async fn test_talk_needs_an_npc_in_the_room() {
+        let (context, _sink) = setup();
+        let room = Uuid::new_v4();
+        let alice = {
+            let mut world = context.entities().write().await;
+            world.spawn(player("Bob", room));
+            world.spawn(player("Alice", room))
+        };
+
+        let result = talk_command(
+            context.clone(),
+            alice,
+            "talk".to_string(),
+            vec!["bob".to_string(), "hi".to_string()],
+        )
+        .await;
+        assert!(
+            matches!(result, CommandResult::Failure(ref msg) if msg == "You don't see 'bob' here.")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_yell_reaches_nearby_rooms() {
+        let (context, sink) = setup();
+        let here = Uuid::new_v4();
+        let next = Uuid::new_v4();
+        let far = Uuid::new_v4();
+        let beyond = Uuid::new_v4();
+
+        let (alice, bob, carol, dave) = {
+            let mut world = context.entities().write().await;
+            world.spawn((
+                EntityUuid(here),
+                Exits::new().add_exit(ExitData::new("east", EntityId::from_uuid(next))),
+            ));
+            world.spawn((
+                EntityUuid(next),
+                Exits::new().add_exit(ExitData::new("east", EntityId::from_uuid(far))),
+            ));
+            world.spawn((
+                EntityUuid(far),
+                Exits::new().add_exit(ExitData::new("east", EntityId::from_uuid(beyond))),
+            ));
+            (
+                world.spawn(player("Alice", here)),
+                world.spawn(player("Bob", next)),
+                world.spawn(player("Carol", far)),
+                world.spawn(player("Dave", beyond)),
+            )
+        };
+
+        yell_command(
+            context.clone(),
+            alice,
+            "yell".to_string(),
+            vec!["help!".to_string()],
+        )
+        .await;
+
+        let delivered = sink.delivered.lock().unwrap().clone();
+        assert!(delivered.contains(&(bob, "You hear Alice yell from nearby: 'help!'".to_string())));
+        assert!(delivered.contains(&(carol, "You hear Alice yell from nearby: 'help!'".to_string())));
+        assert_eq!(delivered.len(), 2);
+
+        // Listeners past the yell range hear nothing
+        assert!(delivered.iter().all(|(e, _)| *e != dave));
+    }
 }
@@ -0,0 +1,315 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Crafting commands: craft and recipes
+
+use super::inventory::name_of;
+use crate::ecs::EcsEntity;
+use crate::ecs::components::{
+    EntityUuid, ItemTemplateRegistry, Location, Name, Posture, Recipe, Skills, skill_rank_name,
+};
+use crate::ecs::context::WorldContext;
+use crate::ecs::output::{capitalize, players_in_room};
+use crate::ecs::systems::{
+    CommandResult, InventorySystem, ProgressionSystem, SkillCheckResolver, craft,
+};
+use std::sync::Arc;
+
+/// Describe what a recipe uses, e.g. "2 x iron ingot, smithing hammer, at a forge"
+fn requirements(recipe: &Recipe, templates: &ItemTemplateRegistry) -> String {
+    let item_name = |id: &str| {
+        templates
+            .get(id)
+            .map(|template| template.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+    recipe
+        .ingredients
+        .iter()
+        .map(|ingredient| {
+            format!(
+                "{} x {}",
+                ingredient.quantity,
+                item_name(&ingredient.template_id)
+            )
+        })
+        .chain(recipe.tools.iter().map(|tool| item_name(tool)))
+        .chain(
+            recipe
+                .station
+                .iter()
+                .map(|station| format!("at a {}", station)),
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Craft an item from a recipe
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn craft_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: craft <recipe>".to_string());
+    }
+    let name = args.join(" ");
+    let Some(recipe) = context.recipes().read().await.get(&name).cloned() else {
+        return CommandResult::Failure(format!("You don't know how to craft '{}'.", name));
+    };
+
+    let mut world = context.entities().write().await;
+    let templates = context.item_templates().read().await;
+    if let Ok(Posture::Sleeping) = world.get::<&Posture>(entity).map(|posture| *posture) {
+        return CommandResult::Failure("You can't do that while asleep.".to_string());
+    }
+
+    let inventory = InventorySystem::new(context.event_bus().clone());
+    let mut resolver = SkillCheckResolver::new();
+    let outcome = match craft(
+        &mut world,
+        &inventory,
+        &mut resolver,
+        &templates,
+        entity,
+        &recipe,
+    ) {
+        Ok(outcome) => outcome,
+        Err(message) => return CommandResult::Failure(message),
+    };
+
+    // Ruined work still teaches something
+    let progression = ProgressionSystem::new(context.event_bus().clone());
+    progression.record_use(
+        &world,
+        entity,
+        recipe.skill,
+        outcome.check.challenge_level(),
+        outcome.check.outcome.is_success(),
+    );
+
+    let actor = capitalize(&name_of(&world, entity));
+    let (message, room_message) = match outcome.produced.first() {
+        Some((item, _)) => {
+            let made = world
+                .get::<&Name>(*item)
+                .map(|name| name.display.clone())
+                .unwrap_or_else(|_| recipe.name.clone());
+            let count = if outcome.produced.len() > 1 {
+                format!("{} x ", outcome.produced.len())
+            } else {
+                String::new()
+            };
+            (
+                format!("You craft {}{}.", count, made),
+                format!("{} crafts {}.", actor, made),
+            )
+        }
+        None => (
+            format!("You ruin the materials trying to craft {}.", recipe.name),
+            format!("{} ruins an attempt at {}.", actor, recipe.name),
+        ),
+    };
+    let observers: Vec<EcsEntity> = world
+        .get::<&Location>(entity)
+        .map(|location| players_in_room(&world, location.room_id.uuid()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|other| *other != entity)
+        .collect();
+    let crafter_uuid = world.get::<&EntityUuid>(entity).ok().map(|uuid| uuid.0);
+    drop(templates);
+    drop(world);
+
+    for (item, uuid) in &outcome.produced {
+        context.register_entity(*item, *uuid).await;
+        context.mark_dirty(*uuid).await;
+    }
+    if let Some(uuid) = crafter_uuid {
+        context.mark_dirty(uuid).await;
+    }
+    if !outcome.consumed.is_empty() {
+        let consumed = outcome.consumed.clone();
+        let context = context.clone();
+        tokio::spawn(async move {
+            for uuid in consumed {
+                if let Err(e) = context.delete_entity(uuid).await {
+                    tracing::warn!("Failed to delete consumed ingredient {}: {}", uuid, e);
+                }
+            }
+        });
+    }
+    context.send_to_entities(&observers, &room_message).await;
+
+    CommandResult::Success(message)
+}
+
+/// List the recipes an entity has the skill to attempt
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn recipes_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let world = context.entities().read().await;
+    let recipes = context.recipes().read().await;
+    let templates = context.item_templates().read().await;
+    let filter = args.join(" ").to_lowercase();
+
+    let mut known: Vec<&Recipe> = recipes
+        .iter()
+        .filter(|recipe| {
+            let level = world
+                .get::<&Skills>(entity)
+                .map(|skills| skills.level(recipe.skill))
+                .unwrap_or(0);
+            level >= recipe.min_level
+        })
+        .filter(|recipe| {
+            filter.is_empty()
+                || recipe.name.to_lowercase().contains(&filter)
+                || recipe.skill.name().to_lowercase().contains(&filter)
+        })
+        .collect();
+    if known.is_empty() {
+        return CommandResult::Success("You don't know any recipes.".to_string());
+    }
+
+    known.sort_by(|a, b| {
+        a.skill
+            .name()
+            .cmp(b.skill.name())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    let mut output = String::from("Recipes:\r\n");
+    for recipe in known {
+        output.push_str(&format!(
+            "  {:<20} {:<15} {:<10} {}\r\n",
+            recipe.name,
+            recipe.skill.name(),
+            skill_rank_name(recipe.min_level),
+            requirements(recipe, &templates)
+        ));
+    }
+    CommandResult::Success(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{
+        ContainedBy, Container, CraftingStation, EntityId, Ingredient, ItemTemplate, Skill,
+        Template,
+    };
+    use crate::persistence::PersistenceManager;
+    use uuid::Uuid;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    /// A tailor at a loom carrying two bolts of cloth
+    async fn spawn_scene(context: &WorldContext) -> EcsEntity {
+        {
+            let mut templates = context.item_templates().write().await;
+            templates.register(ItemTemplate::new("cloth_bolt", "bolt of cloth", 1.0));
+            templates.register(ItemTemplate::new("cloth_tunic", "cloth tunic", 1.0));
+            let recipe = Recipe {
+                name: "Cloth Tunic".to_string(),
+                skill: Skill::Tailoring,
+                min_level: 0,
+                // Only a natural 1 fails
+                difficulty: -100,
+                ingredients: vec![Ingredient::new("cloth_bolt", 2)],
+                tools: vec![],
+                station: Some("loom".to_string()),
+                output: "cloth_tunic".to_string(),
+                output_quantity: 1,
+            };
+            let mut recipes = context.recipes().write().await;
+            recipes.register(recipe, &templates).unwrap();
+        }
+
+        let mut world = context.entities().write().await;
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        world.spawn((CraftingStation::new("loom"), Location::new(area, room)));
+        let tailor = world.spawn((
+            Name::new("Alice"),
+            EntityUuid::new(),
+            Location::new(area, room),
+            Container::new(None),
+            Skills::new(),
+        ));
+        let holder = InventorySystem::holder_id(&world, tailor);
+        for _ in 0..2 {
+            world.spawn((
+                EntityUuid::new(),
+                Template::new("cloth_bolt"),
+                ContainedBy::new(holder),
+            ));
+        }
+        tailor
+    }
+
+    #[tokio::test]
+    async fn test_craft_uses_ingredients() {
+        let context = setup();
+        let tailor = spawn_scene(&context).await;
+
+        let result =
+            craft_command(context.clone(), tailor, "craft".into(), args("cloth tunic")).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        let world = context.entities().read().await;
+        let inventory = InventorySystem::new(context.event_bus().clone());
+        let carried: Vec<String> = inventory
+            .get_items_in_container(&world, tailor)
+            .into_iter()
+            .filter_map(|item| {
+                world
+                    .get::<&Template>(item)
+                    .ok()
+                    .map(|t| t.template_id.clone())
+            })
+            .collect();
+        assert!(!carried.contains(&"cloth_bolt".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_craft_and_recipes_report_problems() {
+        let context = setup();
+        let tailor = spawn_scene(&context).await;
+
+        let result =
+            craft_command(context.clone(), tailor, "craft".into(), args("iron helm")).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+        let result = craft_command(context.clone(), tailor, "craft".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Invalid(_)));
+
+        let result = recipes_command(context.clone(), tailor, "recipes".into(), vec![]).await;
+        assert!(
+            matches!(result, CommandResult::Success(ref msg) if msg.contains("2 x bolt of cloth, at a loom"))
+        );
+    }
+}
@@ -0,0 +1,573 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Door and container commands: open, close, lock, unlock and pick
+
+use super::CommandSystem;
+use super::inventory::{name_of, room_entities};
+use crate::ecs::components::{
+    Container, Enterable, EntityUuid, Exits, Key, Location, LockError, Name, Openable, Room, Skill,
+};
+use crate::ecs::context::WorldContext;
+use crate::ecs::output::{capitalize, players_in_room};
+use crate::ecs::systems::{
+    BASE_DIFFICULTY, CommandResult, InventorySystem, ProgressionSystem, SkillCheck,
+    SkillCheckResolver,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Something with a door or lid
+#[derive(Debug, Clone, PartialEq)]
+enum Target {
+    /// An exit of the given room
+    Exit { room: EcsEntity, direction: String },
+    /// A container or enterable entity
+    Object(EcsEntity),
+}
+
+/// What to do with a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Open,
+    Close,
+    Lock,
+    Unlock,
+    Pick,
+}
+
+impl Action {
+    /// Verb as used in messages ("You open the chest.")
+    fn verb(&self) -> &'static str {
+        match self {
+            Action::Open => "open",
+            Action::Close => "close",
+            Action::Lock => "lock",
+            Action::Unlock => "unlock",
+            Action::Pick => "pick",
+        }
+    }
+
+    /// Third person verb as seen by others ("Alice opens the chest.")
+    fn verb_s(&self) -> &'static str {
+        match self {
+            Action::Open => "opens",
+            Action::Close => "closes",
+            Action::Lock => "locks",
+            Action::Unlock => "unlocks",
+            Action::Pick => "picks the lock of",
+        }
+    }
+}
+
+/// Lockpicking check difficulty for a lock rating
+///
+/// Each rating point matches one Lockpicking level, so an average character
+/// with a matching skill level succeeds about half the time.
+pub fn pick_difficulty(lock_rating: i32) -> i32 {
+    BASE_DIFFICULTY + 2 * lock_rating.max(0)
+}
+
+/// Find a room entity by its UUID
+fn find_room(world: &GameWorld, room_uuid: Uuid) -> Option<EcsEntity> {
+    world
+        .query::<(Entity, &EntityUuid, &Room)>()
+        .iter()
+        .find(|(_, uuid, _)| uuid.0 == room_uuid)
+        .map(|(room, _, _)| room)
+}
+
+/// Resolve a command argument to an exit, container or enterable
+///
+/// Directions name an exit of the current room and "door" names its first
+/// door; anything else is matched against containers and enterables carried
+/// or in the room.
+fn resolve_target(
+    world: &GameWorld,
+    inventory: &InventorySystem,
+    entity: EcsEntity,
+    keyword: &str,
+) -> Option<Target> {
+    let keyword = keyword.to_lowercase();
+    let room = world
+        .get::<&Location>(entity)
+        .ok()
+        .and_then(|location| find_room(world, location.room_id.uuid()));
+
+    if let Some(room) = room {
+        if let Ok(exits) = world.get::<&Exits>(room) {
+            let exit = match CommandSystem::normalize_direction(&keyword) {
+                Some(direction) => exits.find_exit(&direction),
+                None if keyword == "door" => exits.exits.iter().find(|exit| exit.is_closeable()),
+                None => None,
+            };
+            if let Some(exit) = exit {
+                return Some(Target::Exit {
+                    room,
+                    direction: exit.direction.clone(),
+                });
+            }
+        }
+    }
+
+    let mut candidates = inventory.get_items_in_container(world, entity);
+    candidates.extend(room_entities(world, entity));
+    candidates
+        .into_iter()
+        .filter(|candidate| {
+            world.get::<&Container>(*candidate).is_ok()
+                || world.get::<&Enterable>(*candidate).is_ok()
+        })
+        .find(|candidate| {
+            world
+                .get::<&Name>(*candidate)
+                .map(|name| name.matches(&keyword))
+                .unwrap_or(false)
+        })
+        .map(Target::Object)
+}
+
+/// Name of a target as used in messages
+fn target_name(world: &GameWorld, target: &Target) -> String {
+    match target {
+        Target::Exit { direction, .. } => format!("the {} door", direction.to_lowercase()),
+        Target::Object(object) => name_of(world, *object),
+    }
+}
+
+/// Apply a state change to a target's door or lid
+fn apply(
+    world: &GameWorld,
+    target: &Target,
+    change: impl FnOnce(&mut dyn Openable) -> Result<(), LockError>,
+) -> Option<Result<(), LockError>> {
+    match target {
+        Target::Exit { room, direction } => {
+            let mut exits = world.get::<&mut Exits>(*room).ok()?;
+            let exit = exits.find_exit_mut(direction)?;
+            Some(change(exit))
+        }
+        Target::Object(object) => {
+            if let Ok(mut container) = world.get::<&mut Container>(*object) {
+                return Some(change(&mut *container));
+            }
+            let mut enterable = world.get::<&mut Enterable>(*object).ok()?;
+            Some(change(&mut *enterable))
+        }
+    }
+}
+
+/// Copy an exit's door state onto the matching exit of the room it leads to
+///
+/// Returns the destination room and the direction of its mirrored exit.
+fn mirror_exit(world: &GameWorld, room: EcsEntity, direction: &str) -> Option<(EcsEntity, String)> {
+    let room_uuid = world.get::<&EntityUuid>(room).ok()?.0;
+    let (dest_uuid, closed, locked) = {
+        let exits = world.get::<&Exits>(room).ok()?;
+        let exit = exits.find_exit(direction)?;
+        (exit.dest_id.uuid(), exit.is_closed(), exit.is_locked())
+    };
+
+    let dest = find_room(world, dest_uuid)?;
+    let mut dest_exits = world.get::<&mut Exits>(dest).ok()?;
+    let reverse = dest_exits
+        .exits
+        .iter_mut()
+        .find(|exit| exit.dest_id.uuid() == room_uuid)?;
+    reverse.set_closed(closed);
+    reverse.set_locked(locked);
+    Some((dest, reverse.direction.clone()))
+}
+
+/// Unlock codes of the keys an entity carries
+fn carried_key_codes(
+    world: &GameWorld,
+    inventory: &InventorySystem,
+    entity: EcsEntity,
+) -> Vec<String> {
+    inventory
+        .get_items_in_container(world, entity)
+        .into_iter()
+        .filter_map(|item| {
+            world
+                .get::<&Key>(item)
+                .ok()
+                .map(|key| key.unlock_code.clone())
+        })
+        .collect()
+}
+
+/// Explain why a state change was refused
+fn refusal(error: LockError, action: Action, name: &str) -> String {
+    let subject = capitalize(name);
+    match error {
+        LockError::NotCloseable => format!("{} cannot be opened or closed.", subject),
+        LockError::NotLockable => format!("{} has no lock.", subject),
+        LockError::AlreadyOpen => format!("{} is already open.", subject),
+        LockError::AlreadyClosed => format!("{} is already closed.", subject),
+        LockError::AlreadyLocked => format!("{} is already locked.", subject),
+        LockError::AlreadyUnlocked => format!("{} is not locked.", subject),
+        LockError::NotClosed => format!("You must close {} first.", name),
+        LockError::Locked => format!("{} is locked.", subject),
+        LockError::NoKey => format!("You don't have a key to {} {}.", action.verb(), name),
+    }
+}
+
+/// Resolve the target and perform an action on it
+async fn act(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    args: Vec<String>,
+    action: Action,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid(format!(
+            "Usage: {} <direction|door|container>",
+            action.verb()
+        ));
+    }
+    let keyword = args.join(" ");
+
+    let world = context.entities().write().await;
+    let inventory = InventorySystem::new(context.event_bus().clone());
+    let Some(target) = resolve_target(&world, &inventory, entity, &keyword) else {
+        return CommandResult::Failure(format!("You don't see '{}' here.", keyword));
+    };
+    let name = target_name(&world, &target);
+
+    let key_codes = carried_key_codes(&world, &inventory, entity);
+    let codes: Vec<&str> = key_codes.iter().map(String::as_str).collect();
+    let mut learner = None;
+    let outcome = match action {
+        Action::Open => apply(&world, &target, |o| o.open()),
+        Action::Close => apply(&world, &target, |o| o.close()),
+        Action::Lock => apply(&world, &target, |o| o.lock(&codes)),
+        Action::Unlock => apply(&world, &target, |o| o.unlock(&codes)),
+        Action::Pick => {
+            let mut rating = None;
+            // Check there is a locked lock before rolling against it
+            let ready = apply(&world, &target, |o| {
+                rating = o.lock_rating();
+                if !o.is_lockable() {
+                    Err(LockError::NotLockable)
+                } else if !o.is_locked() {
+                    Err(LockError::AlreadyUnlocked)
+                } else {
+                    Ok(())
+                }
+            });
+            if let Some(Ok(())) = ready {
+                let check = SkillCheck::for_skill(Skill::Lockpicking)
+                    .against(pick_difficulty(rating.unwrap_or(0)));
+                let result = SkillCheckResolver::new().resolve(&world, entity, &check);
+                let success = result.outcome.is_success();
+
+                // Every attempt is practice, successful or not
+                let progression = ProgressionSystem::new(context.event_bus().clone());
+                if progression
+                    .record_use(
+                        &world,
+                        entity,
+                        Skill::Lockpicking,
+                        result.challenge_level(),
+                        success,
+                    )
+                    .is_some()
+                {
+                    learner = world.get::<&EntityUuid>(entity).ok().map(|uuid| uuid.0);
+                }
+                if !success {
+                    drop(world);
+                    if let Some(uuid) = learner {
+                        context.mark_dirty(uuid).await;
+                    }
+                    return CommandResult::Failure(format!(
+                        "You fail to pick the lock of {}.",
+                        name
+                    ));
+                }
+                apply(&world, &target, |o| o.pick())
+            } else {
+                ready
+            }
+        }
+    };
+
+    match outcome {
+        None => return CommandResult::Failure(format!("You don't see '{}' here.", keyword)),
+        Some(Err(error)) => return CommandResult::Failure(refusal(error, action, &name)),
+        Some(Ok(())) => {}
+    }
+
+    // Keep both sides of a two-way door in the same state
+    let mut changed = Vec::new();
+    let mut far_side = None;
+    match &target {
+        Target::Exit { room, direction } => {
+            changed.push(*room);
+            if let Some((dest, reverse)) = mirror_exit(&world, *room, direction) {
+                changed.push(dest);
+                far_side = Some((dest, reverse));
+            }
+        }
+        Target::Object(object) => changed.push(*object),
+    }
+    let dirty: Vec<Uuid> = changed
+        .iter()
+        .filter_map(|e| world.get::<&EntityUuid>(*e).ok().map(|uuid| uuid.0))
+        .chain(learner)
+        .collect();
+
+    // Tell the room, and the far side when a door opens or closes
+    let actor = capitalize(&name_of(&world, entity));
+    let mut notices = Vec::new();
+    if let Ok(location) = world.get::<&Location>(entity) {
+        let observers: Vec<EcsEntity> = players_in_room(&world, location.room_id.uuid())
+            .into_iter()
+            .filter(|other| *other != entity)
+            .collect();
+        notices.push((
+            observers,
+            format!("{} {} {}.", actor, action.verb_s(), name),
+        ));
+    }
+    if let (Some((dest, reverse)), Action::Open | Action::Close) = (far_side, action) {
+        if let Ok(dest_uuid) = world.get::<&EntityUuid>(dest).map(|uuid| uuid.0) {
+            notices.push((
+                players_in_room(&world, dest_uuid),
+                format!("The {} door {}.", reverse.to_lowercase(), action.verb_s()),
+            ));
+        }
+    }
+    drop(world);
+
+    for uuid in dirty {
+        context.mark_dirty(uuid).await;
+    }
+    for (recipients, message) in notices {
+        context.send_to_entities(&recipients, &message).await;
+    }
+
+    CommandResult::Success(match action {
+        Action::Pick => format!("You pick the lock of {}.", name),
+        _ => format!("You {} {}.", action.verb(), name),
+    })
+}
+
+/// Command to open a door or container
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn open_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    act(context, entity, args, Action::Open).await
+}
+
+/// Command to close a door or container
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn close_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    act(context, entity, args, Action::Close).await
+}
+
+/// Command to lock a door or container with a carried key
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn lock_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    act(context, entity, args, Action::Lock).await
+}
+
+/// Command to unlock a door or container with a carried key
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn unlock_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    act(context, entity, args, Action::Unlock).await
+}
+
+/// Command to pick a lock using the Lockpicking skill
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn pick_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    act(context, entity, args, Action::Pick).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Avatar, ContainedBy, EntityId, ExitData};
+    use crate::persistence::PersistenceManager;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    /// Two rooms joined by a locked north/south door and a player in the first
+    async fn spawn_rooms(context: &WorldContext) -> (EcsEntity, EcsEntity, EcsEntity) {
+        let mut world = context.entities().write().await;
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let (hall_uuid, vault_uuid) = (EntityUuid::new(), EntityUuid::new());
+        let hall_id = EntityId::from_uuid(hall_uuid.0);
+        let vault_id = EntityId::from_uuid(vault_uuid.0);
+        let hall = world.spawn((
+            hall_uuid,
+            Room::new(area),
+            Exits::new().add_exit(
+                ExitData::new("North", vault_id)
+                    .with_lock(3, 3, "vault")
+                    .closed()
+                    .locked(),
+            ),
+        ));
+        let vault = world.spawn((
+            vault_uuid,
+            Room::new(area),
+            Exits::new().add_exit(
+                ExitData::new("South", hall_id)
+                    .with_lock(3, 3, "vault")
+                    .closed()
+                    .locked(),
+            ),
+        ));
+        let player = world.spawn((
+            Name::new("Alice"),
+            EntityUuid::new(),
+            Avatar::new(Uuid::new_v4()),
+            Location::new(area, hall_id),
+        ));
+        (hall, vault, player)
+    }
+
+    async fn exit_state(context: &WorldContext, room: EcsEntity) -> (bool, bool) {
+        let world = context.entities().read().await;
+        let exits = world.get::<&Exits>(room).unwrap();
+        let exit = &exits.exits[0];
+        (exit.is_closed(), exit.is_locked())
+    }
+
+    #[test]
+    fn test_pick_difficulty() {
+        assert_eq!(pick_difficulty(0), BASE_DIFFICULTY);
+        assert!(pick_difficulty(5) > pick_difficulty(3));
+        assert_eq!(pick_difficulty(-2), BASE_DIFFICULTY);
+
+        let check = SkillCheck::for_skill(Skill::Lockpicking).against(pick_difficulty(3));
+        let mut resolver = SkillCheckResolver::seeded(1);
+        let mut world = GameWorld::new();
+        let thief = world.spawn(());
+        assert_eq!(resolver.resolve(&world, thief, &check).challenge_level(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_requires_key_and_mirrors_exit() {
+        let context = setup();
+        let (hall, vault, player) = spawn_rooms(&context).await;
+
+        let result = unlock_command(context.clone(), player, "unlock".into(), args("north")).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("key")));
+
+        {
+            let mut world = context.entities().write().await;
+            let holder = InventorySystem::holder_id(&world, player);
+            world.spawn((
+                Name::new("iron key"),
+                EntityUuid::new(),
+                Key::new("vault"),
+                ContainedBy::new(holder),
+            ));
+        }
+
+        let result = unlock_command(context.clone(), player, "unlock".into(), args("n")).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        assert_eq!(exit_state(&context, hall).await, (true, false));
+        assert_eq!(exit_state(&context, vault).await, (true, false));
+
+        let result = open_command(context.clone(), player, "open".into(), args("door")).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        assert_eq!(exit_state(&context, hall).await, (false, false));
+        assert_eq!(exit_state(&context, vault).await, (false, false));
+
+        let result = lock_command(context.clone(), player, "lock".into(), args("north")).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("close")));
+    }
+
+    #[tokio::test]
+    async fn test_closed_door_blocks_movement() {
+        let context = setup();
+        let (hall, vault, player) = spawn_rooms(&context).await;
+        {
+            let world = context.entities().write().await;
+            let mut exits = world.get::<&mut Exits>(hall).unwrap();
+            exits.exits[0].set_locked(false);
+        }
+
+        let result = CommandSystem::attempt_move(context.clone(), player, "north".into()).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("closed")));
+
+        open_command(context.clone(), player, "open".into(), args("north")).await;
+        CommandSystem::attempt_move(context.clone(), player, "north".into()).await;
+        let world = context.entities().read().await;
+        let room = world.get::<&Location>(player).unwrap().room_id.uuid();
+        assert_eq!(
+            Some(room),
+            world.get::<&EntityUuid>(vault).ok().map(|uuid| uuid.0)
+        );
+    }
+    #[tokio::test]
+    async fn test_closed_flag_without_door_does_not_block() {
+        let context = setup();
+        let (hall, vault, player) = spawn_rooms(&context).await;
+        {
+            // Older exit data may carry the flags without a door or lock
+            let world = context.entities().write().await;
+            let mut exits = world.get::<&mut Exits>(hall).unwrap();
+            let dest_id = exits.exits[0].dest_id;
+            exits.exits[0] = ExitData::new("North", dest_id).closed().locked();
+        }
+
+        CommandSystem::attempt_move(context.clone(), player, "north".into()).await;
+        let world = context.entities().read().await;
+        let room = world.get::<&Location>(player).unwrap().room_id.uuid();
+        assert_eq!(
+            Some(room),
+            world.get::<&EntityUuid>(vault).ok().map(|uuid| uuid.0)
+        );
+    }
+}
@@ -0,0 +1,425 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Gathering commands: gather, mine, fish, chop, forage, skin and harvest,
+//! plus the builder commands for placing resource nodes
+
+use super::inventory::name_of;
+use crate::ecs::EcsEntity;
+use crate::ecs::components::{
+    Description, EntityId, EntityUuid, Location, Name, Persistent, Posture, ResourceNode,
+    ResourceYield, Skill, SkillCategory,
+};
+use crate::ecs::context::WorldContext;
+use crate::ecs::output::{capitalize, players_in_room};
+use crate::ecs::systems::{
+    CommandResult, InventorySystem, ProgressionSystem, SkillCheckResolver, find_node, gather,
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Gathering skill used by a command, or `None` for plain `gather`
+fn command_skill(cmd: &str) -> Option<Skill> {
+    match cmd {
+        "mine" => Some(Skill::Mining),
+        "fish" => Some(Skill::Fishing),
+        "chop" => Some(Skill::Logging),
+        "forage" => Some(Skill::Foraging),
+        "skin" => Some(Skill::Skinning),
+        "harvest" => Some(Skill::Herbalism),
+        _ => None,
+    }
+}
+
+/// Gather from a resource node in the room
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn gather_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let skill = command_skill(&cmd);
+    let keyword = args.join(" ").to_lowercase();
+
+    let mut world = context.entities().write().await;
+    let templates = context.item_templates().read().await;
+    if let Ok(Posture::Sleeping) = world.get::<&Posture>(entity).map(|posture| *posture) {
+        return CommandResult::Failure("You can't do that while asleep.".to_string());
+    }
+    let Some(node) = find_node(&world, entity, &keyword, skill) else {
+        return CommandResult::Failure(match (skill, keyword.is_empty()) {
+            (_, false) => format!("You don't see '{}' to {} here.", keyword, cmd),
+            (Some(_), true) => format!("There is nowhere to {} here.", cmd),
+            (None, true) => "There is nothing to gather here.".to_string(),
+        });
+    };
+    let node_skill = world
+        .get::<&ResourceNode>(node)
+        .map(|resources| resources.skill)
+        .ok();
+
+    let inventory = InventorySystem::new(context.event_bus().clone());
+    let mut resolver = SkillCheckResolver::new();
+    let outcome = match gather(
+        &mut world,
+        &inventory,
+        &mut resolver,
+        &templates,
+        entity,
+        node,
+    ) {
+        Ok(outcome) => outcome,
+        Err(message) => return CommandResult::Failure(message),
+    };
+
+    if let Some(node_skill) = node_skill {
+        let progression = ProgressionSystem::new(context.event_bus().clone());
+        progression.record_use(
+            &world,
+            entity,
+            node_skill,
+            outcome.check.challenge_level(),
+            outcome.check.outcome.is_success(),
+        );
+    }
+
+    let actor = capitalize(&name_of(&world, entity));
+    let node_name = name_of(&world, node);
+    let (mut message, room_message) = match outcome.produced.first() {
+        Some((item, _)) => {
+            let found = name_of(&world, *item);
+            let count = if outcome.produced.len() > 1 {
+                format!("{} x ", outcome.produced.len())
+            } else {
+                String::new()
+            };
+            (
+                format!("You gather {}{} from the {}.", count, found, node_name),
+                format!("{} gathers {} from the {}.", actor, found, node_name),
+            )
+        }
+        None => (
+            format!("You fail to gather anything from the {}.", node_name),
+            format!("{} works the {} without success.", actor, node_name),
+        ),
+    };
+    if outcome.depleted {
+        message.push_str(&format!("\r\nThe {} is exhausted for now.", node_name));
+    }
+    let observers: Vec<EcsEntity> = world
+        .get::<&Location>(entity)
+        .map(|location| players_in_room(&world, location.room_id.uuid()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|other| *other != entity)
+        .collect();
+    let dirty: Vec<Uuid> = [entity, node]
+        .iter()
+        .filter_map(|e| world.get::<&EntityUuid>(*e).ok().map(|uuid| uuid.0))
+        .collect();
+    drop(templates);
+    drop(world);
+
+    for (item, uuid) in &outcome.produced {
+        context.register_entity(*item, *uuid).await;
+        context.mark_dirty(*uuid).await;
+    }
+    for uuid in dirty {
+        context.mark_dirty(uuid).await;
+    }
+    context.send_to_entities(&observers, &room_message).await;
+
+    CommandResult::Success(message)
+}
+
+/// Create a resource node in the current room
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn node_create_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.len() < 2 {
+        return CommandResult::Failure(
+            "Usage: node create <skill> <name>\n\
+             Skills: Mining, Herbalism, Logging, Fishing, Foraging, Skinning\n\
+             Example: node create Mining iron vein"
+                .to_string(),
+        );
+    }
+    let skill = match Skill::from_str(&args[0]) {
+        Ok(skill) if skill.category() == SkillCategory::Gathering => skill,
+        _ => {
+            return CommandResult::Failure(format!("{} is not a gathering skill", args[0]));
+        }
+    };
+    let node_name = args[1..].join(" ");
+
+    let mut world = context.entities().write().await;
+    let Ok(location) = world.get::<&Location>(entity).map(|loc| *loc) else {
+        return CommandResult::Failure("You have no location".to_string());
+    };
+    let node_uuid = Uuid::new_v4();
+    let node_entity = world.spawn((
+        EntityUuid(node_uuid),
+        Name::new(&node_name),
+        Description::new(
+            format!("A {} is here.", node_name),
+            "This is a newly created resource node. Use 'node edit' to add yields.".to_string(),
+        ),
+        Location::new(
+            EntityId::from_uuid(location.area_id.uuid()),
+            EntityId::from_uuid(location.room_id.uuid()),
+        ),
+        ResourceNode::new(skill, 10),
+        Persistent,
+    ));
+    drop(world);
+
+    context.register_entity(node_entity, node_uuid).await;
+    context.mark_entity_dirty(node_entity).await;
+
+    CommandResult::Success(format!(
+        "Resource node created successfully!\r\n\
+         Name: {}\r\n\
+         Skill: {}\r\n\
+         UUID: {}\r\n\
+         Use 'node edit' to add yields and a tool",
+        node_name,
+        skill.name(),
+        node_uuid
+    ))
+}
+
+/// Edit a resource node
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn node_edit_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.len() < 3 {
+        return CommandResult::Failure(
+            "Usage: node edit <uuid> <field> <value>\n\
+             Fields: difficulty, tool, charges, respawn, yield, unyield\n\
+             Example: node edit <uuid> tool pickaxe\n\
+             Example: node edit <uuid> yield iron_ore <weight> [min] [max] [min_level]"
+                .to_string(),
+        );
+    }
+    let Ok(target_uuid) = Uuid::parse_str(&args[0]) else {
+        return CommandResult::Failure("Invalid UUID format".to_string());
+    };
+    let Some(node_entity) = context.get_entity_by_uuid(target_uuid).await else {
+        return CommandResult::Failure(format!("Node {} not found", target_uuid));
+    };
+    let field = args[1].to_lowercase();
+    let values = &args[2..];
+    let number = |index: usize| {
+        values
+            .get(index)
+            .and_then(|value| value.parse::<i32>().ok())
+    };
+
+    let world = context.entities().read().await;
+    let templates = context.item_templates().read().await;
+    let Ok(mut node) = world.get::<&mut ResourceNode>(node_entity) else {
+        return CommandResult::Failure(format!("{} is not a resource node", target_uuid));
+    };
+    let result = match field.as_str() {
+        "difficulty" => match number(0) {
+            Some(difficulty) => {
+                node.difficulty = difficulty;
+                Ok(format!("Difficulty set to {}", difficulty))
+            }
+            None => Err("Difficulty must be a number".to_string()),
+        },
+        "tool" => match values[0].as_str() {
+            "none" => {
+                node.tool = None;
+                Ok("Tool removed".to_string())
+            }
+            tool if templates.contains(tool) => {
+                node.tool = Some(tool.to_string());
+                Ok(format!("Tool set to {}", tool))
+            }
+            tool => Err(format!("Unknown item template '{}'", tool)),
+        },
+        "charges" => match number(0) {
+            Some(charges) if charges > 0 => {
+                node.max_charges = charges as u32;
+                node.charges = charges as u32;
+                node.respawn_timer = 0.0;
+                Ok(format!("Charges set to {}", charges))
+            }
+            _ => Err("Charges must be a positive number".to_string()),
+        },
+        "respawn" => match values[0].parse::<f32>() {
+            Ok(seconds) if seconds >= 0.0 => {
+                node.respawn_time = seconds;
+                Ok(format!("Respawn time set to {} seconds", seconds))
+            }
+            _ => Err("Respawn time must be a number of seconds".to_string()),
+        },
+        "yield" => {
+            let template_id = values[0].clone();
+            let weight = number(1).unwrap_or(1).max(0) as u32;
+            let min = number(2).unwrap_or(1).max(1) as u32;
+            let max = number(3).unwrap_or(min as i32).max(0) as u32;
+            let min_level = number(4).unwrap_or(0);
+            if templates.contains(&template_id) {
+                node.yields.retain(|entry| entry.template_id != template_id);
+                node.yields.push(
+                    ResourceYield::new(&template_id, weight)
+                        .with_quantity(min, max)
+                        .with_min_level(min_level),
+                );
+                Ok(format!("Yield {} added", template_id))
+            } else {
+                Err(format!("Unknown item template '{}'", template_id))
+            }
+        }
+        "unyield" => {
+            let count = node.yields.len();
+            node.yields.retain(|entry| entry.template_id != values[0]);
+            if node.yields.len() < count {
+                Ok(format!("Yield {} removed", values[0]))
+            } else {
+                Err(format!("Node has no yield '{}'", values[0]))
+            }
+        }
+        _ => Err(format!("Unknown field: {}", field)),
+    };
+    drop(node);
+    drop(templates);
+    drop(world);
+
+    match result {
+        Ok(message) => {
+            context.mark_entity_dirty(node_entity).await;
+            CommandResult::Success(message)
+        }
+        Err(message) => CommandResult::Failure(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{ContainedBy, Container, ItemTemplate, Skills, Template};
+    use crate::persistence::PersistenceManager;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    /// An angler at a fishing spot, carrying a fishing pole
+    async fn spawn_scene(context: &WorldContext) -> (EcsEntity, Uuid) {
+        {
+            let mut templates = context.item_templates().write().await;
+            templates.register(ItemTemplate::new("raw_fish", "raw fish", 1.0));
+            templates.register(ItemTemplate::new("fishing_pole", "fishing pole", 2.0));
+        }
+
+        let mut world = context.entities().write().await;
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        let spot_uuid = Uuid::new_v4();
+        let spot = world.spawn((
+            EntityUuid(spot_uuid),
+            Name::new("fishing spot"),
+            Location::new(area, room),
+            // Only a natural 1 fails
+            ResourceNode::new(Skill::Fishing, -100)
+                .with_tool("fishing_pole")
+                .with_yield(ResourceYield::new("raw_fish", 1)),
+        ));
+        let angler = world.spawn((
+            Name::new("Alice"),
+            EntityUuid::new(),
+            Location::new(area, room),
+            Container::new(None),
+            Skills::new(),
+        ));
+        let holder = InventorySystem::holder_id(&world, angler);
+        world.spawn((
+            EntityUuid::new(),
+            Template::new("fishing_pole"),
+            ContainedBy::new(holder),
+        ));
+        drop(world);
+        context.register_entity(spot, spot_uuid).await;
+        (angler, spot_uuid)
+    }
+
+    #[test]
+    fn test_command_skill() {
+        assert_eq!(command_skill("mine"), Some(Skill::Mining));
+        assert_eq!(command_skill("harvest"), Some(Skill::Herbalism));
+        assert_eq!(command_skill("gather"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fish_finds_matching_node() {
+        let context = setup();
+        let (angler, _) = spawn_scene(&context).await;
+
+        let result = gather_command(context.clone(), angler, "mine".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("nowhere")));
+
+        let result = gather_command(context.clone(), angler, "fish".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Success(ref msg) if msg.contains("fishing spot")));
+    }
+
+    #[tokio::test]
+    async fn test_node_edit_validates_templates() {
+        let context = setup();
+        let (angler, spot) = spawn_scene(&context).await;
+
+        let result = node_edit_command(
+            context.clone(),
+            angler,
+            "node edit".into(),
+            args(&format!("{} yield gold_ore 1", spot)),
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        let result = node_edit_command(
+            context.clone(),
+            angler,
+            "node edit".into(),
+            args(&format!("{} yield raw_fish 3 1 2", spot)),
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        let world = context.entities().read().await;
+        let entity = context.registry().read().await.get_entity(spot).unwrap();
+        let node = world.get::<&ResourceNode>(entity).unwrap();
+        assert_eq!(node.yields.len(), 1);
+        assert_eq!(node.yields[0].weight, 3);
+        assert_eq!(node.yields[0].max_quantity, 2);
+    }
+}
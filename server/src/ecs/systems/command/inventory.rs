@@ -14,11 +14,178 @@
 // limitations under the License.
 //
 
-use crate::ecs::EcsEntity;
-use crate::ecs::components::Container;
+//! Object manipulation commands: inventory, get, drop, put, give, wear,
+//! wield, remove and equipment
+
+use crate::ecs::components::{
+    Containable, Container, EntityUuid, EquipSlot, Equipment, Location, Name,
+};
 use crate::ecs::context::WorldContext;
-use crate::ecs::systems::CommandResult;
+use crate::ecs::systems::{CommandResult, InventorySystem};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// Which items a command argument refers to
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    /// The first item matching a keyword
+    One(String),
+    /// Every item, or every item matching a keyword (`all`, `all.sword`)
+    All(Option<String>),
+}
+
+impl Selector {
+    fn parse(text: &str) -> Self {
+        let text = text.trim().to_lowercase();
+        if text == "all" {
+            Selector::All(None)
+        } else if let Some(keyword) = text.strip_prefix("all.") {
+            Selector::All(Some(keyword.to_string()))
+        } else {
+            Selector::One(text)
+        }
+    }
+
+    fn is_all(&self) -> bool {
+        matches!(self, Selector::All(_))
+    }
+
+    /// Pick the matching items from a list of candidates
+    fn select(&self, world: &GameWorld, candidates: Vec<EcsEntity>) -> Vec<EcsEntity> {
+        let matches = |entity: &EcsEntity, keyword: &str| {
+            world
+                .get::<&Name>(*entity)
+                .map(|name| name.matches(keyword))
+                .unwrap_or(false)
+        };
+        match self {
+            Selector::One(keyword) => candidates
+                .into_iter()
+                .find(|entity| matches(entity, keyword))
+                .into_iter()
+                .collect(),
+            Selector::All(None) => candidates,
+            Selector::All(Some(keyword)) => candidates
+                .into_iter()
+                .filter(|entity| matches(entity, keyword))
+                .collect(),
+        }
+    }
+}
+
+/// Split arguments around the first of the given keywords
+///
+/// `["sword", "in", "chest"]` split on `["in"]` gives `("sword", Some("chest"))`.
+fn split_on(args: &[String], keywords: &[&str]) -> (String, Option<String>) {
+    match args
+        .iter()
+        .position(|arg| keywords.contains(&arg.to_lowercase().as_str()))
+    {
+        Some(pos) => (args[..pos].join(" "), Some(args[pos + 1..].join(" "))),
+        None => (args.join(" "), None),
+    }
+}
+
+/// Display name of an entity
+pub(super) fn name_of(world: &GameWorld, entity: EcsEntity) -> String {
+    world
+        .get::<&Name>(entity)
+        .map(|name| name.display.clone())
+        .unwrap_or_else(|_| "something".to_string())
+}
+
+/// Other entities in the same room as the given entity
+pub(super) fn room_entities(world: &GameWorld, entity: EcsEntity) -> Vec<EcsEntity> {
+    let Ok(location) = world.get::<&Location>(entity).map(|loc| *loc) else {
+        return Vec::new();
+    };
+    world
+        .query::<(Entity, &Location)>()
+        .iter()
+        .filter(|(other, loc)| *other != entity && loc.room_id == location.room_id)
+        .map(|(other, _)| other)
+        .collect()
+}
+
+/// Items carried but not equipped
+fn carried_items(
+    world: &GameWorld,
+    inventory: &InventorySystem,
+    entity: EcsEntity,
+) -> Vec<EcsEntity> {
+    inventory
+        .get_items_in_container(world, entity)
+        .into_iter()
+        .filter(|item| inventory.equipped_slot(world, entity, *item).is_none())
+        .collect()
+}
+
+/// Items currently equipped, in slot display order
+fn equipped_items(world: &GameWorld, entity: EcsEntity) -> Vec<(EquipSlot, EcsEntity)> {
+    let Ok(equipment) = world.get::<&Equipment>(entity) else {
+        return Vec::new();
+    };
+    EquipSlot::ALL
+        .iter()
+        .filter_map(|slot| {
+            let item = equipment.get(*slot)?;
+            (!item.needs_resolution() && world.contains(item.entity()))
+                .then_some((*slot, item.entity()))
+        })
+        .collect()
+}
+
+/// Find a container by keyword, checking the inventory before the room
+pub(super) fn find_container(
+    world: &GameWorld,
+    inventory: &InventorySystem,
+    entity: EcsEntity,
+    keyword: &str,
+) -> Option<EcsEntity> {
+    let mut candidates = inventory.get_items_in_container(world, entity);
+    candidates.extend(room_entities(world, entity));
+    candidates.retain(|candidate| world.get::<&Container>(*candidate).is_ok());
+    Selector::One(keyword.to_lowercase())
+        .select(world, candidates)
+        .into_iter()
+        .next()
+}
+
+/// Persistent UUIDs of the given entities
+fn uuids_of(world: &GameWorld, entities: &[EcsEntity]) -> Vec<Uuid> {
+    entities
+        .iter()
+        .filter_map(|entity| world.get::<&EntityUuid>(*entity).ok().map(|uuid| uuid.0))
+        .collect()
+}
+
+/// Mark changed entities for saving and build the command result
+async fn finish(
+    context: &WorldContext,
+    dirty: Vec<Uuid>,
+    changed: bool,
+    lines: Vec<String>,
+) -> CommandResult {
+    for uuid in dirty {
+        context.mark_dirty(uuid).await;
+    }
+    if changed {
+        CommandResult::Success(lines.join("\r\n"))
+    } else {
+        CommandResult::Failure(lines.join("\r\n"))
+    }
+}
+
+/// Lowercase the first letter of an inventory error for use mid-sentence
+fn reason(error: String) -> String {
+    let mut chars = error.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => error,
+    }
+}
 
 /// Command to get list of inventory items
 #[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
@@ -29,20 +196,587 @@ pub async fn inventory_command(
     _args: Vec<String>,
 ) -> CommandResult {
     let world = context.entities().read().await;
-    let result = if let Ok(container) = world.get::<&Container>(entity) {
-        // Container no longer tracks contents directly
-        // TODO: Query world for entities with this entity as parent
-        CommandResult::Success(format!(
-            "Inventory capacity: {}, max weight: {:.1}",
+    let inventory = InventorySystem::new(context.event_bus().clone());
+
+    let items = carried_items(&world, &inventory, entity);
+    let mut output = if items.is_empty() {
+        "You are not carrying anything.".to_string()
+    } else {
+        let mut output = "You are carrying:".to_string();
+        for item in items {
+            output.push_str(&format!("\r\n  {}", name_of(&world, item)));
+        }
+        output
+    };
+
+    if let Ok(container) = world.get::<&Container>(entity) {
+        output.push_str(&format!(
+            "\r\nItems: {}/{}, weight: {:.1}/{}",
+            inventory.get_item_count(&world, entity),
             container
                 .capacity
                 .map(|c| c.to_string())
                 .unwrap_or("unlimited".to_string()),
-            container.max_weight.unwrap_or(f32::INFINITY)
-        ))
-    } else {
-        CommandResult::Failure("You have no inventory".to_string())
+            inventory.get_total_weight(&world, entity),
+            container
+                .max_weight
+                .map(|w| format!("{:.1}", w))
+                .unwrap_or("unlimited".to_string()),
+        ));
+    }
+
+    CommandResult::Success(output)
+}
+
+/// Command to pick items up from the room or take them out of a container
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn get_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let (target, source) = split_on(&args, &["from"]);
+    if target.is_empty() {
+        return CommandResult::Invalid("Usage: get <item|all> [from <container>]".to_string());
+    }
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    let container = match &source {
+        Some(keyword) => match find_container(&world, &inventory, entity, keyword) {
+            Some(container) => Some(container),
+            None => return CommandResult::Failure(format!("You don't see '{}' here.", keyword)),
+        },
+        None => None,
+    };
+
+    let candidates = match container {
+        Some(container) => inventory.get_items_in_container(&world, container),
+        None => {
+            let mut candidates = room_entities(&world, entity);
+            if selector.is_all() {
+                candidates.retain(|item| world.get::<&Containable>(*item).is_ok());
+            }
+            candidates
+        }
+    };
+    let items = selector.select(&world, candidates);
+    if items.is_empty() {
+        return CommandResult::Failure(match container {
+            Some(container) => format!(
+                "There is no '{}' in {}.",
+                target,
+                name_of(&world, container)
+            ),
+            None => format!("You don't see '{}' here.", target),
+        });
+    }
+
+    let mut lines = Vec::new();
+    let mut moved = Vec::new();
+    for item in items {
+        let item_name = name_of(&world, item);
+        if world.get::<&Containable>(item).is_err() {
+            lines.push(format!("You can't pick up {}.", item_name));
+            continue;
+        }
+        let result = match container {
+            Some(container) => inventory
+                .retrieve_item(&mut world, entity, item, container)
+                .map(|_| format!("You get {} from {}.", item_name, name_of(&world, container))),
+            None => inventory
+                .pickup_item(&mut world, entity, item)
+                .map(|_| format!("You pick up {}.", item_name)),
+        };
+        match result {
+            Ok(line) => {
+                lines.push(line);
+                moved.push(item);
+            }
+            Err(e) => lines.push(format!("You can't take {}: {}.", item_name, reason(e))),
+        }
+    }
+
+    let dirty = uuids_of(&world, &moved);
+    drop(world);
+    finish(&context, dirty, !moved.is_empty(), lines).await
+}
+
+/// Command to drop carried items in the room
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn drop_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: drop <item|all>".to_string());
+    }
+    let target = args.join(" ");
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    let items = selector.select(&world, carried_items(&world, &inventory, entity));
+    if items.is_empty() {
+        return CommandResult::Failure(format!("You aren't carrying '{}'.", target));
+    }
+
+    let mut lines = Vec::new();
+    let mut moved = Vec::new();
+    for item in items {
+        let item_name = name_of(&world, item);
+        match inventory.drop_item(&mut world, entity, item) {
+            Ok(()) => {
+                lines.push(format!("You drop {}.", item_name));
+                moved.push(item);
+            }
+            Err(e) => lines.push(format!("You can't drop {}: {}.", item_name, reason(e))),
+        }
+    }
+
+    let dirty = uuids_of(&world, &moved);
+    drop(world);
+    finish(&context, dirty, !moved.is_empty(), lines).await
+}
+
+/// Command to put carried items into a container
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn put_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let (target, destination) = split_on(&args, &["in", "into"]);
+    let Some(destination) = destination.filter(|d| !d.is_empty() && !target.is_empty()) else {
+        return CommandResult::Invalid("Usage: put <item|all> in <container>".to_string());
+    };
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    let Some(container) = find_container(&world, &inventory, entity, &destination) else {
+        return CommandResult::Failure(format!("You don't see '{}' here.", destination));
     };
+    let container_name = name_of(&world, container);
+
+    let mut candidates = carried_items(&world, &inventory, entity);
+    candidates.retain(|item| *item != container);
+    let items = selector.select(&world, candidates);
+    if items.is_empty() {
+        return CommandResult::Failure(format!("You aren't carrying '{}'.", target));
+    }
+
+    let mut lines = Vec::new();
+    let mut moved = Vec::new();
+    for item in items {
+        let item_name = name_of(&world, item);
+        match inventory.store_item(&mut world, entity, item, container) {
+            Ok(()) => {
+                lines.push(format!("You put {} in {}.", item_name, container_name));
+                moved.push(item);
+            }
+            Err(e) => lines.push(format!(
+                "You can't put {} in {}: {}.",
+                item_name,
+                container_name,
+                reason(e)
+            )),
+        }
+    }
+
+    let dirty = uuids_of(&world, &moved);
     drop(world);
-    result
+    finish(&context, dirty, !moved.is_empty(), lines).await
+}
+
+/// Command to give a carried item to someone in the room
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn give_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    let (target, recipient) = match split_on(&args, &["to"]) {
+        (target, Some(recipient)) => (target, recipient),
+        // Also accept "give <item> <recipient>"
+        _ if args.len() >= 2 => (args[0].clone(), args[1..].join(" ")),
+        _ => (String::new(), String::new()),
+    };
+    if target.is_empty() || recipient.is_empty() {
+        return CommandResult::Invalid("Usage: give <item|all> [to] <recipient>".to_string());
+    }
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    // Only beings can receive items, not other objects in the room
+    let mut beings = room_entities(&world, entity);
+    beings.retain(|other| world.get::<&Containable>(*other).is_err());
+    let Some(recipient) = Selector::One(recipient.to_lowercase())
+        .select(&world, beings)
+        .into_iter()
+        .next()
+    else {
+        return CommandResult::Failure(format!("You don't see '{}' here.", recipient));
+    };
+    let recipient_name = name_of(&world, recipient);
+
+    let items = selector.select(&world, carried_items(&world, &inventory, entity));
+    if items.is_empty() {
+        return CommandResult::Failure(format!("You aren't carrying '{}'.", target));
+    }
+
+    let mut lines = Vec::new();
+    let mut moved = Vec::new();
+    for item in items {
+        let item_name = name_of(&world, item);
+        match inventory.transfer_item(&mut world, entity, recipient, item) {
+            Ok(()) => {
+                lines.push(format!("You give {} to {}.", item_name, recipient_name));
+                moved.push(item);
+            }
+            Err(e) => lines.push(format!(
+                "You can't give {} to {}: {}.",
+                item_name,
+                recipient_name,
+                reason(e)
+            )),
+        }
+    }
+
+    let dirty = uuids_of(&world, &moved);
+    drop(world);
+    finish(&context, dirty, !moved.is_empty(), lines).await
+}
+
+/// Equip carried items into the given candidate slots
+async fn equip(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    target: String,
+    candidates: &[EquipSlot],
+    verb: &str,
+    preposition: &str,
+) -> CommandResult {
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    let items = selector.select(&world, carried_items(&world, &inventory, entity));
+    if items.is_empty() {
+        return CommandResult::Failure(format!("You aren't carrying '{}'.", target));
+    }
+
+    let mut lines = Vec::new();
+    let mut equipped = false;
+    for item in items {
+        let item_name = name_of(&world, item);
+        match inventory.equip_item(&mut world, entity, item, candidates) {
+            Ok(slot) => {
+                lines.push(format!(
+                    "You {} {} {} your {}.",
+                    verb,
+                    item_name,
+                    preposition,
+                    slot.label()
+                ));
+                equipped = true;
+            }
+            // Skip items that don't fit when equipping everything
+            Err(_) if selector.is_all() => {}
+            Err(e) => lines.push(format!("You can't {} {}: {}.", verb, item_name, reason(e))),
+        }
+    }
+    if lines.is_empty() {
+        lines.push(format!("You have nothing to {}.", verb));
+    }
+
+    let dirty = uuids_of(&world, &[entity]);
+    drop(world);
+    finish(&context, dirty, equipped, lines).await
+}
+
+/// Command to wear a carried item
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn wear_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: wear <item|all>".to_string());
+    }
+    let worn: Vec<EquipSlot> = EquipSlot::ALL
+        .into_iter()
+        .filter(|slot| !EquipSlot::HELD.contains(slot))
+        .collect();
+    equip(context, entity, args.join(" "), &worn, "wear", "on").await
+}
+
+/// Command to wield a carried item in hand
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn wield_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: wield <item>".to_string());
+    }
+    equip(
+        context,
+        entity,
+        args.join(" "),
+        &EquipSlot::HELD,
+        "wield",
+        "in",
+    )
+    .await
+}
+
+/// Command to remove equipped items, returning them to the inventory
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn remove_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: remove <item|all>".to_string());
+    }
+    let target = args.join(" ");
+    let selector = Selector::parse(&target);
+
+    let mut world = context.entities().write().await;
+    let mut inventory = InventorySystem::new(context.event_bus().clone());
+
+    let worn = equipped_items(&world, entity)
+        .into_iter()
+        .map(|(_, item)| item)
+        .collect();
+    let items = selector.select(&world, worn);
+    if items.is_empty() {
+        return CommandResult::Failure(format!("You aren't using '{}'.", target));
+    }
+
+    let mut lines = Vec::new();
+    let mut removed = false;
+    for item in items {
+        let item_name = name_of(&world, item);
+        match inventory.unequip_item(&mut world, entity, item) {
+            Ok(_) => {
+                lines.push(format!("You remove {}.", item_name));
+                removed = true;
+            }
+            Err(e) => lines.push(format!("You can't remove {}: {}.", item_name, reason(e))),
+        }
+    }
+
+    let dirty = uuids_of(&world, &[entity]);
+    drop(world);
+    finish(&context, dirty, removed, lines).await
+}
+
+/// Command to show equipped items by slot
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn equipment_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    let world = context.entities().read().await;
+    let equipped = equipped_items(&world, entity);
+    if equipped.is_empty() {
+        return CommandResult::Success("You are not using anything.".to_string());
+    }
+
+    let mut output = "You are using:".to_string();
+    for (slot, item) in equipped {
+        output.push_str(&format!(
+            "\r\n  {:<12}{}",
+            format!("<{}>", slot.label()),
+            name_of(&world, item)
+        ));
+    }
+    CommandResult::Success(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Avatar, EntityId, Equipable};
+    use crate::persistence::PersistenceManager;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn here(room: Uuid) -> Location {
+        Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    async fn set_closed(context: &WorldContext, container: EcsEntity, closed: bool) {
+        let world = context.entities().write().await;
+        world.get::<&mut Container>(container).unwrap().closed = closed;
+    }
+
+    #[test]
+    fn test_selector_parse() {
+        assert_eq!(Selector::parse("all"), Selector::All(None));
+        assert_eq!(
+            Selector::parse("all.Coin"),
+            Selector::All(Some("coin".to_string()))
+        );
+        assert_eq!(Selector::parse("sword"), Selector::One("sword".to_string()));
+        assert_eq!(
+            split_on(&args("long sword into chest"), &["in", "into"]),
+            ("long sword".to_string(), Some("chest".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_put_and_get_all_from_container() {
+        let context = setup();
+        let room = Uuid::new_v4();
+        let (player, sword, coin, chest) = {
+            let mut world = context.entities().write().await;
+            (
+                world.spawn((
+                    Name::new("Alice"),
+                    EntityUuid::new(),
+                    Avatar::new(Uuid::new_v4()),
+                    here(room),
+                )),
+                world.spawn((
+                    Name::new("sword"),
+                    EntityUuid::new(),
+                    Containable::new(5.0),
+                    here(room),
+                )),
+                world.spawn((
+                    Name::new("coin"),
+                    EntityUuid::new(),
+                    Containable::new(0.1),
+                    here(room),
+                )),
+                world.spawn((
+                    Name::new("chest"),
+                    EntityUuid::new(),
+                    Container::new(None),
+                    here(room),
+                )),
+            )
+        };
+
+        // The chest has no Containable, so "get all" leaves it alone
+        let result = get_command(context.clone(), player, "get".into(), args("all")).await;
+        assert!(matches!(result, CommandResult::Success(ref msg) if msg.lines().count() == 2));
+
+        let result = put_command(context.clone(), player, "put".into(), args("all in chest")).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        {
+            let world = context.entities().read().await;
+            let inventory = InventorySystem::new(context.event_bus().clone());
+            assert!(inventory.has_item(&world, chest, sword));
+            assert!(inventory.has_item(&world, chest, coin));
+            assert!(world.get::<&Location>(sword).is_err());
+        }
+
+        set_closed(&context, chest, true).await;
+        let result = get_command(
+            context.clone(),
+            player,
+            "get".into(),
+            args("all from chest"),
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        set_closed(&context, chest, false).await;
+        let result = get_command(
+            context.clone(),
+            player,
+            "get".into(),
+            args("all from chest"),
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Success(_)));
+
+        let world = context.entities().read().await;
+        let inventory = InventorySystem::new(context.event_bus().clone());
+        assert!(inventory.has_item(&world, player, sword));
+        assert!(inventory.has_item(&world, player, coin));
+        let sword_uuid = world.get::<&EntityUuid>(sword).unwrap().0;
+        drop(world);
+        assert!(context.is_dirty(sword_uuid).await);
+    }
+
+    #[tokio::test]
+    async fn test_wear_wield_remove_and_equipment() {
+        let context = setup();
+        let room = Uuid::new_v4();
+        let (player, helmet) = {
+            let mut world = context.entities().write().await;
+            let player = world.spawn((
+                Name::new("Alice"),
+                EntityUuid::new(),
+                Avatar::new(Uuid::new_v4()),
+                here(room),
+            ));
+            let helmet = world.spawn((
+                Name::new("iron helmet"),
+                EntityUuid::new(),
+                Containable::new(2.0),
+                Equipable::new(vec![EquipSlot::Head]),
+                here(room),
+            ));
+            (player, helmet)
+        };
+
+        get_command(context.clone(), player, "get".into(), args("iron")).await;
+
+        // A helmet cannot be held
+        let result = wield_command(context.clone(), player, "wield".into(), args("iron")).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        let result = wear_command(context.clone(), player, "wear".into(), args("iron")).await;
+        assert!(
+            matches!(result, CommandResult::Success(ref msg) if msg == "You wear iron helmet on your head.")
+        );
+
+        let result = equipment_command(context.clone(), player, "equipment".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Success(ref msg) if msg.contains("<head>")));
+
+        // Worn items are neither listed in nor dropped from the inventory
+        let result = drop_command(context.clone(), player, "drop".into(), args("iron")).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        let result = remove_command(context.clone(), player, "remove".into(), args("iron")).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+
+        let world = context.entities().read().await;
+        let inventory = InventorySystem::new(context.event_bus().clone());
+        assert!(inventory.has_item(&world, player, helmet));
+        assert!(inventory.equipped_slot(&world, player, helmet).is_none());
+    }
 }
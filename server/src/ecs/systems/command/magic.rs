@@ -0,0 +1,526 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Magic commands: cast and spells
+
+use super::inventory::{name_of, room_entities};
+use crate::ecs::components::{
+    Combatant, EntityUuid, Location, Name, Posture, Spell, SpellRegistry, SpellTarget, Spellbook,
+};
+use crate::ecs::context::WorldContext;
+use crate::ecs::events::GameEvent;
+use crate::ecs::output::{capitalize, players_in_room};
+use crate::ecs::registry::EntityRegistry;
+use crate::ecs::systems::{
+    AppliedEffect, CastOutcome, CombatSystem, CommandResult, ProgressionSystem, SkillCheckResolver,
+    THREAT_PER_DAMAGE, add_threat, cast_spell, vitals_of,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use rand::Rng;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Find the spell named by the longest leading run of arguments
+///
+/// `["mending", "ward", "bob"]` gives Mending Ward with `"bob"` left over.
+fn parse_spell(registry: &SpellRegistry, args: &[String]) -> Option<(Spell, String)> {
+    (1..=args.len()).rev().find_map(|count| {
+        registry
+            .get(&args[..count].join(" "))
+            .map(|spell| (spell.clone(), args[count..].join(" ")))
+    })
+}
+
+/// Pick the target of a spell, defaulting by the kind of spell
+fn resolve_target(
+    world: &GameWorld,
+    registry: &EntityRegistry,
+    caster: EcsEntity,
+    spell: &Spell,
+    keyword: &str,
+) -> Result<EcsEntity, String> {
+    let named = || {
+        if matches!(keyword, "self" | "me") {
+            return Some(caster);
+        }
+        room_entities(world, caster).into_iter().find(|other| {
+            world
+                .get::<&Name>(*other)
+                .is_ok_and(|name| name.matches(keyword))
+        })
+    };
+    let target = match (spell.target, keyword.is_empty()) {
+        (SpellTarget::Caster, true) => Some(caster),
+        (SpellTarget::Caster, false) => {
+            return Err(format!("You can only cast {} on yourself.", spell.name));
+        }
+        (SpellTarget::Ally, true) => Some(caster),
+        (SpellTarget::Ally, false) => named(),
+        (SpellTarget::Enemy, true) => {
+            let target = world
+                .get::<&Combatant>(caster)
+                .ok()
+                .filter(|combatant| combatant.in_combat)
+                .and_then(|combatant| combatant.target_id)
+                .and_then(|target| registry.get_entity(target.uuid()));
+            return target.ok_or_else(|| format!("Cast {} at whom?", spell.name));
+        }
+        (SpellTarget::Enemy, false) => named(),
+    };
+    let target = target.ok_or_else(|| format!("You don't see '{}' here.", keyword))?;
+    if spell.is_hostile() && target == caster {
+        return Err(format!("You can't cast {} on yourself.", spell.name));
+    }
+    Ok(target)
+}
+
+/// Messages for the caster, the target and everyone else in the room
+fn cast_messages(
+    spell: &Spell,
+    outcome: &CastOutcome,
+    caster: &str,
+    target: Option<&str>,
+) -> (String, Option<String>, String) {
+    let actor = capitalize(caster);
+    let critical = if outcome.check.outcome.is_critical() {
+        " *CRITICAL*"
+    } else {
+        ""
+    };
+    let Some(effect) = outcome.effect else {
+        return (
+            format!("Your {} fizzles.", spell.name),
+            None,
+            format!("{}'s {} fizzles.", actor, spell.name),
+        );
+    };
+    match (effect, target) {
+        (
+            AppliedEffect::Damage {
+                amount,
+                damage_type,
+            },
+            Some(target),
+        ) => (
+            format!(
+                "Your {} hits {} for {} {} damage!{}",
+                spell.name,
+                target,
+                amount,
+                damage_type.as_str().to_lowercase(),
+                critical
+            ),
+            Some(format!(
+                "{}'s {} hits you for {} {} damage!",
+                actor,
+                spell.name,
+                amount,
+                damage_type.as_str().to_lowercase()
+            )),
+            format!("{}'s {} hits {}!", actor, spell.name, target),
+        ),
+        (AppliedEffect::Heal { amount }, Some(target)) => (
+            format!(
+                "Your {} heals {} for {}.{}",
+                spell.name, target, amount, critical
+            ),
+            Some(format!(
+                "{}'s {} heals you for {}.",
+                actor, spell.name, amount
+            )),
+            format!("{}'s {} heals {}.", actor, spell.name, target),
+        ),
+        (AppliedEffect::Heal { amount }, None) => (
+            format!("Your {} heals you for {}.{}", spell.name, amount, critical),
+            None,
+            format!("{} casts {}.", actor, spell.name),
+        ),
+        (AppliedEffect::Status { effect_type, .. }, Some(target)) => (
+            format!(
+                "Your {} leaves {} {}.{}",
+                spell.name,
+                target,
+                effect_type.as_str().to_lowercase(),
+                critical
+            ),
+            Some(format!(
+                "{}'s {} leaves you {}.",
+                actor,
+                spell.name,
+                effect_type.as_str().to_lowercase()
+            )),
+            format!("{} casts {} on {}.", actor, spell.name, target),
+        ),
+        (AppliedEffect::Status { effect_type, .. }, None) => (
+            format!(
+                "Your {} leaves you {}.{}",
+                spell.name,
+                effect_type.as_str().to_lowercase(),
+                critical
+            ),
+            None,
+            format!("{} casts {}.", actor, spell.name),
+        ),
+        (AppliedEffect::Cure { cured: true, .. }, Some(target)) => (
+            format!("Your {} cures {}.{}", spell.name, target, critical),
+            Some(format!("{}'s {} cures you.", actor, spell.name)),
+            format!("{}'s {} cures {}.", actor, spell.name, target),
+        ),
+        (AppliedEffect::Cure { cured: true, .. }, None) => (
+            format!("Your {} cures you.{}", spell.name, critical),
+            None,
+            format!("{} casts {}.", actor, spell.name),
+        ),
+        (AppliedEffect::Cure { cured: false, .. }, Some(target)) => (
+            format!("Your {} finds nothing to cure in {}.", spell.name, target),
+            None,
+            format!("{} casts {} on {}.", actor, spell.name, target),
+        ),
+        (AppliedEffect::Cure { cured: false, .. }, None) => (
+            format!("Your {} finds nothing to cure.", spell.name),
+            None,
+            format!("{} casts {}.", actor, spell.name),
+        ),
+        // Hostile spells never target the caster
+        (AppliedEffect::Damage { .. }, None) => (
+            format!("You cast {}.", spell.name),
+            None,
+            format!("{} casts {}.", actor, spell.name),
+        ),
+    }
+}
+
+/// Cast a spell
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn cast_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    args: Vec<String>,
+) -> CommandResult {
+    cast_with(context, entity, args, &mut SkillCheckResolver::new()).await
+}
+
+/// Cast a spell, rolling its check with the given resolver
+async fn cast_with<R: Rng + Send>(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    args: Vec<String>,
+    resolver: &mut SkillCheckResolver<R>,
+) -> CommandResult {
+    if args.is_empty() {
+        return CommandResult::Invalid("Usage: cast <spell> [target]".to_string());
+    }
+    let Some((spell, keyword)) = parse_spell(&*context.spells().read().await, &args) else {
+        return CommandResult::Failure(format!(
+            "You don't know a spell called '{}'.",
+            args.join(" ")
+        ));
+    };
+    let keyword = keyword.to_lowercase();
+
+    let mut world = context.entities().write().await;
+    let registry = context.registry().read().await;
+    if !world
+        .get::<&Spellbook>(entity)
+        .is_ok_and(|spellbook| spellbook.knows(&spell.name))
+    {
+        return CommandResult::Failure(format!("You don't know how to cast {}.", spell.name));
+    }
+    if let Ok(Posture::Sleeping) = world.get::<&Posture>(entity).map(|posture| *posture) {
+        return CommandResult::Failure("You can't do that while asleep.".to_string());
+    }
+    let target = match resolve_target(&world, &registry, entity, &spell, &keyword) {
+        Ok(target) => target,
+        Err(message) => return CommandResult::Failure(message),
+    };
+
+    let event_bus = context.event_bus().clone();
+    let mut combat = CombatSystem::new(event_bus.clone());
+    let outcome = match cast_spell(&mut world, &mut combat, resolver, entity, target, &spell) {
+        Ok(outcome) => outcome,
+        Err(message) => return CommandResult::Failure(message),
+    };
+
+    // Every cast is practice, even one that fizzles
+    let progression = ProgressionSystem::new(context.event_bus().clone());
+    progression.record_use(
+        &world,
+        entity,
+        spell.school,
+        outcome.check.challenge_level(),
+        outcome.check.outcome.is_success(),
+    );
+
+    if spell.is_hostile() {
+        if outcome.target_died {
+            event_bus.publish(GameEvent::EntityDied {
+                entity: target,
+                killer: Some(entity),
+            });
+            combat.end_combat(&mut world, target);
+        } else if let Err(e) =
+            combat.start_combat_with_registry(&mut world, &registry, entity, target)
+        {
+            tracing::debug!("Spell did not start combat: {}", e);
+        } else if let Some(AppliedEffect::Damage { amount, .. }) = outcome.effect {
+            if let Some(caster_id) = registry.get_entity_id(entity) {
+                add_threat(
+                    &mut world,
+                    target,
+                    caster_id,
+                    amount as f32 * THREAT_PER_DAMAGE,
+                );
+            }
+        }
+    } else if let Some(AppliedEffect::Heal { amount }) = outcome.effect {
+        // Whoever is fighting the patient turns on the healer
+        combat.add_healing_threat(&mut world, entity, target, amount);
+    }
+    event_bus.publish(GameEvent::VitalsChanged {
+        entity,
+        vitals: vitals_of(&world, entity),
+    });
+    if target != entity && outcome.effect.is_some() {
+        event_bus.publish(GameEvent::VitalsChanged {
+            entity: target,
+            vitals: vitals_of(&world, target),
+        });
+    }
+
+    let target_name = (target != entity).then(|| name_of(&world, target));
+    let (message, target_message, room_message) = cast_messages(
+        &spell,
+        &outcome,
+        &name_of(&world, entity),
+        target_name.as_deref(),
+    );
+    let observers: Vec<EcsEntity> = world
+        .get::<&Location>(entity)
+        .map(|location| players_in_room(&world, location.room_id.uuid()))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|other| *other != entity && *other != target)
+        .collect();
+    let dirty: Vec<Uuid> = [entity, target]
+        .iter()
+        .filter_map(|e| world.get::<&EntityUuid>(*e).ok().map(|uuid| uuid.0))
+        .collect();
+    drop(registry);
+    drop(world);
+
+    for uuid in dirty {
+        context.mark_dirty(uuid).await;
+    }
+    if let Some(target_message) = target_message {
+        context.send_to_entities(&[target], &target_message).await;
+    }
+    context.send_to_entities(&observers, &room_message).await;
+
+    CommandResult::Success(message)
+}
+
+/// List the spells an entity knows
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn spells_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    let world = context.entities().read().await;
+    let registry = context.spells().read().await;
+    let mut spells: Vec<&Spell> = world
+        .get::<&Spellbook>(entity)
+        .map(|spellbook| {
+            spellbook
+                .iter()
+                .filter_map(|name| registry.get(name))
+                .collect()
+        })
+        .unwrap_or_default();
+    if spells.is_empty() {
+        return CommandResult::Success("You don't know any spells.".to_string());
+    }
+
+    spells.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut output = String::from("Spells:\r\n");
+    for spell in spells {
+        output.push_str(&format!(
+            "  {:<20} {:<14} {:>4.0} energy  {}\r\n",
+            spell.name,
+            spell.school.name(),
+            spell.energy_cost,
+            spell.description
+        ));
+    }
+    CommandResult::Success(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{
+        AttributeScores, DamageType, EntityId, Skill, SoulAttributeScores, SpellEffect,
+    };
+    use crate::persistence::PersistenceManager;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    fn spell(name: &str, target: SpellTarget, effect: SpellEffect) -> Spell {
+        Spell {
+            name: name.to_string(),
+            school: Skill::Evocation,
+            description: String::new(),
+            energy_cost: 10.0,
+            cast_time: 1.0,
+            // Only a natural 1 fails
+            difficulty: -100,
+            target,
+            effect,
+        }
+    }
+
+    /// A caster who knows Fire Bolt and Mending Ward, and a goblin to cast at
+    async fn spawn_scene(context: &WorldContext) -> (EcsEntity, EcsEntity) {
+        {
+            let mut spells = context.spells().write().await;
+            spells
+                .register(spell(
+                    "Fire Bolt",
+                    SpellTarget::Enemy,
+                    SpellEffect::Damage {
+                        damage_type: DamageType::Fire,
+                        min: 5,
+                        max: 5,
+                    },
+                ))
+                .unwrap();
+            spells
+                .register(spell(
+                    "Mending Ward",
+                    SpellTarget::Ally,
+                    SpellEffect::Heal { min: 5, max: 5 },
+                ))
+                .unwrap();
+        }
+
+        let mut world = context.entities().write().await;
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        let mut spellbook = Spellbook::new();
+        spellbook.learn("Fire Bolt");
+        let caster = world.spawn((
+            Name::new("Alice"),
+            EntityUuid::new(),
+            Location::new(area, room),
+            SoulAttributeScores::new(),
+            spellbook,
+        ));
+        let goblin = world.spawn((
+            Name::new("goblin"),
+            EntityUuid::new(),
+            Location::new(area, room),
+            AttributeScores::new(),
+        ));
+        (caster, goblin)
+    }
+
+    #[test]
+    fn test_parse_spell_takes_longest_name() {
+        let mut registry = SpellRegistry::new();
+        registry
+            .register(spell(
+                "Mending",
+                SpellTarget::Ally,
+                SpellEffect::Heal { min: 1, max: 1 },
+            ))
+            .unwrap();
+        registry
+            .register(spell(
+                "Mending Ward",
+                SpellTarget::Ally,
+                SpellEffect::Heal { min: 1, max: 1 },
+            ))
+            .unwrap();
+
+        let (spell, rest) = parse_spell(&registry, &args("mending ward bob")).unwrap();
+        assert_eq!(spell.name, "Mending Ward");
+        assert_eq!(rest, "bob");
+        let (spell, rest) = parse_spell(&registry, &args("mending bob")).unwrap();
+        assert_eq!(spell.name, "Mending");
+        assert_eq!(rest, "bob");
+        assert!(parse_spell(&registry, &args("fireball")).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cast_spends_energy_on_target() {
+        let context = setup();
+        let (caster, goblin) = spawn_scene(&context).await;
+
+        // Seed 0 rolls a 17, a critical success that strengthens the bolt
+        let mut resolver = SkillCheckResolver::seeded(0);
+        let result = cast_with(
+            context.clone(),
+            caster,
+            args("fire bolt goblin"),
+            &mut resolver,
+        )
+        .await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        let world = context.entities().read().await;
+        assert_eq!(
+            world
+                .get::<&SoulAttributeScores>(caster)
+                .unwrap()
+                .0
+                .energy_current,
+            90.0
+        );
+        let health = world
+            .get::<&AttributeScores>(goblin)
+            .unwrap()
+            .health_current;
+        assert_eq!(health, 92.0);
+    }
+
+    #[tokio::test]
+    async fn test_cast_requires_known_spell_and_target() {
+        let context = setup();
+        let (caster, _goblin) = spawn_scene(&context).await;
+
+        let result =
+            cast_command(context.clone(), caster, "cast".into(), args("mending ward")).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("know how")));
+
+        let result = cast_command(context.clone(), caster, "cast".into(), args("fireball")).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        // Not fighting anyone, so there is no default target
+        let result = cast_command(context.clone(), caster, "cast".into(), args("fire bolt")).await;
+        assert!(matches!(result, CommandResult::Failure(ref msg) if msg.contains("whom")));
+
+        let result = spells_command(context.clone(), caster, "spells".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Success(ref msg) if msg.contains("Fire Bolt")));
+    }
+}
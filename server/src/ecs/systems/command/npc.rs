@@ -19,9 +19,10 @@
 use crate::ecs::EcsEntity;
 use crate::ecs::components::*;
 use crate::ecs::context::WorldContext;
-use crate::ecs::systems::CommandResult;
+use crate::ecs::systems::{ActionLibrary, CommandResult};
 use hecs::Entity;
 use std::sync::Arc;
+use std::time::Instant;
 // ============================================================================
 // NPC Commands
 // ============================================================================
@@ -213,7 +214,7 @@ pub async fn npc_edit_command(
     if args.len() < 3 {
         return CommandResult::Failure(
             "Usage: npc edit <uuid> <property> <value>\r\n\
-             Properties: name, description, behavior, active\r\n"
+             Properties: name, description, behavior, active, follow, schedule\r\n"
                 .to_string(),
         );
     }
@@ -234,6 +235,23 @@ pub async fn npc_edit_command(
         None => return CommandResult::Failure("NPC not found".to_string()),
     };
 
+    if property == "schedule" {
+        if args.len() < 4 {
+            return CommandResult::Failure(
+                "Usage: npc edit <uuid> schedule <hour> <room-uuid|none>\r\n".to_string(),
+            );
+        }
+        let room = if args[3].eq_ignore_ascii_case("none") {
+            None
+        } else {
+            match uuid::Uuid::parse_str(&args[3]) {
+                Ok(u) => Some(EntityId::from_uuid(u)),
+                Err(_) => return CommandResult::Failure("Invalid room UUID\r\n".to_string()),
+            }
+        };
+        return edit_schedule(&context, npc_entity, &args[2], |block| block.room = room).await;
+    }
+
     let world = context.entities().read().await;
 
     // Check if entity is an NPC
@@ -290,10 +308,90 @@ pub async fn npc_edit_command(
                 CommandResult::Failure("Failed to update active status".to_string())
             }
         }
+        "follow" => {
+            let leader = if value.eq_ignore_ascii_case("none") {
+                None
+            } else {
+                let leader_uuid = match uuid::Uuid::parse_str(&value) {
+                    Ok(u) => u,
+                    Err(_) => return CommandResult::Failure("Invalid UUID format".to_string()),
+                };
+                match context.get_entity_by_uuid(leader_uuid).await {
+                    Some(e) => Some(EntityId::new(e, leader_uuid)),
+                    None => return CommandResult::Failure("Leader not found".to_string()),
+                }
+            };
+
+            if let Ok(mut ai) = world.get::<&mut AIController>(npc_entity) {
+                ai.state_type = if leader.is_some() {
+                    StateType::Following
+                } else {
+                    StateType::Idle
+                };
+                ai.state_target_id = leader;
+                context.mark_entity_dirty(npc_entity).await;
+                match leader {
+                    Some(leader) => {
+                        CommandResult::Success(format!("NPC now following: {}\r\n", leader.uuid()))
+                    }
+                    None => CommandResult::Success("NPC stopped following\r\n".to_string()),
+                }
+            } else {
+                CommandResult::Failure("Failed to update follow target".to_string())
+            }
+        }
         _ => CommandResult::Failure(format!("Unknown property: {}\r\n", property)),
     }
 }
 
+/// Change the schedule block starting at an hour, adding a schedule if needed
+async fn edit_schedule(
+    context: &WorldContext,
+    npc_entity: EcsEntity,
+    hour: &str,
+    edit: impl FnOnce(&mut ScheduleBlock),
+) -> CommandResult {
+    let hour = match hour.parse::<u32>() {
+        Ok(h) if h < HOURS_PER_DAY => h,
+        _ => {
+            return CommandResult::Failure(format!(
+                "Invalid hour, expected 0-{}\r\n",
+                HOURS_PER_DAY - 1
+            ));
+        }
+    };
+
+    let mut world = context.entities().write().await;
+    if world.get::<&Npc>(npc_entity).is_err() {
+        return CommandResult::Failure("Entity is not an NPC".to_string());
+    }
+    if world.get::<&NpcSchedule>(npc_entity).is_err() {
+        if let Err(e) = world.insert_one(npc_entity, NpcSchedule::new()) {
+            return CommandResult::Failure(format!("Failed to add schedule: {}\r\n", e));
+        }
+    }
+    let mut schedule = match world.get::<&mut NpcSchedule>(npc_entity) {
+        Ok(schedule) => schedule,
+        Err(_) => return CommandResult::Failure("Failed to update schedule".to_string()),
+    };
+    let block = schedule.block_mut(hour);
+    edit(block);
+    let summary = format!(
+        "Schedule from {:02}:00: goal {}, room {}\r\n",
+        hour,
+        block.goal.as_deref().unwrap_or("none"),
+        block
+            .room
+            .map_or("none".to_string(), |room| room.uuid().to_string())
+    );
+    schedule.prune();
+    drop(schedule);
+    drop(world);
+
+    context.mark_entity_dirty(npc_entity).await;
+    CommandResult::Success(summary)
+}
+
 /// Configure NPC dialogue
 #[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
 pub async fn npc_dialogue_command(
@@ -393,10 +491,14 @@ pub async fn npc_goap_command(
         return CommandResult::Failure(
             "Usage: npc goap <uuid> <subcommand> [args...]\r\n\
              Subcommands:\r\n\
-             - addgoal <name> <priority> - Add a goal\r\n\
+             - addgoal <name> <priority> [key=value...] - Add a goal\r\n\
              - addaction <name> <cost> - Add an action\r\n\
              - setstate <key> <value> - Set world state\r\n\
-             - show - Show current GOAP configuration\r\n"
+             - guard <room-uuid|none> - Set the guard post\r\n\
+             - patrol <room-uuid...|none> - Set the patrol route\r\n\
+             - schedule <hour> <goal|none> - Pursue a goal from an in-game hour\r\n\
+             - show - Show current GOAP configuration\r\n\
+             - explain - Show the goal being pursued and why\r\n"
                 .to_string(),
         );
     }
@@ -417,6 +519,30 @@ pub async fn npc_goap_command(
     }
 
     let subcommand = &args[1].to_lowercase();
+
+    if subcommand == "schedule" {
+        if args.len() < 4 {
+            return CommandResult::Failure(
+                "Usage: npc goap <uuid> schedule <hour> <goal|none>\r\n".to_string(),
+            );
+        }
+        let goal = if args[3].eq_ignore_ascii_case("none") {
+            None
+        } else {
+            let known = context
+                .entities()
+                .read()
+                .await
+                .get::<&GoapPlanner>(npc_entity)
+                .is_ok_and(|planner| planner.goals.iter().any(|goal| goal.id == args[3]));
+            if !known {
+                return CommandResult::Failure(format!("Unknown goal: {}\r\n", args[3]));
+            }
+            Some(args[3].clone())
+        };
+        return edit_schedule(&context, npc_entity, &args[2], |block| block.goal = goal).await;
+    }
+
     let world = context.entities().read().await;
 
     let mut planner = match world.get::<&mut GoapPlanner>(npc_entity) {
@@ -428,7 +554,8 @@ pub async fn npc_goap_command(
         "addgoal" => {
             if args.len() < 4 {
                 return CommandResult::Failure(
-                    "Usage: npc goap <uuid> addgoal <name> <priority>\r\n".to_string(),
+                    "Usage: npc goap <uuid> addgoal <name> <priority> [key=value...]\r\n"
+                        .to_string(),
                 );
             }
             let name = &args[2];
@@ -437,7 +564,20 @@ pub async fn npc_goap_command(
                 Err(_) => return CommandResult::Failure("Invalid priority value\r\n".to_string()),
             };
 
-            let goal = GoapGoal::new(name, name, priority);
+            let mut goal = GoapGoal::new(name, name, priority);
+            for condition in &args[4..] {
+                match condition.split_once('=') {
+                    Some((key, value)) => {
+                        goal = goal.with_condition(key, value == "true" || value == "1");
+                    }
+                    None => {
+                        return CommandResult::Failure(format!(
+                            "Invalid condition '{}', expected key=value\r\n",
+                            condition
+                        ));
+                    }
+                }
+            }
             planner.add_goal(goal);
             context.mark_entity_dirty(npc_entity).await;
             CommandResult::Success(format!(
@@ -457,7 +597,12 @@ pub async fn npc_goap_command(
                 Err(_) => return CommandResult::Failure("Invalid cost value\r\n".to_string()),
             };
 
-            let action = GoapAction::new(name, name).with_cost(cost);
+            // Built-in actions bring their preconditions and effects along
+            let action = ActionLibrary::new()
+                .get(name)
+                .map(|handler| handler.definition())
+                .unwrap_or_else(|| GoapAction::new(name, name))
+                .with_cost(cost);
             planner.add_action(action);
             context.mark_entity_dirty(npc_entity).await;
             CommandResult::Success(format!("Action '{}' added with cost {}\r\n", name, cost))
@@ -475,6 +620,54 @@ pub async fn npc_goap_command(
             context.mark_entity_dirty(npc_entity).await;
             CommandResult::Success(format!("World state '{}' set to {}\r\n", key, value))
         }
+        "guard" => {
+            if args.len() < 3 {
+                return CommandResult::Failure(
+                    "Usage: npc goap <uuid> guard <room-uuid|none>\r\n".to_string(),
+                );
+            }
+            if args[2].eq_ignore_ascii_case("none") {
+                planner.guard_post = None;
+                planner.replan();
+                context.mark_entity_dirty(npc_entity).await;
+                return CommandResult::Success("Guard post cleared\r\n".to_string());
+            }
+            let post = match uuid::Uuid::parse_str(&args[2]) {
+                Ok(u) => u,
+                Err(_) => return CommandResult::Failure("Invalid room UUID\r\n".to_string()),
+            };
+            planner.guard_post = Some(EntityId::from_uuid(post));
+            planner.replan();
+            context.mark_entity_dirty(npc_entity).await;
+            CommandResult::Success(format!("Guard post set to {}\r\n", post))
+        }
+        "patrol" => {
+            if args.len() < 3 {
+                return CommandResult::Failure(
+                    "Usage: npc goap <uuid> patrol <room-uuid...|none>\r\n".to_string(),
+                );
+            }
+            let route = if args[2].eq_ignore_ascii_case("none") {
+                Vec::new()
+            } else {
+                match args[2..]
+                    .iter()
+                    .map(|room| uuid::Uuid::parse_str(room).map(EntityId::from_uuid))
+                    .collect::<Result<Vec<_>, _>>()
+                {
+                    Ok(route) => route,
+                    Err(_) => {
+                        return CommandResult::Failure("Invalid room UUID\r\n".to_string());
+                    }
+                }
+            };
+            let rooms = route.len();
+            planner.patrol_route = route;
+            planner.patrol_index = 0;
+            planner.replan();
+            context.mark_entity_dirty(npc_entity).await;
+            CommandResult::Success(format!("Patrol route set to {} rooms\r\n", rooms))
+        }
         "show" => {
             let mut output = format!("\r\nGOAP Configuration:\r\n{}\r\n\r\n", "=".repeat(80));
             output.push_str(&format!("Goals: {}\r\n", planner.goals.len()));
@@ -488,6 +681,170 @@ pub async fn npc_goap_command(
             for action in &planner.actions {
                 output.push_str(&format!("  - {} (cost: {})\r\n", action.name, action.cost));
             }
+            output.push_str(&format!(
+                "\r\nCurrent Action: {}\r\n",
+                planner.current_action.as_deref().unwrap_or("none")
+            ));
+            if let Some(post) = planner.guard_post {
+                output.push_str(&format!("Guard Post: {}\r\n", post.uuid()));
+            }
+            if !planner.patrol_route.is_empty() {
+                let route: Vec<String> = planner
+                    .patrol_route
+                    .iter()
+                    .map(|room| room.uuid().to_string())
+                    .collect();
+                output.push_str(&format!("Patrol Route: {}\r\n", route.join(" -> ")));
+            }
+            if let Some(destination) = planner.destination {
+                output.push_str(&format!("Destination: {}\r\n", destination.uuid()));
+            }
+            if let Ok(schedule) = world.get::<&NpcSchedule>(npc_entity) {
+                output.push_str("\r\nSchedule:\r\n");
+                for block in &schedule.blocks {
+                    output.push_str(&format!(
+                        "  {:02}:00 goal: {}, room: {}\r\n",
+                        block.start_hour,
+                        block.goal.as_deref().unwrap_or("none"),
+                        block
+                            .room
+                            .map_or("none".to_string(), |room| room.uuid().to_string())
+                    ));
+                }
+            }
+            output.push_str(&format!("\r\n{}\r\n", "=".repeat(80)));
+            CommandResult::Success(output)
+        }
+        "explain" => {
+            let mut output = format!("\r\nGOAP Plan Explanation:\r\n{}\r\n\r\n", "=".repeat(80));
+            let pursued = planner.current_goal.clone();
+            let pursued_name = pursued.as_deref().map(|id| {
+                planner
+                    .get_goal(id)
+                    .map_or(id.to_string(), |goal| goal.name.clone())
+            });
+            output.push_str(&format!(
+                "Current Goal: {}\r\nCurrent Action: {}\r\nBudget: {} nodes, {}ms\r\n",
+                pursued_name.as_deref().unwrap_or("none"),
+                planner.current_action.as_deref().unwrap_or("none"),
+                planner.limits.max_nodes,
+                planner.limits.max_time.as_millis()
+            ));
+            if !planner.current_plan.is_empty() {
+                let steps: Vec<&str> = planner.current_plan.iter().map(String::as_str).collect();
+                output.push_str(&format!("Remaining Plan: {}\r\n", steps.join(" -> ")));
+            }
+
+            // What the last update actually found
+            match &planner.last_report {
+                Some(report) => {
+                    output.push_str(&format!(
+                        "\r\nLast Search: {} - {}, {} nodes in {:.2}ms{}\r\n",
+                        report.goal,
+                        report.outcome.as_str().to_lowercase(),
+                        report.nodes_expanded,
+                        report.elapsed.as_secs_f64() * 1000.0,
+                        if report.cached { " (cached)" } else { "" }
+                    ));
+                    if report.outcome == SearchOutcome::Found {
+                        output.push_str(&format!("  Plan costing {:.1}:\r\n", report.cost));
+                        for (step, action_id) in report.plan.iter().enumerate() {
+                            let cost = planner
+                                .get_action(action_id)
+                                .map_or(0.0, |action| action.cost);
+                            output.push_str(&format!(
+                                "    {}. {} (cost: {})\r\n",
+                                step + 1,
+                                action_id,
+                                cost
+                            ));
+                        }
+                    }
+                    if !report.rejected.is_empty() {
+                        output.push_str("  Rejected actions:\r\n");
+                        for (action_id, reason) in &report.rejected {
+                            output.push_str(&format!("    {}: {}\r\n", action_id, reason));
+                        }
+                    }
+                }
+                None => output.push_str("\r\nLast Search: none yet\r\n"),
+            }
+
+            // Goals in the order update() tries them, sharing one budget
+            output.push_str("\r\nGoals:\r\n");
+            let mut goals: Vec<&GoapGoal> = planner.goals.iter().collect();
+            goals.sort_by_key(|goal| std::cmp::Reverse(goal.priority));
+            let started = Instant::now();
+            let mut nodes_left = planner.limits.max_nodes;
+            let mut budget_spent = false;
+            let mut passed_for: Option<&str> = None;
+            for goal in goals {
+                let header = format!("{} (priority: {})", goal.name, goal.priority);
+                let is_pursued = pursued.as_deref() == Some(goal.id.as_str());
+                if !goal.active {
+                    output.push_str(&format!("  - {}: inactive\r\n", header));
+                    continue;
+                }
+                if goal.is_satisfied(&planner.world_state) {
+                    let note = if is_pursued { ", pursued" } else { "" };
+                    output.push_str(&format!("  - {}: already satisfied{}\r\n", header, note));
+                    continue;
+                }
+                if is_pursued {
+                    passed_for = Some(&goal.name);
+                    output.push_str(&format!("  - {}: pursued\r\n", header));
+                    continue;
+                }
+                if let Some(chosen) = passed_for {
+                    output.push_str(&format!(
+                        "  - {}: not tried, {} ranks higher\r\n",
+                        header, chosen
+                    ));
+                    continue;
+                }
+                if budget_spent {
+                    output.push_str(&format!("  - {}: not tried, budget spent\r\n", header));
+                    continue;
+                }
+
+                let limits = SearchLimits {
+                    max_nodes: nodes_left,
+                    max_time: planner.limits.max_time.saturating_sub(started.elapsed()),
+                };
+                let report = planner.search(goal, limits);
+                nodes_left = nodes_left.saturating_sub(report.nodes_expanded);
+                let searched = format!(
+                    "{} nodes in {:.2}ms",
+                    report.nodes_expanded,
+                    report.elapsed.as_secs_f64() * 1000.0
+                );
+                match report.outcome {
+                    SearchOutcome::Found => {
+                        output.push_str(&format!(
+                            "  - {}: plan costing {:.1} found after {}, not yet taken up\r\n",
+                            header, report.cost, searched
+                        ));
+                    }
+                    SearchOutcome::Unreachable => {
+                        output.push_str(&format!(
+                            "  - {}: unreachable after {}\r\n",
+                            header, searched
+                        ));
+                    }
+                    SearchOutcome::NodeLimit | SearchOutcome::TimeLimit => {
+                        budget_spent = true;
+                        output.push_str(&format!(
+                            "  - {}: no plan, {} after {}\r\n",
+                            header,
+                            report.outcome.as_str().to_lowercase(),
+                            searched
+                        ));
+                    }
+                }
+            }
+            if planner.goals.is_empty() {
+                output.push_str("No goals defined.\r\n");
+            }
             output.push_str(&format!("\r\n{}\r\n", "=".repeat(80)));
             CommandResult::Success(output)
         }
@@ -0,0 +1,174 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Posture commands: stand, sit, rest and sleep
+
+use super::inventory::name_of;
+use crate::ecs::EcsEntity;
+use crate::ecs::components::{Combatant, Location, Posture};
+use crate::ecs::context::WorldContext;
+use crate::ecs::output::players_in_room;
+use crate::ecs::systems::CommandResult;
+use std::sync::Arc;
+
+/// Move an entity into a new posture and tell the room
+async fn change_posture(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    posture: Posture,
+) -> CommandResult {
+    let (observers, line) = {
+        let mut world = context.entities().write().await;
+        let current = world
+            .get::<&Posture>(entity)
+            .map(|posture| *posture)
+            .unwrap_or_default();
+        if current == posture {
+            return CommandResult::Failure(format!("You are already {}.", posture.label()));
+        }
+        let fighting = world
+            .get::<&Combatant>(entity)
+            .is_ok_and(|combatant| combatant.in_combat);
+        if fighting && posture != Posture::Standing {
+            return CommandResult::Failure("You can't do that while fighting!".to_string());
+        }
+
+        if world.insert_one(entity, posture).is_err() {
+            return CommandResult::Failure("You can't do that.".to_string());
+        }
+
+        let name = name_of(&world, entity);
+        let line = match (current, posture) {
+            (Posture::Sleeping, Posture::Standing) => format!("{} wakes and stands up.", name),
+            (_, Posture::Standing) => format!("{} stands up.", name),
+            (_, Posture::Sitting) => format!("{} sits down.", name),
+            (_, Posture::Resting) => format!("{} sits down and rests.", name),
+            (_, Posture::Sleeping) => format!("{} lies down and goes to sleep.", name),
+        };
+        let observers: Vec<EcsEntity> = match world.get::<&Location>(entity) {
+            Ok(location) => players_in_room(&world, location.room_id.uuid())
+                .into_iter()
+                .filter(|&other| other != entity)
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        (observers, line)
+    };
+    context.send_to_entities(&observers, &line).await;
+
+    CommandResult::Success(match posture {
+        Posture::Standing => "You stand up.".to_string(),
+        Posture::Sitting => "You sit down.".to_string(),
+        Posture::Resting => "You sit down and rest.".to_string(),
+        Posture::Sleeping => "You lie down and go to sleep.".to_string(),
+    })
+}
+
+/// Command to stand up (or wake up)
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn stand_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    change_posture(context, entity, Posture::Standing).await
+}
+
+/// Command to sit down
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn sit_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    change_posture(context, entity, Posture::Sitting).await
+}
+
+/// Command to rest and recover faster
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn rest_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    change_posture(context, entity, Posture::Resting).await
+}
+
+/// Command to go to sleep and recover fastest
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn sleep_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    change_posture(context, entity, Posture::Sleeping).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Avatar, Name};
+    use crate::persistence::PersistenceManager;
+    use uuid::Uuid;
+
+    fn setup() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    #[tokio::test]
+    async fn test_posture_changes() {
+        let context = setup();
+        let player = context
+            .entities()
+            .write()
+            .await
+            .spawn((Name::new("Alice"), Avatar::new(Uuid::new_v4())));
+
+        let result = stand_command(context.clone(), player, "stand".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        let result = rest_command(context.clone(), player, "rest".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+        assert_eq!(
+            *context
+                .entities()
+                .read()
+                .await
+                .get::<&Posture>(player)
+                .unwrap(),
+            Posture::Resting
+        );
+
+        let mut combatant = Combatant::new();
+        combatant.in_combat = true;
+        context
+            .entities()
+            .write()
+            .await
+            .insert_one(player, combatant)
+            .unwrap();
+        let result = sleep_command(context.clone(), player, "sleep".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Failure(_)));
+
+        let result = stand_command(context.clone(), player, "stand".into(), vec![]).await;
+        assert!(matches!(result, CommandResult::Success(_)));
+    }
+}
@@ -15,7 +15,7 @@
 //
 
 use crate::ecs::EcsEntity;
-use crate::ecs::components::{AttributeScores, Name, Skills};
+use crate::ecs::components::{AttributeScores, Name, Skills, skill_rank_name};
 use crate::ecs::context::WorldContext;
 use crate::ecs::systems::CommandResult;
 use std::sync::Arc;
@@ -64,3 +64,34 @@ pub async fn score_command(
     drop(world);
     result
 }
+
+/// Command to list learned skills with their rank and experience
+#[tracing::instrument(skip(context), fields(entity_id = entity.id()))]
+pub async fn skills_command(
+    context: Arc<WorldContext>,
+    entity: EcsEntity,
+    _cmd: String,
+    _args: Vec<String>,
+) -> CommandResult {
+    let world = context.entities().read().await;
+    let Ok(skills) = world.get::<&Skills>(entity) else {
+        return CommandResult::Failure("You have no skills.".to_string());
+    };
+    if skills.is_empty() {
+        return CommandResult::Success("You have not learned any skills yet.".to_string());
+    }
+
+    let mut rows: Vec<_> = skills.iter().collect();
+    rows.sort_by_key(|(skill, _, _, _)| skill.name());
+    let mut output = String::from("Skills:\r\n");
+    for (skill, level, experience, knowledge) in rows {
+        output.push_str(&format!(
+            "  {:<20} {:<11} {:>6} xp {:>6} knowledge\r\n",
+            skill.name(),
+            skill_rank_name(level),
+            experience,
+            knowledge
+        ));
+    }
+    CommandResult::Success(output)
+}
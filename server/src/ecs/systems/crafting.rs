@@ -0,0 +1,432 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Crafting
+//!
+//! Crafting a recipe needs the recipe's skill level, its tools in hand or
+//! pack, its station in the room and its ingredients in the pack. The
+//! ingredients are used up whether or not the skill check succeeds; success
+//! produces the output with a [`Quality`] set by the check's margin, which
+//! scales the damage of weapons and the defenses of armor.
+//!
+//! Talents: Master Craftsman raises the quality of everything crafted by one
+//! step, and Artificer adds [`ARTIFICER_BONUS`] to Jewelcrafting and Alchemy.
+
+use crate::ecs::components::{
+    Armor, Containable, ContainedBy, CraftingStation, Description, EntityId, EntityUuid, Equipable,
+    ItemTemplate, ItemTemplateRegistry, Location, Material, Name, Persistent, Quality, Recipe,
+    Skill, Skills, Talent, Talents, Template, Weapon, skill_rank_name,
+};
+use crate::ecs::systems::{
+    CheckOutcome, CheckResult, InventorySystem, SkillCheck, SkillCheckResolver,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use rand::Rng;
+use uuid::Uuid;
+
+/// Check bonus from the Artificer talent on its skills
+pub const ARTIFICER_BONUS: i32 = 2;
+
+/// Skills the Artificer talent helps with
+pub const ARTIFICER_SKILLS: [Skill; 2] = [Skill::Jewelcrafting, Skill::Alchemy];
+
+/// Result of a crafting attempt
+#[derive(Debug, Clone, PartialEq)]
+pub struct CraftOutcome {
+    pub check: CheckResult,
+    /// Quality of the output, or `None` if the attempt failed
+    pub quality: Option<Quality>,
+    /// UUIDs of the ingredients used up, already despawned
+    pub consumed: Vec<Uuid>,
+    /// Items made, held by the crafter
+    pub produced: Vec<(EcsEntity, Uuid)>,
+}
+
+/// Attempt a recipe
+///
+/// Fails without using anything if the crafter lacks the skill level, a
+/// tool, the station or an ingredient. Items made are new persistent
+/// entities the caller must register.
+pub fn craft<R: Rng>(
+    world: &mut GameWorld,
+    inventory: &InventorySystem,
+    resolver: &mut SkillCheckResolver<R>,
+    templates: &ItemTemplateRegistry,
+    crafter: EcsEntity,
+    recipe: &Recipe,
+) -> Result<CraftOutcome, String> {
+    let ingredients = gather_ingredients(world, inventory, templates, crafter, recipe)?;
+    let output = templates
+        .get(&recipe.output)
+        .ok_or_else(|| format!("Nobody knows how to make {} any more.", recipe.name))?;
+
+    let talents = world.get::<&Talents>(crafter).ok();
+    let has_talent = |talent| talents.as_ref().is_some_and(|t| t.has_talent(talent));
+    let mut check = SkillCheck::for_skill(recipe.skill).against(recipe.difficulty);
+    if has_talent(Talent::Artificer) && ARTIFICER_SKILLS.contains(&recipe.skill) {
+        check = check.with_modifier(Talent::Artificer.name(), ARTIFICER_BONUS);
+    }
+    let master = has_talent(Talent::MasterCraftsman);
+    drop(talents);
+
+    let check = resolver.resolve(world, crafter, &check);
+    let quality = quality_of(check.outcome, check.margin)
+        .map(|quality| if master { quality.improved() } else { quality });
+
+    let mut consumed = Vec::new();
+    for item in ingredients {
+        if let Ok(uuid) = world.get::<&EntityUuid>(item).map(|uuid| uuid.0) {
+            consumed.push(uuid);
+        }
+        let _ = world.despawn(item);
+    }
+
+    let mut produced = Vec::new();
+    if let Some(quality) = quality {
+        let holder = InventorySystem::holder_id(world, crafter);
+        for _ in 0..recipe.output_quantity.max(1) {
+            produced.push(spawn_item(world, output, quality, holder));
+        }
+    }
+
+    Ok(CraftOutcome {
+        check,
+        quality,
+        consumed,
+        produced,
+    })
+}
+
+/// Quality of work from a skill check, or `None` if it failed
+pub fn quality_of(outcome: CheckOutcome, margin: i32) -> Option<Quality> {
+    match outcome {
+        CheckOutcome::CriticalFailure | CheckOutcome::Failure => None,
+        CheckOutcome::CriticalSuccess => Some(Quality::Masterwork),
+        CheckOutcome::Success => Some(match margin {
+            ..2 => Quality::Poor,
+            2..5 => Quality::Common,
+            5..8 => Quality::Fine,
+            _ => Quality::Superior,
+        }),
+    }
+}
+
+/// Create an item from a template, held by the given holder
+///
+/// Quality other than common is added to the name and scales weapon damage
+/// and armor defenses.
+pub fn spawn_item(
+    world: &mut GameWorld,
+    template: &ItemTemplate,
+    quality: Quality,
+    holder: EntityId,
+) -> (EcsEntity, Uuid) {
+    let uuid = Uuid::new_v4();
+    let (display, mut keywords) = match quality.prefix() {
+        Some(prefix) => (
+            format!("{} {}", prefix, template.name),
+            vec![prefix.to_string()],
+        ),
+        None => (template.name.clone(), Vec::new()),
+    };
+    keywords.extend(template.keywords.iter().cloned());
+
+    let item = world.spawn((
+        EntityUuid(uuid),
+        Name::new(display).with_keywords(keywords),
+        Description::new(&template.short, &template.long),
+        Containable::new(template.weight),
+        ContainedBy::new(holder),
+        Template::new(&template.id),
+        Persistent,
+    ));
+    if let Some(material) = template.material {
+        let _ = world.insert_one(item, Material::new(material));
+    }
+    if !template.slots.is_empty() {
+        let _ = world.insert_one(item, Equipable::new(template.slots.clone()));
+    }
+    if let Some(weapon) = &template.weapon {
+        let _ = world.insert_one(item, scaled_weapon(weapon, quality));
+    }
+    if let Some(armor) = &template.armor {
+        let _ = world.insert_one(item, scaled_armor(armor, quality));
+    }
+    (item, uuid)
+}
+
+fn scaled_weapon(weapon: &Weapon, quality: Quality) -> Weapon {
+    let scale = |value: i32| ((value as f32 * quality.factor()).round() as i32).max(1);
+    Weapon {
+        damage_min: scale(weapon.damage_min),
+        damage_max: scale(weapon.damage_max),
+        damage_cap: scale(weapon.damage_cap),
+        ..weapon.clone()
+    }
+}
+
+fn scaled_armor(armor: &Armor, quality: Quality) -> Armor {
+    let mut scaled = armor.clone();
+    for defense in scaled.defenses.values_mut() {
+        *defense = (*defense as f32 * quality.factor()).round() as i32;
+    }
+    scaled
+}
+
+/// Find the ingredients for a recipe, checking every other requirement
+fn gather_ingredients(
+    world: &GameWorld,
+    inventory: &InventorySystem,
+    templates: &ItemTemplateRegistry,
+    crafter: EcsEntity,
+    recipe: &Recipe,
+) -> Result<Vec<EcsEntity>, String> {
+    let item_name = |id: &str| {
+        templates
+            .get(id)
+            .map(|template| template.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    let level = world
+        .get::<&Skills>(crafter)
+        .map(|skills| skills.level(recipe.skill))
+        .unwrap_or(0);
+    if level < recipe.min_level {
+        return Err(format!(
+            "You must be at least {} in {} to craft {}.",
+            skill_rank_name(recipe.min_level),
+            recipe.skill.name(),
+            recipe.name
+        ));
+    }
+
+    if let Some(station) = &recipe.station {
+        if !station_in_room(world, crafter, station) {
+            return Err(format!("You need a {} to craft {}.", station, recipe.name));
+        }
+    }
+
+    let carried: Vec<(EcsEntity, String)> = inventory
+        .get_items_in_container(world, crafter)
+        .into_iter()
+        .filter_map(|item| {
+            world
+                .get::<&Template>(item)
+                .ok()
+                .map(|template| (item, template.template_id.clone()))
+        })
+        .collect();
+
+    // Tools may be in hand, ingredients must not be
+    if let Some(tool) = recipe
+        .tools
+        .iter()
+        .find(|tool| !carried.iter().any(|(_, id)| id == *tool))
+    {
+        return Err(format!(
+            "You need a {} to craft {}.",
+            item_name(tool),
+            recipe.name
+        ));
+    }
+
+    let mut ingredients = Vec::new();
+    for ingredient in &recipe.ingredients {
+        let found: Vec<EcsEntity> = carried
+            .iter()
+            .filter(|(item, id)| {
+                *id == ingredient.template_id
+                    && inventory.equipped_slot(world, crafter, *item).is_none()
+            })
+            .map(|(item, _)| *item)
+            .take(ingredient.quantity as usize)
+            .collect();
+        if found.len() < ingredient.quantity as usize {
+            return Err(format!(
+                "You need {} x {} to craft {}.",
+                ingredient.quantity,
+                item_name(&ingredient.template_id),
+                recipe.name
+            ));
+        }
+        ingredients.extend(found);
+    }
+    Ok(ingredients)
+}
+
+/// Check whether a station of the given kind is in the entity's room
+pub fn station_in_room(world: &GameWorld, entity: EcsEntity, station: &str) -> bool {
+    let Ok(room) = world.get::<&Location>(entity).map(|loc| loc.room_id.uuid()) else {
+        return false;
+    };
+    world
+        .query::<(Entity, &Location, &CraftingStation)>()
+        .iter()
+        .any(|(_, location, fixture)| {
+            location.room_id.uuid() == room && fixture.station.eq_ignore_ascii_case(station)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Container, DamageType, EquipSlot, Ingredient, MaterialKind};
+    use crate::ecs::events::EventBus;
+
+    fn templates() -> ItemTemplateRegistry {
+        let mut templates = ItemTemplateRegistry::new();
+        templates.register(ItemTemplate::new("iron_ingot", "iron ingot", 1.0));
+        templates.register(ItemTemplate::new("smithing_hammer", "smithing hammer", 2.0));
+        let mut dagger = ItemTemplate::new("iron_dagger", "iron dagger", 1.0);
+        dagger.material = Some(MaterialKind::Iron);
+        dagger.slots = vec![EquipSlot::MainHand];
+        dagger.weapon = Some(Weapon::new(2, 4, DamageType::Piercing));
+        templates.register(dagger);
+        templates
+    }
+
+    fn dagger_recipe(difficulty: i32) -> Recipe {
+        Recipe {
+            name: "Iron Dagger".to_string(),
+            skill: Skill::Weaponsmithing,
+            min_level: 0,
+            difficulty,
+            ingredients: vec![Ingredient::new("iron_ingot", 2)],
+            tools: vec!["smithing_hammer".to_string()],
+            station: Some("forge".to_string()),
+            output: "iron_dagger".to_string(),
+            output_quantity: 1,
+        }
+    }
+
+    /// A smith in a room with a forge, carrying the given templates
+    fn smithy(world: &mut GameWorld, carried: &[&str]) -> EcsEntity {
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        world.spawn((CraftingStation::new("forge"), Location::new(area, room)));
+        let smith = world.spawn((
+            EntityUuid::new(),
+            Location::new(area, room),
+            Container::new(None),
+            Skills::new(),
+        ));
+        let holder = InventorySystem::holder_id(world, smith);
+        for id in carried {
+            world.spawn((
+                EntityUuid::new(),
+                Template::new(*id),
+                ContainedBy::new(holder),
+            ));
+        }
+        smith
+    }
+
+    #[test]
+    fn test_quality_of() {
+        assert_eq!(quality_of(CheckOutcome::Failure, -1), None);
+        assert_eq!(quality_of(CheckOutcome::Success, 0), Some(Quality::Poor));
+        assert_eq!(quality_of(CheckOutcome::Success, 3), Some(Quality::Common));
+        assert_eq!(
+            quality_of(CheckOutcome::Success, 9),
+            Some(Quality::Superior)
+        );
+        assert_eq!(
+            quality_of(CheckOutcome::CriticalSuccess, 10),
+            Some(Quality::Masterwork)
+        );
+    }
+
+    #[test]
+    fn test_craft_requires_tool_station_and_ingredients() {
+        let inventory = InventorySystem::new(EventBus::new());
+        let templates = templates();
+        let mut resolver = SkillCheckResolver::seeded(2);
+
+        let mut world = GameWorld::new();
+        let smith = smithy(&mut world, &["iron_ingot", "iron_ingot"]);
+        let result = craft(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates,
+            smith,
+            &dagger_recipe(0),
+        );
+        assert!(result.unwrap_err().contains("smithing hammer"));
+
+        let smith = smithy(&mut world, &["iron_ingot", "smithing_hammer"]);
+        let result = craft(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates,
+            smith,
+            &dagger_recipe(0),
+        );
+        assert!(result.unwrap_err().contains("2 x iron ingot"));
+
+        let mut recipe = dagger_recipe(0);
+        recipe.station = Some("loom".to_string());
+        let result = craft(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates,
+            smith,
+            &recipe,
+        );
+        assert!(result.unwrap_err().contains("loom"));
+        assert_eq!(inventory.get_item_count(&world, smith), 2);
+    }
+
+    #[test]
+    fn test_craft_consumes_ingredients_and_makes_item() {
+        let inventory = InventorySystem::new(EventBus::new());
+        let templates = templates();
+        let mut resolver = SkillCheckResolver::seeded(4);
+
+        let mut world = GameWorld::new();
+        let smith = smithy(&mut world, &["iron_ingot", "iron_ingot", "smithing_hammer"]);
+        // Seed 4 rolls a 14, far past this difficulty: a critical success
+        let outcome = craft(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates,
+            smith,
+            &dagger_recipe(-100),
+        )
+        .unwrap();
+        assert_eq!(outcome.consumed.len(), 2);
+
+        let carried: Vec<String> = inventory
+            .get_items_in_container(&world, smith)
+            .into_iter()
+            .map(|item| world.get::<&Template>(item).unwrap().template_id.clone())
+            .collect();
+        assert!(carried.contains(&"smithing_hammer".to_string()));
+        assert!(!carried.contains(&"iron_ingot".to_string()));
+        assert_eq!(outcome.check.roll, 14);
+        assert_eq!(outcome.quality, Some(Quality::Masterwork));
+        let (dagger, _) = outcome.produced[0];
+        assert!(carried.contains(&"iron_dagger".to_string()));
+        assert_eq!(world.get::<&Weapon>(dagger).unwrap().damage_max, 6);
+        assert!(world.get::<&Name>(dagger).unwrap().matches("masterwork"));
+        assert!(world.get::<&Material>(dagger).is_ok());
+    }
+}
@@ -0,0 +1,530 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Death system handling corpses, respawn and kill credit
+//!
+//! Reacts to `GameEvent::EntityDied`: the victim leaves combat, along with
+//! anyone it leaves without a hostile, the killer is credited with
+//! `ExperienceGained`, NPCs are replaced by a corpse holding their inventory,
+//! and players are restored at their [`BindPoint`], or the starting location
+//! when they have none. Corpses decay along with their contents after
+//! [`DeathConfig::corpse_decay_seconds`].
+
+use crate::config::DeathConfig;
+use crate::ecs::components::{
+    AttributeScores, Avatar, BindPoint, BodyAttributeScores, ContainedBy, Container, Corpse,
+    Description, EntityId, EntityUuid, Equipment, Location, MindAttributeScores, Name, Posture,
+    Room, SoulAttributeScores, StatusEffects,
+};
+use crate::ecs::events::{EventBus, GameEvent};
+use crate::ecs::output::{Narration, capitalize, players_in_room};
+use crate::ecs::systems::{InventorySystem, leave_combat, vitals_of};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Work left for the caller once the world lock is released
+#[derive(Debug, Default)]
+pub struct DeathOutcome {
+    /// Persistent entities whose components changed
+    pub dirty: Vec<Uuid>,
+    /// Entities removed from the world, to be unregistered
+    pub despawned: Vec<EcsEntity>,
+    /// Entities gone for good (decayed corpse contents), to be deleted from the database
+    pub destroyed: Vec<Uuid>,
+    /// Messages for the players involved
+    pub notices: Vec<Narration>,
+}
+
+pub struct DeathSystem {
+    event_bus: EventBus,
+    inventory: InventorySystem,
+    config: DeathConfig,
+    starting_room: Option<Uuid>,
+    deaths: Arc<Mutex<Vec<(EcsEntity, Option<EcsEntity>)>>>,
+}
+
+impl DeathSystem {
+    /// Create a new death system with the default configuration
+    pub fn new(event_bus: EventBus) -> Self {
+        Self::with_config(event_bus, DeathConfig::default())
+    }
+
+    /// Create a new death system collecting deaths published on the event bus
+    pub fn with_config(event_bus: EventBus, config: DeathConfig) -> Self {
+        let deaths = Arc::new(Mutex::new(Vec::new()));
+        let queue = deaths.clone();
+        event_bus.subscribe(move |event| {
+            if let GameEvent::EntityDied { entity, killer } = event {
+                queue.lock().unwrap().push((*entity, *killer));
+            }
+        });
+
+        Self {
+            inventory: InventorySystem::new(event_bus.clone()),
+            event_bus,
+            config,
+            starting_room: None,
+            deaths,
+        }
+    }
+
+    /// Replace the death configuration
+    pub fn set_config(&mut self, config: DeathConfig) {
+        self.config = config;
+    }
+
+    /// Set the room players without a bind point respawn in
+    pub fn set_starting_room(&mut self, room: Option<Uuid>) {
+        self.starting_room = room;
+    }
+
+    /// Handle deaths reported since the last update and decay corpses
+    #[instrument(skip(self, world))]
+    pub fn update(&mut self, world: &mut GameWorld, delta_time: f32) -> DeathOutcome {
+        let mut outcome = DeathOutcome::default();
+
+        let deaths = std::mem::take(&mut *self.deaths.lock().unwrap());
+        let mut handled = HashSet::new();
+        for (entity, killer) in deaths {
+            if handled.insert(entity) && world.contains(entity) {
+                self.handle_death(world, entity, killer, &mut outcome);
+            }
+        }
+
+        self.decay_corpses(world, delta_time, &mut outcome);
+        outcome
+    }
+
+    fn handle_death(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        killer: Option<EcsEntity>,
+        outcome: &mut DeathOutcome,
+    ) {
+        let participants = leave_combat(world, entity);
+        if !participants.is_empty() {
+            self.event_bus
+                .publish(GameEvent::CombatEnded { participants });
+        }
+
+        if let Some(killer) = killer.filter(|killer| world.contains(*killer)) {
+            self.event_bus.publish(GameEvent::ExperienceGained {
+                entity: killer,
+                skill: None,
+                amount: experience_value(world, entity),
+            });
+        }
+
+        let is_player = world.get::<&Avatar>(entity).is_ok();
+        if !is_player || self.config.player_corpse {
+            self.leave_corpse(world, entity, outcome);
+        }
+
+        if is_player {
+            self.respawn(world, entity, outcome);
+        } else {
+            let _ = world.despawn(entity);
+            outcome.despawned.push(entity);
+        }
+    }
+
+    /// Spawn a corpse where the entity died and move its inventory into it
+    fn leave_corpse(&self, world: &mut GameWorld, entity: EcsEntity, outcome: &mut DeathOutcome) {
+        let Ok(location) = world.get::<&Location>(entity).map(|location| *location) else {
+            return;
+        };
+        let (display, mut keywords) = match world.get::<&Name>(entity) {
+            Ok(name) => (name.display.clone(), name.keywords.clone()),
+            Err(_) => ("something".to_string(), Vec::new()),
+        };
+        keywords.insert(0, "corpse".to_string());
+
+        let corpse = world.spawn((
+            Name::new(format!("the corpse of {}", display)).with_keywords(keywords),
+            Description::new(
+                format!("The corpse of {} lies here.", display),
+                format!(
+                    "The lifeless body of {} lies crumpled on the ground.",
+                    display
+                ),
+            ),
+            EntityUuid::new(),
+            location,
+            Container::new(None),
+            Corpse::new(self.config.corpse_decay_seconds),
+        ));
+
+        if let Ok(uuid) = world.get::<&EntityUuid>(corpse) {
+            outcome.dirty.push(uuid.0);
+        }
+        let holder = InventorySystem::holder_id(world, corpse);
+        for item in self.inventory.get_items_in_container(world, entity) {
+            if let Ok(mut contained) = world.get::<&mut ContainedBy>(item) {
+                *contained = ContainedBy::new(holder);
+            }
+            if let Ok(uuid) = world.get::<&EntityUuid>(item) {
+                outcome.dirty.push(uuid.0);
+            }
+        }
+        if let Ok(mut equipment) = world.get::<&mut Equipment>(entity) {
+            equipment.slots.clear();
+            if let Ok(uuid) = world.get::<&EntityUuid>(entity) {
+                outcome.dirty.push(uuid.0);
+            }
+        }
+    }
+
+    /// Return a dead player to their bind point with reduced vitals
+    fn respawn(&self, world: &mut GameWorld, entity: EcsEntity, outcome: &mut DeathOutcome) {
+        let fraction = self.config.respawn_vitals.clamp(0.0, 1.0);
+        if let Ok(mut scores) = world.get::<&mut AttributeScores>(entity) {
+            restore(&mut scores, fraction);
+        }
+        if let Ok(mut scores) = world.get::<&mut BodyAttributeScores>(entity) {
+            restore(&mut scores.0, fraction);
+        }
+        if let Ok(mut scores) = world.get::<&mut MindAttributeScores>(entity) {
+            restore(&mut scores.0, fraction);
+        }
+        if let Ok(mut scores) = world.get::<&mut SoulAttributeScores>(entity) {
+            restore(&mut scores.0, fraction);
+        }
+        if let Ok(mut posture) = world.get::<&mut Posture>(entity) {
+            *posture = Posture::Standing;
+        }
+        if let Ok(mut status_effects) = world.get::<&mut StatusEffects>(entity) {
+            status_effects.effects.clear();
+        }
+
+        let destination = world
+            .get::<&BindPoint>(entity)
+            .ok()
+            .and_then(|bind_point| find_room(world, bind_point.room_id.uuid()))
+            .or_else(|| {
+                self.starting_room
+                    .and_then(|room_uuid| find_room(world, room_uuid))
+            });
+        match destination {
+            Some((room, room_uuid)) => {
+                let area_id = world.get::<&Room>(room).map(|room| room.area_id);
+                if let (Ok(area_id), Ok(mut location)) =
+                    (area_id, world.get::<&mut Location>(entity))
+                {
+                    *location = Location::new(area_id, EntityId::new(room, room_uuid));
+                }
+                let room_name = world
+                    .get::<&Name>(room)
+                    .map(|name| name.display.clone())
+                    .unwrap_or_else(|_| "a familiar place".to_string());
+                let name = world
+                    .get::<&Name>(entity)
+                    .map(|name| name.display.clone())
+                    .unwrap_or_else(|_| "Someone".to_string());
+                let observers = players_in_room(world, room_uuid)
+                    .into_iter()
+                    .filter(|other| *other != entity)
+                    .collect();
+                outcome.notices.push(Narration {
+                    recipients: vec![entity],
+                    message: format!("You awaken in {}, weak but alive.", room_name),
+                });
+                outcome.notices.push(Narration {
+                    recipients: observers,
+                    message: format!("{} appears, looking pale and shaken.", name),
+                });
+            }
+            None => {
+                tracing::warn!(
+                    "Entity {:?} died with no bind point or starting room",
+                    entity
+                );
+                outcome.notices.push(Narration {
+                    recipients: vec![entity],
+                    message: "You awaken, weak but alive.".to_string(),
+                });
+            }
+        }
+
+        if let Ok(uuid) = world.get::<&EntityUuid>(entity) {
+            outcome.dirty.push(uuid.0);
+        }
+        self.event_bus.publish(GameEvent::VitalsChanged {
+            entity,
+            vitals: vitals_of(world, entity),
+        });
+    }
+
+    /// Count down corpses and remove the ones that have decayed
+    fn decay_corpses(&self, world: &mut GameWorld, delta_time: f32, outcome: &mut DeathOutcome) {
+        let mut decayed = Vec::new();
+        for (entity, corpse) in world.query_mut::<(Entity, &mut Corpse)>() {
+            corpse.decay_remaining -= delta_time;
+            if corpse.decay_remaining <= 0.0 {
+                decayed.push(entity);
+            }
+        }
+
+        for corpse in decayed {
+            let mut contents = Vec::new();
+            let mut pending = vec![corpse];
+            while let Some(holder) = pending.pop() {
+                let items = self.inventory.get_items_in_container(world, holder);
+                pending.extend(items.iter().copied());
+                contents.extend(items);
+            }
+
+            if let Ok(location) = world.get::<&Location>(corpse) {
+                let name = world
+                    .get::<&Name>(corpse)
+                    .map(|name| name.display.clone())
+                    .unwrap_or_else(|_| "a corpse".to_string());
+                outcome.notices.push(Narration {
+                    recipients: players_in_room(world, location.room_id.uuid()),
+                    message: format!("{} rots away.", capitalize(&name)),
+                });
+            }
+
+            for item in contents {
+                if let Ok(uuid) = world.get::<&EntityUuid>(item) {
+                    outcome.destroyed.push(uuid.0);
+                }
+                let _ = world.despawn(item);
+                outcome.despawned.push(item);
+            }
+            if let Ok(uuid) = world.get::<&EntityUuid>(corpse) {
+                outcome.destroyed.push(uuid.0);
+            }
+            let _ = world.despawn(corpse);
+            outcome.despawned.push(corpse);
+        }
+    }
+}
+
+/// Experience awarded for killing an entity, based on its body scores
+fn experience_value(world: &GameWorld, entity: EcsEntity) -> u64 {
+    let total = world
+        .get::<&BodyAttributeScores>(entity)
+        .map(|scores| scores.0.score_offence + scores.0.score_finesse + scores.0.score_defence)
+        .or_else(|_| {
+            world
+                .get::<&AttributeScores>(entity)
+                .map(|scores| scores.score_offence + scores.score_finesse + scores.score_defence)
+        })
+        .unwrap_or(1);
+    total.max(1) as u64
+}
+
+/// Reset health and energy to a fraction of their maximums
+fn restore(scores: &mut AttributeScores, fraction: f32) {
+    scores.health_current = (scores.health_maximum * fraction).max(1.0);
+    scores.energy_current = scores.energy_maximum * fraction;
+}
+
+/// Find a room entity by its UUID
+fn find_room(world: &GameWorld, room_uuid: Uuid) -> Option<(EcsEntity, Uuid)> {
+    world
+        .query::<(Entity, &EntityUuid, &Room)>()
+        .iter()
+        .find(|(_, uuid, _)| uuid.0 == room_uuid)
+        .map(|(room, uuid, _)| (room, uuid.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Combatant, Containable};
+
+    fn here(room: Uuid) -> Location {
+        Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room))
+    }
+
+    fn slain(health: f32) -> AttributeScores {
+        let mut scores = AttributeScores::new();
+        scores.health_current = health;
+        scores
+    }
+
+    fn recorded(event_bus: &EventBus) -> Arc<Mutex<Vec<GameEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        event_bus.subscribe(move |event| sink.lock().unwrap().push(event.clone()));
+        events
+    }
+
+    #[test]
+    fn test_npc_leaves_corpse_that_decays() {
+        let event_bus = EventBus::new();
+        let events = recorded(&event_bus);
+        let mut system = DeathSystem::with_config(
+            event_bus.clone(),
+            DeathConfig {
+                corpse_decay_seconds: 10.0,
+                ..Default::default()
+            },
+        );
+
+        let mut world = GameWorld::new();
+        let room = Uuid::new_v4();
+        let goblin = world.spawn((
+            Name::new("a goblin").with_keywords(vec!["goblin".to_string()]),
+            EntityUuid::new(),
+            here(room),
+            slain(0.0),
+            Combatant::new(),
+        ));
+        let dagger_uuid = Uuid::new_v4();
+        let dagger = world.spawn((
+            Name::new("dagger"),
+            EntityUuid(dagger_uuid),
+            Containable::new(1.0),
+            ContainedBy::new(InventorySystem::holder_id(&world, goblin)),
+        ));
+        let mut fighting = Combatant::new();
+        fighting.in_combat = true;
+        fighting.target_id = Some(InventorySystem::holder_id(&world, goblin));
+        let hero = world.spawn((Name::new("Hero"), EntityUuid::new(), fighting));
+
+        event_bus.publish(GameEvent::EntityDied {
+            entity: goblin,
+            killer: Some(hero),
+        });
+        event_bus.process_events();
+        let outcome = system.update(&mut world, 1.0);
+        event_bus.process_events();
+
+        assert!(!world.contains(goblin));
+        assert_eq!(outcome.despawned, vec![goblin]);
+        assert!(!world.get::<&Combatant>(hero).unwrap().in_combat);
+
+        let corpse = world
+            .query::<(Entity, &Corpse)>()
+            .iter()
+            .map(|(corpse, _)| corpse)
+            .next()
+            .unwrap();
+        assert!(world.get::<&Name>(corpse).unwrap().matches("corpse"));
+        assert!(world.get::<&Name>(corpse).unwrap().matches("goblin"));
+        assert!(system.inventory.has_item(&world, corpse, dagger));
+
+        // The corpse and the dagger's new container are saved
+        let corpse_uuid = world.get::<&EntityUuid>(corpse).unwrap().0;
+        assert!(outcome.dirty.contains(&corpse_uuid));
+        assert!(outcome.dirty.contains(&dagger_uuid));
+
+        let events = events.lock().unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            GameEvent::ExperienceGained { entity, amount: 30, .. } if *entity == hero
+        )));
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, GameEvent::CombatEnded { .. }))
+        );
+        drop(events);
+
+        let outcome = system.update(&mut world, 10.0);
+        assert!(!world.contains(corpse));
+        assert!(!world.contains(dagger));
+        assert_eq!(outcome.destroyed.len(), 2);
+        assert!(outcome.destroyed.contains(&corpse_uuid));
+    }
+
+    #[test]
+    fn test_player_respawns_at_bind_point() {
+        let event_bus = EventBus::new();
+        let mut system = DeathSystem::new(event_bus.clone());
+
+        let mut world = GameWorld::new();
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let temple_uuid = EntityUuid::new();
+        let temple_id = temple_uuid.0;
+        world.spawn((temple_uuid, Name::new("the Temple"), Room::new(area)));
+        let player_uuid = Uuid::new_v4();
+        let player = world.spawn((
+            Name::new("Alice"),
+            EntityUuid(player_uuid),
+            Avatar::new(Uuid::new_v4()),
+            here(Uuid::new_v4()),
+            BodyAttributeScores(slain(0.0)),
+            BindPoint::new(EntityId::from_uuid(temple_id)),
+            Posture::Sleeping,
+        ));
+
+        event_bus.publish(GameEvent::EntityDied {
+            entity: player,
+            killer: None,
+        });
+        event_bus.process_events();
+        let outcome = system.update(&mut world, 0.25);
+
+        assert!(world.contains(player));
+        assert!(outcome.despawned.is_empty());
+        assert_eq!(outcome.dirty, vec![player_uuid]);
+        assert_eq!(
+            world.get::<&Location>(player).unwrap().room_id.uuid(),
+            temple_id
+        );
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(player)
+                .unwrap()
+                .0
+                .health_current,
+            50.0
+        );
+        assert_eq!(*world.get::<&Posture>(player).unwrap(), Posture::Standing);
+        assert!(world.query::<&Corpse>().iter().next().is_none());
+        assert!(outcome.notices[0].message.contains("the Temple"));
+    }
+
+    #[test]
+    fn test_player_without_bind_point_respawns_at_start() {
+        let event_bus = EventBus::new();
+        let mut system = DeathSystem::new(event_bus.clone());
+
+        let mut world = GameWorld::new();
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let square = Uuid::new_v4();
+        world.spawn((EntityUuid(square), Name::new("the Square"), Room::new(area)));
+        system.set_starting_room(Some(square));
+        let player = world.spawn((
+            Name::new("Bob"),
+            EntityUuid::new(),
+            Avatar::new(Uuid::new_v4()),
+            here(Uuid::new_v4()),
+            BodyAttributeScores(slain(0.0)),
+        ));
+
+        event_bus.publish(GameEvent::EntityDied {
+            entity: player,
+            killer: None,
+        });
+        event_bus.process_events();
+        let outcome = system.update(&mut world, 0.25);
+
+        assert_eq!(
+            world.get::<&Location>(player).unwrap().room_id.uuid(),
+            square
+        );
+        assert!(outcome.notices[0].message.contains("the Square"));
+    }
+}
@@ -0,0 +1,350 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Resource gathering
+//!
+//! Gathering from a [`ResourceNode`] needs the node's tool, if any, and a
+//! check of its gathering skill. Success picks an entry from the yield table
+//! by weight, gives an extra item for every [`MARGIN_PER_EXTRA`] points the
+//! check beat the difficulty by, up to the entry's maximum, and uses up one
+//! of the node's charges. Depleted nodes replenish on their respawn timer.
+
+use crate::ecs::components::{
+    EntityUuid, ItemTemplateRegistry, Location, Name, Quality, ResourceNode, ResourceYield, Skill,
+    Skills, Template,
+};
+use crate::ecs::systems::{
+    CheckOutcome, CheckResult, InventorySystem, SkillCheck, SkillCheckResolver, spawn_item,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use rand::Rng;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Points of check margin needed for each item past the minimum
+pub const MARGIN_PER_EXTRA: i32 = 5;
+
+/// Result of a gathering attempt
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatherOutcome {
+    pub check: CheckResult,
+    /// Template gathered, or `None` if the attempt failed
+    pub template_id: Option<String>,
+    /// Items gathered, held by the gatherer
+    pub produced: Vec<(EcsEntity, Uuid)>,
+    /// Whether this attempt used up the node's last charge
+    pub depleted: bool,
+}
+
+/// Replenishes depleted resource nodes
+#[derive(Debug, Default)]
+pub struct GatheringSystem;
+
+impl GatheringSystem {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Advance the respawn timers of depleted nodes
+    ///
+    /// Returns the UUIDs of nodes that replenished so they can be marked dirty.
+    #[instrument(skip(self, world))]
+    pub fn update(&mut self, world: &mut GameWorld, delta_time: f32) -> Vec<Uuid> {
+        world
+            .query_mut::<(&mut ResourceNode, Option<&EntityUuid>)>()
+            .into_iter()
+            .filter_map(|(node, uuid)| node.tick(delta_time).then_some(uuid).flatten())
+            .map(|uuid| uuid.0)
+            .collect()
+    }
+}
+
+/// Attempt to gather from a resource node
+///
+/// Fails without a check if the node is elsewhere, depleted, needs a tool the
+/// gatherer lacks, or has nothing the gatherer is skilled enough to find.
+/// Items gathered are new persistent entities the caller must register.
+pub fn gather<R: Rng>(
+    world: &mut GameWorld,
+    inventory: &InventorySystem,
+    resolver: &mut SkillCheckResolver<R>,
+    templates: &ItemTemplateRegistry,
+    gatherer: EcsEntity,
+    node: EcsEntity,
+) -> Result<GatherOutcome, String> {
+    let node_name = world
+        .get::<&Name>(node)
+        .map(|name| name.display.clone())
+        .unwrap_or_else(|_| "resource".to_string());
+    let Some(resources) = world.get::<&ResourceNode>(node).ok().map(|n| (*n).clone()) else {
+        return Err(format!("You can't gather anything from the {}.", node_name));
+    };
+    let room = |entity| {
+        world
+            .get::<&Location>(entity)
+            .ok()
+            .map(|l| l.room_id.uuid())
+    };
+    if room(gatherer).is_none() || room(gatherer) != room(node) {
+        return Err(format!("You don't see the {} here.", node_name));
+    }
+    if resources.is_depleted() {
+        return Err(format!("The {} has nothing left to gather.", node_name));
+    }
+
+    if let Some(tool) = &resources.tool {
+        let has_tool = inventory
+            .get_items_in_container(world, gatherer)
+            .into_iter()
+            .any(|item| template_of(world, item).as_deref() == Some(tool.as_str()));
+        if !has_tool {
+            let tool_name = templates
+                .get(tool)
+                .map(|template| template.name.clone())
+                .unwrap_or_else(|| tool.clone());
+            return Err(format!(
+                "You need a {} to gather from the {}.",
+                tool_name, node_name
+            ));
+        }
+    }
+
+    let level = world
+        .get::<&Skills>(gatherer)
+        .map(|skills| skills.level(resources.skill))
+        .unwrap_or(0);
+    let available: Vec<&ResourceYield> = resources.yields_for(level).collect();
+    if available.is_empty() {
+        return Err(format!(
+            "You don't know how to gather anything from the {}.",
+            node_name
+        ));
+    }
+
+    let check = SkillCheck::for_skill(resources.skill).against(resources.difficulty);
+    let check = resolver.resolve(world, gatherer, &check);
+    if !check.outcome.is_success() {
+        return Ok(GatherOutcome {
+            check,
+            template_id: None,
+            produced: Vec::new(),
+            depleted: false,
+        });
+    }
+
+    let entry = pick_yield(resolver, &available);
+    let quantity = yield_quantity(entry, check.outcome, check.margin);
+    let mut produced = Vec::new();
+    if let Some(template) = templates.get(&entry.template_id) {
+        let holder = InventorySystem::holder_id(world, gatherer);
+        for _ in 0..quantity {
+            produced.push(spawn_item(world, template, Quality::Common, holder));
+        }
+    } else {
+        tracing::warn!(
+            "Resource node yields unknown item template '{}'",
+            entry.template_id
+        );
+    }
+
+    let mut depleted = false;
+    if let Ok(mut resources) = world.get::<&mut ResourceNode>(node) {
+        resources.use_charge();
+        depleted = resources.is_depleted();
+    }
+
+    Ok(GatherOutcome {
+        check,
+        template_id: Some(entry.template_id.clone()),
+        produced,
+        depleted,
+    })
+}
+
+/// Pick a yield table entry by weight
+fn pick_yield<'a, R: Rng>(
+    resolver: &mut SkillCheckResolver<R>,
+    entries: &[&'a ResourceYield],
+) -> &'a ResourceYield {
+    let total: i32 = entries.iter().map(|entry| entry.weight as i32).sum();
+    let mut roll = resolver.roll_between(1, total.max(1));
+    for entry in entries {
+        roll -= entry.weight as i32;
+        if roll <= 0 {
+            return entry;
+        }
+    }
+    entries[entries.len() - 1]
+}
+
+/// Number of items a successful check gathers from an entry
+pub fn yield_quantity(entry: &ResourceYield, outcome: CheckOutcome, margin: i32) -> u32 {
+    if outcome == CheckOutcome::CriticalSuccess {
+        return entry.max_quantity;
+    }
+    let extra = (margin.max(0) / MARGIN_PER_EXTRA) as u32;
+    (entry.min_quantity + extra).min(entry.max_quantity)
+}
+
+/// Find a resource node in the entity's room
+///
+/// Nodes can be narrowed by keyword and by gathering skill. Nodes with
+/// charges left are preferred over depleted ones.
+pub fn find_node(
+    world: &GameWorld,
+    entity: EcsEntity,
+    keyword: &str,
+    skill: Option<Skill>,
+) -> Option<EcsEntity> {
+    let room = world.get::<&Location>(entity).ok()?.room_id.uuid();
+    let mut nodes: Vec<(EcsEntity, bool)> = world
+        .query::<(Entity, &Location, &ResourceNode, Option<&Name>)>()
+        .iter()
+        .filter(|(_, location, node, name)| {
+            location.room_id.uuid() == room
+                && skill.is_none_or(|skill| node.skill == skill)
+                && (keyword.is_empty() || name.is_some_and(|name| name.matches(keyword)))
+        })
+        .map(|(node_entity, _, node, _)| (node_entity, node.is_depleted()))
+        .collect();
+    nodes.sort_by_key(|(_, depleted)| *depleted);
+    nodes.first().map(|(node, _)| *node)
+}
+
+fn template_of(world: &GameWorld, item: EcsEntity) -> Option<String> {
+    world
+        .get::<&Template>(item)
+        .ok()
+        .map(|template| template.template_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{ContainedBy, Container, EntityId, ItemTemplate};
+    use crate::ecs::events::EventBus;
+
+    fn templates() -> ItemTemplateRegistry {
+        let mut templates = ItemTemplateRegistry::new();
+        templates.register(ItemTemplate::new("iron_ore", "iron ore", 2.0));
+        templates.register(ItemTemplate::new("pickaxe", "pickaxe", 4.0));
+        templates
+    }
+
+    /// A miner beside an iron vein, carrying the given templates
+    fn mine(world: &mut GameWorld, carried: &[&str], difficulty: i32) -> (EcsEntity, EcsEntity) {
+        let area = EntityId::from_uuid(Uuid::new_v4());
+        let room = EntityId::from_uuid(Uuid::new_v4());
+        let vein = world.spawn((
+            EntityUuid::new(),
+            Name::new("iron vein"),
+            Location::new(area, room),
+            ResourceNode::new(Skill::Mining, difficulty)
+                .with_tool("pickaxe")
+                .with_yield(ResourceYield::new("iron_ore", 1).with_quantity(1, 3))
+                .with_charges(1),
+        ));
+        let miner = world.spawn((
+            EntityUuid::new(),
+            Location::new(area, room),
+            Container::new(None),
+            Skills::new(),
+        ));
+        let holder = InventorySystem::holder_id(world, miner);
+        for id in carried {
+            world.spawn((
+                EntityUuid::new(),
+                Template::new(*id),
+                ContainedBy::new(holder),
+            ));
+        }
+        (miner, vein)
+    }
+
+    #[test]
+    fn test_yield_quantity() {
+        let entry = ResourceYield::new("iron_ore", 1).with_quantity(1, 3);
+        assert_eq!(yield_quantity(&entry, CheckOutcome::Success, 0), 1);
+        assert_eq!(yield_quantity(&entry, CheckOutcome::Success, 6), 2);
+        assert_eq!(yield_quantity(&entry, CheckOutcome::Success, 40), 3);
+        assert_eq!(yield_quantity(&entry, CheckOutcome::CriticalSuccess, 0), 3);
+    }
+
+    #[test]
+    fn test_gather_requires_tool() {
+        let inventory = InventorySystem::new(EventBus::new());
+        let mut resolver = SkillCheckResolver::seeded(1);
+        let mut world = GameWorld::new();
+        let (miner, vein) = mine(&mut world, &[], 0);
+
+        let result = gather(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates(),
+            miner,
+            vein,
+        );
+        assert!(result.unwrap_err().contains("pickaxe"));
+        assert_eq!(
+            find_node(&world, miner, "vein", Some(Skill::Mining)),
+            Some(vein)
+        );
+        assert_eq!(find_node(&world, miner, "", Some(Skill::Fishing)), None);
+    }
+
+    #[test]
+    fn test_gather_depletes_and_respawns() {
+        let inventory = InventorySystem::new(EventBus::new());
+        let mut resolver = SkillCheckResolver::seeded(3);
+        let mut world = GameWorld::new();
+        // Only a natural 1 fails
+        let (miner, vein) = mine(&mut world, &["pickaxe"], -100);
+
+        let outcome = loop {
+            let outcome = gather(
+                &mut world,
+                &inventory,
+                &mut resolver,
+                &templates(),
+                miner,
+                vein,
+            )
+            .unwrap();
+            if outcome.check.outcome.is_success() {
+                break outcome;
+            }
+        };
+        assert_eq!(outcome.template_id.as_deref(), Some("iron_ore"));
+        assert_eq!(outcome.produced.len(), 3);
+        assert!(outcome.depleted);
+        let result = gather(
+            &mut world,
+            &inventory,
+            &mut resolver,
+            &templates(),
+            miner,
+            vein,
+        );
+        assert!(result.unwrap_err().contains("nothing left"));
+
+        let mut system = GatheringSystem::new();
+        assert!(system.update(&mut world, 299.0).is_empty());
+        assert_eq!(system.update(&mut world, 1.0).len(), 1);
+        assert!(!world.get::<&ResourceNode>(vein).unwrap().is_depleted());
+    }
+}
@@ -15,10 +15,24 @@
 //
 
 //! Inventory system for item management
-
-use crate::ecs::components::{Containable, Container, EntityId, Location};
+//!
+//! Carried and stored items are linked to their holder with a [`ContainedBy`]
+//! component and have no [`Location`] of their own. Equipped items stay in
+//! their wearer's inventory and are additionally recorded in [`Equipment`].
+
+use crate::ecs::components::{
+    Containable, ContainedBy, Container, EntityId, EntityUuid, EquipSlot, Equipable, Equipment,
+    Location,
+};
 use crate::ecs::events::{EventBus, GameEvent};
 use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+
+/// Limit a holder would exceed by taking an item
+enum Limit {
+    Count,
+    Weight,
+}
 
 pub struct InventorySystem {
     event_bus: EventBus,
@@ -37,41 +51,16 @@ impl InventorySystem {
         entity: EcsEntity,
         item: EcsEntity,
     ) -> Result<(), String> {
-        // Get item weight
-        let weight = world
-            .get::<&Containable>(item)
-            .map(|c| c.weight)
-            .unwrap_or(0.0);
-
-        // Check if entity has container
-        if let Ok(container) = world.get::<&Container>(entity) {
-            // Check capacity constraints
-            if let Some(capacity) = container.capacity {
-                let current_count = self.get_item_count(world, entity);
-                if current_count >= capacity as usize {
-                    return Err("Inventory is full".to_string());
-                }
-            }
-
-            if let Some(max_weight) = container.max_weight {
-                let current_weight = self.get_total_weight(world, entity);
-                if current_weight + weight > max_weight {
-                    return Err("Item is too heavy".to_string());
-                }
-            }
-
-            // Remove item from world location (set to invalid location)
-            if let Ok(mut loc) = world.get::<&mut Location>(item) {
-                loc.area_id = EntityId::from_uuid(uuid::Uuid::nil());
-                loc.room_id = EntityId::from_uuid(uuid::Uuid::nil());
-            }
-
-            self.event_bus
-                .publish(GameEvent::ItemPickedUp { entity, item });
-            Ok(())
-        } else {
-            Err("Entity has no inventory".to_string())
+        match self.check_limits(world, entity, item) {
+            Some(Limit::Count) => return Err("Inventory is full".to_string()),
+            Some(Limit::Weight) => return Err("Item is too heavy".to_string()),
+            None => {}
         }
+
+        self.move_into(world, entity, item)?;
+        self.event_bus
+            .publish(GameEvent::ItemPickedUp { entity, item });
+        Ok(())
     }
 
     /// Drop an item to the ground
@@ -81,21 +70,19 @@ impl InventorySystem {
         entity: EcsEntity,
         item: EcsEntity,
     ) -> Result<(), String> {
-        // Check if entity has container
-        if world.get::<&Container>(entity).is_ok() {
-            // Set item location to entity's location
-            if let Ok(entity_loc) = world.get::<&Location>(entity) {
-                if let Ok(mut item_loc) = world.get::<&mut Location>(item) {
-                    *item_loc = *entity_loc;
-                }
-            }
+        self.check_releasable(world, entity, item)?;
 
-            self.event_bus
-                .publish(GameEvent::ItemDropped { entity, item });
-            Ok(())
-        } else {
-            Err("Entity has no inventory".to_string())
-        }
+        let location = *world
+            .get::<&Location>(entity)
+            .map_err(|_| "Entity has no location")?;
+        let _ = world.remove_one::<ContainedBy>(item);
+        world
+            .insert_one(item, location)
+            .map_err(|e| format!("Failed to drop item: {}", e))?;
+
+        self.event_bus
+            .publish(GameEvent::ItemDropped { entity, item });
+        Ok(())
     }
 
     /// Transfer an item from one entity to another
@@ -106,80 +93,308 @@ impl InventorySystem {
         to: EcsEntity,
         item: EcsEntity,
     ) -> Result<(), String> {
-        // Get item weight
-        let weight = world
-            .get::<&Containable>(item)
-            .map(|c| c.weight)
-            .unwrap_or(0.0);
-
-        // Check if recipient can receive the item
-        {
-            let to_container = world
-                .get::<&Container>(to)
-                .map_err(|_| "Recipient has no inventory")?;
-
-            // Check capacity constraints
-            if let Some(max_weight) = to_container.max_weight {
-                // TODO: Calculate current weight properly
-                if weight > max_weight {
-                    return Err("Recipient cannot carry the item".to_string());
-                }
-            }
+        self.check_releasable(world, from, item)?;
+        if self.check_limits(world, to, item).is_some() {
+            return Err("Recipient cannot carry the item".to_string());
         }
 
-        // Check source has inventory
-        world
-            .get::<&Container>(from)
-            .map_err(|_| "Source has no inventory")?;
+        self.move_into(world, to, item)?;
+        self.event_bus.publish(GameEvent::ItemGiven {
+            entity: from,
+            recipient: to,
+            item,
+        });
+        Ok(())
+    }
 
-        // Publish events
-        self.event_bus
-            .publish(GameEvent::ItemDropped { entity: from, item });
-        self.event_bus
-            .publish(GameEvent::ItemPickedUp { entity: to, item });
+    /// Put a carried item into a container
+    pub fn store_item(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+        container: EcsEntity,
+    ) -> Result<(), String> {
+        self.check_releasable(world, entity, item)?;
+        self.check_open(world, container)?;
+        if item == container || self.is_within(world, container, item) {
+            return Err("Item cannot be put inside itself".to_string());
+        }
+        match self.check_limits(world, container, item) {
+            Some(Limit::Count) => return Err("Container is full".to_string()),
+            Some(Limit::Weight) => return Err("Item is too heavy for the container".to_string()),
+            None => {}
+        }
+
+        self.move_into(world, container, item)?;
+        self.event_bus.publish(GameEvent::ItemStored {
+            entity,
+            item,
+            container,
+        });
+        Ok(())
+    }
+
+    /// Take an item out of a container into an entity's inventory
+    pub fn retrieve_item(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+        container: EcsEntity,
+    ) -> Result<(), String> {
+        self.check_open(world, container)?;
+        if !self.has_item(world, container, item) {
+            return Err("Item is not in the container".to_string());
+        }
+        match self.check_limits(world, entity, item) {
+            Some(Limit::Count) => return Err("Inventory is full".to_string()),
+            Some(Limit::Weight) => return Err("Item is too heavy".to_string()),
+            None => {}
+        }
 
+        self.move_into(world, entity, item)?;
+        self.event_bus.publish(GameEvent::ItemRetrieved {
+            entity,
+            item,
+            container,
+        });
         Ok(())
     }
 
+    /// Equip a carried item in the first free slot from `candidates` it fits
+    ///
+    /// Returns the slot the item was equipped in.
+    pub fn equip_item(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+        candidates: &[EquipSlot],
+    ) -> Result<EquipSlot, String> {
+        if !self.has_item(world, entity, item) {
+            return Err("Item is not in inventory".to_string());
+        }
+        if self.equipped_slot(world, entity, item).is_some() {
+            return Err("Item is already equipped".to_string());
+        }
+
+        let slots: Vec<EquipSlot> = world
+            .get::<&Equipable>(item)
+            .map_err(|_| "Item cannot be equipped")?
+            .slots
+            .iter()
+            .copied()
+            .filter(|slot| candidates.contains(slot))
+            .collect();
+        if slots.is_empty() {
+            return Err("Item does not fit that slot".to_string());
+        }
+
+        let slot = match world.get::<&Equipment>(entity) {
+            Ok(equipment) => slots
+                .iter()
+                .copied()
+                .find(|slot| !equipment.is_equipped(*slot)),
+            Err(_) => slots.first().copied(),
+        }
+        .ok_or_else(|| "Every slot for that item is already in use".to_string())?;
+
+        let item_id = Self::holder_id(world, item);
+        if world.get::<&Equipment>(entity).is_err() {
+            world
+                .insert_one(entity, Equipment::new())
+                .map_err(|e| format!("Failed to add equipment: {}", e))?;
+        }
+        world
+            .get::<&mut Equipment>(entity)
+            .map_err(|_| "Entity has no equipment")?
+            .equip(slot, item_id);
+
+        self.event_bus.publish(GameEvent::ItemEquipped {
+            entity,
+            item,
+            slot: slot.as_str().to_string(),
+        });
+        Ok(slot)
+    }
+
+    /// Unequip an item, leaving it in the entity's inventory
+    ///
+    /// Returns the slot the item was removed from.
+    pub fn unequip_item(
+        &mut self,
+        world: &mut GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+    ) -> Result<EquipSlot, String> {
+        let slot = self
+            .equipped_slot(world, entity, item)
+            .ok_or_else(|| "Item is not equipped".to_string())?;
+        world
+            .get::<&mut Equipment>(entity)
+            .map_err(|_| "Entity has no equipment")?
+            .unequip(slot);
+
+        self.event_bus.publish(GameEvent::ItemUnequipped {
+            entity,
+            item,
+            slot: slot.as_str().to_string(),
+        });
+        Ok(slot)
+    }
+
     /// Get all items in a container
-    pub fn get_items_in_container(&self, _world: &GameWorld, _entity: EcsEntity) -> Vec<EcsEntity> {
-        // TODO: Query world for entities with parent = entity
-        // Container no longer tracks contents directly
-        Vec::new()
+    pub fn get_items_in_container(&self, world: &GameWorld, entity: EcsEntity) -> Vec<EcsEntity> {
+        let holder = Self::holder_id(world, entity);
+        world
+            .query::<(Entity, &ContainedBy)>()
+            .iter()
+            .filter(|(_, contained)| Self::is_held_by(contained, holder))
+            .map(|(item, _)| item)
+            .collect()
     }
 
     /// Check if an entity has a specific item
-    pub fn has_item(&self, world: &GameWorld, _entity: EcsEntity, item: EcsEntity) -> bool {
-        // Check if item has nil location (meaning it's in inventory)
-        if let Ok(loc) = world.get::<&Location>(item) {
-            loc.area_id.uuid() == uuid::Uuid::nil() && loc.room_id.uuid() == uuid::Uuid::nil()
+    pub fn has_item(&self, world: &GameWorld, entity: EcsEntity, item: EcsEntity) -> bool {
+        let holder = Self::holder_id(world, entity);
+        world
+            .get::<&ContainedBy>(item)
+            .map(|contained| Self::is_held_by(&contained, holder))
+            .unwrap_or(false)
+    }
+
+    /// Get the slot an item is equipped in by an entity, if any
+    pub fn equipped_slot(
+        &self,
+        world: &GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+    ) -> Option<EquipSlot> {
+        let item_id = Self::holder_id(world, item);
+        let equipment = world.get::<&Equipment>(entity).ok()?;
+        equipment
+            .slots
+            .iter()
+            .find(|(_, equipped)| Self::same_entity(**equipped, item_id))
+            .map(|(slot, _)| *slot)
+    }
+
+    /// Get the total weight of items in a container, including nested contents
+    pub fn get_total_weight(&self, world: &GameWorld, entity: EcsEntity) -> f32 {
+        self.get_items_in_container(world, entity)
+            .into_iter()
+            .map(|item| {
+                let weight = world
+                    .get::<&Containable>(item)
+                    .map(|c| c.weight)
+                    .unwrap_or(0.0);
+                weight + self.get_total_weight(world, item)
+            })
+            .sum()
+    }
+
+    /// Get the number of items in a container
+    pub fn get_item_count(&self, world: &GameWorld, entity: EcsEntity) -> usize {
+        self.get_items_in_container(world, entity).len()
+    }
+
+    /// EntityId used to link items to the given holder
+    pub fn holder_id(world: &GameWorld, entity: EcsEntity) -> EntityId {
+        let uuid = world
+            .get::<&EntityUuid>(entity)
+            .map(|uuid| uuid.0)
+            .unwrap_or_else(|_| uuid::Uuid::nil());
+        EntityId::new(entity, uuid)
+    }
+
+    /// Check whether a containment link points at the given holder
+    ///
+    /// Links loaded before their holder (such as an offline character's
+    /// inventory) are unresolved and compare by UUID instead.
+    fn is_held_by(contained: &ContainedBy, holder: EntityId) -> bool {
+        Self::same_entity(contained.container_id, holder)
+    }
+
+    /// Compare EntityIds by runtime handle, or by UUID when unresolved
+    fn same_entity(a: EntityId, b: EntityId) -> bool {
+        if a.needs_resolution() || b.needs_resolution() {
+            !a.uuid().is_nil() && a.uuid() == b.uuid()
         } else {
-            false
+            a.entity() == b.entity()
         }
     }
 
-    /// Get the total weight of items in a container
-    pub fn get_total_weight(&self, world: &GameWorld, _entity: EcsEntity) -> f32 {
-        // Sum weight of items with nil location (in inventory)
-        let mut total_weight = 0.0;
-        for (loc, containable) in world.query::<(&Location, &Containable)>().iter() {
-            if loc.area_id.uuid() == uuid::Uuid::nil() && loc.room_id.uuid() == uuid::Uuid::nil() {
-                total_weight += containable.weight;
+    /// Check whether `entity` is somewhere inside `ancestor`
+    fn is_within(&self, world: &GameWorld, entity: EcsEntity, ancestor: EcsEntity) -> bool {
+        self.get_items_in_container(world, ancestor)
+            .into_iter()
+            .any(|item| item == entity || self.is_within(world, entity, item))
+    }
+
+    /// Check whether a holder can take an item without exceeding its Container limits
+    fn check_limits(&self, world: &GameWorld, holder: EcsEntity, item: EcsEntity) -> Option<Limit> {
+        let container = world.get::<&Container>(holder).ok()?;
+
+        if let Some(capacity) = container.capacity {
+            if self.get_item_count(world, holder) >= capacity as usize {
+                return Some(Limit::Count);
             }
         }
-        total_weight
-    }
 
-    /// Get the number of items in a container
-    pub fn get_item_count(&self, world: &GameWorld, _entity: EcsEntity) -> usize {
-        // Count items with nil location (in inventory)
-        let mut count = 0;
-        for loc in world.query::<&Location>().iter() {
-            if loc.area_id.uuid() == uuid::Uuid::nil() && loc.room_id.uuid() == uuid::Uuid::nil() {
-                count += 1;
+        if let Some(max_weight) = container.max_weight {
+            let weight = world
+                .get::<&Containable>(item)
+                .map(|c| c.weight)
+                .unwrap_or(0.0)
+                + self.get_total_weight(world, item);
+            if self.get_total_weight(world, holder) + weight > max_weight {
+                return Some(Limit::Weight);
             }
         }
-        count
+
+        None
+    }
+
+    /// Check that an entity carries an item and is free to let go of it
+    fn check_releasable(
+        &self,
+        world: &GameWorld,
+        entity: EcsEntity,
+        item: EcsEntity,
+    ) -> Result<(), String> {
+        if !self.has_item(world, entity, item) {
+            return Err("Item is not in inventory".to_string());
+        }
+        if self.equipped_slot(world, entity, item).is_some() {
+            return Err("Item is equipped".to_string());
+        }
+        Ok(())
+    }
+
+    /// Check that a container exists and is open
+    fn check_open(&self, world: &GameWorld, container: EcsEntity) -> Result<(), String> {
+        let container = world
+            .get::<&Container>(container)
+            .map_err(|_| "Target is not a container")?;
+        if container.closed {
+            return Err("Container is closed".to_string());
+        }
+        Ok(())
+    }
+
+    /// Link an item to a new holder, taking it out of the room
+    fn move_into(
+        &self,
+        world: &mut GameWorld,
+        holder: EcsEntity,
+        item: EcsEntity,
+    ) -> Result<(), String> {
+        let holder_id = Self::holder_id(world, holder);
+        let _ = world.remove_one::<Location>(item);
+        world
+            .insert_one(item, ContainedBy::new(holder_id))
+            .map_err(|e| format!("Failed to move item: {}", e))
     }
 }
 
@@ -240,7 +455,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Requires parent/owner tracking system to be implemented"]
     fn test_transfer() {
         let mut world = GameWorld::new();
         let event_bus = EventBus::new();
@@ -376,4 +590,114 @@ mod tests {
 
         assert!(system.pickup_item(&mut world, player, heavy_item).is_err());
     }
+
+    #[test]
+    fn test_store_and_retrieve() {
+        let mut world = GameWorld::new();
+        let event_bus = EventBus::new();
+        let mut system = InventorySystem::new(event_bus);
+
+        let player = world.spawn((Name::new("Player"), EntityUuid::new()));
+        let bag = world.spawn((Name::new("Bag"), EntityUuid::new(), Container::new(Some(1))));
+        let sword = world.spawn((Name::new("Sword"), EntityUuid::new(), Containable::new(5.0)));
+        let dagger = world.spawn((
+            Name::new("Dagger"),
+            EntityUuid::new(),
+            Containable::new(1.0),
+        ));
+
+        for item in [bag, sword, dagger] {
+            assert!(system.pickup_item(&mut world, player, item).is_ok());
+        }
+
+        assert!(system.store_item(&mut world, player, sword, bag).is_ok());
+        assert!(system.has_item(&world, bag, sword));
+        assert!(!system.has_item(&world, player, sword));
+        assert_eq!(system.get_total_weight(&world, player), 6.0);
+
+        // Bag only holds one item, and cannot hold itself
+        assert!(system.store_item(&mut world, player, dagger, bag).is_err());
+        assert!(system.store_item(&mut world, player, bag, bag).is_err());
+
+        world.get::<&mut Container>(bag).unwrap().closed = true;
+        assert!(
+            system
+                .retrieve_item(&mut world, player, sword, bag)
+                .is_err()
+        );
+        world.get::<&mut Container>(bag).unwrap().closed = false;
+
+        assert!(system.retrieve_item(&mut world, player, sword, bag).is_ok());
+        assert!(system.has_item(&world, player, sword));
+        assert_eq!(system.get_item_count(&world, bag), 0);
+    }
+
+    #[test]
+    fn test_equip_validates_slots() {
+        let mut world = GameWorld::new();
+        let event_bus = EventBus::new();
+        let mut system = InventorySystem::new(event_bus);
+
+        let player = world.spawn((
+            Name::new("Player"),
+            EntityUuid::new(),
+            Location::new(
+                EntityId::from_uuid(uuid::Uuid::new_v4()),
+                EntityId::from_uuid(uuid::Uuid::new_v4()),
+            ),
+        ));
+        let helmet = world.spawn((
+            Name::new("Helmet"),
+            EntityUuid::new(),
+            Containable::new(2.0),
+            Equipable::new(vec![EquipSlot::Head]),
+        ));
+        let cap = world.spawn((
+            Name::new("Cap"),
+            EntityUuid::new(),
+            Containable::new(0.5),
+            Equipable::new(vec![EquipSlot::Head]),
+        ));
+        let rock = world.spawn((Name::new("Rock"), EntityUuid::new(), Containable::new(1.0)));
+
+        // Items must be carried before they can be equipped
+        assert!(
+            system
+                .equip_item(&mut world, player, helmet, &[EquipSlot::Head])
+                .is_err()
+        );
+
+        for item in [helmet, cap, rock] {
+            assert!(system.pickup_item(&mut world, player, item).is_ok());
+        }
+
+        assert!(
+            system
+                .equip_item(&mut world, player, helmet, &[EquipSlot::MainHand])
+                .is_err()
+        );
+        assert!(
+            system
+                .equip_item(&mut world, player, rock, &[EquipSlot::Head])
+                .is_err()
+        );
+        assert_eq!(
+            system.equip_item(&mut world, player, helmet, &[EquipSlot::Head]),
+            Ok(EquipSlot::Head)
+        );
+        assert!(
+            system
+                .equip_item(&mut world, player, cap, &[EquipSlot::Head])
+                .is_err()
+        );
+
+        // Equipped items cannot be dropped until removed
+        assert!(system.drop_item(&mut world, player, helmet).is_err());
+        assert_eq!(
+            system.unequip_item(&mut world, player, helmet),
+            Ok(EquipSlot::Head)
+        );
+        assert!(system.equipped_slot(&world, player, helmet).is_none());
+        assert!(system.drop_item(&mut world, player, helmet).is_ok());
+    }
 }
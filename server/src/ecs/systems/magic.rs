@@ -0,0 +1,501 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Spell casting
+//!
+//! Casting spends the spell's energy from the caster's soul, then checks the
+//! spell's school skill against its difficulty. A failed check fizzles; a
+//! successful one applies the spell's effect, and a critical success
+//! strengthens it by [`CRITICAL_SPELL_FACTOR`]. In combat the caster cannot
+//! act again until the spell's cast time has passed.
+//!
+//! Talents: Channeler adds to every school check (see
+//! [`crate::ecs::systems::check_modifiers`]), Spellweaver adds
+//! [`SPELLWEAVER_BONUS`], and Elemental Affinity strengthens fire and acid
+//! damage by [`ELEMENTAL_AFFINITY_BONUS`].
+
+use crate::ecs::components::{
+    AttributeScores, BodyAttributeScores, Combatant, DamageType, EntityId, EntityUuid,
+    SoulAttributeScores, Spell, SpellEffect, StatusEffect, StatusEffectType, StatusEffects, Talent,
+    Talents,
+};
+use crate::ecs::systems::{
+    CheckResult, CombatSystem, SkillCheck, SkillCheckResolver, status_duration,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use rand::Rng;
+
+/// Multiplier applied to a spell's effect on a critical success
+pub const CRITICAL_SPELL_FACTOR: f32 = 1.5;
+
+/// Casting check bonus from the Spellweaver talent
+pub const SPELLWEAVER_BONUS: i32 = 2;
+
+/// Extra fire and acid damage from the Elemental Affinity talent
+pub const ELEMENTAL_AFFINITY_BONUS: f32 = 0.25;
+
+/// What a successful spell did to its target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppliedEffect {
+    Damage {
+        amount: i32,
+        damage_type: DamageType,
+    },
+    Heal {
+        amount: i32,
+    },
+    Status {
+        effect_type: StatusEffectType,
+        duration: f32,
+    },
+    Cure {
+        effect_type: StatusEffectType,
+        /// Whether the target had the effect to be cured of
+        cured: bool,
+    },
+}
+
+/// Result of casting a spell
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastOutcome {
+    /// The school skill check
+    pub check: CheckResult,
+    pub energy_spent: f32,
+    /// The effect applied, or `None` if the spell fizzled
+    pub effect: Option<AppliedEffect>,
+    /// Whether the spell killed its target
+    pub target_died: bool,
+}
+
+/// Cast a spell from `caster` at `target`
+///
+/// Fails without spending energy if the caster is stunned, still recovering
+/// from a previous action in combat or short of energy, or if the spell
+/// cannot affect the target. Status effects are applied and cured through
+/// the combat system, which announces them.
+pub fn cast_spell<R: Rng>(
+    world: &mut GameWorld,
+    combat: &mut CombatSystem,
+    resolver: &mut SkillCheckResolver<R>,
+    caster: EcsEntity,
+    target: EcsEntity,
+    spell: &Spell,
+) -> Result<CastOutcome, String> {
+    if world
+        .get::<&StatusEffects>(caster)
+        .is_ok_and(|effects| effects.has_effect(StatusEffectType::Stunned))
+    {
+        return Err("You are stunned!".to_string());
+    }
+    if !can_affect(world, target, &spell.effect) {
+        return Err(format!("{} can't affect that.", spell.name));
+    }
+    if world
+        .get::<&Combatant>(caster)
+        .is_ok_and(|combatant| combatant.in_combat && !combatant.can_attack())
+    {
+        return Err("You are not ready to cast again yet.".to_string());
+    }
+    if energy_of(world, caster) < spell.energy_cost {
+        return Err(format!(
+            "You don't have enough energy to cast {}.",
+            spell.name
+        ));
+    }
+    spend_energy(world, caster, spell.energy_cost);
+
+    let talents = world.get::<&Talents>(caster).ok();
+    let has_talent = |talent| talents.as_ref().is_some_and(|t| t.has_talent(talent));
+    let mut check = SkillCheck::for_skill(spell.school).against(spell.difficulty);
+    if has_talent(Talent::Spellweaver) {
+        check = check.with_modifier(Talent::Spellweaver.name(), SPELLWEAVER_BONUS);
+    }
+    let affinity = has_talent(Talent::ElementalAffinity);
+    drop(talents);
+
+    let check = resolver.resolve(world, caster, &check);
+
+    // The caster can't act again until the spell is finished
+    if let Ok(mut combatant) = world.get::<&mut Combatant>(caster) {
+        combatant.time_since_action = combatant.action_cooldown - spell.cast_time;
+    }
+
+    if !check.outcome.is_success() {
+        return Ok(CastOutcome {
+            check,
+            energy_spent: spell.energy_cost,
+            effect: None,
+            target_died: false,
+        });
+    }
+
+    let scale = if check.outcome.is_critical() {
+        CRITICAL_SPELL_FACTOR
+    } else {
+        1.0
+    };
+    let mut target_died = false;
+    let effect = match &spell.effect {
+        SpellEffect::Damage {
+            damage_type,
+            min,
+            max,
+        } => {
+            let mut amount = resolver.roll_between(*min, *max) as f32 * scale;
+            if affinity && matches!(damage_type, DamageType::Fire | DamageType::Acid) {
+                amount *= 1.0 + ELEMENTAL_AFFINITY_BONUS;
+            }
+            let amount = (amount.round() as i32).max(1);
+            target_died = change_health(world, target, -(amount as f32));
+            AppliedEffect::Damage {
+                amount,
+                damage_type: *damage_type,
+            }
+        }
+        SpellEffect::Heal { min, max } => {
+            let amount = ((resolver.roll_between(*min, *max) as f32 * scale).round() as i32).max(1);
+            change_health(world, target, amount as f32);
+            AppliedEffect::Heal { amount }
+        }
+        SpellEffect::Status {
+            effect_type,
+            duration,
+            magnitude,
+        } => {
+            let mut effect = StatusEffect::new(*effect_type, duration * scale, *magnitude);
+            if let Ok(uuid) = world.get::<&EntityUuid>(caster) {
+                effect = effect.with_source(EntityId::new(caster, uuid.0));
+            }
+            let duration = status_duration(world, target, *effect_type, effect.duration);
+            combat.apply_status_effect(world, target, effect);
+            AppliedEffect::Status {
+                effect_type: *effect_type,
+                duration,
+            }
+        }
+        SpellEffect::Cure { effect_type } => {
+            let cured = combat.cure(world, target, *effect_type);
+            AppliedEffect::Cure {
+                effect_type: *effect_type,
+                cured,
+            }
+        }
+    };
+
+    Ok(CastOutcome {
+        check,
+        energy_spent: spell.energy_cost,
+        effect: Some(effect),
+        target_died,
+    })
+}
+
+/// Current soul energy, falling back to bare scores for simple creatures
+pub fn energy_of(world: &GameWorld, entity: EcsEntity) -> f32 {
+    world
+        .get::<&SoulAttributeScores>(entity)
+        .map(|scores| scores.0.energy_current)
+        .or_else(|_| {
+            world
+                .get::<&AttributeScores>(entity)
+                .map(|scores| scores.energy_current)
+        })
+        .unwrap_or(0.0)
+}
+
+fn spend_energy(world: &GameWorld, entity: EcsEntity, amount: f32) {
+    if let Ok(mut scores) = world.get::<&mut SoulAttributeScores>(entity) {
+        scores.0.energy_current = (scores.0.energy_current - amount).max(0.0);
+    } else if let Ok(mut scores) = world.get::<&mut AttributeScores>(entity) {
+        scores.energy_current = (scores.energy_current - amount).max(0.0);
+    }
+}
+
+/// Check whether a spell effect has anything to work on in the target
+pub fn can_affect(world: &GameWorld, target: EcsEntity, effect: &SpellEffect) -> bool {
+    match effect {
+        SpellEffect::Damage { .. } | SpellEffect::Heal { .. } => {
+            world.get::<&BodyAttributeScores>(target).is_ok()
+                || world.get::<&AttributeScores>(target).is_ok()
+        }
+        SpellEffect::Status { .. } | SpellEffect::Cure { .. } => {
+            world.get::<&StatusEffects>(target).is_ok()
+        }
+    }
+}
+
+/// Change body health, capped at the maximum; returns whether this killed it
+fn change_health(world: &GameWorld, entity: EcsEntity, amount: f32) -> bool {
+    let apply = |scores: &mut AttributeScores| {
+        let was_alive = scores.health_current > 0.0;
+        let healed_cap = scores.health_maximum.max(scores.health_current);
+        scores.health_current = (scores.health_current + amount).clamp(0.0, healed_cap);
+        was_alive && scores.health_current <= 0.0
+    };
+    if let Ok(mut scores) = world.get::<&mut BodyAttributeScores>(entity) {
+        apply(&mut scores.0)
+    } else if let Ok(mut scores) = world.get::<&mut AttributeScores>(entity) {
+        apply(&mut scores)
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Skill, SpellTarget};
+    use crate::ecs::events::{EventBus, GameEvent};
+    use crate::ecs::systems::CheckOutcome;
+    use std::sync::{Arc, Mutex};
+
+    fn spell(effect: SpellEffect, difficulty: i32) -> Spell {
+        Spell {
+            name: "Test".to_string(),
+            school: Skill::Evocation,
+            description: String::new(),
+            energy_cost: 10.0,
+            cast_time: 2.0,
+            difficulty,
+            target: SpellTarget::Enemy,
+            effect,
+        }
+    }
+
+    fn firebolt(difficulty: i32) -> Spell {
+        spell(
+            SpellEffect::Damage {
+                damage_type: DamageType::Fire,
+                min: 5,
+                max: 5,
+            },
+            difficulty,
+        )
+    }
+
+    #[test]
+    fn test_cast_spends_energy_and_damages() {
+        let mut world = GameWorld::new();
+        let caster = world.spawn((SoulAttributeScores::new(), Combatant::new()));
+        let target = world.spawn((AttributeScores::new(),));
+        let mut combat = CombatSystem::new(EventBus::new());
+        let mut resolver = SkillCheckResolver::seeded(3);
+
+        // Seed 3 rolls a 13, far past this difficulty: a critical success
+        let outcome = cast_spell(
+            &mut world,
+            &mut combat,
+            &mut resolver,
+            caster,
+            target,
+            &firebolt(-100),
+        )
+        .unwrap();
+        assert_eq!(energy_of(&world, caster), 90.0);
+        assert_eq!(outcome.energy_spent, 10.0);
+        assert_eq!(outcome.check.roll, 13);
+        assert_eq!(outcome.check.outcome, CheckOutcome::CriticalSuccess);
+        let Some(AppliedEffect::Damage { amount, .. }) = outcome.effect else {
+            panic!("expected damage");
+        };
+        assert_eq!(amount, 8);
+        assert_eq!(
+            world
+                .get::<&AttributeScores>(target)
+                .unwrap()
+                .health_current,
+            92.0
+        );
+        let combatant = world.get::<&Combatant>(caster).unwrap();
+        assert_eq!(combatant.time_since_action, combatant.action_cooldown - 2.0);
+    }
+
+    #[test]
+    fn test_cast_refused_without_energy_or_while_recovering() {
+        let mut world = GameWorld::new();
+        let mut drained = SoulAttributeScores::new();
+        drained.0.energy_current = 5.0;
+        let tired = world.spawn((drained,));
+        let target = world.spawn((AttributeScores::new(),));
+        let mut combat = CombatSystem::new(EventBus::new());
+        let mut resolver = SkillCheckResolver::seeded(1);
+        assert!(
+            cast_spell(
+                &mut world,
+                &mut combat,
+                &mut resolver,
+                tired,
+                target,
+                &firebolt(0)
+            )
+            .is_err()
+        );
+        assert_eq!(energy_of(&world, tired), 5.0);
+
+        let mut combatant = Combatant::new();
+        combatant.in_combat = true;
+        combatant.reset_timer();
+        let busy = world.spawn((SoulAttributeScores::new(), combatant));
+        assert!(
+            cast_spell(
+                &mut world,
+                &mut combat,
+                &mut resolver,
+                busy,
+                target,
+                &firebolt(0)
+            )
+            .is_err()
+        );
+        assert_eq!(energy_of(&world, busy), 100.0);
+
+        // Nothing to hold a status effect
+        let caster = world.spawn((SoulAttributeScores::new(),));
+        let hex = spell(
+            SpellEffect::Status {
+                effect_type: StatusEffectType::Weakened,
+                duration: 10.0,
+                magnitude: 1,
+            },
+            0,
+        );
+        assert!(cast_spell(&mut world, &mut combat, &mut resolver, caster, target, &hex).is_err());
+        assert_eq!(energy_of(&world, caster), 100.0);
+    }
+
+    #[test]
+    fn test_failed_check_fizzles_and_heal_caps_at_maximum() {
+        let mut world = GameWorld::new();
+        let caster = world.spawn((SoulAttributeScores::new(),));
+        let mut wounded = BodyAttributeScores::new();
+        wounded.0.health_current = 95.0;
+        let patient = world.spawn((wounded, StatusEffects::new()));
+        let event_bus = EventBus::new();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        event_bus.subscribe(move |event| sink.lock().unwrap().push(event.clone()));
+        let mut combat = CombatSystem::new(event_bus.clone());
+        // Seed 0 rolls 17, 15, 12 and 16
+        let mut resolver = SkillCheckResolver::seeded(0);
+
+        // Only a natural 20 beats this
+        let outcome = cast_spell(
+            &mut world,
+            &mut combat,
+            &mut resolver,
+            caster,
+            patient,
+            &firebolt(1_000),
+        )
+        .unwrap();
+        assert_eq!(outcome.check.roll, 17);
+        assert_eq!(outcome.check.outcome, CheckOutcome::CriticalFailure);
+        assert!(outcome.effect.is_none());
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(patient)
+                .unwrap()
+                .0
+                .health_current,
+            95.0
+        );
+
+        let heal = spell(SpellEffect::Heal { min: 20, max: 20 }, -100);
+        let outcome = cast_spell(
+            &mut world,
+            &mut combat,
+            &mut resolver,
+            caster,
+            patient,
+            &heal,
+        )
+        .unwrap();
+        assert_eq!(outcome.check.outcome, CheckOutcome::CriticalSuccess);
+        assert_eq!(outcome.effect, Some(AppliedEffect::Heal { amount: 30 }));
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(patient)
+                .unwrap()
+                .0
+                .health_current,
+            100.0
+        );
+
+        let slow = spell(
+            SpellEffect::Status {
+                effect_type: StatusEffectType::Slowed,
+                duration: 10.0,
+                magnitude: 2,
+            },
+            -100,
+        );
+        let outcome = cast_spell(
+            &mut world,
+            &mut combat,
+            &mut resolver,
+            caster,
+            patient,
+            &slow,
+        )
+        .unwrap();
+        assert_eq!(outcome.check.outcome, CheckOutcome::CriticalSuccess);
+        assert!(
+            world
+                .get::<&StatusEffects>(patient)
+                .unwrap()
+                .has_effect(StatusEffectType::Slowed)
+        );
+        event_bus.process_events();
+        assert!(events.lock().unwrap().iter().any(|event| matches!(
+            event,
+            GameEvent::StatusEffectStarted {
+                entity,
+                effect_type: StatusEffectType::Slowed,
+            } if *entity == patient
+        )));
+
+        let cure = spell(
+            SpellEffect::Cure {
+                effect_type: StatusEffectType::Slowed,
+            },
+            -100,
+        );
+        let outcome = cast_spell(
+            &mut world,
+            &mut combat,
+            &mut resolver,
+            caster,
+            patient,
+            &cure,
+        )
+        .unwrap();
+        assert_eq!(outcome.check.outcome, CheckOutcome::CriticalSuccess);
+        assert!(
+            !world
+                .get::<&StatusEffects>(patient)
+                .unwrap()
+                .has_effect(StatusEffectType::Slowed)
+        );
+        event_bus.process_events();
+        assert!(events.lock().unwrap().iter().any(|event| matches!(
+            event,
+            GameEvent::StatusEffectEnded {
+                entity,
+                effect_type: StatusEffectType::Slowed,
+            } if *entity == patient
+        )));
+    }
+}
@@ -15,23 +15,86 @@
 //
 
 //! NPC AI system integrating GOAP planning with behavior execution
+//!
+//! NPCs whose [`GoapPlanner`] has goals [`sense`] the world into its state each
+//! update, plan toward their most pressing goal and carry the plan out through
+//! the [`ActionLibrary`], one action at a time and over as many updates as each
+//! action needs. The plan is dropped and made afresh whenever the action in
+//! hand stops being possible, such as a followed target vanishing.
+//!
+//! NPCs with an [`NpcSchedule`] switch goals or head for a room as each block
+//! of their day starts on the in-game clock, see [`follow_schedule`].
+//!
+//! Other NPCs follow their [`AIController`] state:
+//!
+//! | State     | Behavior                                                        |
+//! |-----------|-----------------------------------------------------------------|
+//! | Idle      | Wanderers now and then set off for a neighbouring room in their area |
+//! | Moving    | Walk toward the room in `state_target_id`, idling on arrival    |
+//! | Combat    | Fight until combat ends, then idle                              |
+//! | Fleeing   | Try to escape the fight, then run through a random exit         |
+//! | Following | Walk toward wherever `state_target_id` is                       |
+//! | Dialogue  | Wait while the partner in `state_target_id` stays in the room   |
+//!
+//! Aggressive NPCs attack any player they see, and NPCs that don't fight to
+//! the death flee once their health drops below [`FLEE_HEALTH_FRACTION`].
+//! NPCs only walk through exits that are neither closed nor locked. Every
+//! change of state or room is saved with the NPC.
 
 use crate::ecs::components::*;
 use crate::ecs::context::WorldContext;
-use crate::models::{LLMMessage, LLMRequest, ModelManager};
+use crate::ecs::events::{EventBus, GameEvent};
+use crate::ecs::output::players_in_room;
+use crate::ecs::systems::{
+    ActionHandler, ActionLibrary, ActionStatus, CombatSystem, TravelAction, body_scores,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use crate::models::{LLMError, LLMMessage, LLMRequest, ModelManager};
 use hecs::Entity;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 use tracing::instrument;
+use uuid::Uuid;
+
+/// Fraction of maximum health below which NPCs that don't fight to the death flee
+pub const FLEE_HEALTH_FRACTION: f32 = 0.25;
+
+/// Chance that an idle wanderer sets off each time it updates
+pub const WANDER_CHANCE: f32 = 0.25;
+
+/// Most rooms an NPC will walk through to reach a destination
+pub const MAX_PATH_LENGTH: usize = 20;
+
+/// Fraction of maximum health or energy below which a GOAP NPC feels tired
+pub const TIRED_FRACTION: f32 = 0.5;
+
+/// Goal a GOAP NPC is given to reach the room of its current schedule block
+pub const SCHEDULE_TRAVEL_GOAL: &str = "scheduled_travel";
+
+/// Priority of [`SCHEDULE_TRAVEL_GOAL`], so the NPC gets there before anything else
+pub const SCHEDULE_TRAVEL_PRIORITY: i32 = 100;
 
 /// NPC AI system for updating NPC behavior
 pub struct NpcAiSystem {
     llm_manager: Arc<ModelManager>,
+    actions: ActionLibrary,
 }
 
 impl NpcAiSystem {
     /// Create a new NPC AI system
     pub fn new(llm_manager: Arc<ModelManager>) -> Self {
-        Self { llm_manager }
+        Self {
+            llm_manager,
+            actions: ActionLibrary::new(),
+        }
+    }
+
+    /// Replace the actions GOAP NPCs know how to carry out
+    pub fn with_actions(mut self, actions: ActionLibrary) -> Self {
+        self.actions = actions;
+        self
     }
 
     /// Update all NPCs
@@ -62,96 +125,295 @@ impl NpcAiSystem {
 
     /// Update a single NPC
     async fn update_npc(&self, context: Arc<WorldContext>, entity: hecs::Entity) {
-        let mut world = context.entities().write().await;
+        let hour = context.scheduler().clock().now().hour;
+        let scheduled = follow_schedule(&mut *context.entities().write().await, entity, hour);
 
-        // Get NPC components
-        let has_goap = world.get::<&GoapPlanner>(entity).is_ok();
+        // NPCs plan with GOAP once they have been given goals
+        let has_goap = context
+            .entities()
+            .read()
+            .await
+            .get::<&GoapPlanner>(entity)
+            .is_ok_and(|planner| !planner.goals.is_empty());
 
-        if has_goap {
-            // Use GOAP planning
-            self.update_with_goap(&mut world, entity).await;
+        let changed = if has_goap {
+            self.update_with_goap(context.clone(), entity).await
         } else {
             // Use traditional AI controller
-            self.update_traditional(&mut world, entity).await;
-        }
+            let mut world = context.entities().write().await;
+            self.update_traditional(&mut world, context.event_bus(), entity)
+        };
+
+        let mut world = context.entities().write().await;
 
         // Mark as updated
         if let Ok(mut ai_controller) = world.get::<&mut AIController>(entity) {
             ai_controller.mark_updated();
         }
+
+        let dirty = world
+            .get::<&EntityUuid>(entity)
+            .ok()
+            .filter(|_| changed || scheduled)
+            .map(|uuid| uuid.0);
+        drop(world);
+        if let Some(uuid) = dirty {
+            context.mark_dirty(uuid).await;
+        }
     }
 
     /// Update NPC using GOAP planning
-    async fn update_with_goap(&self, world: &mut hecs::World, entity: hecs::Entity) {
-        // Get GOAP planner
-        let mut planner = match world.get::<&mut GoapPlanner>(entity) {
-            Ok(p) => p,
-            Err(_) => return,
-        };
+    ///
+    /// Senses the world into the planner, abandons the plan when the action in
+    /// hand can no longer run, and carries out the current action through the
+    /// [`ActionLibrary`]. Actions the library has no handler for finish at once.
+    ///
+    /// Returns whether the NPC acted, so it can be saved.
+    async fn update_with_goap(&self, context: Arc<WorldContext>, entity: hecs::Entity) -> bool {
+        let action_id = {
+            let world = context.entities().write().await;
+            let facts = sense(&world, entity);
+            let Ok(mut planner) = world.get::<&mut GoapPlanner>(entity) else {
+                return false;
+            };
+            planner.world_state.extend(facts);
 
-        // Update planner to select goal and create plan
-        if !planner.update() {
-            tracing::debug!("NPC {:?}: No valid plan found", entity);
-            return;
-        }
+            // A scheduled trip ends on arrival, leaving the block's goal to pursue
+            if planner.get_state("at_destination") {
+                if let Some(goal) = planner
+                    .goals
+                    .iter_mut()
+                    .find(|goal| goal.id == SCHEDULE_TRAVEL_GOAL && goal.active)
+                {
+                    goal.active = false;
+                    planner.current_goal = None;
+                }
+            }
+
+            if let Some(current) = planner.current_action.clone() {
+                let runnable = planner
+                    .get_action(&current)
+                    .is_some_and(|action| action.preconditions_met(&planner.world_state));
+                if !runnable {
+                    tracing::debug!("NPC {:?}: Can no longer '{}', replanning", entity, current);
+                    planner.replan();
+                }
+            }
 
-        // Get next action
-        let action_id = match planner.next_action() {
-            Some(id) => id,
-            None => return,
+            if planner.current_action.is_none() {
+                // Update planner to select goal and create plan
+                if !planner.update() {
+                    tracing::debug!("NPC {:?}: No valid plan found", entity);
+                    return false;
+                }
+                match planner.next_action() {
+                    Some(next)
+                        if planner.get_action(&next).is_some_and(|action| {
+                            action.preconditions_met(&planner.world_state)
+                        }) =>
+                    {
+                        planner.current_action = Some(next);
+                    }
+                    Some(next) => {
+                        tracing::debug!("NPC {:?}: Can't '{}' yet, replanning", entity, next);
+                        planner.replan();
+                        return false;
+                    }
+                    None => return false,
+                }
+            }
+            planner.current_action.clone()
+        };
+        let Some(action_id) = action_id else {
+            return false;
         };
 
+        // Commands lock the world themselves, so it must be free while the action runs
         tracing::debug!("NPC {:?}: Executing action '{}'", entity, action_id);
+        let status = match self.actions.get(&action_id) {
+            Some(handler) => handler.execute(context.clone(), entity).await,
+            None => Ok(ActionStatus::Done),
+        };
 
-        // Execute the action (simplified - in a real implementation,
-        // you'd have action handlers)
-        if let Some(action) = planner.get_action(&action_id) {
-            // Apply action effects to world state
-            let mut new_state = planner.world_state.clone();
-            action.apply_effects(&mut new_state);
-            planner.world_state = new_state;
+        let world = context.entities().write().await;
+        let Ok(mut planner) = world.get::<&mut GoapPlanner>(entity) else {
+            return false;
+        };
+        match status {
+            Ok(ActionStatus::Running) => true,
+            Ok(ActionStatus::Done) => {
+                if let Some(action) = planner.get_action(&action_id).cloned() {
+                    action.apply_effects(&mut planner.world_state);
+                }
+                planner.current_action = None;
+                true
+            }
+            Err(e) => {
+                tracing::debug!("NPC {:?}: Action '{}' failed: {}", entity, action_id, e);
+                planner.replan();
+                false
+            }
         }
     }
 
     /// Update NPC using traditional AI controller
-    async fn update_traditional(&self, world: &mut hecs::World, entity: hecs::Entity) {
+    ///
+    /// Returns whether the NPC changed state or moved, so it can be saved.
+    fn update_traditional(
+        &self,
+        world: &mut GameWorld,
+        event_bus: &EventBus,
+        entity: hecs::Entity,
+    ) -> bool {
         let ai_controller = match world.get::<&AIController>(entity) {
             Ok(ai) => ai.clone(),
-            Err(_) => return,
+            Err(_) => return false,
+        };
+        let fighting = |world: &GameWorld| {
+            world
+                .get::<&Combatant>(entity)
+                .is_ok_and(|combatant| combatant.in_combat)
         };
+        let mut in_combat = fighting(world);
 
-        match ai_controller.state_type {
-            StateType::Idle => {
-                // Check if should start wandering, etc.
-                if ai_controller.behavior_type == BehaviorType::Wandering {
-                    // TODO: Implement wandering behavior
-                    tracing::debug!("NPC {:?}: Wandering", entity);
+        // Fights interrupt whatever the NPC was doing
+        let mut state = ai_controller.state_type;
+        let target = ai_controller.state_target_id;
+        if in_combat
+            && ai_controller.behavior_type != BehaviorType::Aggressive
+            && health_fraction(world, entity) < FLEE_HEALTH_FRACTION
+        {
+            state = StateType::Fleeing;
+        } else if in_combat && state != StateType::Fleeing {
+            state = StateType::Combat;
+        } else if !in_combat
+            && state != StateType::Fleeing
+            && ai_controller.behavior_type == BehaviorType::Aggressive
+        {
+            if let Some(victim) = visible_player(world, entity) {
+                tracing::debug!("NPC {:?}: Attacking {:?}", entity, victim);
+                CombatSystem::new(event_bus.clone()).start_combat(world, entity, victim);
+                in_combat = fighting(world);
+                if in_combat {
+                    state = StateType::Combat;
                 }
             }
-            StateType::Moving => {
-                // TODO: Continue movement
-                tracing::debug!("NPC {:?}: Moving", entity);
+        }
+
+        let mut moved = false;
+        let (state, target) = match state {
+            StateType::Idle => {
+                let wander = ai_controller.behavior_type == BehaviorType::Wandering
+                    && rand::rng().random::<f32>() < WANDER_CHANCE;
+                match wander.then(|| wander_exit(world, entity)).flatten() {
+                    Some(dest) => {
+                        tracing::debug!("NPC {:?}: Wandering", entity);
+                        (StateType::Moving, Some(EntityId::from_uuid(dest)))
+                    }
+                    None => (StateType::Idle, None),
+                }
             }
+            StateType::Moving => match target.zip(room_of(world, entity)) {
+                Some((dest, room)) if dest.uuid() != room => {
+                    match path_step(world, room, dest.uuid()) {
+                        Some((direction, next)) => {
+                            moved = step(world, event_bus, entity, &direction, next);
+                            let arrived = moved && next == dest.uuid();
+                            if arrived {
+                                (StateType::Idle, None)
+                            } else {
+                                (StateType::Moving, target)
+                            }
+                        }
+                        None => (StateType::Idle, None),
+                    }
+                }
+                _ => (StateType::Idle, None),
+            },
             StateType::Combat => {
-                // TODO: Combat behavior
-                tracing::debug!("NPC {:?}: In combat", entity);
+                if in_combat {
+                    let opponent = world
+                        .get::<&Combatant>(entity)
+                        .ok()
+                        .and_then(|combatant| combatant.target_id);
+                    (StateType::Combat, opponent)
+                } else {
+                    (StateType::Idle, None)
+                }
             }
             StateType::Fleeing => {
-                // TODO: Fleeing behavior
-                tracing::debug!("NPC {:?}: Fleeing", entity);
+                let escaped = !in_combat
+                    || CombatSystem::new(event_bus.clone())
+                        .flee(world, entity)
+                        .unwrap_or(true);
+                if escaped {
+                    let exit = room_of(world, entity)
+                        .map(|room| passable_exits(world, room))
+                        .and_then(|exits| exits.choose(&mut rand::rng()).cloned());
+                    if let Some((direction, dest)) = exit {
+                        tracing::debug!("NPC {:?}: Fleeing {}", entity, direction);
+                        moved = step(world, event_bus, entity, &direction, dest);
+                    }
+                    (StateType::Idle, None)
+                } else {
+                    (StateType::Fleeing, target)
+                }
             }
             StateType::Following => {
-                // TODO: Following behavior
-                tracing::debug!("NPC {:?}: Following", entity);
+                let leader = target.and_then(|target| resolve(world, target));
+                let rooms = leader
+                    .and_then(|leader| room_of(world, leader))
+                    .zip(room_of(world, entity));
+                match rooms {
+                    Some((there, here)) if there == here => (StateType::Following, target),
+                    Some((there, here)) => match path_step(world, here, there) {
+                        Some((direction, next)) => {
+                            moved = step(world, event_bus, entity, &direction, next);
+                            (StateType::Following, target)
+                        }
+                        None => (StateType::Idle, None),
+                    },
+                    None => (StateType::Idle, None),
+                }
             }
             StateType::Dialogue => {
-                // TODO: Dialogue behavior
-                tracing::debug!("NPC {:?}: In dialogue", entity);
+                let partner_here = target
+                    .and_then(|target| resolve(world, target))
+                    .and_then(|partner| room_of(world, partner))
+                    .is_some_and(|room| room_of(world, entity) == Some(room));
+                if partner_here {
+                    (StateType::Dialogue, target)
+                } else {
+                    (StateType::Idle, None)
+                }
+            }
+        };
+
+        let changed = state != ai_controller.state_type
+            || target.map(|target| target.uuid())
+                != ai_controller.state_target_id.map(|target| target.uuid());
+        if changed {
+            tracing::debug!(
+                "NPC {:?}: {} -> {}",
+                entity,
+                ai_controller.state_type.as_str(),
+                state.as_str()
+            );
+            if let Ok(mut ai) = world.get::<&mut AIController>(entity) {
+                ai.state_type = state;
+                ai.state_target_id = target;
             }
         }
+        changed || moved
     }
 
     /// Handle NPC dialogue using LLM
+    ///
+    /// Falls back to one of the NPC's canned responses when its LLM is
+    /// disabled or unavailable. Both sides of the exchange are kept in the
+    /// NPC's conversation with the speaker, trimmed to its history limit.
+    /// A `situation`, such as how a social check went, is added to the LLM's
+    /// instructions for this reply only.
     #[instrument(skip(self, context))]
     pub async fn handle_dialogue(
         &self,
@@ -159,82 +421,97 @@ impl NpcAiSystem {
         npc_entity: hecs::Entity,
         player_entity: hecs::Entity,
         message: String,
+        situation: Option<String>,
     ) -> Result<String, String> {
         // Get all data we need from the world
-        enum DialogueData {
-            Fallback(String),
-            LlmEnabled {
-                dialogue_config: NpcDialogue,
-                personality: Option<Personality>,
-                conversation: NpcConversation,
-                player_uuid: uuid::Uuid,
-                npc_uuid: uuid::Uuid,
-            },
-        }
-
-        let data = {
+        let (dialogue_config, personality, history, player_uuid, npc_uuid) = {
             let world = context.entities().read().await;
 
-            // Get dialogue config
             let dialogue_config = match world.get::<&NpcDialogue>(npc_entity) {
                 Ok(c) => (*c).clone(),
                 Err(_) => return Err("NPC has no dialogue configuration".to_string()),
             };
+            let personality = world
+                .get::<&Personality>(npc_entity)
+                .ok()
+                .map(|p| (*p).clone());
 
-            // Check if LLM is enabled
-            if !dialogue_config.llm_enabled {
-                let fallback = dialogue_config.get_fallback().unwrap_or("...").to_string();
-                DialogueData::Fallback(fallback)
-            } else {
-                // Get personality and conversation
-                let personality = world
-                    .get::<&Personality>(npc_entity)
-                    .ok()
-                    .map(|p| (*p).clone());
-                let conversation = match world.get::<&NpcConversation>(npc_entity) {
-                    Ok(c) => (*c).clone(),
-                    Err(_) => NpcConversation::new(),
-                };
-
-                // Get UUIDs
-                let player_uuid = match world.get::<&EntityId>(player_entity) {
-                    Ok(id) => id.uuid(),
-                    Err(_) => return Err("Player has no UUID".to_string()),
-                };
-
-                let npc_uuid = match world.get::<&EntityId>(npc_entity) {
-                    Ok(id) => id.uuid(),
-                    Err(_) => return Err("NPC has no UUID".to_string()),
-                };
-
-                DialogueData::LlmEnabled {
-                    dialogue_config,
+            let player_uuid = match world.get::<&EntityUuid>(player_entity) {
+                Ok(uuid) => uuid.0,
+                Err(_) => return Err("Player has no UUID".to_string()),
+            };
+            let npc_uuid = match world.get::<&EntityUuid>(npc_entity) {
+                Ok(uuid) => uuid.0,
+                Err(_) => return Err("NPC has no UUID".to_string()),
+            };
+
+            let history: Vec<ConversationMessage> = world
+                .get::<&NpcConversation>(npc_entity)
+                .map(|conv| {
+                    conv.get_recent(player_uuid, dialogue_config.history_limit)
+                        .into_iter()
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            (dialogue_config, personality, history, player_uuid, npc_uuid)
+        };
+
+        let reply = if dialogue_config.llm_enabled {
+            match self
+                .complete_dialogue(
+                    &dialogue_config,
                     personality,
-                    conversation,
-                    player_uuid,
+                    situation.as_deref(),
+                    &history,
                     npc_uuid,
+                    &message,
+                )
+                .await
+            {
+                Ok(reply) => reply,
+                Err(e) => {
+                    tracing::error!("LLM error: {}", e);
+                    dialogue_config
+                        .get_fallback()
+                        .unwrap_or("I'm having trouble thinking right now.")
+                        .to_string()
                 }
             }
+        } else {
+            dialogue_config.get_fallback().unwrap_or("...").to_string()
         };
 
-        // Handle based on data type
-        let (dialogue_config, personality, conversation, player_uuid, npc_uuid) = match data {
-            DialogueData::Fallback(msg) => return Ok(msg),
-            DialogueData::LlmEnabled {
-                dialogue_config,
-                personality,
-                conversation,
-                player_uuid,
-                npc_uuid,
-            } => (
-                dialogue_config,
-                personality,
-                conversation,
-                player_uuid,
-                npc_uuid,
-            ),
-        };
+        // Update conversation history
+        {
+            let mut world = context.entities().write().await;
+            if world.get::<&NpcConversation>(npc_entity).is_err() {
+                world
+                    .insert_one(npc_entity, NpcConversation::new())
+                    .map_err(|e| format!("Failed to add NpcConversation component: {}", e))?;
+            }
+            if let Ok(mut conv) = world.get::<&mut NpcConversation>(npc_entity) {
+                conv.add_message(player_uuid, player_uuid, message);
+                conv.add_message(player_uuid, npc_uuid, reply.clone());
+                conv.truncate(player_uuid, dialogue_config.history_limit);
+            }
+        }
+        context.mark_entity_dirty(npc_entity).await;
 
+        Ok(reply)
+    }
+
+    /// Ask the NPC's LLM for its reply to a message
+    async fn complete_dialogue(
+        &self,
+        dialogue_config: &NpcDialogue,
+        personality: Option<Personality>,
+        situation: Option<&str>,
+        history: &[ConversationMessage],
+        npc_uuid: Uuid,
+        message: &str,
+    ) -> Result<String, LLMError> {
         // Build LLM request
         let mut request = LLMRequest::new(&dialogue_config.llm_model)
             .with_temperature(dialogue_config.temperature)
@@ -248,10 +525,12 @@ impl NpcAiSystem {
                 p.background, p.speaking_style
             ));
         }
+        if let Some(situation) = situation {
+            system_prompt.push_str(&format!("\n\n{}", situation));
+        }
         request = request.with_message(LLMMessage::system(system_prompt));
 
         // Add conversation history
-        let history = conversation.get_recent(player_uuid, dialogue_config.history_limit);
         for msg in history {
             let role = if msg.speaker == npc_uuid {
                 LLMMessage::assistant(&msg.message)
@@ -262,7 +541,7 @@ impl NpcAiSystem {
         }
 
         // Add current message
-        request = request.with_message(LLMMessage::user(&message));
+        request = request.with_message(LLMMessage::user(message));
 
         // Send to LLM
         let response = if let Some(provider) = &dialogue_config.llm_provider {
@@ -272,31 +551,341 @@ impl NpcAiSystem {
         } else {
             self.llm_manager.complete(request).await
         };
+        response.map(|resp| resp.content)
+    }
+}
 
-        match response {
-            Ok(resp) => {
-                // Update conversation history
-                let world = context.entities().read().await;
-                if let Ok(mut conv) = world.get::<&mut NpcConversation>(npc_entity) {
-                    conv.add_message(player_uuid, player_uuid, message);
-                    conv.add_message(player_uuid, npc_uuid, resp.content.clone());
-                }
-                Ok(resp.content)
+/// Fraction of its maximum health an entity has left
+pub fn health_fraction(world: &GameWorld, entity: EcsEntity) -> f32 {
+    body_scores(world, entity)
+        .filter(|scores| scores.health_maximum > 0.0)
+        .map_or(1.0, |scores| scores.health_current / scores.health_maximum)
+}
+
+/// Put the block of an NPC's schedule for the hour into effect when it starts
+///
+/// Goals named in the schedule are active only during their own blocks. A
+/// block's room becomes the GOAP destination, reached through the `travel`
+/// action and the [`SCHEDULE_TRAVEL_GOAL`], or else a walk for NPCs without
+/// GOAP goals. Returns whether a new block started.
+pub fn follow_schedule(world: &mut GameWorld, entity: EcsEntity, hour: u32) -> bool {
+    let Ok(mut schedule) = world.get::<&mut NpcSchedule>(entity) else {
+        return false;
+    };
+    let Some(block) = schedule.block_at(hour).cloned() else {
+        return false;
+    };
+    if schedule.applied == Some(block.start_hour) {
+        return false;
+    }
+    schedule.applied = Some(block.start_hour);
+    let scheduled_goals: HashSet<String> = schedule.goals().map(String::from).collect();
+    drop(schedule);
+    tracing::debug!(
+        "NPC {:?}: Following schedule from {:02}:00",
+        entity,
+        block.start_hour
+    );
+
+    let mut planning = false;
+    if let Ok(mut planner) = world.get::<&mut GoapPlanner>(entity) {
+        planning = !planner.goals.is_empty();
+        for goal in planner
+            .goals
+            .iter_mut()
+            .filter(|goal| scheduled_goals.contains(&goal.id))
+        {
+            goal.active = block.goal.as_deref() == Some(goal.id.as_str());
+        }
+        if planning {
+            if block.room.is_some() && planner.get_action("travel").is_none() {
+                planner.add_action(TravelAction.definition());
             }
-            Err(e) => {
-                tracing::error!("LLM error: {}", e);
-                Ok(dialogue_config
-                    .get_fallback()
-                    .unwrap_or("I'm having trouble thinking right now.")
-                    .to_string())
+            match planner
+                .goals
+                .iter_mut()
+                .find(|goal| goal.id == SCHEDULE_TRAVEL_GOAL)
+            {
+                Some(goal) => goal.active = block.room.is_some(),
+                None if block.room.is_some() => planner.add_goal(
+                    GoapGoal::new(
+                        SCHEDULE_TRAVEL_GOAL,
+                        "Travel to the scheduled room",
+                        SCHEDULE_TRAVEL_PRIORITY,
+                    )
+                    .with_condition("at_destination", true),
+                ),
+                None => {}
+            }
+        }
+        planner.destination = block.room;
+        planner.current_goal = None;
+        planner.replan();
+    }
+    if !planning {
+        if let (Some(room), Ok(mut ai)) = (block.room, world.get::<&mut AIController>(entity)) {
+            if !matches!(ai.state_type, StateType::Combat | StateType::Fleeing) {
+                ai.state_type = StateType::Moving;
+                ai.state_target_id = Some(room);
+            }
+        }
+    }
+    true
+}
+
+/// Facts a GOAP NPC senses about itself and its surroundings
+///
+/// | Key               | True when                                                |
+/// |-------------------|----------------------------------------------------------|
+/// | `in_combat`       | The NPC is fighting                                      |
+/// | `health_low`      | Health is below [`FLEE_HEALTH_FRACTION`]                 |
+/// | `in_danger`       | The NPC is fighting with low health                      |
+/// | `is_safe`         | The NPC is not fighting                                  |
+/// | `is_idle`         | The NPC is not fighting                                  |
+/// | `is_hostile`      | The NPC is aggressive or already fighting                |
+/// | `has_target`      | The NPC's target is still in the world                   |
+/// | `near_target`     | The target is a character in the same room               |
+/// | `near_object`     | The target is an item lying in the same room             |
+/// | `is_tired`        | Health or energy is below [`TIRED_FRACTION`]             |
+/// | `is_rested`       | Health and energy are full                               |
+/// | `is_guard`        | The NPC has a guard post                                 |
+/// | `at_post`         | The NPC is standing at its guard post                    |
+/// | `on_patrol`       | The NPC has a patrol route                               |
+/// | `at_waypoint`     | The NPC is standing at the next room on its patrol route |
+/// | `has_destination` | The NPC has a room to travel to                          |
+/// | `at_destination`  | The NPC is standing in the room it travels to            |
+///
+/// The NPC's target is the entity in its `AIController` state target, or
+/// else the opponent it is fighting.
+pub fn sense(world: &GameWorld, entity: EcsEntity) -> WorldState {
+    let in_combat = world
+        .get::<&Combatant>(entity)
+        .is_ok_and(|combatant| combatant.in_combat);
+    let health_low = health_fraction(world, entity) < FLEE_HEALTH_FRACTION;
+    let aggressive = world
+        .get::<&AIController>(entity)
+        .is_ok_and(|ai| ai.behavior_type == BehaviorType::Aggressive);
+    let (tired, rested) = body_scores(world, entity)
+        .map(|scores| {
+            let fraction = |current: f32, maximum: f32| {
+                if maximum > 0.0 {
+                    current / maximum
+                } else {
+                    1.0
+                }
+            };
+            let health = fraction(scores.health_current, scores.health_maximum);
+            let energy = fraction(scores.energy_current, scores.energy_maximum);
+            (
+                health < TIRED_FRACTION || energy < TIRED_FRACTION,
+                health >= 1.0 && energy >= 1.0,
+            )
+        })
+        .unwrap_or((false, true));
+
+    let here = room_of(world, entity);
+    let target = npc_target(world, entity);
+    let target_here = target
+        .and_then(|target| room_of(world, target))
+        .is_some_and(|room| Some(room) == here);
+    let target_is_item = target.is_some_and(|target| world.get::<&Containable>(target).is_ok());
+
+    let (post, waypoint, destination) = world
+        .get::<&GoapPlanner>(entity)
+        .map(|planner| {
+            (
+                planner.guard_post.map(|post| post.uuid()),
+                planner.next_waypoint().map(|waypoint| waypoint.uuid()),
+                planner.destination.map(|destination| destination.uuid()),
+            )
+        })
+        .unwrap_or((None, None, None));
+
+    [
+        ("in_combat", in_combat),
+        ("health_low", health_low),
+        ("in_danger", in_combat && health_low),
+        ("is_safe", !in_combat),
+        ("is_idle", !in_combat),
+        ("is_hostile", aggressive || in_combat),
+        ("has_target", target.is_some()),
+        ("near_target", target_here && !target_is_item),
+        ("near_object", target_here && target_is_item),
+        ("is_tired", tired),
+        ("is_rested", rested),
+        ("is_guard", post.is_some()),
+        ("at_post", post.is_some_and(|post| Some(post) == here)),
+        ("on_patrol", waypoint.is_some()),
+        (
+            "at_waypoint",
+            waypoint.is_some_and(|waypoint| Some(waypoint) == here),
+        ),
+        ("has_destination", destination.is_some()),
+        (
+            "at_destination",
+            destination.is_some_and(|destination| Some(destination) == here),
+        ),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect()
+}
+
+/// The entity an NPC's actions are aimed at
+///
+/// This is the entity in its `AIController` state target, or else the
+/// opponent it is fighting.
+pub fn npc_target(world: &GameWorld, entity: EcsEntity) -> Option<EcsEntity> {
+    let state_target = world
+        .get::<&AIController>(entity)
+        .ok()
+        .and_then(|ai| ai.state_target_id);
+    let opponent = world
+        .get::<&Combatant>(entity)
+        .ok()
+        .filter(|combatant| combatant.in_combat)
+        .and_then(|combatant| combatant.target_id);
+    state_target
+        .or(opponent)
+        .and_then(|target| resolve(world, target))
+        .filter(|target| *target != entity)
+}
+
+/// UUID of the room an entity is standing in
+pub fn room_of(world: &GameWorld, entity: EcsEntity) -> Option<Uuid> {
+    world
+        .get::<&Location>(entity)
+        .ok()
+        .map(|location| location.room_id.uuid())
+}
+
+/// Find the live entity behind an `EntityId`, which may only carry a UUID
+pub fn resolve(world: &GameWorld, id: EntityId) -> Option<EcsEntity> {
+    if !id.needs_resolution() && world.contains(id.entity()) {
+        return Some(id.entity());
+    }
+    world
+        .query::<(Entity, &EntityUuid)>()
+        .iter()
+        .find(|(_, uuid)| uuid.0 == id.uuid())
+        .map(|(entity, _)| entity)
+}
+
+/// Find a room entity by its UUID
+fn find_room(world: &GameWorld, room_uuid: Uuid) -> Option<EcsEntity> {
+    world
+        .query::<(Entity, &EntityUuid, &Room)>()
+        .iter()
+        .find(|(_, uuid, _)| uuid.0 == room_uuid)
+        .map(|(room, _, _)| room)
+}
+
+fn area_of(world: &GameWorld, room_uuid: Uuid) -> Option<Uuid> {
+    find_room(world, room_uuid).and_then(|room| {
+        world
+            .get::<&Room>(room)
+            .ok()
+            .map(|room| room.area_id.uuid())
+    })
+}
+
+/// Exits of a room an NPC can walk through, with their destinations
+pub fn passable_exits(world: &GameWorld, room_uuid: Uuid) -> Vec<(String, Uuid)> {
+    find_room(world, room_uuid)
+        .and_then(|room| world.get::<&Exits>(room).ok())
+        .map(|exits| {
+            exits
+                .exits
+                .iter()
+                .filter(|exit| {
+                    !(exit.is_closeable() && exit.is_closed())
+                        && !(exit.is_lockable() && exit.is_locked())
+                })
+                .map(|exit| (exit.direction.clone(), exit.dest_id.uuid()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Pick a random neighbouring room in the same area as the NPC's current room
+pub fn wander_exit(world: &GameWorld, entity: EcsEntity) -> Option<Uuid> {
+    let room = room_of(world, entity)?;
+    let area = area_of(world, room);
+    let rooms: Vec<Uuid> = passable_exits(world, room)
+        .into_iter()
+        .map(|(_, dest)| dest)
+        .filter(|dest| *dest != room && area_of(world, *dest) == area)
+        .collect();
+    rooms.choose(&mut rand::rng()).copied()
+}
+
+/// First exit on the shortest passable path between two rooms
+///
+/// Gives up on destinations more than [`MAX_PATH_LENGTH`] rooms away.
+pub fn path_step(world: &GameWorld, from: Uuid, to: Uuid) -> Option<(String, Uuid)> {
+    let mut first_steps: HashMap<Uuid, (String, Uuid)> = HashMap::new();
+    let mut visited = HashSet::from([from]);
+    let mut queue = VecDeque::from([(from, 0)]);
+
+    while let Some((room, distance)) = queue.pop_front() {
+        if room == to {
+            return first_steps.remove(&room);
+        }
+        if distance >= MAX_PATH_LENGTH {
+            continue;
+        }
+        for (direction, dest) in passable_exits(world, room) {
+            if visited.insert(dest) {
+                let first = first_steps.get(&room).cloned().unwrap_or((direction, dest));
+                first_steps.insert(dest, first);
+                queue.push_back((dest, distance + 1));
             }
         }
     }
+    None
+}
+
+/// Move an NPC through an exit into a neighbouring room
+fn step(
+    world: &mut GameWorld,
+    event_bus: &EventBus,
+    entity: EcsEntity,
+    direction: &str,
+    dest: Uuid,
+) -> bool {
+    let dest_room = find_room(world, dest);
+    let area = area_of(world, dest);
+    let Ok(mut location) = world.get::<&mut Location>(entity) else {
+        return false;
+    };
+    let from = *location;
+    let room_id = dest_room.map_or(EntityId::from_uuid(dest), |room| EntityId::new(room, dest));
+    let area_id = area.map_or(from.area_id, EntityId::from_uuid);
+    *location = Location::new(area_id, room_id);
+    drop(location);
+
+    tracing::debug!("NPC {:?}: Moving {}", entity, direction);
+    event_bus.publish(GameEvent::EntityMoved {
+        entity,
+        from: (from.area_id.uuid(), from.room_id.uuid()),
+        to: (area_id.uuid(), dest),
+    });
+    true
+}
+
+/// A living player in the same room as an NPC
+fn visible_player(world: &GameWorld, entity: EcsEntity) -> Option<EcsEntity> {
+    let room = room_of(world, entity)?;
+    players_in_room(world, room).into_iter().find(|player| {
+        *player != entity
+            && body_scores(world, *player).is_some_and(|scores| scores.health_current > 0.0)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::persistence::PersistenceManager;
 
     #[tokio::test]
     async fn test_npc_ai_system_creation() {
@@ -306,4 +895,502 @@ mod tests {
         // System should be created successfully
         assert!(true);
     }
+
+    fn room(world: &mut GameWorld, uuid: Uuid, area: Uuid, exits: Vec<ExitData>) {
+        let mut room_exits = Exits::new();
+        for exit in exits {
+            room_exits = room_exits.add_exit(exit);
+        }
+        world.spawn((
+            EntityUuid(uuid),
+            Room::new(EntityId::from_uuid(area)),
+            room_exits,
+        ));
+    }
+
+    fn exit(direction: &str, dest: Uuid) -> ExitData {
+        ExitData::new(direction, EntityId::from_uuid(dest))
+    }
+
+    fn npc(world: &mut GameWorld, room: Uuid, behavior: BehaviorType) -> EcsEntity {
+        world.spawn((
+            EntityUuid::new(),
+            Name::new("a goblin"),
+            Npc::new(),
+            AIController::new(behavior),
+            Combatant::new(),
+            AttributeScores::new(),
+            Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room)),
+        ))
+    }
+
+    fn player(world: &mut GameWorld, room: Uuid) -> EcsEntity {
+        world.spawn((
+            EntityUuid::new(),
+            Name::new("Alice"),
+            Avatar::new(Uuid::new_v4()),
+            Combatant::new(),
+            AttributeScores::new(),
+            Location::new(EntityId::from_uuid(Uuid::nil()), EntityId::from_uuid(room)),
+        ))
+    }
+
+    fn state(world: &GameWorld, entity: EcsEntity) -> StateType {
+        world.get::<&AIController>(entity).unwrap().state_type
+    }
+
+    fn system() -> NpcAiSystem {
+        NpcAiSystem::new(Arc::new(ModelManager::new()))
+    }
+
+    #[test]
+    fn test_wanderer_stays_in_home_area() {
+        let mut world = GameWorld::new();
+        let (forest, road) = (Uuid::new_v4(), Uuid::new_v4());
+        let (glade, clearing, town, vault) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        room(
+            &mut world,
+            glade,
+            forest,
+            vec![
+                exit("North", clearing),
+                exit("South", town),
+                exit("Down", vault).with_lock(1, 1, "key").closed().locked(),
+            ],
+        );
+        room(&mut world, clearing, forest, vec![exit("South", glade)]);
+        room(&mut world, town, road, vec![exit("North", glade)]);
+        room(&mut world, vault, forest, vec![exit("Up", glade)]);
+        let goblin = npc(&mut world, glade, BehaviorType::Wandering);
+
+        let event_bus = EventBus::new();
+        let system = system();
+        let mut visited = HashSet::new();
+        for _ in 0..200 {
+            system.update_traditional(&mut world, &event_bus, goblin);
+            visited.insert(room_of(&world, goblin).unwrap());
+        }
+        assert_eq!(visited, HashSet::from([glade, clearing]));
+        assert_eq!(
+            world.get::<&Location>(goblin).unwrap().area_id.uuid(),
+            forest
+        );
+    }
+
+    #[test]
+    fn test_follower_tracks_target_across_rooms() {
+        let mut world = GameWorld::new();
+        let area = Uuid::new_v4();
+        let (hall, stairs, tower) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        room(&mut world, hall, area, vec![exit("East", stairs)]);
+        room(
+            &mut world,
+            stairs,
+            area,
+            vec![exit("West", hall), exit("Up", tower)],
+        );
+        room(&mut world, tower, area, vec![exit("Down", stairs)]);
+        let leader = player(&mut world, tower);
+        let leader_uuid = world.get::<&EntityUuid>(leader).unwrap().0;
+        let squire = npc(&mut world, hall, BehaviorType::Friendly);
+        {
+            let mut ai = world.get::<&mut AIController>(squire).unwrap();
+            ai.state_type = StateType::Following;
+            ai.state_target_id = Some(EntityId::from_uuid(leader_uuid));
+        }
+
+        let event_bus = EventBus::new();
+        let system = system();
+        assert!(system.update_traditional(&mut world, &event_bus, squire));
+        assert_eq!(room_of(&world, squire), Some(stairs));
+        assert!(system.update_traditional(&mut world, &event_bus, squire));
+        assert_eq!(room_of(&world, squire), Some(tower));
+        assert!(!system.update_traditional(&mut world, &event_bus, squire));
+        assert_eq!(state(&world, squire), StateType::Following);
+
+        // Losing the leader ends the chase
+        world.despawn(leader).unwrap();
+        assert!(system.update_traditional(&mut world, &event_bus, squire));
+        assert_eq!(state(&world, squire), StateType::Idle);
+    }
+
+    #[test]
+    fn test_aggressive_npc_attacks_players() {
+        let mut world = GameWorld::new();
+        let cave = Uuid::new_v4();
+        room(&mut world, cave, Uuid::new_v4(), Vec::new());
+        let goblin = npc(&mut world, cave, BehaviorType::Aggressive);
+
+        let event_bus = EventBus::new();
+        let system = system();
+        assert!(!system.update_traditional(&mut world, &event_bus, goblin));
+        assert_eq!(state(&world, goblin), StateType::Idle);
+
+        let alice = player(&mut world, cave);
+        assert!(system.update_traditional(&mut world, &event_bus, goblin));
+        assert_eq!(state(&world, goblin), StateType::Combat);
+        let target = world.get::<&AIController>(goblin).unwrap().state_target_id;
+        assert_eq!(target.map(|target| target.entity()), Some(alice));
+        assert!(world.get::<&Combatant>(alice).unwrap().in_combat);
+
+        // Back to idle once the fight is over
+        world.get::<&mut Combatant>(goblin).unwrap().in_combat = false;
+        world.despawn(alice).unwrap();
+        assert!(system.update_traditional(&mut world, &event_bus, goblin));
+        assert_eq!(state(&world, goblin), StateType::Idle);
+    }
+
+    #[test]
+    fn test_wounded_npc_flees() {
+        let mut world = GameWorld::new();
+        let area = Uuid::new_v4();
+        let (den, tunnel) = (Uuid::new_v4(), Uuid::new_v4());
+        room(&mut world, den, area, vec![exit("North", tunnel)]);
+        room(&mut world, tunnel, area, vec![exit("South", den)]);
+        let rat = npc(&mut world, den, BehaviorType::Passive);
+        let alice = player(&mut world, den);
+
+        let event_bus = EventBus::new();
+        let mut combat = CombatSystem::new(event_bus.clone());
+        combat.start_combat(&mut world, alice, rat);
+        let system = system();
+        assert!(system.update_traditional(&mut world, &event_bus, rat));
+        assert_eq!(state(&world, rat), StateType::Combat);
+
+        world
+            .get::<&mut AttributeScores>(rat)
+            .unwrap()
+            .health_current = 10.0;
+        for _ in 0..20 {
+            system.update_traditional(&mut world, &event_bus, rat);
+            if state(&world, rat) != StateType::Fleeing {
+                break;
+            }
+        }
+        assert_eq!(state(&world, rat), StateType::Idle);
+        assert_eq!(room_of(&world, rat), Some(tunnel));
+        assert!(!world.get::<&Combatant>(rat).unwrap().in_combat);
+    }
+
+    #[test]
+    fn test_loaded_characters_sensed_by_body_scores() {
+        let mut world = GameWorld::new();
+        let cave = Uuid::new_v4();
+        room(&mut world, cave, Uuid::new_v4(), Vec::new());
+        let goblin = npc(&mut world, cave, BehaviorType::Aggressive);
+        // Characters loaded from the database carry only body scores
+        let alice = player(&mut world, cave);
+        world.remove_one::<AttributeScores>(alice).unwrap();
+        world.insert_one(alice, BodyAttributeScores::new()).unwrap();
+
+        let event_bus = EventBus::new();
+        assert!(system().update_traditional(&mut world, &event_bus, goblin));
+        let target = world.get::<&AIController>(goblin).unwrap().state_target_id;
+        assert_eq!(target.map(|target| target.entity()), Some(alice));
+
+        let mut wounded = BodyAttributeScores::new();
+        wounded.0.health_current = 10.0;
+        world.remove_one::<AttributeScores>(goblin).unwrap();
+        world.insert_one(goblin, wounded).unwrap();
+        assert_eq!(health_fraction(&world, goblin), 0.1);
+        let facts = sense(&world, goblin);
+        assert_eq!(facts.get("health_low"), Some(&true));
+        assert_eq!(facts.get("is_tired"), Some(&true));
+        assert_eq!(facts.get("is_rested"), Some(&false));
+    }
+
+    #[test]
+    fn test_schedule_moves_shopkeeper_and_switches_goals() {
+        let mut world = GameWorld::new();
+        let area = Uuid::new_v4();
+        let (home, shop) = (Uuid::new_v4(), Uuid::new_v4());
+        room(&mut world, home, area, vec![exit("East", shop)]);
+        room(&mut world, shop, area, vec![exit("West", home)]);
+        let mut schedule = NpcSchedule::new();
+        schedule.block_mut(8).room = Some(EntityId::from_uuid(shop));
+        schedule.block_mut(20).room = Some(EntityId::from_uuid(home));
+        let keeper = npc(&mut world, home, BehaviorType::Passive);
+        world.insert_one(keeper, schedule).unwrap();
+
+        // Opening time sends the shopkeeper to the shop once
+        assert!(follow_schedule(&mut world, keeper, 9));
+        assert_eq!(state(&world, keeper), StateType::Moving);
+        system().update_traditional(&mut world, &EventBus::new(), keeper);
+        assert_eq!(room_of(&world, keeper), Some(shop));
+        assert!(!follow_schedule(&mut world, keeper, 12));
+        assert_eq!(state(&world, keeper), StateType::Idle);
+
+        // Blocks wrap past midnight
+        assert!(follow_schedule(&mut world, keeper, 2));
+        assert_eq!(
+            world.get::<&AIController>(keeper).unwrap().state_target_id,
+            Some(EntityId::from_uuid(home))
+        );
+
+        // Scheduled goals are only active in their own block
+        let mut schedule = NpcSchedule::new();
+        schedule.block_mut(6).goal = Some("work".to_string());
+        schedule.block_mut(18).goal = Some("sleep".to_string());
+        let mut goals = planner(GoapGoal::new("work", "Work", 5), &[]);
+        goals.add_goal(GoapGoal::new("sleep", "Sleep", 5));
+        goals.add_goal(GoapGoal::new("eat", "Eat", 1));
+        world.insert(keeper, (schedule, goals)).unwrap();
+        assert!(follow_schedule(&mut world, keeper, 19));
+        let planner = world.get::<&GoapPlanner>(keeper).unwrap();
+        let active = |id: &str| planner.goals.iter().any(|g| g.id == id && g.active);
+        assert!(!active("work"));
+        assert!(active("sleep"));
+        assert!(active("eat"));
+    }
+
+    fn goap_context() -> Arc<WorldContext> {
+        Arc::new(WorldContext::new(Arc::new(PersistenceManager::new_mock())))
+    }
+
+    fn planner(goal: GoapGoal, actions: &[&str]) -> GoapPlanner {
+        let library = ActionLibrary::new();
+        let mut planner = GoapPlanner::new();
+        for id in actions {
+            planner.add_action(library.get(id).unwrap().definition());
+        }
+        planner.add_goal(goal);
+        planner
+    }
+
+    async fn register(context: &WorldContext, entity: EcsEntity) {
+        let uuid = context
+            .entities()
+            .read()
+            .await
+            .get::<&EntityUuid>(entity)
+            .unwrap()
+            .0;
+        context.register_entity(entity, uuid).await;
+    }
+
+    /// Gate, yard and tower in a row, joined east to west
+    async fn keep(context: &WorldContext) -> (Uuid, Uuid, Uuid) {
+        let area = Uuid::new_v4();
+        let (gate, yard, tower) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut world = context.entities().write().await;
+        room(&mut world, gate, area, vec![exit("East", yard)]);
+        room(
+            &mut world,
+            yard,
+            area,
+            vec![exit("West", gate), exit("East", tower)],
+        );
+        room(&mut world, tower, area, vec![exit("West", yard)]);
+        (gate, yard, tower)
+    }
+
+    #[tokio::test]
+    async fn test_goap_guard_walks_to_post_over_several_updates() {
+        let context = goap_context();
+        let (gate, yard, tower) = keep(&context).await;
+        let mut guard_planner = planner(
+            GoapGoal::new("guard", "Guard the tower", 10).with_condition("at_post", true),
+            &["guard"],
+        );
+        guard_planner.guard_post = Some(EntityId::from_uuid(tower));
+        let guard = {
+            let mut world = context.entities().write().await;
+            let guard = npc(&mut world, gate, BehaviorType::Passive);
+            world.insert_one(guard, guard_planner).unwrap();
+            guard
+        };
+        register(&context, guard).await;
+        let system = system();
+
+        // One room per update, with the action kept in hand until it is done
+        assert!(system.update_with_goap(context.clone(), guard).await);
+        {
+            let world = context.entities().read().await;
+            assert_eq!(room_of(&world, guard), Some(yard));
+            let planner = world.get::<&GoapPlanner>(guard).unwrap();
+            assert_eq!(planner.current_action.as_deref(), Some("guard"));
+        }
+
+        assert!(system.update_with_goap(context.clone(), guard).await);
+        {
+            let world = context.entities().read().await;
+            assert_eq!(room_of(&world, guard), Some(tower));
+            let planner = world.get::<&GoapPlanner>(guard).unwrap();
+            assert!(planner.current_action.is_none());
+            assert!(planner.get_state("at_post"));
+        }
+
+        // Nothing left to do once at the post
+        assert!(!system.update_with_goap(context.clone(), guard).await);
+        let world = context.entities().read().await;
+        assert_eq!(room_of(&world, guard), Some(tower));
+    }
+
+    #[tokio::test]
+    async fn test_goap_schedule_travels_to_room() {
+        let context = goap_context();
+        let (gate, yard, tower) = keep(&context).await;
+        let mut schedule = NpcSchedule::new();
+        schedule.block_mut(8).room = Some(EntityId::from_uuid(tower));
+        schedule.block_mut(20).goal = Some("work".to_string());
+        // Work needs something no action provides, so only travel can be planned
+        let work = GoapGoal::new("work", "Work", 5).with_condition("at_work", true);
+        let clerk = {
+            let mut world = context.entities().write().await;
+            let clerk = npc(&mut world, gate, BehaviorType::Passive);
+            world.insert(clerk, (schedule, planner(work, &[]))).unwrap();
+            assert!(follow_schedule(&mut world, clerk, 9));
+            let planner = world.get::<&GoapPlanner>(clerk).unwrap();
+            assert!(planner.get_action("travel").is_some());
+            assert!(
+                planner
+                    .get_goal(SCHEDULE_TRAVEL_GOAL)
+                    .is_some_and(|goal| goal.active)
+            );
+            drop(planner);
+            clerk
+        };
+        register(&context, clerk).await;
+        let system = system();
+
+        assert!(system.update_with_goap(context.clone(), clerk).await);
+        assert_eq!(
+            room_of(&*context.entities().read().await, clerk),
+            Some(yard)
+        );
+        assert!(system.update_with_goap(context.clone(), clerk).await);
+        assert_eq!(
+            room_of(&*context.entities().read().await, clerk),
+            Some(tower)
+        );
+
+        // Arrival ends the trip
+        assert!(!system.update_with_goap(context.clone(), clerk).await);
+        {
+            let world = context.entities().read().await;
+            let planner = world.get::<&GoapPlanner>(clerk).unwrap();
+            assert!(!planner.get_goal(SCHEDULE_TRAVEL_GOAL).unwrap().active);
+        }
+
+        // A block without a room sends the NPC nowhere
+        let mut world = context.entities().write().await;
+        assert!(follow_schedule(&mut world, clerk, 21));
+        let planner = world.get::<&GoapPlanner>(clerk).unwrap();
+        assert!(planner.destination.is_none());
+        assert!(!planner.get_goal(SCHEDULE_TRAVEL_GOAL).unwrap().active);
+        assert_eq!(
+            planner
+                .goals
+                .iter()
+                .filter(|goal| goal.id == SCHEDULE_TRAVEL_GOAL)
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_goap_replans_when_target_vanishes() {
+        let context = goap_context();
+        let (gate, yard, tower) = keep(&context).await;
+        let (hound, alice) = {
+            let mut world = context.entities().write().await;
+            let alice = player(&mut world, tower);
+            let alice_uuid = world.get::<&EntityUuid>(alice).unwrap().0;
+            let hound = npc(&mut world, gate, BehaviorType::Passive);
+            world
+                .get::<&mut AIController>(hound)
+                .unwrap()
+                .state_target_id = Some(EntityId::new(alice, alice_uuid));
+            let follow = planner(
+                GoapGoal::new("heel", "Stay close", 10).with_condition("near_target", true),
+                &["follow"],
+            );
+            world.insert_one(hound, follow).unwrap();
+            (hound, alice)
+        };
+        register(&context, hound).await;
+        let system = system();
+
+        assert!(system.update_with_goap(context.clone(), hound).await);
+        {
+            let world = context.entities().read().await;
+            assert_eq!(room_of(&world, hound), Some(yard));
+            let planner = world.get::<&GoapPlanner>(hound).unwrap();
+            assert_eq!(planner.current_action.as_deref(), Some("follow"));
+        }
+
+        // With the target gone following is impossible, so the plan is dropped
+        context.entities().write().await.despawn(alice).unwrap();
+        assert!(!system.update_with_goap(context.clone(), hound).await);
+        let world = context.entities().read().await;
+        assert_eq!(room_of(&world, hound), Some(yard));
+        let planner = world.get::<&GoapPlanner>(hound).unwrap();
+        assert!(planner.current_action.is_none());
+        assert!(planner.current_plan.is_empty());
+        assert!(!planner.get_state("has_target"));
+    }
+
+    #[tokio::test]
+    async fn test_loaded_npc_stands_once_rested() {
+        let context = goap_context();
+        let (gate, _, _) = keep(&context).await;
+        // NPCs loaded from the database carry only body scores
+        let hermit = {
+            let mut world = context.entities().write().await;
+            let hermit = npc(&mut world, gate, BehaviorType::Passive);
+            world.remove_one::<AttributeScores>(hermit).unwrap();
+            world
+                .insert(hermit, (BodyAttributeScores::new(), Posture::Resting))
+                .unwrap();
+            hermit
+        };
+        register(&context, hermit).await;
+
+        let rest = ActionLibrary::new();
+        let status = rest
+            .get("rest")
+            .unwrap()
+            .execute(context.clone(), hermit)
+            .await;
+        assert!(matches!(status, Ok(ActionStatus::Done)));
+        let world = context.entities().read().await;
+        assert_eq!(*world.get::<&Posture>(hermit).unwrap(), Posture::Standing);
+    }
+
+    #[tokio::test]
+    async fn test_goap_attack_uses_attack_command() {
+        let context = goap_context();
+        let (gate, _, _) = keep(&context).await;
+        let (wolf, alice) = {
+            let mut world = context.entities().write().await;
+            let alice = player(&mut world, gate);
+            let alice_uuid = world.get::<&EntityUuid>(alice).unwrap().0;
+            let wolf = npc(&mut world, gate, BehaviorType::Aggressive);
+            world
+                .get::<&mut AIController>(wolf)
+                .unwrap()
+                .state_target_id = Some(EntityId::new(alice, alice_uuid));
+            let hunt = planner(
+                GoapGoal::new("hunt", "Hunt", 10).with_condition("target_damaged", true),
+                &["attack"],
+            );
+            world.insert_one(wolf, hunt).unwrap();
+            (wolf, alice)
+        };
+        register(&context, wolf).await;
+        register(&context, alice).await;
+
+        assert!(system().update_with_goap(context.clone(), wolf).await);
+        let world = context.entities().read().await;
+        let combatant = world.get::<&Combatant>(wolf).unwrap();
+        assert!(combatant.in_combat);
+        let alice_uuid = world.get::<&EntityUuid>(alice).unwrap().0;
+        assert_eq!(combatant.target_id.map(|id| id.uuid()), Some(alice_uuid));
+        assert!(world.get::<&Combatant>(alice).unwrap().in_combat);
+    }
 }
@@ -97,6 +97,24 @@ impl PersistenceSystem {
             );
         }
 
+        if let Ok(contained) = world.get::<&ContainedBy>(entity) {
+            components.insert(
+                "contained_by".to_string(),
+                serde_json::to_value(&*contained).unwrap(),
+            );
+        }
+
+        if let Ok(key) = world.get::<&Key>(entity) {
+            components.insert("key".to_string(), serde_json::to_value(&*key).unwrap());
+        }
+
+        if let Ok(bind_point) = world.get::<&BindPoint>(entity) {
+            components.insert(
+                "bind_point".to_string(),
+                serde_json::to_value(&*bind_point).unwrap(),
+            );
+        }
+
         if let Ok(attrs) = world.get::<&BodyAttributeScores>(entity) {
             components.insert(
                 "body_attributes".to_string(),
@@ -125,6 +143,13 @@ impl PersistenceSystem {
             );
         }
 
+        if let Ok(spellbook) = world.get::<&Spellbook>(entity) {
+            components.insert(
+                "spellbook".to_string(),
+                serde_json::to_value(&*spellbook).unwrap(),
+            );
+        }
+
         if let Ok(ai) = world.get::<&AIController>(entity) {
             components.insert(
                 "ai_controller".to_string(),
@@ -208,6 +233,21 @@ impl PersistenceSystem {
                         world.insert_one(entity, containable).ok();
                     }
                 }
+                "contained_by" => {
+                    if let Ok(contained) = serde_json::from_value::<ContainedBy>(value) {
+                        world.insert_one(entity, contained).ok();
+                    }
+                }
+                "key" => {
+                    if let Ok(key) = serde_json::from_value::<Key>(value) {
+                        world.insert_one(entity, key).ok();
+                    }
+                }
+                "bind_point" => {
+                    if let Ok(bind_point) = serde_json::from_value::<BindPoint>(value) {
+                        world.insert_one(entity, bind_point).ok();
+                    }
+                }
                 "body_attributes" => {
                     if let Ok(attrs) = serde_json::from_value::<BodyAttributeScores>(value) {
                         world.insert_one(entity, attrs).ok();
@@ -228,6 +268,11 @@ impl PersistenceSystem {
                         world.insert_one(entity, skills).ok();
                     }
                 }
+                "spellbook" => {
+                    if let Ok(spellbook) = serde_json::from_value::<Spellbook>(value) {
+                        world.insert_one(entity, spellbook).ok();
+                    }
+                }
                 "ai_controller" => {
                     if let Ok(ai) = serde_json::from_value::<AIController>(value) {
                         world.insert_one(entity, ai).ok();
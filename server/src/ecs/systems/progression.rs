@@ -0,0 +1,261 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Skill progression from use
+//!
+//! Every attempt at a skill teaches something. The experience earned starts
+//! at [`SKILL_USE_EXPERIENCE`], grows with how hard the attempt was compared
+//! to the current skill level, is halved on failure, is accelerated by
+//! knowledge (see [`skill_experience_gain`]) and is boosted by the Fast
+//! Learner talent. Skill levels are derived from experience, so an award that
+//! crosses a level threshold publishes `LevelUp` for the skill.
+
+use crate::ecs::components::{
+    Skill, Skills, Talent, Talents, skill_experience_gain, skill_level_from_experience,
+};
+use crate::ecs::events::{EventBus, GameEvent};
+use crate::ecs::{EcsEntity, GameWorld};
+
+/// Experience for one successful use at a challenge matching the skill level
+pub const SKILL_USE_EXPERIENCE: f32 = 2.0;
+
+/// Fraction of experience earned by a failed attempt
+pub const FAILURE_EXPERIENCE_FACTOR: f32 = 0.5;
+
+/// Extra experience per rank of the Fast Learner talent
+pub const FAST_LEARNER_BONUS: f32 = 0.25;
+
+/// Experience awarded for a single use of a skill
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillGain {
+    pub skill: Skill,
+    /// Experience added to the skill
+    pub experience: i32,
+    /// Level before the award
+    pub previous_level: i32,
+    /// Level after the award
+    pub level: i32,
+}
+
+impl SkillGain {
+    /// Check whether the award raised the skill level
+    pub fn improved(&self) -> bool {
+        self.level > self.previous_level
+    }
+}
+
+pub struct ProgressionSystem {
+    event_bus: EventBus,
+}
+
+impl ProgressionSystem {
+    /// Create a new progression system
+    pub fn new(event_bus: EventBus) -> Self {
+        Self { event_bus }
+    }
+
+    /// Award experience for an attempt at a skill against a challenge level
+    ///
+    /// Entities without a `Skills` component do not learn. A skill the entity
+    /// has never used is learned from scratch. The caller is responsible for
+    /// marking the entity dirty when a gain is returned.
+    pub fn record_use(
+        &self,
+        world: &GameWorld,
+        entity: EcsEntity,
+        skill: Skill,
+        challenge: i32,
+        success: bool,
+    ) -> Option<SkillGain> {
+        let rate = learning_rate(world.get::<&Talents>(entity).ok().as_deref());
+        let mut skills = world.get::<&mut Skills>(entity).ok()?;
+        let gain = apply_use(&mut skills, skill, challenge, success, rate)?;
+        drop(skills);
+
+        self.event_bus.publish(GameEvent::ExperienceGained {
+            entity,
+            skill: Some(skill),
+            amount: gain.experience as u64,
+        });
+        if gain.improved() {
+            self.event_bus.publish(GameEvent::LevelUp {
+                entity,
+                skill: Some(skill),
+                new_level: gain.level as u32,
+            });
+        }
+        Some(gain)
+    }
+}
+
+/// Apply a single skill use to a skill set
+///
+/// Returns `None` when the attempt was too easy to teach anything.
+pub fn apply_use(
+    skills: &mut Skills,
+    skill: Skill,
+    challenge: i32,
+    success: bool,
+    rate: f32,
+) -> Option<SkillGain> {
+    if !skills.has_skill(skill) {
+        skills.add_skill(skill, 0, 0);
+    }
+    let previous_level = skills.level(skill);
+    let factor = challenge_factor(previous_level, challenge);
+    if factor <= 0.0 {
+        return None;
+    }
+
+    let base = SKILL_USE_EXPERIENCE
+        * factor
+        * rate
+        * if success {
+            1.0
+        } else {
+            FAILURE_EXPERIENCE_FACTOR
+        };
+    let knowledge = skills.get_knowledge(skill).unwrap_or(0);
+    let gain = skill_experience_gain(base, knowledge, previous_level, skill.difficulty());
+    let experience = (gain.round() as i32).max(1);
+
+    skills.advance(skill, experience, 0);
+    let level = skill_level_from_experience(
+        skills.get_experience(skill).unwrap_or(0),
+        skill.difficulty(),
+    );
+    Some(SkillGain {
+        skill,
+        experience,
+        previous_level,
+        level,
+    })
+}
+
+/// Experience multiplier for an attempt at `challenge` with a skill at `level`
+///
+/// Attempts well below the skill level teach nothing; harder attempts teach
+/// more, up to three times the base.
+pub fn challenge_factor(level: i32, challenge: i32) -> f32 {
+    let gap = challenge - level;
+    if gap < -2 {
+        0.0
+    } else {
+        (1.0 + gap as f32 * 0.5).clamp(0.5, 3.0)
+    }
+}
+
+/// Experience multiplier from an entity's talents
+pub fn learning_rate(talents: Option<&Talents>) -> f32 {
+    let rank = talents
+        .and_then(|talents| talents.get_talent_rank(Talent::FastLearner))
+        .unwrap_or(0);
+    1.0 + FAST_LEARNER_BONUS * rank as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_challenge_factor() {
+        assert_eq!(challenge_factor(5, 2), 0.0);
+        assert_eq!(challenge_factor(5, 3), 0.5);
+        assert_eq!(challenge_factor(5, 5), 1.0);
+        assert_eq!(challenge_factor(5, 7), 2.0);
+        assert_eq!(challenge_factor(0, 10), 3.0);
+    }
+
+    #[test]
+    fn test_apply_use_rewards_success_knowledge_and_talent() {
+        let mut skills = Skills::new();
+        let gain = apply_use(&mut skills, Skill::Lockpicking, 0, true, 1.0).unwrap();
+        assert_eq!(gain.experience, 2);
+        assert!(skills.has_skill(Skill::Lockpicking));
+
+        let mut failed = Skills::new();
+        let gain = apply_use(&mut failed, Skill::Lockpicking, 0, false, 1.0).unwrap();
+        assert_eq!(gain.experience, 1);
+
+        let mut talents = Talents::new();
+        talents.add_talent(Talent::FastLearner, 0);
+        let mut fast = Skills::new();
+        let gain = apply_use(
+            &mut fast,
+            Skill::Lockpicking,
+            0,
+            true,
+            learning_rate(Some(&talents)),
+        )
+        .unwrap();
+        assert!(gain.experience > 2);
+
+        let mut learned = Skills::new();
+        learned.add_skill(Skill::Lockpicking, 0, 1_000);
+        let gain = apply_use(&mut learned, Skill::Lockpicking, 0, true, 1.0).unwrap();
+        assert_eq!(gain.experience, 4);
+
+        let mut expert = Skills::new();
+        expert.add_skill(Skill::Lockpicking, 3_200, 0);
+        assert!(apply_use(&mut expert, Skill::Lockpicking, 1, true, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_record_use_publishes_level_up() {
+        let event_bus = EventBus::new();
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let recorded = published.clone();
+        event_bus.subscribe(move |event| recorded.lock().unwrap().push(event.clone()));
+
+        let mut world = GameWorld::new();
+        let mut skills = Skills::new();
+        // Moderate skills reach level 1 at 4 experience
+        skills.add_skill(Skill::Swordsmanship, 3, 0);
+        let fighter = world.spawn((skills,));
+        let untrained = world.spawn(());
+
+        let system = ProgressionSystem::new(event_bus.clone());
+        let gain = system
+            .record_use(&world, fighter, Skill::Swordsmanship, 0, true)
+            .unwrap();
+        assert!(gain.improved());
+        assert_eq!(gain.level, 1);
+        assert!(
+            system
+                .record_use(&world, untrained, Skill::Swordsmanship, 0, true)
+                .is_none()
+        );
+        event_bus.process_events();
+
+        let published = published.lock().unwrap();
+        assert!(published.iter().any(|event| matches!(
+            event,
+            GameEvent::LevelUp {
+                entity,
+                skill: Some(Skill::Swordsmanship),
+                new_level: 1,
+            } if *entity == fighter
+        )));
+        assert_eq!(
+            world
+                .get::<&Skills>(fighter)
+                .unwrap()
+                .get_experience(Skill::Swordsmanship),
+            Some(5)
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Regeneration system restoring health and energy over time
+//!
+//! Every [`REGEN_INTERVAL`] seconds each living entity recovers the
+//! `health_regen` and `energy_regen` of its body, mind and soul scores, scaled
+//! by its [`Posture`] and reduced while it is in combat. Talents like Hardy
+//! speed up its health regeneration.
+
+use crate::ecs::components::{
+    AttributeScores, BodyAttributeScores, Combatant, EntityUuid, MindAttributeScores, Posture,
+    SoulAttributeScores, Talents,
+};
+use crate::ecs::events::{EventBus, GameEvent, VitalPool, Vitals};
+use crate::ecs::{EcsEntity, GameWorld};
+use hecs::Entity;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Seconds between regeneration ticks
+pub const REGEN_INTERVAL: f32 = 3.0;
+
+/// Fraction of the normal rate recovered while in combat
+pub const COMBAT_REGEN_FACTOR: f32 = 0.25;
+
+pub struct RegenSystem {
+    event_bus: EventBus,
+    elapsed: f32,
+}
+
+impl RegenSystem {
+    /// Create a new regeneration system
+    pub fn new(event_bus: EventBus) -> Self {
+        Self {
+            event_bus,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Advance the regeneration clock and apply any regeneration ticks that are due
+    ///
+    /// Publishes a `VitalsChanged` event for every entity that recovered and
+    /// returns the UUIDs of those entities so they can be marked dirty.
+    #[instrument(skip(self, world))]
+    pub fn update(&mut self, world: &mut GameWorld, delta_time: f32) -> Vec<Uuid> {
+        self.elapsed += delta_time;
+        let ticks = (self.elapsed / REGEN_INTERVAL).floor();
+        if ticks < 1.0 {
+            return Vec::new();
+        }
+        self.elapsed -= ticks * REGEN_INTERVAL;
+
+        // Work out each living entity's rate before touching any scores
+        let rates: Vec<(EcsEntity, f32, f32)> = world
+            .query::<(
+                Entity,
+                Option<&Posture>,
+                Option<&Combatant>,
+                Option<&Talents>,
+                Option<&BodyAttributeScores>,
+                Option<&AttributeScores>,
+            )>()
+            .iter()
+            .filter(|(_, _, _, _, body, bare)| {
+                let health = body
+                    .map(|b| b.0.health_current)
+                    .or(bare.map(|b| b.health_current));
+                health.is_some_and(|health| health > 0.0)
+            })
+            .map(|(entity, posture, combatant, talents, _, _)| {
+                let scale = ticks * regen_multiplier(posture, combatant);
+                let health_factor = talents.map_or(1.0, |talents| talents.health_regen());
+                (entity, scale * health_factor, scale)
+            })
+            .collect();
+
+        let mut dirty = Vec::new();
+        for (entity, health_scale, energy_scale) in rates {
+            let mut changed = false;
+            if let Ok(mut scores) = world.get::<&mut AttributeScores>(entity) {
+                changed |= regenerate(&mut scores, health_scale, energy_scale);
+            }
+            if let Ok(mut scores) = world.get::<&mut BodyAttributeScores>(entity) {
+                changed |= regenerate(&mut scores.0, health_scale, energy_scale);
+            }
+            if let Ok(mut scores) = world.get::<&mut MindAttributeScores>(entity) {
+                changed |= regenerate(&mut scores.0, health_scale, energy_scale);
+            }
+            if let Ok(mut scores) = world.get::<&mut SoulAttributeScores>(entity) {
+                changed |= regenerate(&mut scores.0, health_scale, energy_scale);
+            }
+            if !changed {
+                continue;
+            }
+
+            if let Ok(uuid) = world.get::<&EntityUuid>(entity) {
+                dirty.push(uuid.0);
+            }
+            self.event_bus.publish(GameEvent::VitalsChanged {
+                entity,
+                vitals: vitals_of(world, entity),
+            });
+        }
+        dirty
+    }
+}
+
+/// Regeneration multiplier from an entity's posture and combat state
+pub fn regen_multiplier(posture: Option<&Posture>, combatant: Option<&Combatant>) -> f32 {
+    let multiplier = posture.copied().unwrap_or_default().regen_multiplier();
+    if combatant.is_some_and(|combatant| combatant.in_combat) {
+        multiplier * COMBAT_REGEN_FACTOR
+    } else {
+        multiplier
+    }
+}
+
+/// Apply regeneration ticks to a set of scores, scaled separately for health and energy
+///
+/// Values never rise past their maximum, and values already above it (from
+/// a temporary bonus) are left alone. Returns whether anything changed.
+pub fn regenerate(scores: &mut AttributeScores, health_scale: f32, energy_scale: f32) -> bool {
+    let health = recover(
+        scores.health_current,
+        scores.health_maximum,
+        scores.health_regen * health_scale,
+    );
+    let energy = recover(
+        scores.energy_current,
+        scores.energy_maximum,
+        scores.energy_regen * energy_scale,
+    );
+    let changed = health != scores.health_current || energy != scores.energy_current;
+    scores.health_current = health;
+    scores.energy_current = energy;
+    changed
+}
+
+fn recover(current: f32, maximum: f32, amount: f32) -> f32 {
+    if current < maximum {
+        (current + amount.max(0.0)).min(maximum)
+    } else {
+        current
+    }
+}
+
+/// Snapshot an entity's vitals for side channel updates
+pub fn vitals_of(world: &GameWorld, entity: EcsEntity) -> Vitals {
+    let body = world
+        .get::<&BodyAttributeScores>(entity)
+        .map(|scores| pool(&scores.0))
+        .or_else(|_| {
+            world
+                .get::<&AttributeScores>(entity)
+                .map(|scores| pool(&scores))
+        })
+        .ok();
+    Vitals {
+        body,
+        mind: world
+            .get::<&MindAttributeScores>(entity)
+            .map(|scores| pool(&scores.0))
+            .ok(),
+        soul: world
+            .get::<&SoulAttributeScores>(entity)
+            .map(|scores| pool(&scores.0))
+            .ok(),
+    }
+}
+
+fn pool(scores: &AttributeScores) -> VitalPool {
+    VitalPool {
+        health: scores.health_current,
+        health_maximum: scores.health_maximum,
+        energy: scores.energy_current,
+        energy_maximum: scores.energy_maximum,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::Talent;
+    use std::sync::{Arc, Mutex};
+
+    fn wounded(health: f32, energy: f32) -> AttributeScores {
+        let mut scores = AttributeScores::new();
+        scores.health_current = health;
+        scores.energy_current = energy;
+        scores
+    }
+
+    #[test]
+    fn test_regenerate_respects_maximum() {
+        let mut scores = wounded(99.5, 50.0);
+        assert!(regenerate(&mut scores, 1.0, 1.0));
+        assert_eq!(scores.health_current, 100.0);
+        assert_eq!(scores.energy_current, 51.0);
+
+        let mut full = AttributeScores::new();
+        full.health_current = 120.0;
+        full.energy_current = 100.0;
+        assert!(!regenerate(&mut full, 1.0, 1.0));
+        assert_eq!(full.health_current, 120.0);
+    }
+
+    #[test]
+    fn test_regen_multiplier() {
+        let mut combatant = Combatant::new();
+        assert_eq!(regen_multiplier(None, None), 1.0);
+        assert_eq!(regen_multiplier(Some(&Posture::Sleeping), None), 3.0);
+        assert_eq!(
+            regen_multiplier(Some(&Posture::Resting), Some(&combatant)),
+            2.0
+        );
+
+        combatant.in_combat = true;
+        assert_eq!(
+            regen_multiplier(None, Some(&combatant)),
+            COMBAT_REGEN_FACTOR
+        );
+    }
+
+    #[test]
+    fn test_update_applies_ticks_and_publishes_vitals() {
+        let event_bus = EventBus::new();
+        let published = Arc::new(Mutex::new(Vec::new()));
+        let recorded = published.clone();
+        event_bus.subscribe(move |event| {
+            if let GameEvent::VitalsChanged { entity, vitals } = event {
+                recorded.lock().unwrap().push((*entity, *vitals));
+            }
+        });
+
+        let mut world = GameWorld::new();
+        let uuid = Uuid::new_v4();
+        let resting = world.spawn((
+            EntityUuid(uuid),
+            BodyAttributeScores(wounded(50.0, 100.0)),
+            MindAttributeScores(wounded(100.0, 90.0)),
+            Posture::Resting,
+        ));
+        let standing = world.spawn((wounded(50.0, 100.0),));
+        let dead = world.spawn((wounded(0.0, 0.0),));
+
+        let mut system = RegenSystem::new(event_bus.clone());
+        assert!(system.update(&mut world, REGEN_INTERVAL / 2.0).is_empty());
+        assert_eq!(system.update(&mut world, REGEN_INTERVAL / 2.0), vec![uuid]);
+        event_bus.process_events();
+
+        assert_eq!(
+            world
+                .get::<&BodyAttributeScores>(resting)
+                .unwrap()
+                .0
+                .health_current,
+            52.0
+        );
+        assert_eq!(
+            world
+                .get::<&MindAttributeScores>(resting)
+                .unwrap()
+                .0
+                .energy_current,
+            92.0
+        );
+        assert_eq!(
+            world
+                .get::<&AttributeScores>(standing)
+                .unwrap()
+                .health_current,
+            51.0
+        );
+        assert_eq!(
+            world.get::<&AttributeScores>(dead).unwrap().health_current,
+            0.0
+        );
+
+        let published = published.lock().unwrap();
+        assert_eq!(published.len(), 2);
+        let (_, vitals) = published
+            .iter()
+            .find(|(entity, _)| *entity == resting)
+            .unwrap();
+        assert_eq!(vitals.body.unwrap().health, 52.0);
+        assert_eq!(vitals.mind.unwrap().energy, 92.0);
+        assert!(vitals.soul.is_none());
+    }
+
+    #[test]
+    fn test_hardy_regenerates_health_faster() {
+        let mut world = GameWorld::new();
+        let mut talents = Talents::new();
+        talents.add_talent(Talent::Hardy, 0);
+        let hardy = world.spawn((wounded(50.0, 50.0), talents));
+        let plain = world.spawn((wounded(50.0, 50.0),));
+
+        let mut system = RegenSystem::new(EventBus::new());
+        system.update(&mut world, REGEN_INTERVAL * 2.0);
+
+        let hardy = world.get::<&AttributeScores>(hardy).unwrap();
+        let plain = world.get::<&AttributeScores>(plain).unwrap();
+        assert_eq!(plain.health_current, 52.0);
+        assert_eq!(hardy.health_current, 53.0);
+        assert_eq!(hardy.energy_current, plain.energy_current);
+    }
+}
@@ -0,0 +1,640 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! World tick scheduler driving the ECS systems at a fixed pulse rate
+//!
+//! Each pulse runs the world systems in a fixed order:
+//!
+//! 1. NPC AI (`NpcAiSystem::update`)
+//! 2. Death (`DeathSystem::update`)
+//! 3. Combat (`CombatSystem::update_with_registry`)
+//! 4. Status effects (`CombatSystem::update_status_effects`)
+//! 5. Regeneration (`RegenSystem::update`)
+//! 6. Resource node respawns (`GatheringSystem::update`)
+//! 7. Movement (`MovementSystem::update`)
+//! 8. Event dispatch (`EventBus::process_events`)
+//!
+//! Pulses are scheduled against a fixed timeline, so a slow pulse causes the
+//! missed pulses to be skipped rather than shifting every later pulse. Systems
+//! always receive the real time elapsed since the previous pulse as `delta_time`.
+//!
+//! Each pulse also advances the in-game [`GameClock`], which NPC schedules follow.
+
+use crate::config::{ClockConfig, DeathConfig};
+use crate::ecs::context::WorldContext;
+use crate::ecs::events::EventBus;
+use crate::ecs::systems::{
+    CombatSystem, DeathSystem, GatheringSystem, MovementSystem, NpcAiSystem, RegenSystem,
+};
+use crate::models::ModelManager;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::time::MissedTickBehavior;
+use tracing::instrument;
+
+/// Default pulse interval (4 pulses per second)
+pub const DEFAULT_PULSE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Largest delta (in pulses) handed to systems after a stall
+const MAX_DELTA_PULSES: u32 = 10;
+
+/// Minutes in an in-game day
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Real time an in-game day lasts by default
+pub const DEFAULT_DAY_LENGTH: Duration = Duration::from_secs(2 * 60 * 60);
+
+/// In-game hour the clock starts at by default
+pub const DEFAULT_START_HOUR: u32 = 6;
+
+/// A moment on the in-game clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameTime {
+    /// Days since the clock started
+    pub day: u32,
+    /// Hour of the day (0-23)
+    pub hour: u32,
+    /// Minute of the hour (0-59)
+    pub minute: u32,
+}
+
+impl std::fmt::Display for GameTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {}, {:02}:{:02}",
+            self.day + 1,
+            self.hour,
+            self.minute
+        )
+    }
+}
+
+/// In-game clock, running faster than real time
+pub struct GameClock {
+    /// In-game minutes since the clock started, as `f64` bits
+    minutes: AtomicU64,
+    /// Real microseconds an in-game day lasts
+    day_length_us: AtomicU64,
+}
+
+impl GameClock {
+    /// Create a clock at the default start hour
+    pub fn new() -> Self {
+        Self {
+            minutes: AtomicU64::new(((DEFAULT_START_HOUR * 60) as f64).to_bits()),
+            day_length_us: AtomicU64::new(DEFAULT_DAY_LENGTH.as_micros() as u64),
+        }
+    }
+
+    /// Current in-game time
+    pub fn now(&self) -> GameTime {
+        let minutes = f64::from_bits(self.minutes.load(Ordering::Relaxed)) as u64;
+        let of_day = (minutes % MINUTES_PER_DAY as u64) as u32;
+        GameTime {
+            day: (minutes / MINUTES_PER_DAY as u64) as u32,
+            hour: of_day / 60,
+            minute: of_day % 60,
+        }
+    }
+
+    /// Real time an in-game day lasts
+    pub fn day_length(&self) -> Duration {
+        Duration::from_micros(self.day_length_us.load(Ordering::Relaxed))
+    }
+
+    /// Set how much real time an in-game day lasts
+    pub fn set_day_length(&self, length: Duration) {
+        let micros = (length.as_micros() as u64).max(1_000_000);
+        self.day_length_us.store(micros, Ordering::Relaxed);
+    }
+
+    /// Move the clock to a time of day, keeping the day
+    pub fn set_time(&self, hour: u32, minute: u32) {
+        let day = self.now().day as f64;
+        let of_day = ((hour % 24) * 60 + minute % 60) as f64;
+        self.minutes.store(
+            (day * MINUTES_PER_DAY as f64 + of_day).to_bits(),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Apply clock settings from the configuration
+    pub fn configure(&self, config: &ClockConfig) {
+        self.set_day_length(Duration::from_secs_f32(
+            config.day_length_minutes.max(1.0 / 60.0) * 60.0,
+        ));
+        self.set_time(config.start_hour, 0);
+    }
+
+    /// Let real time pass on the clock
+    pub fn advance(&self, real_seconds: f32) {
+        let rate = MINUTES_PER_DAY as f64 / self.day_length().as_secs_f64();
+        let _ = self
+            .minutes
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f64::from_bits(bits) + real_seconds.max(0.0) as f64 * rate).to_bits())
+            });
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Stages executed by the scheduler, in execution order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TickStage {
+    NpcAi,
+    Death,
+    Combat,
+    StatusEffects,
+    Regen,
+    Resources,
+    Movement,
+    Events,
+}
+
+impl TickStage {
+    /// All stages in the order they run each pulse
+    pub const ORDER: [TickStage; 8] = [
+        TickStage::NpcAi,
+        TickStage::Death,
+        TickStage::Combat,
+        TickStage::StatusEffects,
+        TickStage::Regen,
+        TickStage::Resources,
+        TickStage::Movement,
+        TickStage::Events,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TickStage::NpcAi => "npc_ai",
+            TickStage::Death => "death",
+            TickStage::Combat => "combat",
+            TickStage::StatusEffects => "status_effects",
+            TickStage::Regen => "regen",
+            TickStage::Resources => "resources",
+            TickStage::Movement => "movement",
+            TickStage::Events => "events",
+        }
+    }
+}
+
+/// Timing information for a single stage
+#[derive(Debug, Clone, Default)]
+pub struct StageTiming {
+    /// Duration of the most recent run
+    pub last: Duration,
+    /// Longest observed run
+    pub max: Duration,
+    /// Accumulated run time across all pulses
+    pub total: Duration,
+}
+
+impl StageTiming {
+    fn record(&mut self, elapsed: Duration) {
+        self.last = elapsed;
+        self.max = self.max.max(elapsed);
+        self.total += elapsed;
+    }
+}
+
+/// Aggregate statistics collected by the scheduler
+#[derive(Debug, Clone, Default)]
+pub struct TickStatistics {
+    /// Number of pulses executed (including single steps)
+    pub pulse_count: u64,
+    /// Number of pulses that took longer than the pulse interval
+    pub overrun_count: u64,
+    /// Number of scheduled pulses skipped because of overruns
+    pub skipped_pulses: u64,
+    /// Delta time handed to systems on the last pulse, in seconds
+    pub last_delta: f32,
+    /// Total duration of the last pulse
+    pub last_pulse: Duration,
+    /// Longest observed pulse
+    pub max_pulse: Duration,
+    /// Per-stage timings
+    pub stages: HashMap<TickStage, StageTiming>,
+}
+
+impl TickStatistics {
+    /// Average run time of a stage across all pulses
+    pub fn average(&self, stage: TickStage) -> Duration {
+        match self.stages.get(&stage) {
+            Some(timing) if self.pulse_count > 0 => {
+                timing.total / self.pulse_count.min(u32::MAX as u64) as u32
+            }
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// Systems owned and driven by the scheduler
+struct ScheduledSystems {
+    npc_ai: NpcAiSystem,
+    death: DeathSystem,
+    combat: CombatSystem,
+    regen: RegenSystem,
+    gathering: GatheringSystem,
+    movement: MovementSystem,
+}
+
+/// Fixed-rate scheduler that drives the world systems
+pub struct WorldScheduler {
+    systems: Mutex<ScheduledSystems>,
+    event_bus: EventBus,
+    pulse_interval_us: AtomicU64,
+    paused: AtomicBool,
+    running: AtomicBool,
+    statistics: std::sync::RwLock<TickStatistics>,
+    clock: GameClock,
+}
+
+impl WorldScheduler {
+    /// Create a new scheduler publishing on the given event bus
+    pub fn new(event_bus: EventBus, llm_manager: Arc<ModelManager>) -> Self {
+        Self {
+            systems: Mutex::new(ScheduledSystems {
+                npc_ai: NpcAiSystem::new(llm_manager),
+                death: DeathSystem::new(event_bus.clone()),
+                combat: CombatSystem::new(event_bus.clone()),
+                regen: RegenSystem::new(event_bus.clone()),
+                gathering: GatheringSystem::new(),
+                movement: MovementSystem::new(event_bus.clone()),
+            }),
+            event_bus,
+            pulse_interval_us: AtomicU64::new(DEFAULT_PULSE_INTERVAL.as_micros() as u64),
+            paused: AtomicBool::new(false),
+            running: AtomicBool::new(false),
+            statistics: std::sync::RwLock::new(TickStatistics::default()),
+            clock: GameClock::new(),
+        }
+    }
+
+    /// Get the interval between pulses
+    pub fn pulse_interval(&self) -> Duration {
+        Duration::from_micros(self.pulse_interval_us.load(Ordering::Relaxed))
+    }
+
+    /// Set the interval between pulses (takes effect on the next pulse)
+    pub fn set_pulse_interval(&self, interval: Duration) {
+        let micros = (interval.as_micros() as u64).max(1_000);
+        self.pulse_interval_us.store(micros, Ordering::Relaxed);
+    }
+
+    /// Set the pulse rate in pulses per second
+    pub fn set_pulse_rate(&self, pulses_per_second: u32) {
+        self.set_pulse_interval(Duration::from_secs_f64(
+            1.0 / pulses_per_second.max(1) as f64,
+        ));
+    }
+
+    /// Set how deaths, corpses and respawns are handled
+    pub async fn set_death_config(&self, config: DeathConfig) {
+        self.systems.lock().await.death.set_config(config);
+    }
+
+    /// Set the room players without a bind point respawn in
+    pub async fn set_starting_room(&self, room: Option<uuid::Uuid>) {
+        self.systems.lock().await.death.set_starting_room(room);
+    }
+
+    /// Get the in-game clock
+    pub fn clock(&self) -> &GameClock {
+        &self.clock
+    }
+
+    /// Pause the scheduler; systems stop running until resumed or stepped
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resume a paused scheduler
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /// Check if the scheduler is paused
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Check if the tick task has been started
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Get a snapshot of the collected statistics
+    pub fn statistics(&self) -> TickStatistics {
+        self.statistics.read().unwrap().clone()
+    }
+
+    /// Run a single pulse while paused, using the nominal pulse interval as delta
+    pub async fn step(&self, context: Arc<WorldContext>) -> Result<TickStatistics, String> {
+        if !self.is_paused() {
+            return Err("World must be paused before stepping".to_string());
+        }
+        let delta_time = self.pulse_interval().as_secs_f32();
+        self.pulse(context, delta_time).await;
+        Ok(self.statistics())
+    }
+
+    /// Run every system once with the given delta time
+    #[instrument(skip(self, context))]
+    pub async fn pulse(&self, context: Arc<WorldContext>, delta_time: f32) {
+        let mut systems = self.systems.lock().await;
+        let pulse_start = Instant::now();
+        let mut timings = Vec::with_capacity(TickStage::ORDER.len());
+        self.clock.advance(delta_time);
+
+        let start = Instant::now();
+        systems.npc_ai.update(context.clone(), delta_time).await;
+        timings.push((TickStage::NpcAi, start.elapsed()));
+
+        let (deaths, fought, afflicted, recovered, replenished) = {
+            let mut world = context.entities().write().await;
+            let registry = context.registry().read().await;
+
+            let start = Instant::now();
+            let deaths = systems.death.update(&mut world, delta_time);
+            timings.push((TickStage::Death, start.elapsed()));
+
+            let start = Instant::now();
+            let fought = systems
+                .combat
+                .update_with_registry(&mut world, &registry, delta_time);
+            timings.push((TickStage::Combat, start.elapsed()));
+
+            let start = Instant::now();
+            let afflicted = systems.combat.update_status_effects(&mut world, delta_time);
+            timings.push((TickStage::StatusEffects, start.elapsed()));
+
+            let start = Instant::now();
+            let recovered = systems.regen.update(&mut world, delta_time);
+            timings.push((TickStage::Regen, start.elapsed()));
+
+            let start = Instant::now();
+            let replenished = systems.gathering.update(&mut world, delta_time);
+            timings.push((TickStage::Resources, start.elapsed()));
+
+            let start = Instant::now();
+            systems.movement.update(&mut world, delta_time);
+            timings.push((TickStage::Movement, start.elapsed()));
+
+            (deaths, fought, afflicted, recovered, replenished)
+        };
+
+        for entity in deaths.despawned {
+            context.unregister_entity(entity).await;
+        }
+        for uuid in deaths
+            .dirty
+            .into_iter()
+            .chain(fought)
+            .chain(afflicted)
+            .chain(recovered)
+            .chain(replenished)
+        {
+            context.mark_dirty(uuid).await;
+        }
+        for notice in deaths.notices {
+            context
+                .send_to_entities(&notice.recipients, &notice.message)
+                .await;
+        }
+        if !deaths.destroyed.is_empty() {
+            // Database deletes must not hold up the pulse
+            let context = context.clone();
+            tokio::spawn(async move {
+                for uuid in deaths.destroyed {
+                    if let Err(e) = context.delete_entity(uuid).await {
+                        tracing::warn!("Failed to delete decayed entity {}: {}", uuid, e);
+                    }
+                }
+            });
+        }
+
+        let start = Instant::now();
+        self.event_bus.process_events();
+        timings.push((TickStage::Events, start.elapsed()));
+
+        let pulse_elapsed = pulse_start.elapsed();
+        let mut stats = self.statistics.write().unwrap();
+        stats.pulse_count += 1;
+        stats.last_delta = delta_time;
+        stats.last_pulse = pulse_elapsed;
+        stats.max_pulse = stats.max_pulse.max(pulse_elapsed);
+        for (stage, elapsed) in timings {
+            stats.stages.entry(stage).or_default().record(elapsed);
+        }
+    }
+
+    /// Start the background tick task
+    pub fn start_tick_task(self: Arc<Self>, context: Arc<WorldContext>) {
+        if self.running.swap(true, Ordering::SeqCst) {
+            tracing::warn!("World tick task already running");
+            return;
+        }
+
+        let interval = self.pulse_interval();
+
+        tokio::spawn(async move {
+            let mut period = self.pulse_interval();
+            let mut interval_timer = Self::make_interval(period);
+            let mut last_pulse = Instant::now();
+
+            loop {
+                interval_timer.tick().await;
+
+                // Pick up pulse rate changes made at runtime
+                let current = self.pulse_interval();
+                if current != period {
+                    period = current;
+                    interval_timer = Self::make_interval(period);
+                }
+
+                if self.is_paused() {
+                    // Paused time must not leak into the next delta
+                    last_pulse = Instant::now();
+                    continue;
+                }
+
+                let now = Instant::now();
+                let delta = now.duration_since(last_pulse).min(period * MAX_DELTA_PULSES);
+                last_pulse = now;
+
+                self.pulse(context.clone(), delta.as_secs_f32()).await;
+
+                let elapsed = now.elapsed();
+                if elapsed > period {
+                    let skipped = (elapsed.as_micros() / period.as_micros().max(1)) as u64;
+                    let mut stats = self.statistics.write().unwrap();
+                    stats.overrun_count += 1;
+                    stats.skipped_pulses += skipped;
+                    tracing::warn!(
+                        "World pulse overran: {:?} (interval {:?}, skipping {} pulses)",
+                        elapsed,
+                        period,
+                        skipped
+                    );
+                }
+            }
+        });
+
+        tracing::info!("World tick task started (interval: {:?})", interval);
+    }
+
+    fn make_interval(period: Duration) -> tokio::time::Interval {
+        let mut interval_timer = tokio::time::interval(period);
+        interval_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        interval_timer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{AttributeScores, Combatant, EntityId, EntityUuid, Name};
+    use crate::persistence::PersistenceManager;
+
+    fn create_context() -> Arc<WorldContext> {
+        let persistence_manager = Arc::new(PersistenceManager::new_mock());
+        Arc::new(WorldContext::new(persistence_manager))
+    }
+
+    #[test]
+    fn test_pulse_rate() {
+        let scheduler = WorldScheduler::new(EventBus::new(), Arc::new(ModelManager::new()));
+        assert_eq!(scheduler.pulse_interval(), DEFAULT_PULSE_INTERVAL);
+
+        scheduler.set_pulse_rate(10);
+        assert_eq!(scheduler.pulse_interval(), Duration::from_millis(100));
+
+        scheduler.set_pulse_rate(0);
+        assert_eq!(scheduler.pulse_interval(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_game_clock() {
+        let clock = GameClock::new();
+        assert_eq!(
+            clock.now(),
+            GameTime {
+                day: 0,
+                hour: DEFAULT_START_HOUR,
+                minute: 0
+            }
+        );
+
+        // A day lasting 24 real minutes makes each real minute an hour
+        clock.set_day_length(Duration::from_secs(24 * 60));
+        clock.set_time(23, 30);
+        clock.advance(60.0);
+        let now = clock.now();
+        assert_eq!((now.day, now.hour, now.minute), (1, 0, 30));
+        assert_eq!(now.to_string(), "Day 2, 00:30");
+    }
+
+    #[tokio::test]
+    async fn test_pulse_records_statistics() {
+        let context = create_context();
+        let scheduler = context.scheduler().clone();
+
+        scheduler.pulse(context.clone(), 0.25).await;
+        scheduler.pulse(context.clone(), 0.25).await;
+
+        let stats = scheduler.statistics();
+        assert_eq!(stats.pulse_count, 2);
+        assert_eq!(stats.last_delta, 0.25);
+        for stage in TickStage::ORDER {
+            assert!(stats.stages.contains_key(&stage));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pulse_resolves_combat() {
+        let context = create_context();
+        let attacker_uuid = uuid::Uuid::new_v4();
+        let defender_uuid = uuid::Uuid::new_v4();
+
+        let (attacker, defender) = {
+            let mut world = context.entities().write().await;
+            let attacker = world.spawn((Name::new("Attacker"), AttributeScores::new()));
+            let defender = world.spawn((Name::new("Defender"), AttributeScores::new()));
+
+            let mut attacker_combatant = Combatant::new();
+            attacker_combatant.in_combat = true;
+            attacker_combatant.target_id = Some(EntityId::new(defender, defender_uuid));
+            world.insert_one(attacker, attacker_combatant).unwrap();
+            world.insert_one(defender, Combatant::new()).unwrap();
+            (attacker, defender)
+        };
+        context.register_entity(attacker, attacker_uuid).await;
+        context.register_entity(defender, defender_uuid).await;
+
+        context.scheduler().pulse(context.clone(), 1.0).await;
+
+        let world = context.entities().read().await;
+        let health = world.get::<&AttributeScores>(defender).unwrap();
+        assert!(health.health_current < health.health_maximum);
+    }
+
+    #[tokio::test]
+    async fn test_pulse_regenerates_and_marks_dirty() {
+        let context = create_context();
+        let uuid = uuid::Uuid::new_v4();
+        let entity = {
+            let mut world = context.entities().write().await;
+            let mut scores = AttributeScores::new();
+            scores.health_current = 50.0;
+            world.spawn((EntityUuid(uuid), scores))
+        };
+
+        context
+            .scheduler()
+            .pulse(context.clone(), crate::ecs::systems::REGEN_INTERVAL)
+            .await;
+
+        let world = context.entities().read().await;
+        let health = world.get::<&AttributeScores>(entity).unwrap();
+        assert!(health.health_current > 50.0);
+        assert!(context.is_dirty(uuid).await);
+    }
+
+    #[tokio::test]
+    async fn test_step_requires_pause() {
+        let context = create_context();
+        let scheduler = context.scheduler().clone();
+
+        assert!(scheduler.step(context.clone()).await.is_err());
+
+        scheduler.pause();
+        let stats = scheduler.step(context.clone()).await.unwrap();
+        assert_eq!(stats.pulse_count, 1);
+        assert_eq!(stats.last_delta, DEFAULT_PULSE_INTERVAL.as_secs_f32());
+
+        scheduler.resume();
+        assert!(!scheduler.is_paused());
+    }
+}
@@ -0,0 +1,703 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Skill check resolution
+//!
+//! A check rolls a d20 and adds the character's modifiers for a skill:
+//!
+//! | Source    | Modifier                                                  |
+//! |-----------|-----------------------------------------------------------|
+//! | Skill     | Twice the skill level                                     |
+//! | Attribute | `(score - 10) / 2` of the attribute backing the check     |
+//! | Talent    | Rank of the talent the skill requires, and talent bonuses |
+//! | Status    | Stunned, Weakened, Strengthened, Slowed and Hasted        |
+//! | Equipment | [`SkillBonus`] of equipped items and heavy armor penalties |
+//!
+//! The total is compared against a difficulty to get a graded
+//! [`CheckOutcome`]. A natural 20 or beating the difficulty by
+//! [`CRITICAL_MARGIN`] is a critical success; a natural 1 or missing it by
+//! as much is a critical failure. Talents that reroll fumbles, like Lucky,
+//! reroll natural 1s once.
+
+use crate::ecs::components::{
+    Armor, AttributeScores, AttributeType, BodyAttributeScores, Equipment, MaterialKind,
+    MindAttributeScores, Name, Skill, SkillBonus, SkillCategory, Skills, SoulAttributeScores,
+    StatusEffectType, StatusEffects, Talents,
+};
+use crate::ecs::{EcsEntity, GameWorld};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Sides of the check die
+pub const CHECK_DIE: i32 = 20;
+
+/// Margin by which a check must pass or fail to be critical
+pub const CRITICAL_MARGIN: i32 = 10;
+
+/// Difficulty an untrained, average character passes about half the time
+pub const BASE_DIFFICULTY: i32 = 10;
+
+/// Penalty to checks while stunned
+pub const STUNNED_PENALTY: i32 = 5;
+
+/// Standard difficulties for checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CheckDifficulty {
+    Trivial,
+    Easy,
+    Moderate,
+    Hard,
+    VeryHard,
+    Legendary,
+}
+
+impl CheckDifficulty {
+    /// Target number a check total must reach
+    pub fn target(&self) -> i32 {
+        match self {
+            CheckDifficulty::Trivial => 5,
+            CheckDifficulty::Easy => 10,
+            CheckDifficulty::Moderate => 15,
+            CheckDifficulty::Hard => 20,
+            CheckDifficulty::VeryHard => 25,
+            CheckDifficulty::Legendary => 30,
+        }
+    }
+}
+
+/// Graded result of a check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CheckOutcome {
+    CriticalFailure,
+    Failure,
+    Success,
+    CriticalSuccess,
+}
+
+impl CheckOutcome {
+    /// Check whether the outcome counts as a success
+    pub fn is_success(&self) -> bool {
+        matches!(self, CheckOutcome::Success | CheckOutcome::CriticalSuccess)
+    }
+
+    /// Check whether the outcome is critical either way
+    pub fn is_critical(&self) -> bool {
+        matches!(
+            self,
+            CheckOutcome::CriticalFailure | CheckOutcome::CriticalSuccess
+        )
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckOutcome::CriticalFailure => "critical failure",
+            CheckOutcome::Failure => "failure",
+            CheckOutcome::Success => "success",
+            CheckOutcome::CriticalSuccess => "critical success",
+        }
+    }
+}
+
+/// A single named contribution to a check total
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CheckModifier {
+    pub source: String,
+    pub value: i32,
+}
+
+impl CheckModifier {
+    pub fn new(source: impl Into<String>, value: i32) -> Self {
+        Self {
+            source: source.into(),
+            value,
+        }
+    }
+}
+
+/// Description of a check to make
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillCheck {
+    pub skill: Skill,
+    pub attribute: AttributeType,
+    pub difficulty: i32,
+    /// Situational modifiers supplied by the caller
+    pub modifiers: Vec<CheckModifier>,
+}
+
+impl SkillCheck {
+    /// Create a check of a skill backed by a specific attribute
+    pub fn new(skill: Skill, attribute: AttributeType) -> Self {
+        Self {
+            skill,
+            attribute,
+            difficulty: CheckDifficulty::Moderate.target(),
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Create a check of a skill backed by its usual attribute
+    pub fn for_skill(skill: Skill) -> Self {
+        Self::new(skill, skill_attribute(skill))
+    }
+
+    /// Set the difficulty to beat
+    pub fn against(mut self, difficulty: i32) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+
+    /// Add a situational modifier
+    pub fn with_modifier(mut self, source: impl Into<String>, value: i32) -> Self {
+        self.modifiers.push(CheckModifier::new(source, value));
+        self
+    }
+}
+
+/// Full record of a resolved check, for rendering and logging
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckResult {
+    pub skill: Skill,
+    pub attribute: AttributeType,
+    /// Natural die roll
+    pub roll: i32,
+    /// Every non-zero modifier applied to the roll
+    pub modifiers: Vec<CheckModifier>,
+    pub total: i32,
+    pub difficulty: i32,
+    /// Total minus difficulty; negative on failure
+    pub margin: i32,
+    pub outcome: CheckOutcome,
+}
+
+impl CheckResult {
+    /// Sum of all modifiers
+    pub fn modifier_total(&self) -> i32 {
+        self.modifiers.iter().map(|modifier| modifier.value).sum()
+    }
+
+    /// Skill level the difficulty corresponds to, for skill progression
+    pub fn challenge_level(&self) -> i32 {
+        ((self.difficulty - BASE_DIFFICULTY) / 2).clamp(0, 10)
+    }
+}
+
+impl std::fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}): rolled {} {:+} = {} vs {}, {} by {}",
+            self.skill.name(),
+            self.attribute.name(),
+            self.roll,
+            self.modifier_total(),
+            self.total,
+            self.difficulty,
+            self.outcome.label(),
+            self.margin.abs()
+        )
+    }
+}
+
+/// Result of two entities' checks against each other
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OpposedResult {
+    /// The acting entity's check, made against the opponent's total
+    pub actor: CheckResult,
+    /// The opponent's check, made against the actor's total
+    pub opponent: CheckResult,
+}
+
+impl OpposedResult {
+    /// Check whether the actor won; ties go to the opponent
+    pub fn actor_wins(&self) -> bool {
+        self.actor.outcome.is_success()
+    }
+}
+
+/// Resolves skill checks with its own random number generator
+pub struct SkillCheckResolver<R = StdRng> {
+    rng: R,
+}
+
+impl SkillCheckResolver<StdRng> {
+    /// Create a resolver seeded from the operating system
+    pub fn new() -> Self {
+        Self::with_rng(StdRng::from_os_rng())
+    }
+
+    /// Create a resolver with a fixed seed, for reproducible checks
+    pub fn seeded(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for SkillCheckResolver<StdRng> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Rng> SkillCheckResolver<R> {
+    /// Create a resolver around any random number generator
+    pub fn with_rng(rng: R) -> Self {
+        Self { rng }
+    }
+
+    /// Roll a number between `min` and `max` inclusive with the resolver's generator
+    ///
+    /// Keeps effect rolls (damage, healing) reproducible alongside their checks.
+    pub fn roll_between(&mut self, min: i32, max: i32) -> i32 {
+        if max <= min {
+            min
+        } else {
+            self.rng.random_range(min..=max)
+        }
+    }
+
+    /// Resolve a check by an entity against the check's difficulty
+    pub fn resolve(
+        &mut self,
+        world: &GameWorld,
+        entity: EcsEntity,
+        check: &SkillCheck,
+    ) -> CheckResult {
+        let (roll, modifiers) = self.roll(world, entity, check);
+        let result = grade(check, roll, modifiers, check.difficulty);
+        tracing::debug!(?entity, %result, "Skill check");
+        result
+    }
+
+    /// Resolve a contest between two entities' checks
+    ///
+    /// The actor must beat the opponent's total, so ties go to the opponent.
+    /// Natural 20s and 1s still decide each side's outcome.
+    pub fn resolve_opposed(
+        &mut self,
+        world: &GameWorld,
+        actor: EcsEntity,
+        actor_check: &SkillCheck,
+        opponent: EcsEntity,
+        opponent_check: &SkillCheck,
+    ) -> OpposedResult {
+        let (actor_roll, actor_modifiers) = self.roll(world, actor, actor_check);
+        let (opponent_roll, opponent_modifiers) = self.roll(world, opponent, opponent_check);
+        let actor_total = actor_roll + sum(&actor_modifiers);
+        let opponent_total = opponent_roll + sum(&opponent_modifiers);
+
+        let result = OpposedResult {
+            actor: grade(actor_check, actor_roll, actor_modifiers, opponent_total + 1),
+            opponent: grade(
+                opponent_check,
+                opponent_roll,
+                opponent_modifiers,
+                actor_total,
+            ),
+        };
+        tracing::debug!(
+            ?actor,
+            ?opponent,
+            actor_result = %result.actor,
+            opponent_result = %result.opponent,
+            "Opposed skill check"
+        );
+        result
+    }
+
+    /// Roll the die and gather modifiers for a check
+    fn roll(
+        &mut self,
+        world: &GameWorld,
+        entity: EcsEntity,
+        check: &SkillCheck,
+    ) -> (i32, Vec<CheckModifier>) {
+        let mut roll = self.rng.random_range(1..=CHECK_DIE);
+        let lucky = world
+            .get::<&Talents>(entity)
+            .is_ok_and(|talents| talents.rerolls_fumbles());
+        if roll == 1 && lucky {
+            roll = self.rng.random_range(1..=CHECK_DIE);
+        }
+
+        let mut modifiers = check_modifiers(world, entity, check.skill, check.attribute);
+        modifiers.extend(
+            check
+                .modifiers
+                .iter()
+                .filter(|modifier| modifier.value != 0)
+                .cloned(),
+        );
+        (roll, modifiers)
+    }
+}
+
+/// Grade a roll against a difficulty
+fn grade(
+    check: &SkillCheck,
+    roll: i32,
+    modifiers: Vec<CheckModifier>,
+    difficulty: i32,
+) -> CheckResult {
+    let total = roll + sum(&modifiers);
+    let margin = total - difficulty;
+    CheckResult {
+        skill: check.skill,
+        attribute: check.attribute,
+        roll,
+        modifiers,
+        total,
+        difficulty,
+        margin,
+        outcome: outcome_of(roll, margin),
+    }
+}
+
+fn sum(modifiers: &[CheckModifier]) -> i32 {
+    modifiers.iter().map(|modifier| modifier.value).sum()
+}
+
+/// Outcome of a natural roll that beat (or missed) its difficulty by `margin`
+pub fn outcome_of(roll: i32, margin: i32) -> CheckOutcome {
+    if roll >= CHECK_DIE || margin >= CRITICAL_MARGIN {
+        CheckOutcome::CriticalSuccess
+    } else if roll <= 1 || margin <= -CRITICAL_MARGIN {
+        CheckOutcome::CriticalFailure
+    } else if margin >= 0 {
+        CheckOutcome::Success
+    } else {
+        CheckOutcome::Failure
+    }
+}
+
+/// The attribute a skill is usually checked with
+pub fn skill_attribute(skill: Skill) -> AttributeType {
+    match skill.category() {
+        SkillCategory::Combat => AttributeType::BodyOffence,
+        SkillCategory::Crafting | SkillCategory::Gathering | SkillCategory::Survival => {
+            AttributeType::BodyFinesse
+        }
+        SkillCategory::Social | SkillCategory::Psionic => AttributeType::MindOffence,
+        SkillCategory::Knowledge => AttributeType::MindFinesse,
+        SkillCategory::Magic => AttributeType::SoulOffence,
+        SkillCategory::Akashic => AttributeType::SoulFinesse,
+    }
+}
+
+/// Gather an entity's own modifiers for a check
+///
+/// Zero modifiers are left out so results only list what mattered.
+pub fn check_modifiers(
+    world: &GameWorld,
+    entity: EcsEntity,
+    skill: Skill,
+    attribute: AttributeType,
+) -> Vec<CheckModifier> {
+    let mut modifiers = Vec::new();
+
+    let level = world
+        .get::<&Skills>(entity)
+        .map(|skills| skills.level(skill))
+        .unwrap_or(0);
+    modifiers.push(CheckModifier::new(skill.name(), level * 2));
+
+    if let Some(score) = attribute_score(world, entity, attribute) {
+        modifiers.push(CheckModifier::new(attribute.name(), (score - 10) / 2));
+    }
+
+    if let Ok(talents) = world.get::<&Talents>(entity) {
+        if let Some(talent) = skill.requires() {
+            if let Some(rank) = talents.get_talent_rank(talent) {
+                modifiers.push(CheckModifier::new(talent.name(), rank as i32));
+            }
+        }
+        for (talent, bonus) in talents.skill_bonuses(skill) {
+            modifiers.push(CheckModifier::new(talent.name(), bonus));
+        }
+    }
+
+    if let Ok(effects) = world.get::<&StatusEffects>(entity) {
+        for effect in &effects.effects {
+            let value = status_modifier(effect.effect_type, effect.magnitude, attribute);
+            modifiers.push(CheckModifier::new(effect.effect_type.as_str(), value));
+        }
+    }
+
+    if let Ok(equipment) = world.get::<&Equipment>(entity) {
+        let mut seen = HashSet::new();
+        for item in equipment.slots.values() {
+            if item.needs_resolution() || !world.contains(item.entity()) || !seen.insert(*item) {
+                continue;
+            }
+            let item = item.entity();
+            let name = world
+                .get::<&Name>(item)
+                .map(|name| name.display.clone())
+                .unwrap_or_else(|_| "equipment".to_string());
+            let mut value = world
+                .get::<&SkillBonus>(item)
+                .map(|bonus| bonus.get(skill))
+                .unwrap_or(0);
+            if attribute == AttributeType::BodyFinesse {
+                value -= world
+                    .get::<&Armor>(item)
+                    .map(|armor| armor_penalty(armor.armor_type))
+                    .unwrap_or(0);
+            }
+            modifiers.push(CheckModifier::new(name, value));
+        }
+    }
+
+    modifiers.retain(|modifier| modifier.value != 0);
+    modifiers
+}
+
+/// Modifier a status effect applies to checks of an attribute
+pub fn status_modifier(
+    effect_type: StatusEffectType,
+    magnitude: i32,
+    attribute: AttributeType,
+) -> i32 {
+    let forceful = matches!(
+        attribute,
+        AttributeType::BodyOffence | AttributeType::BodyDefence
+    );
+    match effect_type {
+        StatusEffectType::Stunned => -STUNNED_PENALTY,
+        StatusEffectType::Weakened if forceful => -magnitude,
+        StatusEffectType::Strengthened if forceful => magnitude,
+        StatusEffectType::Slowed if attribute == AttributeType::BodyFinesse => -magnitude,
+        StatusEffectType::Hasted if attribute == AttributeType::BodyFinesse => magnitude,
+        _ => 0,
+    }
+}
+
+/// Penalty heavy armor applies to checks of physical finesse
+pub fn armor_penalty(material: MaterialKind) -> i32 {
+    match material {
+        MaterialKind::Chain => 1,
+        MaterialKind::Iron | MaterialKind::Steel => 2,
+        MaterialKind::Cloth | MaterialKind::Leather | MaterialKind::Mana => 0,
+    }
+}
+
+/// Look up the score behind an attribute
+///
+/// Entities with only bare `AttributeScores` use them for every class.
+fn attribute_score(world: &GameWorld, entity: EcsEntity, attribute: AttributeType) -> Option<i32> {
+    let pick = |scores: &AttributeScores| match attribute {
+        AttributeType::BodyOffence | AttributeType::MindOffence | AttributeType::SoulOffence => {
+            scores.score_offence
+        }
+        AttributeType::BodyFinesse | AttributeType::MindFinesse | AttributeType::SoulFinesse => {
+            scores.score_finesse
+        }
+        AttributeType::BodyDefence | AttributeType::MindDefence | AttributeType::SoulDefence => {
+            scores.score_defence
+        }
+    };
+    let classed = match attribute {
+        AttributeType::BodyOffence | AttributeType::BodyFinesse | AttributeType::BodyDefence => {
+            world
+                .get::<&BodyAttributeScores>(entity)
+                .ok()
+                .map(|scores| pick(&scores.0))
+        }
+        AttributeType::MindOffence | AttributeType::MindFinesse | AttributeType::MindDefence => {
+            world
+                .get::<&MindAttributeScores>(entity)
+                .ok()
+                .map(|scores| pick(&scores.0))
+        }
+        AttributeType::SoulOffence | AttributeType::SoulFinesse | AttributeType::SoulDefence => {
+            world
+                .get::<&SoulAttributeScores>(entity)
+                .ok()
+                .map(|scores| pick(&scores.0))
+        }
+    };
+    classed.or_else(|| {
+        world
+            .get::<&AttributeScores>(entity)
+            .ok()
+            .map(|scores| pick(&scores))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{EntityId, EquipSlot, StatusEffect, Talent};
+    use uuid::Uuid;
+
+    fn scores(finesse: i32) -> BodyAttributeScores {
+        let mut scores = AttributeScores::new();
+        scores.score_finesse = finesse;
+        BodyAttributeScores(scores)
+    }
+
+    #[test]
+    fn test_outcome_grading() {
+        assert_eq!(outcome_of(20, -15), CheckOutcome::CriticalSuccess);
+        assert_eq!(outcome_of(1, 15), CheckOutcome::CriticalFailure);
+        assert_eq!(outcome_of(12, 10), CheckOutcome::CriticalSuccess);
+        assert_eq!(outcome_of(12, 0), CheckOutcome::Success);
+        assert_eq!(outcome_of(12, -1), CheckOutcome::Failure);
+        assert_eq!(outcome_of(12, -10), CheckOutcome::CriticalFailure);
+    }
+
+    #[test]
+    fn test_modifiers_from_skill_attribute_status_and_equipment() {
+        let mut world = GameWorld::new();
+        let mut skills = Skills::new();
+        // Hard skills reach level 2 at 32 experience
+        skills.add_skill(Skill::Lockpicking, 32, 0);
+
+        let lockpicks = world.spawn((
+            Name::new("a set of lockpicks"),
+            SkillBonus::new().with_bonus(Skill::Lockpicking, 3),
+        ));
+        let mut mail = Armor::new();
+        mail.armor_type = MaterialKind::Steel;
+        let breastplate = world.spawn((Name::new("a steel breastplate"), mail));
+
+        let mut equipment = Equipment::new();
+        equipment.equip(
+            EquipSlot::MainHand,
+            EntityId::new(lockpicks, Uuid::new_v4()),
+        );
+        equipment.equip(EquipSlot::Chest, EntityId::new(breastplate, Uuid::new_v4()));
+        let mut effects = StatusEffects::new();
+        effects.add_effect(StatusEffect::new(StatusEffectType::Hasted, 10.0, 2));
+
+        let thief = world.spawn((skills, scores(14), equipment, effects));
+        let modifiers = check_modifiers(
+            &world,
+            thief,
+            Skill::Lockpicking,
+            AttributeType::BodyFinesse,
+        );
+        let total: i32 = modifiers.iter().map(|modifier| modifier.value).sum();
+        // Skill 4, attribute 2, hasted 2, lockpicks 3, breastplate -2
+        assert_eq!(total, 9);
+        assert!(modifiers.contains(&CheckModifier::new("a set of lockpicks", 3)));
+        assert!(modifiers.contains(&CheckModifier::new("a steel breastplate", -2)));
+
+        // Untrained with average scores has nothing to add
+        let novice = world.spawn((scores(10),));
+        assert!(
+            check_modifiers(
+                &world,
+                novice,
+                Skill::Lockpicking,
+                AttributeType::BodyFinesse
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_talent_skill_bonuses() {
+        let mut world = GameWorld::new();
+        let mut talents = Talents::new();
+        talents.add_talent(Talent::SilverTongue, 0);
+        talents.add_talent(Talent::Tactician, 0);
+        let diplomat = world.spawn((scores(10), talents));
+
+        let modifiers =
+            |skill| check_modifiers(&world, diplomat, skill, AttributeType::MindFinesse);
+        assert_eq!(
+            modifiers(Skill::Persuasion),
+            vec![CheckModifier::new("Silver Tongue", 3)]
+        );
+        assert_eq!(
+            modifiers(Skill::Bartering),
+            vec![CheckModifier::new("Silver Tongue", 3)]
+        );
+        assert_eq!(
+            modifiers(Skill::Dodging),
+            vec![CheckModifier::new("Tactician", 1)]
+        );
+        assert!(modifiers(Skill::Lockpicking).is_empty());
+    }
+
+    #[test]
+    fn test_lucky_rerolls_natural_ones() {
+        let mut world = GameWorld::new();
+        let plain = world.spawn((scores(10),));
+        let mut talents = Talents::new();
+        talents.add_talent(Talent::Lucky, 0);
+        let lucky = world.spawn((scores(10), talents));
+        let check = SkillCheck::for_skill(Skill::Lockpicking);
+
+        let fumbles = |entity| {
+            let mut resolver = SkillCheckResolver::seeded(11);
+            (0..400)
+                .filter(|_| resolver.resolve(&world, entity, &check).roll == 1)
+                .count()
+        };
+        // One in twenty rolls is a 1, but only one in four hundred twice running
+        assert!(fumbles(plain) > 10);
+        assert!(fumbles(lucky) < 5);
+    }
+
+    #[test]
+    fn test_seeded_resolver_is_reproducible() {
+        let mut world = GameWorld::new();
+        let thief = world.spawn((scores(14),));
+        let check = SkillCheck::for_skill(Skill::Lockpicking)
+            .against(CheckDifficulty::Moderate.target())
+            .with_modifier("Good light", 1);
+
+        let first: Vec<CheckResult> = {
+            let mut resolver = SkillCheckResolver::seeded(7);
+            (0..20)
+                .map(|_| resolver.resolve(&world, thief, &check))
+                .collect()
+        };
+        let mut resolver = SkillCheckResolver::seeded(7);
+        for expected in &first {
+            assert_eq!(&resolver.resolve(&world, thief, &check), expected);
+        }
+
+        for result in &first {
+            assert!((1..=CHECK_DIE).contains(&result.roll));
+            assert_eq!(result.total, result.roll + 3);
+            assert_eq!(result.margin, result.total - 15);
+            assert_eq!(result.outcome, outcome_of(result.roll, result.margin));
+        }
+        assert_eq!(first[0].challenge_level(), 2);
+    }
+
+    #[test]
+    fn test_opposed_ties_go_to_opponent() {
+        let mut world = GameWorld::new();
+        let sneak = world.spawn((scores(10),));
+        let guard = world.spawn((scores(10),));
+        let check = SkillCheck::for_skill(Skill::Stealth);
+
+        let mut resolver = SkillCheckResolver::seeded(42);
+        for _ in 0..50 {
+            let result = resolver.resolve_opposed(&world, sneak, &check, guard, &check);
+            let (actor, opponent) = (&result.actor, &result.opponent);
+            assert_eq!(actor.difficulty, opponent.total + 1);
+            assert_eq!(opponent.difficulty, actor.total);
+            if actor.roll != 1 && actor.roll != CHECK_DIE && actor.total == opponent.total {
+                assert!(!result.actor_wins());
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//
+// Copyright 2025-2026 Hans W. Uhlig. All Rights Reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+
+//! Social checks
+//!
+//! Rather than simply talking to an NPC, a character can try to persuade,
+//! deceive, intimidate, inspire or console it. Each approach checks a social
+//! skill against a moderate difficulty raised by the NPC's mind defence, so
+//! the talents that add to those skills (Silver Tongue, Intimidating, Natural
+//! Leader and Empathic) make the attempt more likely to land. The NPC is told
+//! how the attempt went before it replies.
+
+use crate::ecs::components::{AttributeScores, MindAttributeScores, Skill};
+use crate::ecs::systems::{CheckDifficulty, CheckResult, SkillCheck, SkillCheckResolver};
+use crate::ecs::{EcsEntity, GameWorld};
+use rand::Rng;
+
+/// Way of pressing an NPC in conversation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocialApproach {
+    Persuade,
+    Deceive,
+    Intimidate,
+    Inspire,
+    Console,
+}
+
+impl SocialApproach {
+    /// Approach taken by a talk command, or `None` for plain talk
+    pub fn from_command(cmd: &str) -> Option<Self> {
+        match cmd.to_lowercase().as_str() {
+            "persuade" => Some(SocialApproach::Persuade),
+            "deceive" | "lie" => Some(SocialApproach::Deceive),
+            "intimidate" | "threaten" => Some(SocialApproach::Intimidate),
+            "inspire" | "rally" => Some(SocialApproach::Inspire),
+            "console" | "comfort" => Some(SocialApproach::Console),
+            _ => None,
+        }
+    }
+
+    /// Skill the approach is checked with
+    pub fn skill(&self) -> Skill {
+        match self {
+            SocialApproach::Persuade => Skill::Persuasion,
+            SocialApproach::Deceive => Skill::Deception,
+            SocialApproach::Intimidate => Skill::Intimidation,
+            SocialApproach::Inspire => Skill::Leadership,
+            SocialApproach::Console => Skill::Insight,
+        }
+    }
+
+    /// Command word for the approach
+    pub fn verb(&self) -> &'static str {
+        match self {
+            SocialApproach::Persuade => "persuade",
+            SocialApproach::Deceive => "deceive",
+            SocialApproach::Intimidate => "intimidate",
+            SocialApproach::Inspire => "inspire",
+            SocialApproach::Console => "console",
+        }
+    }
+
+    /// What the speaker sees of the listener's reaction
+    pub fn reaction(&self, listener: &str, succeeded: bool) -> String {
+        match (self, succeeded) {
+            (SocialApproach::Persuade, true) => format!("{} seems convinced.", listener),
+            (SocialApproach::Persuade, false) => format!("{} is not convinced.", listener),
+            (SocialApproach::Deceive, true) => format!("{} believes you.", listener),
+            (SocialApproach::Deceive, false) => format!("{} sees through your lie.", listener),
+            (SocialApproach::Intimidate, true) => format!("{} looks frightened.", listener),
+            (SocialApproach::Intimidate, false) => format!("{} is not impressed.", listener),
+            (SocialApproach::Inspire, true) => format!("{} looks ready to follow you.", listener),
+            (SocialApproach::Inspire, false) => format!("{} is unmoved.", listener),
+            (SocialApproach::Console, true) => format!("{} seems comforted.", listener),
+            (SocialApproach::Console, false) => {
+                format!("{} doesn't feel understood.", listener)
+            }
+        }
+    }
+
+    /// What the listener is told about the attempt before it replies
+    pub fn situation(&self, speaker: &str, succeeded: bool) -> String {
+        match (self, succeeded) {
+            (SocialApproach::Persuade, true) => {
+                format!(
+                    "{} makes a convincing case. You are inclined to agree.",
+                    speaker
+                )
+            }
+            (SocialApproach::Persuade, false) => {
+                format!(
+                    "{} tries to persuade you, but you are not convinced.",
+                    speaker
+                )
+            }
+            (SocialApproach::Deceive, true) => {
+                format!("{} is lying to you, but you believe every word.", speaker)
+            }
+            (SocialApproach::Deceive, false) => {
+                format!(
+                    "{} is trying to deceive you, and you see through it.",
+                    speaker
+                )
+            }
+            (SocialApproach::Intimidate, true) => {
+                format!("{} threatens you, and you are afraid to refuse.", speaker)
+            }
+            (SocialApproach::Intimidate, false) => {
+                format!(
+                    "{} tries to intimidate you, but you are not afraid.",
+                    speaker
+                )
+            }
+            (SocialApproach::Inspire, true) => {
+                format!(
+                    "{} rallies you, and you are ready to follow their lead.",
+                    speaker
+                )
+            }
+            (SocialApproach::Inspire, false) => {
+                format!("{} tries to rally you, but you are unmoved.", speaker)
+            }
+            (SocialApproach::Console, true) => {
+                format!(
+                    "{} understands how you feel, and you are comforted.",
+                    speaker
+                )
+            }
+            (SocialApproach::Console, false) => {
+                format!(
+                    "{} tries to comfort you, but misreads how you feel.",
+                    speaker
+                )
+            }
+        }
+    }
+}
+
+/// Check a speaker's approach against a listener's resolve
+pub fn social_check<R: Rng>(
+    world: &GameWorld,
+    resolver: &mut SkillCheckResolver<R>,
+    speaker: EcsEntity,
+    listener: EcsEntity,
+    approach: SocialApproach,
+) -> CheckResult {
+    let difficulty = CheckDifficulty::Moderate.target() + resolve_of(world, listener);
+    let check = SkillCheck::for_skill(approach.skill()).against(difficulty);
+    resolver.resolve(world, speaker, &check)
+}
+
+/// Mind defence modifier, falling back to bare scores for simple creatures
+fn resolve_of(world: &GameWorld, entity: EcsEntity) -> i32 {
+    world
+        .get::<&MindAttributeScores>(entity)
+        .map(|scores| scores.0.score_defence)
+        .or_else(|_| {
+            world
+                .get::<&AttributeScores>(entity)
+                .map(|scores| scores.score_defence)
+        })
+        .map_or(0, |score| (score - 10) / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Talent, Talents};
+
+    /// Check the approach once without and once with the talent, both on the
+    /// seed's first roll
+    fn with_and_without(
+        approach: SocialApproach,
+        talent: Talent,
+        seed: u64,
+    ) -> (CheckResult, CheckResult) {
+        let mut world = GameWorld::new();
+        let listener = world.spawn((MindAttributeScores::new(),));
+        let plain = world.spawn((MindAttributeScores::new(),));
+        let mut talents = Talents::new();
+        talents.add_talent(talent, 0);
+        let gifted = world.spawn((MindAttributeScores::new(), talents));
+
+        let plain = social_check(
+            &world,
+            &mut SkillCheckResolver::seeded(seed),
+            plain,
+            listener,
+            approach,
+        );
+        let gifted = social_check(
+            &world,
+            &mut SkillCheckResolver::seeded(seed),
+            gifted,
+            listener,
+            approach,
+        );
+        (plain, gifted)
+    }
+
+    #[test]
+    fn test_approach_from_command() {
+        assert_eq!(
+            SocialApproach::from_command("Persuade"),
+            Some(SocialApproach::Persuade)
+        );
+        assert_eq!(
+            SocialApproach::from_command("threaten"),
+            Some(SocialApproach::Intimidate)
+        );
+        assert_eq!(SocialApproach::from_command("talk"), None);
+        assert_eq!(SocialApproach::Console.skill(), Skill::Insight);
+    }
+
+    #[test]
+    fn test_silver_tongue_persuades() {
+        // Seed 3 rolls a 13, short of the moderate 15 without help
+        let (plain, gifted) = with_and_without(SocialApproach::Persuade, Talent::SilverTongue, 3);
+        assert_eq!((plain.roll, plain.difficulty), (13, 15));
+        assert!(!plain.outcome.is_success());
+        assert!(gifted.outcome.is_success());
+        assert_eq!(gifted.total, 16);
+    }
+
+    #[test]
+    fn test_intimidating_frightens() {
+        // Seed 4 rolls a 14
+        let (plain, gifted) = with_and_without(SocialApproach::Intimidate, Talent::Intimidating, 4);
+        assert_eq!(plain.roll, 14);
+        assert!(!plain.outcome.is_success());
+        assert!(gifted.outcome.is_success());
+        assert_eq!(gifted.total, 17);
+    }
+
+    #[test]
+    fn test_natural_leader_inspires() {
+        // Seed 9 rolls a 12
+        let (plain, gifted) = with_and_without(SocialApproach::Inspire, Talent::NaturalLeader, 9);
+        assert_eq!(plain.roll, 12);
+        assert!(!plain.outcome.is_success());
+        assert!(gifted.outcome.is_success());
+        assert_eq!(gifted.total, 15);
+    }
+
+    #[test]
+    fn test_empathic_consoles() {
+        // Seed 8 rolls a 13
+        let (plain, gifted) = with_and_without(SocialApproach::Console, Talent::Empathic, 8);
+        assert_eq!(plain.roll, 13);
+        assert!(!plain.outcome.is_success());
+        assert!(gifted.outcome.is_success());
+        assert_eq!(gifted.total, 16);
+    }
+
+    #[test]
+    fn test_resolute_listener_is_harder_to_move() {
+        let mut world = GameWorld::new();
+        let mut stubborn = MindAttributeScores::new();
+        stubborn.0.score_defence = 20;
+        let listener = world.spawn((stubborn,));
+        let speaker = world.spawn((MindAttributeScores::new(),));
+
+        let result = social_check(
+            &world,
+            &mut SkillCheckResolver::seeded(3),
+            speaker,
+            listener,
+            SocialApproach::Deceive,
+        );
+        assert_eq!(result.difficulty, 20);
+    }
+}
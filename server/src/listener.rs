@@ -17,26 +17,31 @@
 //! Server RPC handler for gateway-to-server communication
 
 use crate::ecs::components::{AttributeType, CharacterBuilder, EntityId, Skill, Talent};
+use crate::ecs::EcsEntity;
 use crate::ecs::context::WorldContext;
+use crate::ecs::output::OutputSink;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tonic::{Request, Response, Status};
+use tokio::sync::{RwLock, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::Channel;
+use tonic::{Request, Response, Status, Streaming};
 use tracing::info;
 use wyldlands_common::gateway::{PersistentEntityId, SessionId};
 use wyldlands_common::proto::game_output::OutputType;
 use wyldlands_common::proto::{
     AuthenticateGatewayRequest, AuthenticateGatewayResponse, AuthenticateSessionRequest,
     AuthenticateSessionResponse, CheckUsernameRequest, CheckUsernameResponse, CreateAccountRequest,
-    CreateAccountResponse, DataValue, EditResponse, Empty, GameOutput, GatewayHeartbeatRequest,
-    GatewayHeartbeatResponse, GatewayManagement, GatewayPropertiesRequest,
-    GatewayPropertiesResponse, SendInputRequest, SendInputResponse, SendOutputRequest,
-    ServerStatisticsRequest, ServerStatisticsResponse, SessionDisconnectedRequest,
-    SessionHeartbeatRequest, SessionHeartbeatResponse, SessionReconnectedRequest,
-    SessionReconnectedResponse, SessionToWorld, StructuredOutput, TextOutput, WorldToSessionClient,
-    game_output,
+    CreateAccountResponse, DataArray, DataTable, DataValue, DisconnectSessionRequest, EditRequest,
+    EditResponse, Empty, GameOutput, GatewayHeartbeatRequest, GatewayHeartbeatResponse,
+    GatewayManagement, GatewayPropertiesRequest, GatewayPropertiesResponse, GatewayStreamMessage,
+    SendInputRequest, SendInputResponse, SendOutputRequest, SendPromptRequest,
+    ServerStatisticsRequest, ServerStatisticsResponse, ServerStreamMessage,
+    SessionDisconnectedRequest, SessionHeartbeatRequest, SessionHeartbeatResponse,
+    SessionReconnectedRequest, SessionReconnectedResponse, SessionToWorld, StructuredOutput,
+    TextOutput, WorldToSessionClient, data_value, game_output, gateway_stream_message,
+    server_stream_message,
 };
 
 /// Server RPC handler
@@ -65,13 +70,41 @@ pub struct ServerRpcHandler {
     /// Start time of the server handler
     start_time: std::time::Instant,
 
-    /// Client for sending messages back to gateway
-    gateway_client: Arc<RwLock<Option<WorldToSessionClient>>>,
+    /// Connected gateways by gateway ID
+    gateways: Arc<RwLock<HashMap<String, GatewayConnection>>>,
 
-    /// Gateway address for connection
+    /// Gateway owning each session (SessionId -> gateway ID)
+    session_gateways: Arc<RwLock<HashMap<SessionId, String>>>,
+
+    /// Callback address for gateways that don't announce their own
     gateway_addr: String,
 }
 
+/// Gateway ID used for gateways that don't identify themselves
+const DEFAULT_GATEWAY_ID: &str = "default";
+
+/// Capacity of a gateway stream's outbound queue before senders wait
+const GATEWAY_STREAM_BUFFER: usize = 256;
+
+/// Capacity of a session's inbound queue on a gateway stream before the stream waits
+const SESSION_STREAM_BUFFER: usize = 32;
+
+/// Sender half of a gateway's session stream
+type GatewayStreamSender = mpsc::Sender<Result<ServerStreamMessage, Status>>;
+
+/// Connection to a gateway's WorldToSession service
+#[derive(Debug, Clone)]
+struct GatewayConnection {
+    /// Address of the gateway's WorldToSession service
+    addr: String,
+
+    /// Client for sending messages back to the gateway (connected lazily)
+    client: Option<WorldToSessionClient>,
+
+    /// Session stream opened by the gateway, preferred over the unary client
+    stream: Option<GatewayStreamSender>,
+}
+
 /// Session state type for routing commands
 #[derive(Debug, Clone, PartialEq)]
 enum SessionStateType {
@@ -103,8 +136,11 @@ struct SessionState {
 
 impl ServerRpcHandler {
     /// Create a new server RPC handler
+    ///
+    /// The handler registers itself as the world's output sink so that systems
+    /// and commands can push text to other players' sessions.
     pub fn new(auth_key: &str, world_context: Arc<WorldContext>, gateway_addr: &str) -> Self {
-        Self {
+        let handler = Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             active_entities: Arc::new(RwLock::new(HashMap::new())),
             character_builders: Arc::new(RwLock::new(HashMap::new())),
@@ -112,9 +148,21 @@ impl ServerRpcHandler {
             auth_key: auth_key.to_string(),
             authenticated: Arc::new(RwLock::new(false)),
             start_time: std::time::Instant::now(),
-            gateway_client: Arc::new(RwLock::new(None)),
+            gateways: Arc::new(RwLock::new(HashMap::from([(
+                DEFAULT_GATEWAY_ID.to_string(),
+                GatewayConnection {
+                    addr: gateway_addr.to_string(),
+                    client: None,
+                    stream: None,
+                },
+            )]))),
+            session_gateways: Arc::new(RwLock::new(HashMap::new())),
             gateway_addr: gateway_addr.to_string(),
-        }
+        };
+        handler
+            .world_context
+            .set_output_sink(Arc::new(handler.clone()));
+        handler
     }
 
     /// Check if the connection is authenticated
@@ -122,54 +170,530 @@ impl ServerRpcHandler {
         *self.authenticated.read().await
     }
 
-    /// Connect to the gateway server
+    /// Connect to the default gateway server
     pub async fn connect_to_gateway(&self) -> Result<(), String> {
-        tracing::info!("Connecting to gateway at {}", self.gateway_addr);
-        
-        let channel = Channel::from_shared(format!("http://{}", self.gateway_addr))
+        self.connect_gateway(DEFAULT_GATEWAY_ID).await.map(|_| ())
+    }
+
+    /// Register a gateway and the address of its WorldToSession service
+    ///
+    /// Re-registering a gateway under a new address drops its old client so the
+    /// next message dials the new address.
+    pub async fn register_gateway(&self, gateway_id: &str, addr: &str) {
+        let mut gateways = self.gateways.write().await;
+        match gateways.get_mut(gateway_id) {
+            Some(gateway) if gateway.addr == addr => {}
+            Some(gateway) => {
+                gateway.addr = addr.to_string();
+                gateway.client = None;
+            }
+            None => {
+                gateways.insert(
+                    gateway_id.to_string(),
+                    GatewayConnection {
+                        addr: addr.to_string(),
+                        client: None,
+                        stream: None,
+                    },
+                );
+            }
+        }
+        tracing::info!("Registered gateway {} at {}", gateway_id, addr);
+    }
+
+    /// Record which gateway owns a session
+    pub async fn assign_session_gateway(&self, session_id: &str, gateway_id: &str) {
+        let gateway_id = Self::normalize_gateway_id(gateway_id);
+        let mut session_gateways = self.session_gateways.write().await;
+        session_gateways.insert(session_id.to_string(), gateway_id.to_string());
+    }
+
+    /// Get the gateway ID owning a session (the default gateway if unknown)
+    pub async fn gateway_for_session(&self, session_id: &str) -> String {
+        let session_gateways = self.session_gateways.read().await;
+        session_gateways
+            .get(session_id)
+            .cloned()
+            .unwrap_or_else(|| DEFAULT_GATEWAY_ID.to_string())
+    }
+
+    /// Map an empty gateway ID (older gateways) to the default gateway
+    fn normalize_gateway_id(gateway_id: &str) -> &str {
+        if gateway_id.is_empty() {
+            DEFAULT_GATEWAY_ID
+        } else {
+            gateway_id
+        }
+    }
+
+    /// Attach a gateway's session stream, registering the gateway if needed
+    async fn attach_gateway_stream(&self, gateway_id: &str, stream: GatewayStreamSender) {
+        let mut gateways = self.gateways.write().await;
+        gateways
+            .entry(gateway_id.to_string())
+            .or_insert_with(|| GatewayConnection {
+                addr: self.gateway_addr.clone(),
+                client: None,
+                stream: None,
+            })
+            .stream = Some(stream);
+        tracing::info!("Gateway {} opened a session stream", gateway_id);
+    }
+
+    /// Detach a gateway's session stream, falling back to unary calls
+    ///
+    /// Leaves the gateway alone if it has since opened a newer stream.
+    async fn detach_gateway_stream(&self, gateway_id: &str, stream: &GatewayStreamSender) {
+        let mut gateways = self.gateways.write().await;
+        if let Some(gateway) = gateways.get_mut(gateway_id) {
+            if gateway
+                .stream
+                .as_ref()
+                .is_some_and(|current| current.same_channel(stream))
+            {
+                gateway.stream = None;
+            }
+        }
+        tracing::info!("Gateway {} closed its session stream", gateway_id);
+    }
+
+    /// Get the session stream of the gateway owning a session, if it has one open
+    async fn session_stream(&self, session_id: &str) -> Option<GatewayStreamSender> {
+        let gateway_id = self.gateway_for_session(session_id).await;
+        let gateways = self.gateways.read().await;
+        gateways
+            .get(&gateway_id)
+            .and_then(|gateway| gateway.stream.clone())
+            .filter(|stream| !stream.is_closed())
+    }
+
+    /// Send a message over a session stream
+    ///
+    /// Never waits: the world pulse and the event router send to every
+    /// gateway in turn, so a message for a gateway whose outbound queue is full
+    /// is dropped rather than holding up output for all the others.
+    fn send_over_stream(
+        stream: GatewayStreamSender,
+        message: server_stream_message::Message,
+    ) -> Result<(), String> {
+        match stream.try_send(Ok(ServerStreamMessage {
+            message: Some(message),
+        })) {
+            Ok(()) => Ok(()),
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                tracing::warn!("Gateway session stream is full, dropping a message");
+                Err("Gateway session stream full".to_string())
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                Err("Gateway session stream closed".to_string())
+            }
+        }
+    }
+
+    /// Read messages from a gateway's session stream until it closes
+    ///
+    /// Each session gets its own worker and queue, so messages for one session
+    /// are handled in order while different sessions proceed independently.
+    async fn run_session_stream(
+        self,
+        gateway_id: String,
+        stream: GatewayStreamSender,
+        mut inbound: Streaming<GatewayStreamMessage>,
+    ) {
+        let mut workers: HashMap<SessionId, mpsc::Sender<gateway_stream_message::Message>> =
+            HashMap::new();
+
+        loop {
+            let message = match inbound.message().await {
+                Ok(Some(GatewayStreamMessage {
+                    message: Some(message),
+                })) => message,
+                Ok(Some(_)) => continue,
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::warn!("Session stream from gateway {} failed: {}", gateway_id, e);
+                    break;
+                }
+            };
+
+            let (session_id, closes_session) = match &message {
+                gateway_stream_message::Message::Hello(_) => {
+                    tracing::warn!("Ignoring repeated hello from gateway {}", gateway_id);
+                    continue;
+                }
+                gateway_stream_message::Message::Input(req) => (req.session_id.clone(), false),
+                gateway_stream_message::Message::FinishEditing(req) => {
+                    (req.session_id.clone(), false)
+                }
+                gateway_stream_message::Message::Disconnected(req) => {
+                    (req.session_id.clone(), true)
+                }
+            };
+
+            self.queue_for_session(
+                &mut workers,
+                &gateway_id,
+                &session_id,
+                message,
+                closes_session,
+            );
+        }
+
+        self.detach_gateway_stream(&gateway_id, &stream).await;
+    }
+
+    /// Queue a stream message on its session's worker without waiting
+    ///
+    /// A session whose queue is full has its message dropped and is told the
+    /// server is busy, so it can't hold up the other sessions on the stream.
+    /// Disconnects are always delivered. Returns whether the message was queued.
+    fn queue_for_session(
+        &self,
+        workers: &mut HashMap<SessionId, mpsc::Sender<gateway_stream_message::Message>>,
+        gateway_id: &str,
+        session_id: &str,
+        message: gateway_stream_message::Message,
+        closes_session: bool,
+    ) -> bool {
+        let worker = workers
+            .entry(session_id.to_string())
+            .or_insert_with(|| self.spawn_session_worker(gateway_id, session_id));
+        match worker.try_send(message) {
+            Ok(()) => {
+                if closes_session {
+                    // Dropping the sender lets the worker drain its queue and exit
+                    workers.remove(session_id);
+                }
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(message)) if closes_session => {
+                // Wait for room off the reader task; nothing follows a disconnect
+                if let Some(worker) = workers.remove(session_id) {
+                    tokio::spawn(async move {
+                        let _ = worker.send(message).await;
+                    });
+                }
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                tracing::warn!(
+                    "Session {} is overloaded, dropping a stream message",
+                    session_id
+                );
+                let handler = self.clone();
+                let session_id = session_id.to_string();
+                tokio::spawn(async move {
+                    let output = vec![GameOutput {
+                        output_type: Some(game_output::OutputType::Text(TextOutput {
+                            content: "The server is busy; your last command was dropped.\r\n"
+                                .to_string(),
+                        })),
+                    }];
+                    if let Err(e) = handler.send_output_to_session(&session_id, output).await {
+                        tracing::debug!("Failed to report overload to {}: {}", session_id, e);
+                    }
+                });
+                false
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                tracing::warn!("Session worker for {} stopped unexpectedly", session_id);
+                workers.remove(session_id);
+                false
+            }
+        }
+    }
+
+    /// Spawn the worker handling one session's stream messages in order
+    fn spawn_session_worker(
+        &self,
+        gateway_id: &str,
+        session_id: &str,
+    ) -> mpsc::Sender<gateway_stream_message::Message> {
+        let (sender, mut receiver) = mpsc::channel(SESSION_STREAM_BUFFER);
+        let handler = self.clone();
+        let gateway_id = gateway_id.to_string();
+        let session_id = session_id.to_string();
+
+        tokio::spawn(async move {
+            // Sessions arriving over a stream belong to the gateway that owns it
+            if !handler
+                .session_gateways
+                .read()
+                .await
+                .contains_key(&session_id)
+            {
+                handler
+                    .assign_session_gateway(&session_id, &gateway_id)
+                    .await;
+            }
+            while let Some(message) = receiver.recv().await {
+                handler.dispatch_stream_message(&session_id, message).await;
+            }
+        });
+
+        sender
+    }
+
+    /// Handle one message from a session stream using the unary handlers
+    async fn dispatch_stream_message(
+        &self,
+        session_id: &str,
+        message: gateway_stream_message::Message,
+    ) {
+        let result = match message {
+            gateway_stream_message::Message::Hello(_) => Ok(()),
+            // Command output is pushed back over the stream by send_input itself
+            gateway_stream_message::Message::Input(req) => {
+                self.send_input(Request::new(req)).await.map(|response| {
+                    if let Some(error) = response.into_inner().error {
+                        tracing::debug!("Input for session {} failed: {}", session_id, error);
+                    }
+                })
+            }
+            gateway_stream_message::Message::FinishEditing(req) => self
+                .finish_editing(Request::new(EditResponse {
+                    content: req.content,
+                }))
+                .await
+                .map(|_| ()),
+            gateway_stream_message::Message::Disconnected(req) => self
+                .session_disconnected(Request::new(req))
+                .await
+                .map(|_| ()),
+        };
+
+        if let Err(status) = result {
+            tracing::warn!(
+                "Session stream message for {} failed: {}",
+                session_id,
+                status.message()
+            );
+        }
+    }
+
+    /// Connect to a registered gateway, returning its client
+    async fn connect_gateway(&self, gateway_id: &str) -> Result<WorldToSessionClient, String> {
+        let addr = {
+            let gateways = self.gateways.read().await;
+            let gateway = gateways
+                .get(gateway_id)
+                .ok_or_else(|| format!("Unknown gateway {}", gateway_id))?;
+            gateway.addr.clone()
+        };
+
+        tracing::info!("Connecting to gateway {} at {}", gateway_id, addr);
+
+        let channel = Channel::from_shared(format!("http://{}", addr))
             .map_err(|e| format!("Invalid gateway address: {}", e))?
             .connect()
             .await
             .map_err(|e| format!("Failed to connect to gateway: {}", e))?;
 
         let client = WorldToSessionClient::new(channel);
-        let mut gateway_client = self.gateway_client.write().await;
-        *gateway_client = Some(client);
+        let mut gateways = self.gateways.write().await;
+        if let Some(gateway) = gateways.get_mut(gateway_id) {
+            gateway.client = Some(client.clone());
+        }
 
-        tracing::info!("Successfully connected to gateway");
-        Ok(())
+        tracing::info!("Successfully connected to gateway {}", gateway_id);
+        Ok(client)
+    }
+
+    /// Get a client for the gateway owning a session, connecting if needed
+    async fn session_client(&self, session_id: &str) -> Result<WorldToSessionClient, String> {
+        let gateway_id = self.gateway_for_session(session_id).await;
+        let client = {
+            let gateways = self.gateways.read().await;
+            gateways
+                .get(&gateway_id)
+                .ok_or_else(|| format!("Unknown gateway {}", gateway_id))?
+                .client
+                .clone()
+        };
+        match client {
+            Some(client) => Ok(client),
+            None => self.connect_gateway(&gateway_id).await,
+        }
     }
 
-    /// Send output to a session via the gateway
+    /// Drop a gateway's client after a failed call so the next call redials
+    async fn reset_session_client(&self, session_id: &str) {
+        let gateway_id = self.gateway_for_session(session_id).await;
+        let mut gateways = self.gateways.write().await;
+        if let Some(gateway) = gateways.get_mut(&gateway_id) {
+            gateway.client = None;
+        }
+    }
+
+    /// Send output to a session via the gateway that owns it
+    ///
+    /// Uses the gateway's session stream when one is open, otherwise the unary
+    /// WorldToSession call.
     pub async fn send_output_to_session(
         &self,
         session_id: &str,
         output: Vec<GameOutput>,
     ) -> Result<(), String> {
-        let gateway_client = self.gateway_client.read().await;
-        
-        if let Some(client) = gateway_client.as_ref() {
-            let mut client = client.clone();
-            drop(gateway_client); // Release the lock before making the RPC call
+        let request = SendOutputRequest {
+            session_id: session_id.to_string(),
+            output,
+            error: None,
+        };
 
-            let request = SendOutputRequest {
-                session_id: session_id.to_string(),
-                output,
-                error: None,
-            };
+        if let Some(stream) = self.session_stream(session_id).await {
+            return Self::send_over_stream(stream, server_stream_message::Message::Output(request));
+        }
 
-            client
-                .send_output(request)
-                .await
-                .map_err(|e| format!("Failed to send output to gateway: {}", e))?;
+        let mut client = self.session_client(session_id).await?;
 
-            Ok(())
-        } else {
-            Err("Gateway client not connected".to_string())
+        if let Err(e) = client.send_output(request).await {
+            self.reset_session_client(session_id).await;
+            return Err(format!("Failed to send output to gateway: {}", e));
+        }
+        Ok(())
+    }
+
+    /// Send a prompt to a session via the gateway that owns it
+    pub async fn send_prompt_to_session(&self, session_id: &str, prompt: &str) -> Result<(), String> {
+        let request = SendPromptRequest {
+            session_id: session_id.to_string(),
+            prompt: prompt.to_string(),
+        };
+
+        if let Some(stream) = self.session_stream(session_id).await {
+            return Self::send_over_stream(stream, server_stream_message::Message::Prompt(request));
+        }
+
+        let mut client = self.session_client(session_id).await?;
+
+        if let Err(e) = client.send_prompt(request).await {
+            self.reset_session_client(session_id).await;
+            return Err(format!("Failed to send prompt to gateway: {}", e));
         }
+        Ok(())
+    }
+
+    /// Put a session into editing mode via the gateway that owns it
+    pub async fn begin_editing_for_session(
+        &self,
+        session_id: &str,
+        title: &str,
+        content: &str,
+    ) -> Result<(), String> {
+        let request = EditRequest {
+            session_id: session_id.to_string(),
+            title: title.to_string(),
+            content: content.to_string(),
+        };
+
+        if let Some(stream) = self.session_stream(session_id).await {
+            return Self::send_over_stream(
+                stream,
+                server_stream_message::Message::BeginEditing(request),
+            );
+        }
+
+        let mut client = self.session_client(session_id).await?;
+
+        if let Err(e) = client.begin_editing(request).await {
+            self.reset_session_client(session_id).await;
+            return Err(format!("Failed to begin editing on gateway: {}", e));
+        }
+        Ok(())
+    }
+
+    /// Ask the gateway that owns a session to disconnect it
+    pub async fn disconnect_session(&self, session_id: &str, reason: &str) -> Result<(), String> {
+        let request = DisconnectSessionRequest {
+            session_id: session_id.to_string(),
+            reason: reason.to_string(),
+        };
+
+        if let Some(stream) = self.session_stream(session_id).await {
+            return Self::send_over_stream(
+                stream,
+                server_stream_message::Message::Disconnect(request),
+            );
+        }
+
+        let mut client = self.session_client(session_id).await?;
+
+        if let Err(e) = client.disconnect_session(request).await {
+            self.reset_session_client(session_id).await;
+            return Err(format!("Failed to disconnect session on gateway: {}", e));
+        }
+        Ok(())
     }
 }
 
+#[tonic::async_trait]
+impl OutputSink for ServerRpcHandler {
+    /// Deliver text to the session playing the given entity
+    async fn send_to_entity(&self, entity: EcsEntity, message: String) -> Result<(), String> {
+        let session_id = {
+            let active_entities = self.active_entities.read().await;
+            active_entities
+                .iter()
+                .find(|(_, entity_id)| entity_id.entity() == entity)
+                .map(|(session_id, _)| session_id.clone())
+        }
+        .ok_or_else(|| "Entity has no active session".to_string())?;
+
+        let output = vec![GameOutput {
+            output_type: Some(game_output::OutputType::Text(TextOutput {
+                content: format!("{}\r\n", message),
+            })),
+        }];
+        self.send_output_to_session(&session_id, output).await
+    }
+
+    /// Deliver structured side channel data to the session playing the given entity
+    async fn send_structured_to_entity(
+        &self,
+        entity: EcsEntity,
+        output_type: String,
+        data: serde_json::Value,
+    ) -> Result<(), String> {
+        let session_id = {
+            let active_entities = self.active_entities.read().await;
+            active_entities
+                .iter()
+                .find(|(_, entity_id)| entity_id.entity() == entity)
+                .map(|(session_id, _)| session_id.clone())
+        }
+        .ok_or_else(|| "Entity has no active session".to_string())?;
+
+        let output = vec![GameOutput {
+            output_type: Some(game_output::OutputType::Structured(StructuredOutput {
+                output_type,
+                data: Some(json_to_data_value(&data)),
+            })),
+        }];
+        self.send_output_to_session(&session_id, output).await
+    }
+}
+
+/// Convert a JSON value into the protocol's table/array/string data tree
+///
+/// Scalars are sent as strings, matching how MSDP represents every value.
+fn json_to_data_value(value: &serde_json::Value) -> DataValue {
+    let data_value = match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(text) => Some(data_value::DataValue::StringData(text.clone())),
+        serde_json::Value::Array(values) => Some(data_value::DataValue::ArrayData(DataArray {
+            values: values.iter().map(json_to_data_value).collect(),
+        })),
+        serde_json::Value::Object(entries) => Some(data_value::DataValue::TableData(DataTable {
+            entries: entries
+                .iter()
+                .map(|(key, value)| (key.clone(), json_to_data_value(value)))
+                .collect(),
+        })),
+        scalar => Some(data_value::DataValue::StringData(scalar.to_string())),
+    };
+    DataValue { data_value }
+}
+
 // ============================================================================
 // gRPC Server Implementation
 // ============================================================================
@@ -196,8 +720,18 @@ impl GatewayManagement for ServerRpcHandler {
         // Mark as authenticated
         let mut authenticated = self.authenticated.write().await;
         *authenticated = true;
+        drop(authenticated);
 
-        tracing::info!("gRPC Gateway authenticated successfully");
+        // Remember where to send this gateway's output
+        let gateway_id = Self::normalize_gateway_id(&req.gateway_id);
+        let callback_addr = if req.callback_addr.is_empty() {
+            self.gateway_addr.as_str()
+        } else {
+            req.callback_addr.as_str()
+        };
+        self.register_gateway(gateway_id, callback_addr).await;
+
+        tracing::info!("gRPC Gateway {} authenticated successfully", gateway_id);
         Ok(Response::new(AuthenticateGatewayResponse {
             success: true,
             error: None,
@@ -292,6 +826,16 @@ impl GatewayManagement for ServerRpcHandler {
             self.world_context.dirty_count().await.to_string(),
         );
 
+        // Gateway Statistics
+        let gateways = self.gateways.read().await;
+        statistics.insert("gateways".to_string(), gateways.len().to_string());
+        let streams = gateways
+            .values()
+            .filter(|gateway| gateway.stream.is_some())
+            .count();
+        statistics.insert("gateway_streams".to_string(), streams.to_string());
+        drop(gateways);
+
         // Character Creation Statistics
         let builders = self.character_builders.read().await;
         statistics.insert(
@@ -469,6 +1013,45 @@ impl GatewayManagement for ServerRpcHandler {
             error: None,
         }))
     }
+
+    type OpenSessionStreamStream = ReceiverStream<Result<ServerStreamMessage, Status>>;
+
+    /// Open the bidirectional session stream for a gateway
+    async fn open_session_stream(
+        &self,
+        request: Request<Streaming<GatewayStreamMessage>>,
+    ) -> Result<Response<Self::OpenSessionStreamStream>, Status> {
+        let mut inbound = request.into_inner();
+
+        // The gateway authenticates with its first message
+        let hello = match inbound.message().await? {
+            Some(GatewayStreamMessage {
+                message: Some(gateway_stream_message::Message::Hello(hello)),
+            }) => hello,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "Session stream must begin with a hello",
+                ));
+            }
+        };
+        if hello.auth_key != self.auth_key {
+            tracing::warn!("gRPC Gateway session stream rejected: invalid auth key");
+            return Err(Status::unauthenticated("Invalid authentication key"));
+        }
+
+        let mut authenticated = self.authenticated.write().await;
+        *authenticated = true;
+        drop(authenticated);
+
+        let gateway_id = Self::normalize_gateway_id(&hello.gateway_id).to_string();
+        let (sender, receiver) = mpsc::channel(GATEWAY_STREAM_BUFFER);
+        self.attach_gateway_stream(&gateway_id, sender.clone())
+            .await;
+
+        tokio::spawn(self.clone().run_session_stream(gateway_id, sender, inbound));
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
 }
 
 #[tonic::async_trait]
@@ -489,6 +1072,10 @@ impl SessionToWorld for ServerRpcHandler {
             req.username
         );
 
+        // Output for this session goes back through the gateway it came from
+        self.assign_session_gateway(&req.session_id, &req.gateway_id)
+            .await;
+
         // Validate input
         if req.username.is_empty() || req.password.is_empty() {
             return Ok(Response::new(AuthenticateSessionResponse {
@@ -760,6 +1347,11 @@ impl SessionToWorld for ServerRpcHandler {
         // Remove active entity mapping
         let mut active_entities = self.active_entities.write().await;
         active_entities.remove(&req.session_id);
+        drop(active_entities);
+
+        // Forget which gateway owned the session
+        let mut session_gateways = self.session_gateways.write().await;
+        session_gateways.remove(&req.session_id);
 
         Ok(Response::new(Empty {}))
     }
@@ -799,6 +1391,14 @@ impl SessionToWorld for ServerRpcHandler {
                 builders.insert(req.session_id.clone(), builder);
             }
 
+            // The session may have come back through a different gateway
+            let mut session_gateways = self.session_gateways.write().await;
+            session_gateways.remove(&req.old_session_id);
+            session_gateways.insert(
+                req.session_id.clone(),
+                Self::normalize_gateway_id(&req.gateway_id).to_string(),
+            );
+
             tracing::info!("Session state transferred successfully");
             Ok(Response::new(SessionReconnectedResponse {
                 success: true,
@@ -1508,9 +2108,11 @@ impl ServerRpcHandler {
             (parts[0].clone(), parts[1..].to_vec())
         };
 
-        // Execute command through command system
-        let mut command_system = self.world_context.command_system().write().await;
-        let result = command_system
+        // Execute command through command system (commands from different sessions
+        // run concurrently; see CommandAccess)
+        let result = self
+            .world_context
+            .command_system()
             .execute(
                 self.world_context.clone(),
                 entity_id.entity(),
@@ -1518,7 +2120,6 @@ impl ServerRpcHandler {
                 &args,
             )
             .await;
-        drop(command_system);
 
         // Convert CommandResult to response
         let mut output = Vec::new();
@@ -1582,6 +2183,8 @@ mod tests {
         // Authenticate first
         let auth_request = Request::new(AuthenticateGatewayRequest {
             auth_key: "test_key".to_string(),
+            gateway_id: String::new(),
+            callback_addr: String::new(),
         });
         handler.authenticate_gateway(auth_request).await.unwrap();
 
@@ -1600,4 +2203,185 @@ mod tests {
         assert_eq!(stats.get("active_sessions").unwrap(), "0");
         assert_eq!(stats.get("world_entities").unwrap(), "0");
     }
+
+    #[test]
+    fn test_json_to_data_value() {
+        let value = json_to_data_value(&serde_json::json!({
+            "body": { "health": 52.5, "name": "Alice" },
+            "flags": [true],
+        }));
+        let Some(data_value::DataValue::TableData(table)) = value.data_value else {
+            panic!("expected a table");
+        };
+        let Some(data_value::DataValue::TableData(body)) = &table.entries["body"].data_value else {
+            panic!("expected a nested table");
+        };
+        assert_eq!(
+            body.entries["health"].data_value,
+            Some(data_value::DataValue::StringData("52.5".to_string()))
+        );
+        assert_eq!(
+            body.entries["name"].data_value,
+            Some(data_value::DataValue::StringData("Alice".to_string()))
+        );
+        let Some(data_value::DataValue::ArrayData(flags)) = &table.entries["flags"].data_value
+        else {
+            panic!("expected an array");
+        };
+        assert_eq!(flags.values.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_session_gateway_routing() {
+        let persistence = Arc::new(PersistenceManager::new_mock());
+        let world_context = Arc::new(WorldContext::new(persistence));
+        let handler = ServerRpcHandler::new("test_key", world_context, "localhost:6005");
+
+        for (gateway_id, callback_addr) in [("gw-a", "10.0.0.1:6005"), ("gw-b", "10.0.0.2:6005")] {
+            let auth_request = Request::new(AuthenticateGatewayRequest {
+                auth_key: "test_key".to_string(),
+                gateway_id: gateway_id.to_string(),
+                callback_addr: callback_addr.to_string(),
+            });
+            handler.authenticate_gateway(auth_request).await.unwrap();
+        }
+
+        {
+            let gateways = handler.gateways.read().await;
+            assert_eq!(gateways.len(), 3);
+            assert_eq!(gateways.get("gw-b").unwrap().addr, "10.0.0.2:6005");
+            assert_eq!(gateways.get(DEFAULT_GATEWAY_ID).unwrap().addr, "localhost:6005");
+        }
+
+        handler.assign_session_gateway("session-1", "gw-a").await;
+        handler.assign_session_gateway("session-2", "gw-b").await;
+        handler.assign_session_gateway("session-3", "").await;
+
+        assert_eq!(handler.gateway_for_session("session-1").await, "gw-a");
+        assert_eq!(handler.gateway_for_session("session-2").await, "gw-b");
+        assert_eq!(handler.gateway_for_session("session-3").await, DEFAULT_GATEWAY_ID);
+        assert_eq!(handler.gateway_for_session("unknown").await, DEFAULT_GATEWAY_ID);
+
+        // Re-registering under a new address replaces the old address
+        handler.register_gateway("gw-a", "10.0.0.9:6005").await;
+        let gateways = handler.gateways.read().await;
+        assert_eq!(gateways.get("gw-a").unwrap().addr, "10.0.0.9:6005");
+        assert!(gateways.get("gw-a").unwrap().client.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_output_prefers_session_stream() {
+        let persistence = Arc::new(PersistenceManager::new_mock());
+        let world_context = Arc::new(WorldContext::new(persistence));
+        let handler = ServerRpcHandler::new("test_key", world_context, "localhost:6005");
+
+        let (sender, mut receiver) = mpsc::channel(GATEWAY_STREAM_BUFFER);
+        handler
+            .attach_gateway_stream("gw-stream", sender.clone())
+            .await;
+        handler
+            .assign_session_gateway("session-1", "gw-stream")
+            .await;
+
+        for line in ["first", "second"] {
+            let output = vec![GameOutput {
+                output_type: Some(OutputType::Text(TextOutput {
+                    content: line.to_string(),
+                })),
+            }];
+            handler
+                .send_output_to_session("session-1", output)
+                .await
+                .unwrap();
+        }
+        handler
+            .send_prompt_to_session("session-1", "> ")
+            .await
+            .unwrap();
+
+        // Messages arrive in the order they were sent
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            match receiver.recv().await.unwrap().unwrap().message.unwrap() {
+                server_stream_message::Message::Output(output) => {
+                    assert_eq!(output.session_id, "session-1");
+                    match &output.output[0].output_type {
+                        Some(OutputType::Text(text)) => received.push(text.content.clone()),
+                        other => panic!("Unexpected output {:?}", other),
+                    }
+                }
+                server_stream_message::Message::Prompt(prompt) => received.push(prompt.prompt),
+                other => panic!("Unexpected message {:?}", other),
+            }
+        }
+        assert_eq!(received, vec!["first", "second", "> "]);
+
+        // Once the stream closes, sends fall back to the unary client
+        handler.detach_gateway_stream("gw-stream", &sender).await;
+        assert!(handler.session_stream("session-1").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_late_detach_keeps_reconnected_stream() {
+        let persistence = Arc::new(PersistenceManager::new_mock());
+        let world_context = Arc::new(WorldContext::new(persistence));
+        let handler = ServerRpcHandler::new("test_key", world_context, "localhost:6005");
+        handler
+            .assign_session_gateway("session-1", "gw-stream")
+            .await;
+
+        let (old, _old_receiver) = mpsc::channel(GATEWAY_STREAM_BUFFER);
+        let (new, _new_receiver) = mpsc::channel(GATEWAY_STREAM_BUFFER);
+        handler
+            .attach_gateway_stream("gw-stream", old.clone())
+            .await;
+        handler
+            .attach_gateway_stream("gw-stream", new.clone())
+            .await;
+
+        // The old stream tearing down after the reconnect leaves the new one
+        handler.detach_gateway_stream("gw-stream", &old).await;
+        let stream = handler.session_stream("session-1").await.unwrap();
+        assert!(stream.same_channel(&new));
+
+        handler.detach_gateway_stream("gw-stream", &new).await;
+        assert!(handler.session_stream("session-1").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_full_session_queue_does_not_block_others() {
+        let persistence = Arc::new(PersistenceManager::new_mock());
+        let world_context = Arc::new(WorldContext::new(persistence));
+        let handler = ServerRpcHandler::new("test_key", world_context, "localhost:6005");
+
+        // A session whose worker is stuck with a full queue
+        let (stuck, mut stuck_queue) = mpsc::channel(1);
+        let mut workers = HashMap::from([("slow".to_string(), stuck)]);
+        let input = |session_id: &str| {
+            gateway_stream_message::Message::Input(SendInputRequest {
+                session_id: session_id.to_string(),
+                command: "look".to_string(),
+            })
+        };
+
+        assert!(handler.queue_for_session(&mut workers, "gw", "slow", input("slow"), false));
+        assert!(!handler.queue_for_session(&mut workers, "gw", "slow", input("slow"), false));
+        assert!(handler.queue_for_session(&mut workers, "gw", "fast", input("fast"), false));
+
+        // Disconnects still get through once there is room
+        let disconnected =
+            gateway_stream_message::Message::Disconnected(SessionDisconnectedRequest {
+                session_id: "slow".to_string(),
+            });
+        assert!(handler.queue_for_session(&mut workers, "gw", "slow", disconnected, true));
+        assert!(!workers.contains_key("slow"));
+        assert!(matches!(
+            stuck_queue.recv().await,
+            Some(gateway_stream_message::Message::Input(_))
+        ));
+        assert!(matches!(
+            stuck_queue.recv().await,
+            Some(gateway_stream_message::Message::Disconnected(_))
+        ));
+    }
 }
@@ -108,6 +108,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .start_auto_save_task(world_context.entities().clone());
     tracing::info!("Auto-save task started");
 
+    // Start the world tick loop driving NPCs, combat and movement
+    world_context
+        .scheduler()
+        .set_pulse_interval(config.world.pulse_interval());
+    world_context
+        .scheduler()
+        .set_death_config(config.world.death.clone())
+        .await;
+    match persistence_manager.default_starting_room().await {
+        Ok(room) => world_context.scheduler().set_starting_room(room).await,
+        Err(e) => tracing::warn!("Players without a bind point cannot respawn: {}", e),
+    }
+    world_context
+        .scheduler()
+        .clock()
+        .configure(&config.world.clock);
+    world_context
+        .scheduler()
+        .clone()
+        .start_tick_task(world_context.clone());
+
+    // Narrate world events to the players who can observe them
+    wyldlands_server::ecs::output::EventOutputRouter::start(world_context.clone());
+
     // Get Server Address from configuration
     let listen_addr: SocketAddr = config.listener.addr.to_addr();
 
@@ -121,9 +145,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     tracing::info!("Server RPC handler initialized with persistence");
 
-    // Connect to gateway for sending messages back
+    // Connect to the default gateway for sending messages back. Gateways that open a
+    // session stream are reached over that stream instead.
     if let Err(e) = handler.connect_to_gateway().await {
-        tracing::warn!("Failed to connect to gateway initially: {}. Will retry on first message.", e);
+        tracing::info!(
+            "Default gateway not reachable yet: {}. Waiting for a session stream or first message.",
+            e
+        );
     }
 
     // Start gRPC server
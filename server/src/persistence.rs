@@ -366,6 +366,25 @@ impl PersistenceManager {
             );
         }
 
+        // Characters start knowing every spell of the schools they trained in
+        let schools: Vec<String> = builder
+            .skills
+            .iter()
+            .filter(|(skill, ..)| skill.category() == SkillCategory::Magic)
+            .map(|(skill, ..)| skill.name().to_string())
+            .collect();
+        if !schools.is_empty() {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_spellbook (entity_id, spell_name)
+                 SELECT $1, LOWER(name) FROM wyldlands.spells WHERE enabled AND school = ANY($2)",
+            )
+            .bind(entity_uuid)
+            .bind(&schools)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to create spellbook: {}", e))?;
+        }
+
         // Create commandable component
         sqlx::query(
             "INSERT INTO wyldlands.entity_commandable (entity_id, max_queue_size)
@@ -376,6 +395,19 @@ impl PersistenceManager {
         .await
         .map_err(|e| format!("Failed to create commandable component: {}", e))?;
 
+        // The chosen starting location doubles as the initial bind point
+        if let Some(starting_location_id) = &builder.starting_location_id {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_bind_point (entity_id, room_id)
+                 SELECT $1, room_id FROM wyldlands.starting_locations WHERE id = $2",
+            )
+            .bind(entity_uuid)
+            .bind(starting_location_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Failed to create bind point component: {}", e))?;
+        }
+
         // Commit transaction
         tx.commit()
             .await
@@ -416,6 +448,8 @@ impl PersistenceManager {
             .await?;
         self.load_talents_component(entity_uuid, entity_id, world)
             .await?;
+        self.load_spellbook_component(entity_uuid, entity_id, world)
+            .await?;
         self.load_location_component(registry, entity_uuid, entity_id, world)
             .await?;
         self.load_combatant_component(registry, entity_uuid, entity_id, world)
@@ -426,6 +460,10 @@ impl PersistenceManager {
             .await?;
         self.load_personality_component(entity_uuid, entity_id, world)
             .await?;
+        self.load_npc_schedule_component(entity_uuid, entity_id, world)
+            .await?;
+        self.load_npc_conversation_component(entity_uuid, entity_id, world)
+            .await?;
         self.load_area_component(entity_uuid, entity_id, world)
             .await?;
         self.load_room_component(registry, entity_uuid, entity_id, world)
@@ -436,16 +474,32 @@ impl PersistenceManager {
             .await?;
         self.load_containable_component(entity_uuid, entity_id, world)
             .await?;
+        self.load_contained_by_component(registry, entity_uuid, entity_id, world)
+            .await?;
+        self.load_key_component(entity_uuid, entity_id, world)
+            .await?;
+        self.load_bind_point_component(registry, entity_uuid, entity_id, world)
+            .await?;
+        self.load_corpse_component(entity_uuid, entity_id, world)
+            .await?;
         self.load_enterable_component(registry, entity_uuid, entity_id, world)
             .await?;
         self.load_equipable_component(entity_uuid, entity_id, world)
             .await?;
+        self.load_skill_bonus_component(entity_uuid, entity_id, world)
+            .await?;
         self.load_weapon_component(entity_uuid, entity_id, world)
             .await?;
         self.load_material_component(entity_uuid, entity_id, world)
             .await?;
         self.load_armor_defense_component(entity_uuid, entity_id, world)
             .await?;
+        self.load_template_component(entity_uuid, entity_id, world)
+            .await?;
+        self.load_crafting_station_component(entity_uuid, entity_id, world)
+            .await?;
+        self.load_resource_node_component(entity_uuid, entity_id, world)
+            .await?;
         self.load_commandable_component(entity_uuid, entity_id, world)
             .await?;
         self.load_interactable_component(entity_uuid, entity_id, world)
@@ -585,6 +639,287 @@ impl PersistenceManager {
         Ok(loaded_count)
     }
 
+    /// Get the room of the first enabled starting location
+    ///
+    /// Players who die without a bind point respawn here.
+    pub async fn default_starting_room(&self) -> Result<Option<Uuid>, String> {
+        let row: Option<(Uuid,)> = sqlx::query_as(
+            "SELECT room_id FROM wyldlands.starting_locations
+             WHERE enabled
+             ORDER BY sort_order, id
+             LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load starting location: {}", e))?;
+
+        Ok(row.map(|(room_id,)| room_id))
+    }
+
+    /// Load every enabled spell definition into a registry
+    ///
+    /// Rows that do not describe a valid spell are logged and skipped.
+    pub async fn load_spells(&self) -> Result<SpellRegistry, String> {
+        type SpellRow = (
+            String,
+            String,
+            String,
+            f32,
+            f32,
+            i32,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            i32,
+            i32,
+            f32,
+        );
+        let rows: Vec<SpellRow> = sqlx::query_as(
+            "SELECT name, school, description, energy_cost, cast_time, difficulty, target::text,
+                    effect::text, damage_type::text, status_effect, amount_min, amount_max, duration
+             FROM wyldlands.spells WHERE enabled",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load spells: {}", e))?;
+
+        let mut registry = SpellRegistry::new();
+        for (
+            name,
+            school,
+            description,
+            energy_cost,
+            cast_time,
+            difficulty,
+            target,
+            effect,
+            damage_type,
+            status_effect,
+            amount_min,
+            amount_max,
+            duration,
+        ) in rows
+        {
+            let effect = match effect.as_str() {
+                "Damage" => {
+                    damage_type
+                        .as_deref()
+                        .and_then(DamageType::from_str)
+                        .map(|damage_type| SpellEffect::Damage {
+                            damage_type,
+                            min: amount_min,
+                            max: amount_max,
+                        })
+                }
+                "Heal" => Some(SpellEffect::Heal {
+                    min: amount_min,
+                    max: amount_max,
+                }),
+                "Status" => status_effect
+                    .as_deref()
+                    .and_then(StatusEffectType::from_str)
+                    .map(|effect_type| SpellEffect::Status {
+                        effect_type,
+                        duration,
+                        magnitude: amount_max,
+                    }),
+                "Cure" => status_effect
+                    .as_deref()
+                    .and_then(StatusEffectType::from_str)
+                    .map(|effect_type| SpellEffect::Cure { effect_type }),
+                _ => None,
+            };
+            let (Ok(school), Some(target), Some(effect)) = (
+                Skill::from_str(&school),
+                SpellTarget::from_str(&target),
+                effect,
+            ) else {
+                tracing::warn!("Skipping invalid spell definition: {}", name);
+                continue;
+            };
+            let spell = Spell {
+                name,
+                school,
+                description,
+                energy_cost,
+                cast_time,
+                difficulty,
+                target,
+                effect,
+            };
+            if let Err(e) = registry.register(spell) {
+                tracing::warn!("{}", e);
+            }
+        }
+
+        tracing::info!("Loaded {} spells", registry.len());
+        Ok(registry)
+    }
+
+    /// Load item templates into a registry
+    pub async fn load_item_templates(&self) -> Result<ItemTemplateRegistry, String> {
+        type TemplateRow = (
+            String,
+            String,
+            Vec<String>,
+            String,
+            String,
+            f32,
+            Option<String>,
+            Vec<String>,
+            Option<i32>,
+            Option<i32>,
+            Option<String>,
+            f32,
+        );
+        let rows: Vec<TemplateRow> = sqlx::query_as(
+            "SELECT id, name, keywords, short, long, weight, material, slots::text[],
+                    damage_min, damage_max, damage_type::text, attack_speed
+             FROM wyldlands.item_templates",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load item templates: {}", e))?;
+
+        let defense_rows: Vec<(String, String, i32)> = sqlx::query_as(
+            "SELECT template_id, damage_kind::text, defense FROM wyldlands.item_template_defenses",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load item template defenses: {}", e))?;
+        let mut defenses: HashMap<String, Vec<(DamageType, i32)>> = HashMap::new();
+        for (template_id, damage_kind_str, defense) in defense_rows {
+            if let Some(damage_kind) = DamageType::from_str(&damage_kind_str) {
+                defenses
+                    .entry(template_id)
+                    .or_default()
+                    .push((damage_kind, defense));
+            }
+        }
+
+        let mut registry = ItemTemplateRegistry::new();
+        for (
+            id,
+            name,
+            keywords,
+            short,
+            long,
+            weight,
+            material,
+            slot_strs,
+            damage_min,
+            damage_max,
+            damage_type,
+            attack_speed,
+        ) in rows
+        {
+            let material = material.as_deref().and_then(MaterialKind::from_str);
+            let weapon = match (damage_min, damage_max, damage_type.as_deref()) {
+                (Some(min), Some(max), Some(damage_type)) => {
+                    DamageType::from_str(damage_type).map(|damage_type| {
+                        let mut weapon = Weapon::new(min, max, damage_type);
+                        weapon.attack_speed = attack_speed;
+                        weapon
+                    })
+                }
+                _ => None,
+            };
+            let armor = defenses.remove(&id).map(|entries| {
+                let mut armor = Armor::new();
+                if let Some(material) = material {
+                    armor.armor_type = material;
+                }
+                for (damage_kind, defense) in entries {
+                    armor.set_defense(damage_kind, defense);
+                }
+                armor
+            });
+            registry.register(ItemTemplate {
+                id,
+                name,
+                keywords,
+                short,
+                long,
+                weight,
+                material,
+                slots: slot_strs
+                    .iter()
+                    .filter_map(|slot| EquipSlot::from_str(slot))
+                    .collect(),
+                weapon,
+                armor,
+            });
+        }
+
+        tracing::info!("Loaded {} item templates", registry.len());
+        Ok(registry)
+    }
+
+    /// Load crafting recipes into a registry
+    ///
+    /// Recipes naming unknown templates or non-crafting skills are skipped.
+    pub async fn load_recipes(
+        &self,
+        templates: &ItemTemplateRegistry,
+    ) -> Result<RecipeRegistry, String> {
+        let rows: Vec<(String, String, i32, i32, Option<String>, String, i32)> = sqlx::query_as(
+            "SELECT name, skill, min_level, difficulty, station, output, output_quantity
+             FROM wyldlands.recipes WHERE enabled",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load recipes: {}", e))?;
+
+        let component_rows: Vec<(String, String, i32, bool)> = sqlx::query_as(
+            "SELECT recipe_name, template_id, quantity, consumed FROM wyldlands.recipe_components",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load recipe components: {}", e))?;
+        let mut components: HashMap<String, Vec<(String, i32, bool)>> = HashMap::new();
+        for (recipe_name, template_id, quantity, consumed) in component_rows {
+            components
+                .entry(recipe_name)
+                .or_default()
+                .push((template_id, quantity, consumed));
+        }
+
+        let mut registry = RecipeRegistry::new();
+        for (name, skill, min_level, difficulty, station, output, output_quantity) in rows {
+            let Ok(skill) = Skill::from_str(&skill) else {
+                tracing::warn!("Skipping invalid recipe definition: {}", name);
+                continue;
+            };
+            let mut ingredients = Vec::new();
+            let mut tools = Vec::new();
+            for (template_id, quantity, consumed) in components.remove(&name).unwrap_or_default() {
+                if consumed {
+                    ingredients.push(Ingredient::new(template_id, quantity.max(1) as u32));
+                } else {
+                    tools.push(template_id);
+                }
+            }
+            let recipe = Recipe {
+                name,
+                skill,
+                min_level,
+                difficulty,
+                ingredients,
+                tools,
+                station,
+                output,
+                output_quantity: output_quantity.max(1) as u32,
+            };
+            if let Err(e) = registry.register(recipe, templates) {
+                tracing::warn!("{}", e);
+            }
+        }
+
+        tracing::info!("Loaded {} recipes", registry.len());
+        Ok(registry)
+    }
+
     /// Load Name component
     async fn load_name_component(
         &self,
@@ -807,6 +1142,34 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Load Spellbook component
+    async fn load_spellbook_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT spell_name FROM wyldlands.entity_spellbook WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load spellbook component: {}", e))?;
+
+        if !rows.is_empty() {
+            let mut spellbook = Spellbook::new();
+            for (spell_name,) in rows {
+                spellbook.learn(&spell_name);
+            }
+            world
+                .insert_one(entity_id, spellbook)
+                .map_err(|e| format!("Failed to add Spellbook component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
     /// Load Location component
     async fn load_location_component(
         &self,
@@ -988,6 +1351,74 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Load NpcSchedule component
+    async fn load_npc_schedule_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let rows: Vec<(i32, Option<String>, Option<Uuid>)> = sqlx::query_as(
+            "SELECT start_hour, goal, room_id FROM wyldlands.entity_npc_schedule
+             WHERE entity_id = $1 ORDER BY start_hour",
+        )
+        .bind(entity_uuid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load NPC schedule component: {}", e))?;
+
+        if !rows.is_empty() {
+            let mut schedule = NpcSchedule::new();
+            for (start_hour, goal, room_uuid) in rows {
+                let block = schedule.block_mut(start_hour as u32);
+                block.goal = goal;
+                block.room = room_uuid.map(EntityId::from_uuid);
+            }
+            world
+                .insert_one(entity_id, schedule)
+                .map_err(|e| format!("Failed to add NpcSchedule component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Load NpcConversation component
+    async fn load_npc_conversation_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let rows: Vec<(Uuid, Uuid, String, i64)> = sqlx::query_as(
+            "SELECT partner_id, speaker_id, message, spoken_at FROM wyldlands.entity_npc_conversation
+             WHERE entity_id = $1 ORDER BY partner_id, sequence",
+        )
+        .bind(entity_uuid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load NPC conversation component: {}", e))?;
+
+        if !rows.is_empty() {
+            let mut conversation = NpcConversation::new();
+            for (partner_id, speaker, message, spoken_at) in rows {
+                conversation
+                    .conversations
+                    .entry(partner_id)
+                    .or_default()
+                    .push(ConversationMessage {
+                        speaker,
+                        message,
+                        timestamp: spoken_at as u64,
+                    });
+            }
+            world
+                .insert_one(entity_id, conversation)
+                .map_err(|e| format!("Failed to add NpcConversation component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
     /// Load Area component
     async fn load_area_component(
         &self,
@@ -1216,6 +1647,113 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Load ContainedBy component
+    ///
+    /// The holder may not be loaded yet (an offline character's inventory), in
+    /// which case the link keeps only its UUID until the holder is loaded.
+    async fn load_contained_by_component(
+        &self,
+        registry: &EntityRegistry,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(Uuid,)> = sqlx::query_as(
+            "SELECT container_id FROM wyldlands.entity_container_contents WHERE content_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load contained by component: {}", e))?;
+
+        if let Some((container_uuid,)) = row {
+            let container_id = registry
+                .get_entity_id_by_uuid(container_uuid)
+                .unwrap_or_else(|| EntityId::from_uuid(container_uuid));
+            // Contained entities are not in a room
+            let _ = world.remove_one::<Location>(entity_id);
+            world
+                .insert_one(entity_id, ContainedBy::new(container_id))
+                .map_err(|e| format!("Failed to add ContainedBy component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Load Key component
+    async fn load_key_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT unlock_code FROM wyldlands.entity_key WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to load key component: {}", e))?;
+
+        if let Some((unlock_code,)) = row {
+            world
+                .insert_one(entity_id, Key::new(unlock_code))
+                .map_err(|e| format!("Failed to add Key component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Load Corpse component
+    async fn load_corpse_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(f32,)> = sqlx::query_as(
+            "SELECT decay_remaining FROM wyldlands.entity_corpse WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load corpse component: {}", e))?;
+
+        if let Some((decay_remaining,)) = row {
+            world
+                .insert_one(entity_id, Corpse::new(decay_remaining))
+                .map_err(|e| format!("Failed to add Corpse component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Load BindPoint component
+    async fn load_bind_point_component(
+        &self,
+        registry: &EntityRegistry,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(Uuid,)> =
+            sqlx::query_as("SELECT room_id FROM wyldlands.entity_bind_point WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| format!("Failed to load bind point component: {}", e))?;
+
+        if let Some((room_uuid,)) = row {
+            let room_id = registry
+                .get_entity_id_by_uuid(room_uuid)
+                .unwrap_or_else(|| EntityId::from_uuid(room_uuid));
+            world
+                .insert_one(entity_id, BindPoint::new(room_id))
+                .map_err(|e| format!("Failed to add BindPoint component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
     /// Load Enterable component
     async fn load_enterable_component(
         &self,
@@ -1301,6 +1839,42 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Load SkillBonus component
+    async fn load_skill_bonus_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let rows: Vec<(String, i32)> = sqlx::query_as(
+            "SELECT skill_name, bonus FROM wyldlands.entity_skill_bonus WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load skill bonus component: {}", e))?;
+
+        if rows.is_empty() {
+            return Ok(());
+        }
+        let mut skill_bonus = SkillBonus::new();
+        for (skill_name, bonus) in rows {
+            match Skill::from_str(&skill_name) {
+                Ok(skill) => {
+                    skill_bonus.bonuses.insert(skill, bonus);
+                }
+                Err(e) => {
+                    tracing::warn!("Unknown skill name in database: {}", e);
+                }
+            }
+        }
+        world
+            .insert_one(entity_id, skill_bonus)
+            .map_err(|e| format!("Failed to add SkillBonus component: {}", e))?;
+
+        Ok(())
+    }
+
     /// Load Weapon component
     async fn load_weapon_component(
         &self,
@@ -1394,65 +1968,174 @@ impl PersistenceManager {
         Ok(())
     }
 
-    /// Load Commandable component
-    async fn load_commandable_component(
+    /// Load Template component
+    async fn load_template_component(
         &self,
         entity_uuid: Uuid,
         entity_id: EcsEntity,
         world: &mut GameWorld,
     ) -> Result<(), String> {
-        let row: Option<(i32,)> = sqlx::query_as(
-            "SELECT max_queue_size FROM wyldlands.entity_commandable WHERE entity_id = $1",
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT template_id FROM wyldlands.entity_template WHERE entity_id = $1",
         )
         .bind(entity_uuid)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| format!("Failed to load commandable component: {}", e))?;
+        .map_err(|e| format!("Failed to load template component: {}", e))?;
 
-        if let Some((max_queue_size,)) = row {
-            let commandable = Commandable {
-                command_queue: Vec::new(),
-                max_queue_size: max_queue_size as usize,
-            };
+        if let Some((template_id,)) = row {
             world
-                .insert_one(entity_id, commandable)
-                .map_err(|e| format!("Failed to add Commandable component: {}", e))?;
+                .insert_one(entity_id, Template::new(template_id))
+                .map_err(|e| format!("Failed to add Template component: {}", e))?;
         }
 
         Ok(())
     }
 
-    /// Load Interactable component (marker only)
-    async fn load_interactable_component(
+    /// Load CraftingStation component
+    async fn load_crafting_station_component(
         &self,
         entity_uuid: Uuid,
         entity_id: EcsEntity,
         world: &mut GameWorld,
     ) -> Result<(), String> {
-        let exists: Option<(Uuid,)> = sqlx::query_as(
-            "SELECT entity_id FROM wyldlands.entity_interactable WHERE entity_id = $1",
+        let row: Option<(String,)> = sqlx::query_as(
+            "SELECT station FROM wyldlands.entity_crafting_station WHERE entity_id = $1",
         )
         .bind(entity_uuid)
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| format!("Failed to load interactable component: {}", e))?;
+        .map_err(|e| format!("Failed to load crafting station component: {}", e))?;
 
-        if exists.is_some() {
-            let interactable = Interactable {
-                interactions: Vec::new(),
-            };
+        if let Some((station,)) = row {
             world
-                .insert_one(entity_id, interactable)
-                .map_err(|e| format!("Failed to add Interactable component: {}", e))?;
+                .insert_one(entity_id, CraftingStation::new(station))
+                .map_err(|e| format!("Failed to add CraftingStation component: {}", e))?;
         }
 
         Ok(())
     }
 
-    /// Save any entity to database
-    /// Saves all components attached to the entity (characters, rooms, objects, NPCs, etc.)
-    #[instrument(skip(self, world))]
-    pub async fn save_entity(&self, world: &GameWorld, entity_id: EcsEntity) -> Result<(), String> {
+    /// Load ResourceNode component (with its yield table)
+    async fn load_resource_node_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(String, i32, Option<String>, i32, i32, f32, f32)> = sqlx::query_as(
+            "SELECT skill, difficulty, tool, charges, max_charges, respawn_time, respawn_timer
+             FROM wyldlands.entity_resource_node WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load resource node component: {}", e))?;
+
+        let Some((skill, difficulty, tool, charges, max_charges, respawn_time, respawn_timer)) =
+            row
+        else {
+            return Ok(());
+        };
+        let Ok(skill) = Skill::from_str(&skill) else {
+            tracing::warn!("Resource node {} has unknown skill {}", entity_uuid, skill);
+            return Ok(());
+        };
+
+        let yields: Vec<(String, i32, i32, i32, i32)> = sqlx::query_as(
+            "SELECT template_id, weight, min_quantity, max_quantity, min_level
+             FROM wyldlands.entity_resource_yield WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load resource yields: {}", e))?;
+
+        let node = ResourceNode {
+            skill,
+            difficulty,
+            tool,
+            yields: yields
+                .into_iter()
+                .map(
+                    |(template_id, weight, min_quantity, max_quantity, min_level)| {
+                        ResourceYield::new(template_id, weight.max(0) as u32)
+                            .with_quantity(min_quantity.max(0) as u32, max_quantity.max(0) as u32)
+                            .with_min_level(min_level)
+                    },
+                )
+                .collect(),
+            charges: charges.max(0) as u32,
+            max_charges: max_charges.max(0) as u32,
+            respawn_time,
+            respawn_timer,
+        };
+        world
+            .insert_one(entity_id, node)
+            .map_err(|e| format!("Failed to add ResourceNode component: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Load Commandable component
+    async fn load_commandable_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let row: Option<(i32,)> = sqlx::query_as(
+            "SELECT max_queue_size FROM wyldlands.entity_commandable WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load commandable component: {}", e))?;
+
+        if let Some((max_queue_size,)) = row {
+            let commandable = Commandable {
+                command_queue: Vec::new(),
+                max_queue_size: max_queue_size as usize,
+            };
+            world
+                .insert_one(entity_id, commandable)
+                .map_err(|e| format!("Failed to add Commandable component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Load Interactable component (marker only)
+    async fn load_interactable_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &mut GameWorld,
+    ) -> Result<(), String> {
+        let exists: Option<(Uuid,)> = sqlx::query_as(
+            "SELECT entity_id FROM wyldlands.entity_interactable WHERE entity_id = $1",
+        )
+        .bind(entity_uuid)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to load interactable component: {}", e))?;
+
+        if exists.is_some() {
+            let interactable = Interactable {
+                interactions: Vec::new(),
+            };
+            world
+                .insert_one(entity_id, interactable)
+                .map_err(|e| format!("Failed to add Interactable component: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Save any entity to database
+    /// Saves all components attached to the entity (characters, rooms, objects, NPCs, etc.)
+    #[instrument(skip(self, world))]
+    pub async fn save_entity(&self, world: &GameWorld, entity_id: EcsEntity) -> Result<(), String> {
         // Get entity UUID
         let entity_uuid = world
             .get::<&EntityUuid>(entity_id)
@@ -1497,6 +2180,8 @@ impl PersistenceManager {
             .await?;
         self.save_talents_component(uuid, entity_id, world, &mut tx)
             .await?;
+        self.save_spellbook_component(uuid, entity_id, world, &mut tx)
+            .await?;
         self.save_location_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_combatant_component(uuid, entity_id, world, &mut tx)
@@ -1507,6 +2192,10 @@ impl PersistenceManager {
             .await?;
         self.save_personality_component(uuid, entity_id, world, &mut tx)
             .await?;
+        self.save_npc_schedule_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_npc_conversation_component(uuid, entity_id, world, &mut tx)
+            .await?;
         self.save_area_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_room_component(uuid, entity_id, world, &mut tx)
@@ -1517,16 +2206,32 @@ impl PersistenceManager {
             .await?;
         self.save_containable_component(uuid, entity_id, world, &mut tx)
             .await?;
+        self.save_contained_by_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_key_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_bind_point_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_corpse_component(uuid, entity_id, world, &mut tx)
+            .await?;
         self.save_enterable_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_equipable_component(uuid, entity_id, world, &mut tx)
             .await?;
+        self.save_skill_bonus_component(uuid, entity_id, world, &mut tx)
+            .await?;
         self.save_weapon_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_material_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_armor_defense_component(uuid, entity_id, world, &mut tx)
             .await?;
+        self.save_template_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_crafting_station_component(uuid, entity_id, world, &mut tx)
+            .await?;
+        self.save_resource_node_component(uuid, entity_id, world, &mut tx)
+            .await?;
         self.save_commandable_component(uuid, entity_id, world, &mut tx)
             .await?;
         self.save_interactable_component(uuid, entity_id, world, &mut tx)
@@ -1797,6 +2502,37 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Save Spellbook component
+    async fn save_spellbook_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(spellbook) = world.get::<&Spellbook>(entity_id) {
+            // Delete existing spells as they can be forgotten
+            sqlx::query("DELETE FROM wyldlands.entity_spellbook WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete old spellbook: {}", e))?;
+
+            for spell_name in spellbook.iter() {
+                sqlx::query(
+                    "INSERT INTO wyldlands.entity_spellbook (entity_id, spell_name)
+                     VALUES ($1, $2)",
+                )
+                .bind(entity_uuid)
+                .bind(spell_name)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to save spell {}: {}", spell_name, e))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Save Location component
     async fn save_location_component(
         &self,
@@ -1949,6 +2685,84 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Save NpcSchedule component
+    async fn save_npc_schedule_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(schedule) = world.get::<&NpcSchedule>(entity_id) {
+            // Delete existing blocks as they can be removed
+            sqlx::query("DELETE FROM wyldlands.entity_npc_schedule WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete old NPC schedule: {}", e))?;
+
+            for block in &schedule.blocks {
+                sqlx::query(
+                    "INSERT INTO wyldlands.entity_npc_schedule (entity_id, start_hour, goal, room_id)
+                     VALUES ($1, $2, $3, $4)",
+                )
+                .bind(entity_uuid)
+                .bind(block.start_hour as i32)
+                .bind(&block.goal)
+                .bind(block.room.map(|id| id.uuid()))
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| {
+                    format!(
+                        "Failed to save NPC schedule block {:02}:00: {}",
+                        block.start_hour, e
+                    )
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Save NpcConversation component
+    async fn save_npc_conversation_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(conversation) = world.get::<&NpcConversation>(entity_id) {
+            // Delete existing messages as older ones fall out of the history
+            sqlx::query("DELETE FROM wyldlands.entity_npc_conversation WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete old NPC conversations: {}", e))?;
+
+            for (partner_id, messages) in &conversation.conversations {
+                for (sequence, message) in messages.iter().enumerate() {
+                    sqlx::query(
+                        "INSERT INTO wyldlands.entity_npc_conversation
+                             (entity_id, partner_id, sequence, speaker_id, message, spoken_at)
+                         VALUES ($1, $2, $3, $4, $5, $6)",
+                    )
+                    .bind(entity_uuid)
+                    .bind(partner_id)
+                    .bind(sequence as i32)
+                    .bind(message.speaker)
+                    .bind(&message.message)
+                    .bind(message.timestamp as i64)
+                    .execute(&mut **tx)
+                    .await
+                    .map_err(|e| {
+                        format!("Failed to save NPC conversation with {}: {}", partner_id, e)
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Save Area component
     async fn save_area_component(
         &self,
@@ -2136,6 +2950,114 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Save ContainedBy component
+    async fn save_contained_by_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        // An entity is held by at most one container
+        sqlx::query("DELETE FROM wyldlands.entity_container_contents WHERE content_id = $1")
+            .bind(entity_uuid)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to delete old container contents: {}", e))?;
+
+        if let Ok(contained) = world.get::<&ContainedBy>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_container_contents (container_id, content_id)
+                 VALUES ($1, $2)",
+            )
+            .bind(contained.container_id.uuid())
+            .bind(entity_uuid)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save contained by component: {}", e))?;
+
+            // Contained entities are not in a room
+            sqlx::query("DELETE FROM wyldlands.entity_location WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete location of contained entity: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Save Key component
+    async fn save_key_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(key) = world.get::<&Key>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_key (entity_id, unlock_code)
+                 VALUES ($1, $2)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET unlock_code = EXCLUDED.unlock_code",
+            )
+            .bind(entity_uuid)
+            .bind(&key.unlock_code)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save key component: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Save Corpse component
+    async fn save_corpse_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(corpse) = world.get::<&Corpse>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_corpse (entity_id, decay_remaining)
+                 VALUES ($1, $2)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET decay_remaining = EXCLUDED.decay_remaining",
+            )
+            .bind(entity_uuid)
+            .bind(corpse.decay_remaining)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save corpse component: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Save BindPoint component
+    async fn save_bind_point_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(bind_point) = world.get::<&BindPoint>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_bind_point (entity_id, room_id)
+                 VALUES ($1, $2)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET room_id = EXCLUDED.room_id",
+            )
+            .bind(entity_uuid)
+            .bind(bind_point.room_id.uuid())
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save bind point component: {}", e))?;
+        }
+        Ok(())
+    }
+
     /// Save Enterable component
     async fn save_enterable_component(
         &self,
@@ -2208,6 +3130,37 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Save SkillBonus component
+    async fn save_skill_bonus_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(skill_bonus) = world.get::<&SkillBonus>(entity_id) {
+            sqlx::query("DELETE FROM wyldlands.entity_skill_bonus WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete old skill bonuses: {}", e))?;
+
+            for (skill, bonus) in &skill_bonus.bonuses {
+                sqlx::query(
+                    "INSERT INTO wyldlands.entity_skill_bonus (entity_id, skill_name, bonus)
+                     VALUES ($1, $2, $3)",
+                )
+                .bind(entity_uuid)
+                .bind(skill.name())
+                .bind(*bonus)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to save skill bonus {}: {}", skill.name(), e))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Save Weapon component
     async fn save_weapon_component(
         &self,
@@ -2303,6 +3256,116 @@ impl PersistenceManager {
         Ok(())
     }
 
+    /// Save Template component
+    async fn save_template_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(template) = world.get::<&Template>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_template (entity_id, template_id)
+                 VALUES ($1, $2)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET template_id = EXCLUDED.template_id",
+            )
+            .bind(entity_uuid)
+            .bind(&template.template_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save template component: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Save CraftingStation component
+    async fn save_crafting_station_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(station) = world.get::<&CraftingStation>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_crafting_station (entity_id, station)
+                 VALUES ($1, $2)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET station = EXCLUDED.station",
+            )
+            .bind(entity_uuid)
+            .bind(&station.station)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save crafting station component: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// Save ResourceNode component (with its yield table)
+    async fn save_resource_node_component(
+        &self,
+        entity_uuid: Uuid,
+        entity_id: EcsEntity,
+        world: &GameWorld,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<(), String> {
+        if let Ok(node) = world.get::<&ResourceNode>(entity_id) {
+            sqlx::query(
+                "INSERT INTO wyldlands.entity_resource_node
+                     (entity_id, skill, difficulty, tool, charges, max_charges, respawn_time, respawn_timer)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                 ON CONFLICT (entity_id)
+                 DO UPDATE SET skill = EXCLUDED.skill, difficulty = EXCLUDED.difficulty,
+                               tool = EXCLUDED.tool, charges = EXCLUDED.charges,
+                               max_charges = EXCLUDED.max_charges,
+                               respawn_time = EXCLUDED.respawn_time,
+                               respawn_timer = EXCLUDED.respawn_timer",
+            )
+            .bind(entity_uuid)
+            .bind(node.skill.name())
+            .bind(node.difficulty)
+            .bind(&node.tool)
+            .bind(node.charges as i32)
+            .bind(node.max_charges as i32)
+            .bind(node.respawn_time)
+            .bind(node.respawn_timer)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| format!("Failed to save resource node component: {}", e))?;
+
+            // Delete existing yields
+            sqlx::query("DELETE FROM wyldlands.entity_resource_yield WHERE entity_id = $1")
+                .bind(entity_uuid)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| format!("Failed to delete old resource yields: {}", e))?;
+
+            // Insert all yield entries
+            for entry in &node.yields {
+                sqlx::query(
+                    "INSERT INTO wyldlands.entity_resource_yield
+                         (entity_id, template_id, weight, min_quantity, max_quantity, min_level)
+                     VALUES ($1, $2, $3, $4, $5, $6)",
+                )
+                .bind(entity_uuid)
+                .bind(&entry.template_id)
+                .bind(entry.weight as i32)
+                .bind(entry.min_quantity as i32)
+                .bind(entry.max_quantity as i32)
+                .bind(entry.min_level)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| {
+                    format!("Failed to save resource yield {}: {}", entry.template_id, e)
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     /// Save Commandable component
     async fn save_commandable_component(
         &self,
@@ -138,7 +138,7 @@ async fn test_area_create_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Test successful area creation
     let result = command_system
@@ -186,7 +186,7 @@ async fn test_area_list_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create a test area first
     let _ = command_system
@@ -219,7 +219,7 @@ async fn test_area_info_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create a test area
     let create_result = command_system
@@ -287,7 +287,7 @@ async fn test_area_edit_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create a test area
     let create_result = command_system
@@ -353,7 +353,7 @@ async fn test_area_delete_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create a test area
     let create_result = command_system
@@ -417,7 +417,7 @@ async fn test_room_create_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create an area first
     let area_result = command_system
@@ -490,7 +490,7 @@ async fn test_room_list_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create area and room
     let area_result = command_system
@@ -563,7 +563,7 @@ async fn test_room_goto_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create area and room
     let area_result = command_system
@@ -634,7 +634,7 @@ async fn test_exit_commands() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create area and two rooms
     let area_result = command_system
@@ -768,7 +768,7 @@ async fn test_dig_command() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create area and starting room
     let area_result = command_system
@@ -896,7 +896,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create area and room
         let area_result = command_system
@@ -979,7 +979,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create area and rooms
         let area_result = command_system
@@ -1116,7 +1116,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create multiple areas
         let _result1 = command_system
@@ -1175,7 +1175,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create area
         let area_result = command_system
@@ -1258,7 +1258,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create area
         let area_result = command_system
@@ -1337,7 +1337,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Create area and room
         let area_result = command_system
@@ -1437,7 +1437,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Setup
         let area_result = command_system
@@ -1536,7 +1536,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Setup
         let area_result = command_system
@@ -1629,7 +1629,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Setup
         let area_result = command_system
@@ -1719,7 +1719,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Setup
         let area_result = command_system
@@ -1797,7 +1797,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // Setup
         let area_result = command_system
@@ -1890,7 +1890,7 @@ async fn test_dig_command() {
 
         let builder = create_test_builder(&context).await;
         let event_bus = EventBus::new();
-        let mut command_system = CommandSystem::new(event_bus);
+        let command_system = CommandSystem::new(event_bus);
 
         // List all templates
         let result = command_system
@@ -1939,7 +1939,7 @@ async fn test_area_delete_with_rooms_fails() {
     };
     let builder = create_test_builder(&context).await;
     let event_bus = EventBus::new();
-    let mut command_system = CommandSystem::new(event_bus);
+    let command_system = CommandSystem::new(event_bus);
 
     // Create area with a room
     let area_result = command_system